@@ -0,0 +1,191 @@
+// ===============================================
+// 📜 Metadata — Executor v0.0.1 (Gate Command Runner)
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-15
+// _last updated_:  2025-06-15
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Command Executor (timeout-aware child processes)
+// _project_:       OmniCode / Millennium OS
+// _description_:   Runs shell commands with an optional deadline, killing children that overrun it.
+//
+// _notes_:
+// - Output pipes are drained on helper threads so a chatty child cannot block
+// - A timed-out child is killed (with its process group on Unix) and reaped before returning
+// - Pipes a background grandchild keeps open are read for `DRAIN_GRACE`, then abandoned
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+use std::io::{self, Read}; // 📥 Pipe draining
+#[cfg(unix)]
+use std::os::unix::process::CommandExt; // 👪 Own process group per child
+use std::process::{Child, Command, ExitStatus, Stdio}; // 🪟 Child processes
+use std::sync::mpsc; // 📨 Pipe-closed signal from reader threads
+use std::sync::{Arc, Mutex, PoisonError}; // 📥 Output shared with reader threads
+use std::thread; // 🧵 Pipe reader threads
+use std::time::{Duration, Instant}; // ⏱ Deadlines
+
+use watchtower::debugger::{DebugEntry, Severity}; // 🪛 Timeout reporting
+
+/// ⏲ How often a running child is polled for exit
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// ⏳ How long output pipes may stay open once the child has exited — a
+/// background grandchild can hold them open indefinitely
+const DRAIN_GRACE: Duration = Duration::from_millis(500);
+
+// ===============================================
+// 📦 Foundational Declarations — Execution Outcome
+// ===============================================
+
+/// 🏁 ExecOutcome — How a command run ended
+#[derive(Debug)]
+pub enum ExecOutcome {
+    /// ✅ Child exited on its own
+    Completed {
+        status: ExitStatus,
+        stdout: String,
+        stderr: String,
+    },
+
+    /// ⏱ Child overran its timeout and was killed
+    TimedOut { after: Duration },
+}
+
+// ===============================================
+// 🔧 Body — Timeout-Aware Execution
+// ===============================================
+
+/// 🪟 Runs `command` to completion, or kills it once `timeout` elapses.
+///
+/// `None` waits indefinitely. Stdout and stderr are always captured, up to
+/// `DRAIN_GRACE` after the child exits. On Unix the child leads its own
+/// process group, and a timeout kills the whole group.
+pub fn run_with_timeout(
+    mut command: Command,
+    timeout: Option<Duration>,
+) -> io::Result<ExecOutcome> {
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    #[cfg(unix)]
+    command.process_group(0);
+    let mut child = command.spawn()?;
+
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+
+        if let Some(limit) = timeout {
+            if started.elapsed() >= limit {
+                kill_tree(&mut child);
+                let _ = child.wait(); // 🧹 Reap so no zombie remains
+                return Ok(ExecOutcome::TimedOut { after: limit });
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    let deadline = Instant::now() + DRAIN_GRACE;
+    Ok(ExecOutcome::Completed {
+        status,
+        stdout: stdout.collect(deadline),
+        stderr: stderr.collect(deadline),
+    })
+}
+
+/// 🛑 Kills `child` — and, on Unix, every process in its group.
+fn kill_tree(child: &mut Child) {
+    #[cfg(unix)]
+    if let Ok(group) = libc::pid_t::try_from(child.id()) {
+        // SAFETY: `kill` takes no pointers; a negative pid names the process
+        // group `process_group(0)` gave the child, which the child leads.
+        unsafe {
+            libc::kill(-group, libc::SIGKILL);
+        }
+    }
+    let _ = child.kill(); // 🛑 Already-exited races are fine
+}
+
+/// 📥 A pipe being read to EOF on its own thread.
+struct Drain {
+    buffer: Arc<Mutex<Vec<u8>>>, // 📥 Bytes read so far
+    closed: mpsc::Receiver<()>,  // 📨 Signalled once the pipe reaches EOF
+}
+
+impl Drain {
+    /// 📤 Everything read once the pipe closes, or what arrived by `deadline`.
+    fn collect(self, deadline: Instant) -> String {
+        let _ = self
+            .closed
+            .recv_timeout(deadline.saturating_duration_since(Instant::now()));
+        let buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+        String::from_utf8_lossy(&buffer).to_string()
+    }
+}
+
+/// 📥 Reads a pipe to EOF on its own thread.
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> Drain {
+    let buffer = Arc::new(Mutex::new(Vec::new()));
+    let (sender, closed) = mpsc::channel();
+
+    let sink = Arc::clone(&buffer);
+    thread::spawn(move || {
+        if let Some(mut pipe) = pipe {
+            let mut chunk = [0u8; 4096];
+            loop {
+                match pipe.read(&mut chunk) {
+                    Ok(0) | Err(_) => break, // 🔚 EOF, or the pipe broke
+                    Ok(count) => sink
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .extend_from_slice(&chunk[..count]),
+                }
+            }
+        }
+        let _ = sender.send(());
+    });
+
+    Drain { buffer, closed }
+}
+
+/// 🚦 Terminal line reporting a failed exit, or `None` when `status` is success.
+pub fn status_note(status: ExitStatus) -> Option<String> {
+    (!status.success()).then(|| format!("⚠️ Command failed — {}\n", status))
+}
+
+/// ⏱ Builds the Watchtower entry logged when `cmd` is killed for overrunning `timeout`.
+pub fn timeout_entry(cmd: &str, timeout: Duration) -> DebugEntry {
+    DebugEntry::new(
+        cmd,
+        cmd,
+        &format!("completed within {:?}", timeout),
+        &format!("killed after {:?}", timeout),
+    )
+    .with_location("executor::run_with_timeout")
+    .with_suggestion("Raise the timeout, or check the command for hangs or prompts")
+    .with_severity(Severity::Fault)
+}
+
+// ===================================================
+// 🔚 Closing — Executor Notes
+// ===================================================
+//
+// 🪜 Flow:
+//   ⬆️ Upstream:   Gate GUI executor thread
+//   ⬇️ Downstream: Child processes, Watchtower logs
+//
+// 🔮 Future:
+//   - Stream partial output while a command is still running
+//   - Kill whole process trees on Windows too (a Job Object per child)
+//
+// ---------------------------------------------------
@@ -5,5 +5,7 @@
 // Binds together tokenizer, parser, instruction registry, and debug utilities.
 
 pub mod registry; // ✅ This one stays. Terminal command registry.
+pub mod output; // 📜 Structured terminal output lines + search filter
+pub mod executor; // ⏱ Timeout-aware shell command runner
 // use tablet::{parser, tokenizer, instruction_registry};
-use watchtower::debugger::{DebugEntry}; // 🧠 Debugging utilities for logging and diagnostics
\ No newline at end of file
+// use watchtower::debugger::DebugEntry; // 🧠 Debugging utilities for logging and diagnostics
\ No newline at end of file
@@ -25,10 +25,9 @@
 // Provides the core application shell and GUI engine
 use eframe::{egui, App, CreationContext};
 
-// std::process::Command & Stdio:
+// std::process::Command:
 // For spawning system-level shell commands (via "cmd")
-// and capturing their standard output and error streams
-use std::process::{Command, Stdio};
+use std::process::Command;
 
 // std::sync::mpsc (multi-producer, single-consumer):
 // Enables communication between the GUI thread and the command execution thread
@@ -38,10 +37,19 @@ use std::sync::mpsc::{channel, Receiver, Sender};
 // Used to spawn a background thread that handles command execution asynchronously
 use std::thread;
 
+// std::time::Duration:
+// Per-command timeout handed to the executor thread
+use std::time::Duration;
+
 mod registry; // 🔗 Link to the internal OmniCommand registry module
-use registry::CommandRegistry; // ⛓️ Import the command registry for OmniCommands
+use registry::{CommandRegistry, CommandResult}; // ⛓️ Import the command registry for OmniCommands
+
+mod output; // 📜 Structured output lines backing the scroll area
+use output::OutputLog; // 🔍 Line store with search filtering
+
+mod executor; // ⏱ Timeout-aware child process runner
+use executor::ExecOutcome; // 🏁 Completed vs. timed-out runs
 
-use watchtower::debugger; // 🧪 Link to Watchtower diagnostics module
 use watchtower::debugger::DebugEntry; // 📜 Import primary debug structure
 
 // ===============================================
@@ -56,8 +64,10 @@ use watchtower::debugger::DebugEntry; // 📜 Import primary debug structure
 /// into themed terminals, OS-level hooks, or embedded shell layers.
 struct TerminalApp {
     input: String,              // 🔤 Holds text input typed by the user
-    output: String,             // 📜 Cumulative shell output shown in scroll area
-    sender: Sender<String>,     // 📤 Channel: UI → Shell executor thread
+    output: OutputLog,          // 📜 Cumulative shell output, stored line by line
+    search: String,             // 🔍 Filter query applied to the output pane
+    timeout_secs: u64,          // ⏱ Per-command timeout in seconds (0 = none)
+    sender: Sender<(String, Option<Duration>)>, // 📤 Channel: UI → Shell executor thread
     receiver: Receiver<String>, // 📥 Channel: Shell thread → UI for display
     registry: CommandRegistry,  // 📦 Holds internal OmniCommand logic (e.g., 'speak')
 }
@@ -70,48 +80,52 @@ impl TerminalApp {
     ///
     /// Command responses are streamed back to the UI for display,
     /// allowing real-time feedback in a responsive, scrollable terminal.
+    /// Commands that overrun their timeout are killed and reported.
     fn new(_cc: &CreationContext<'_>) -> Self {
         // -----------------------------------------------
         // 1️⃣ Channel Setup — UI <=> Shell Communication
         // -----------------------------------------------
-        let (tx, rx) = channel::<String>(); // UI → Command executor thread
+        let (tx, rx) = channel::<(String, Option<Duration>)>(); // UI → Command executor thread
         let (tx_out, rx_out) = channel::<String>(); // Command output → UI renderer
 
         // -----------------------------------------------
         // 2️⃣ Background Thread — Command Processing Loop
         // -----------------------------------------------
         thread::spawn(move || {
-            while let Ok(cmd) = rx.recv() {
+            while let Ok((cmd, timeout)) = rx.recv() {
                 let expected = "<user expectation>"; // 📌 Placeholder — define per-use or leave empty
                 let input = cmd.clone(); // Save raw input before trimming or execution
 
                 // -----------------------------------------------
                 // 3️⃣ Shell Execution — Windows cmd (/C)
                 // -----------------------------------------------
-                let result = Command::new("cmd")
-                    .args(&["/C", &cmd])
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .output();
+                let mut shell = Command::new("cmd");
+                shell.args(["/C", &cmd]);
+                let result = executor::run_with_timeout(shell, timeout);
 
                 // -----------------------------------------------
                 // 4️⃣ Output Formatting + Debug Logging
                 // -----------------------------------------------
-                let (output, _actual) = match result {
-                    Ok(output) => {
-                        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-                        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-                        let merged = format!("{}{}", stdout, stderr);
+                let (mut output, debug) = match result {
+                    Ok(ExecOutcome::Completed { status, stdout, stderr }) => {
+                        // 🚦 A non-zero exit is called out after the output
+                        let note = executor::status_note(status).unwrap_or_default();
+                        let merged = format!("{}{}{}", stdout, stderr, note);
 
                         // 📜 Log debug entry
                         let debug = DebugEntry::new(&cmd, &input, expected, &merged)
                             .with_location("TerminalApp::new")
                             .with_suggestion("Review command output for minor drift");
 
-                        let _ = debug.write_scroll("Logs/Debug/scrolls/Gate_gui.log");
-                        let _ = debug.write_json("Logs/Debug/json/Gate_gui.json");
+                        (merged, debug)
+                    }
+                    Ok(ExecOutcome::TimedOut { after }) => {
+                        let fail = format!("⏱ Timed out after {:?} — command terminated\n", after);
 
-                        (merged, stdout)
+                        // ⏱ Log timeout condition
+                        let debug = executor::timeout_entry(&cmd, after);
+
+                        (fail, debug)
                     }
                     Err(e) => {
                         let fail = format!("Error: {}\n", e);
@@ -121,13 +135,18 @@ impl TerminalApp {
                             .with_location("TerminalApp::new")
                             .with_suggestion("Shell execution failure");
 
-                        let _ = debug.write_scroll("Logs/Debug/scrolls/Gate_gui.log");
-                        let _ = debug.write_json("Logs/Debug/json/Gate_gui.json");
-
-                        (fail, String::new())
+                        (fail, debug)
                     }
                 };
 
+                // 📜 Surface log failures in the output pane instead of dropping them
+                if let Err(e) = debug.write_logs(
+                    "Logs/Debug/scrolls/Gate_gui.log",
+                    "Logs/Debug/json/Gate_gui.json",
+                ) {
+                    output += &format!("⚠️ Debug log not written: {}\n", e);
+                }
+
                 let _ = tx_out.send(output);
             }
         });
@@ -137,7 +156,9 @@ impl TerminalApp {
         // -----------------------------------------------
         Self {
             input: String::new(),             // 🆕 Start with an empty input buffer
-            output: String::new(),            // 📭 Start with no output displayed
+            output: OutputLog::new(),         // 📭 Start with no output displayed
+            search: String::new(),            // 🔍 No filter applied at launch
+            timeout_secs: 30,                 // ⏱ Default per-command deadline
             sender: tx,                       // 🔗 Store sender for sending new commands
             receiver: rx_out,                 // 🔗 Store receiver for listening to output
             registry: CommandRegistry::new(), // 🏗️ Construct internal registry during setup
@@ -165,9 +186,23 @@ impl App for TerminalApp {
             // -------------------------------------------------------
             // 2️⃣ Output Scroll — Shows All Accumulated Responses
             // -------------------------------------------------------
-            ui.label("Output:"); // 📤 Output section label
+            ui.horizontal(|ui| {
+                ui.label("Output:"); // 📤 Output section label
+                ui.label("🔍");
+                ui.text_edit_singleline(&mut self.search); // 🔎 Search / filter field
+            });
+
+            let visible = self.output.filter(&self.search); // 🧹 Blank query shows everything
+            if !self.search.trim().is_empty() {
+                ui.label(format!("{} of {} lines", visible.len(), self.output.len()));
+            }
+
             egui::ScrollArea::vertical().show(ui, |ui| {
-                ui.label(&self.output); // 📜 Display all terminal output
+                if self.output.is_empty() {
+                    ui.weak("No output yet."); // 📭 Placeholder before first command
+                } else {
+                    ui.label(visible.join("\n")); // 📜 Display matching terminal output
+                }
             });
 
             ui.separator(); // ━━━ Transition to input controls
@@ -177,18 +212,35 @@ impl App for TerminalApp {
             // -------------------------------------------------------
             ui.horizontal(|ui| {
                 ui.text_edit_singleline(&mut self.input); // ⌨️ Editable input field
+                ui.label("⏱");
+                ui.add(egui::DragValue::new(&mut self.timeout_secs).suffix(" s")) // ⏱ 0 = no timeout
+                    .on_hover_text("Kill commands that run longer than this (0 disables)");
                 if ui.button("Run").clicked() {
                     let command = self.input.trim(); // 🧹 Clean input first
 
-                    // 🧠 Internal OmniCommand Dispatch
-                    if let Some(response) = self.registry.run(command) {
-                        self.output.push_str(&format!("{}\n", response)); // 🪶 Append internal result
-                        self.input.clear(); // 🔄 Clear input field
-                        return;
+                    // 🧠 Internal OmniCommand Dispatch — only `Unknown` falls through to the shell
+                    match self.registry.run(command) {
+                        CommandResult::Handled(response) => {
+                            self.output.push(&response); // 🪶 Append internal result
+                            self.input.clear(); // 🔄 Clear input field
+                            return;
+                        }
+                        CommandResult::HandledEmpty => {
+                            self.input.clear(); // 🔄 Nothing to show
+                            return;
+                        }
+                        CommandResult::Failed(reason) => {
+                            self.output.push(&format!("❌ {}", reason)); // 📛 Mark the failure
+                            self.input.clear(); // 🔄 Clear input field
+                            return;
+                        }
+                        CommandResult::Unknown => {}
                     }
 
                     // 🪟 External Command Dispatch
-                    let _ = self.sender.send(command.to_string()); // ✉️ Send to backend executor
+                    let timeout = (self.timeout_secs > 0)
+                        .then(|| Duration::from_secs(self.timeout_secs));
+                    let _ = self.sender.send((command.to_string(), timeout)); // ✉️ Send to backend executor
                     self.input.clear(); // 🔄 Clear input field
                 }
             });
@@ -197,9 +249,8 @@ impl App for TerminalApp {
             // 4️⃣ Poll Output — Async Shell Response Reception
             // -------------------------------------------------------
             if let Ok(response) = self.receiver.try_recv() {
-                let debug_note =
-                    format!("\n[🧪 Debug entry logged — see /Logs/Debug for details]\n");
-                self.output.push_str(&format!("{}{}", response, debug_note));
+                let debug_note = "\n[🧪 Debug entry logged — see /Logs/Debug for details]\n";
+                self.output.push(&format!("{}{}", response, debug_note));
             }
         });
 
@@ -31,11 +31,14 @@ use std::io::{self, Write};
 use std::process::{Command, Stdio};
 
 mod registry; // 🔗 Link to the internal OmniCommand registry module
-use registry::CommandRegistry; // ⛓️ Bring the registry struct into scope
+use registry::{CommandRegistry, CommandResult}; // ⛓️ Bring the registry and its outcomes into scope
 
 use watchtower::debugger; // 🧠 Link to Watchtower scoring + log module
 use debugger::{DebugEntry}; // 📜 Bring core diagnostic structs into scope
 
+const SCROLL_LOG: &str = "Logs/Debug/scrolls/Gate.log"; // 🪶 Plain-text CLI log
+const JSON_LOG: &str = "Logs/Debug/json/Gate.json"; // 🧾 Structured CLI log
+
 // ===============================================
 // 🔧 Body — I/O Loop and Command Handling
 // ===============================================
@@ -73,7 +76,7 @@ fn main() {
         // 2️⃣ Input Handling — Capture user input
         // -----------------------------------------------
         let mut input = String::new();
-        if let Err(_) = io::stdin().read_line(&mut input) {
+        if io::stdin().read_line(&mut input).is_err() {
             println!("Failed to read input"); // ⚠️ Basic read failure message
             continue;
         }
@@ -91,20 +94,32 @@ fn main() {
         // -----------------------------------------------
         // 4️⃣ Internal vs External Command Dispatch
         // -----------------------------------------------
-        if let Some(output) = registry.run(trimmed) {
-            println!("{}", output); // Internal OmniCommand handled
+        let output = match registry.run(trimmed) {
+            CommandResult::Handled(output) => {
+                println!("{}", output); // Internal OmniCommand handled
+                Some(output)
+            }
+            CommandResult::HandledEmpty => Some(String::new()),
+            CommandResult::Failed(reason) => {
+                eprintln!("❌ {}", reason); // 📛 Internal command failed
+                Some(reason)
+            }
+            CommandResult::Unknown => None, // ❓ Hand off to the shell below
+        };
 
+        if let Some(output) = output {
             // 🧪 Watchtower Internal Execution Log
             let entry = DebugEntry::new("internal", trimmed, "[depends on command]", &output)
                 .with_location("OmniCommand")
                 .with_suggestion("Validate command alias output mapping");
-            let _ = entry.write_scroll("Logs/Debug/scrolls/Gate.log");
-            let _ = entry.write_json("Logs/Debug/json/Gate.json");
+            if let Err(e) = entry.write_logs(SCROLL_LOG, JSON_LOG) {
+                eprintln!("⚠️ Debug log not written: {}", e); // 📛 Surface IO failure
+            }
             continue;
         }
 
         let result = Command::new("cmd")
-            .args(&["/C", trimmed]) // 🪞 Execute single-use shell command
+            .args(["/C", trimmed]) // 🪞 Execute single-use shell command
             .stdout(Stdio::piped()) // 📤 Capture standard output
             .stderr(Stdio::piped()) // 📛 Capture error output
             .output(); // 🎬 Perform the execution
@@ -125,8 +140,9 @@ fn main() {
                 let entry = DebugEntry::new("external", trimmed, "[manual validation]", &actual)
                     .with_location("cmd.exe")
                     .with_suggestion("Review command structure for escaping or path issues");
-                let _ = entry.write_scroll("Logs/Debug/scrolls/Gate.log");
-                let _ = entry.write_json("Logs/Debug/json/Gate.json");
+                if let Err(e) = entry.write_logs(SCROLL_LOG, JSON_LOG) {
+                    eprintln!("⚠️ Debug log not written: {}", e); // 📛 Surface IO failure
+                }
             }
             Err(e) => {
                 eprintln!("Error: {}\n", e); // 🧨 Shell execution failure
@@ -140,8 +156,9 @@ fn main() {
                 )
                 .with_location("cmd.exe")
                 .with_suggestion("Check system PATH or permissions");
-                let _ = entry.write_scroll("Logs/Debug/scrolls/Gate.log");
-                let _ = entry.write_json("Logs/Debug/json/Gate.json");
+                if let Err(e) = entry.write_logs(SCROLL_LOG, JSON_LOG) {
+                    eprintln!("⚠️ Debug log not written: {}", e); // 📛 Surface IO failure
+                }
             }
         }
     }
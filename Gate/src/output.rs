@@ -0,0 +1,78 @@
+// ===============================================
+// 📜 Metadata — Gate Output Log v0.0.1
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-03
+// _last updated_:  2025-06-03
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Terminal Output Log (Structured Lines + Search)
+// _project_:       OmniCode / Millennium OS
+// _description_:   Line-based output buffer backing the GUI scroll area and its search filter
+//
+// _notes_:
+// - Output is stored as discrete lines, not one growing String
+// - Filtering walks lines once per frame — no re-splitting of the full buffer
+// ===============================================
+
+// ===============================================
+// 🔧 Body — OutputLog Struct & Filtering
+// ===============================================
+
+/// 📜 `OutputLog` holds every line shown in the terminal's output pane.
+///
+/// Incoming responses are split on newlines as they arrive, so search
+/// and display both operate on ready-made lines.
+#[derive(Debug, Default, Clone)]
+pub struct OutputLog {
+    lines: Vec<String>, // 🧾 One entry per rendered output line
+}
+
+impl OutputLog {
+    /// 🆕 Creates an empty output log
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ➕ Appends a response, splitting it into individual lines
+    pub fn push(&mut self, text: &str) {
+        self.lines.extend(text.lines().map(str::to_string));
+    }
+
+    /// 🔍 Returns lines containing `query` (case-insensitive).
+    ///
+    /// A blank query matches everything, so the pane shows full output
+    /// whenever the search field is empty.
+    pub fn filter(&self, query: &str) -> Vec<&str> {
+        let needle = query.trim().to_lowercase();
+
+        self.lines
+            .iter()
+            .filter(|line| needle.is_empty() || line.to_lowercase().contains(&needle))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// 🔢 Total number of stored lines
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// 📭 True when no output has been recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+// ===================================================
+// 🔚 Closing — Output Log Notes
+// ===================================================
+//
+// ✅ Shared by the GUI (`main.rs`) and exposed via `lib.rs` for tests.
+//
+// 🪧 Future:
+//     • Highlight mode (dim non-matching lines instead of hiding them)
+//     • Line cap / ring buffer for very long sessions
+//
+// ---------------------------------------------------
@@ -25,9 +25,12 @@
 // Provides a hash-based key/value storage used for registering and dispatching OmniCommands
 use std::collections::HashMap;
 
-use rand::{thread_rng, Rng};           // ✅ Correct thread_rng location
-use rand::seq::SliceRandom;     // ✅ Required for .choose()
-use rand::prelude::IndexedRandom;
+use rand::rng;                   // ✅ Thread-local generator (formerly `thread_rng`)
+use rand::prelude::IndexedRandom; // ✅ Required for .choose()
+
+// tablet::instruction_registry:
+// Source of the NovaScript instruction table shown by the `instructions` command
+use tablet::instruction_registry::shared_registry;
 
 // crate-local DebugEntry module (for Watchtower integration)
 // This assumes `debugger.rs` is in the same crate/module tree
@@ -42,13 +45,17 @@ use rand::prelude::IndexedRandom;
 /// This trait defines the core behavior contract for every internal OmniCode command.
 /// Implementations must define:
 /// - `name()` → The unique keyword used to invoke the command
-/// - `execute()` → The function triggered when the command is run, passed with string arguments
+/// - `execute()` → The function triggered when the command is run, passed with string arguments;
+///   `Err` carries a message describing why the command failed
+///
+/// `description()` supplies the one-line text `help` shows (empty by default).
 ///
 /// This trait allows commands to be registered dynamically and dispatched uniformly
 /// in both CLI and GUI environments.
 pub trait OmniCommand {
     fn name(&self) -> &str;                     // 🏷️ Command name used for matching (e.g., "speak")
-    fn execute(&self, args: &[&str]) -> String; // 🧠 Command logic that consumes input arguments
+    fn execute(&self, args: &[&str]) -> Result<String, String>; // 🧠 Command logic that consumes input arguments
+    fn description(&self) -> &str { "" }        // 📖 Help text listed by `help`
 }
 
 // -----------------------------------------------
@@ -72,11 +79,13 @@ pub struct SpeakCommand;
 impl OmniCommand for SpeakCommand {
     fn name(&self) -> &str { "speak" } // 🏷️ Invocation keyword ("speak")
 
-    fn execute(&self, args: &[&str]) -> String {
+    fn description(&self) -> &str { "Echoes its arguments" } // 📖 Help text
+
+    fn execute(&self, args: &[&str]) -> Result<String, String> {
         let output = args.join(" "); // 📤 Outputs all arguments as a space-separated string
         // 🔍 Optional future integration:
         // Send `DebugEntry::new("speak", &args.join(" "), &output, &output)` to logger here
-        output
+        Ok(output)
     }
 }
 
@@ -105,13 +114,17 @@ impl OmniCommand for SpeakAiCommand {
         "speak_ai"
     }
 
-    fn execute(&self, args: &[&str]) -> String {
+    fn description(&self) -> &str {
+        "Replies with a generated sentence"
+    }
+
+    fn execute(&self, args: &[&str]) -> Result<String, String> {
 
         let input = args.join(" ").to_lowercase();
-        let mut rng = thread_rng();
+        let mut rng = rng();
 
         // 🎙️ Greeting responses — balances poetic tone with accessible language
-        let greetings = vec![
+        let greetings = [
             "Nova: Hi there! I’m here and listening. What do you want to say?",
             "Nova: Hello! You found the command. Let’s talk.",
             "Nova: You speak, I speak back. Let’s build something together.",
@@ -123,7 +136,7 @@ impl OmniCommand for SpeakAiCommand {
         ];
 
         // 🎙️ Fallback responses — used when no greeting is detected
-        let fallback = vec![
+        let fallback = [
             "Nova: I see. Can you tell me more?",
             "Nova: That sounds curious. Let’s unfold it.",
             "Nova: Hmmm... not sure what to make of that yet.",
@@ -138,7 +151,7 @@ impl OmniCommand for SpeakAiCommand {
         let objects = ["the Gate", "its own purpose", "what was hidden", "new breath", "a command"];
 
         // 🔍 Determine response type
-        if input.contains("hello")
+        let response = if input.contains("hello")
             || input.contains("hi")
             || input.contains("hey")
             || input.contains("greetings")
@@ -163,6 +176,97 @@ impl OmniCommand for SpeakAiCommand {
                 "Nova: {} {} {} toward {}.",
                 subject, verb, modifier, object
             )
+        };
+
+        Ok(response)
+    }
+}
+
+// -----------------------------------------------
+// 📚 Built-In Command #3 — `instructions` (Registry Table)
+// -----------------------------------------------
+
+/// 📚 `InstructionsCommand` — Lists NovaScript Instructions as a Table
+///
+/// Prints keyword, opcode, category, operand count, and verse anchor for
+/// every registered instruction, ordered by opcode. Any arguments are
+/// joined into a category filter (case-insensitive).
+///
+/// Example Usage:
+/// ```bash
+/// > instructions memory/data
+/// KEYWORD   OPCODE  CATEGORY          OPERANDS  VERSE
+/// let       0x72    Memory/Data       2         Gen 1:3
+/// ```
+pub struct InstructionsCommand;
+
+impl OmniCommand for InstructionsCommand {
+    fn name(&self) -> &str {
+        "instructions"
+    }
+
+    fn description(&self) -> &str {
+        "Lists NovaScript instructions, optionally for one category"
+    }
+
+    fn execute(&self, args: &[&str]) -> Result<String, String> {
+        let filter = args.join(" ");
+        let registry = shared_registry();
+
+        let mut rows: Vec<_> = registry
+            .iter()
+            .map(|(_, instruction)| instruction)
+            .filter(|i| filter.is_empty() || i.category.eq_ignore_ascii_case(&filter))
+            .collect();
+        if rows.is_empty() {
+            return Err(format!("No instructions in category '{}'", filter));
+        }
+        rows.sort_by_key(|i| (i.opcode, i.keyword)); // 🔢 Opcode order, keyword as tiebreak
+
+        let mut table = format!(
+            "{:<10}{:<8}{:<18}{:<10}{}",
+            "KEYWORD", "OPCODE", "CATEGORY", "OPERANDS", "VERSE"
+        );
+        for i in rows {
+            let operands = i
+                .operand_count
+                .map_or_else(|| "-".to_string(), |count| count.to_string());
+            table.push_str(&format!(
+                "\n{:<10}{:<8}{:<18}{:<10}{}",
+                i.keyword,
+                format!("0x{:02X}", i.opcode),
+                i.category,
+                operands,
+                i.verse_anchor
+            ));
+        }
+        Ok(table)
+    }
+}
+
+// -----------------------------------------------
+// 🧾 Command Result — Outcome of a Dispatch
+// -----------------------------------------------
+
+/// 🧾 `CommandResult` — What `CommandRegistry::run` did with the input
+///
+/// Lets terminals tell apart output, silence, failure, and commands the
+/// registry does not own (which they hand to the external shell).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandResult {
+    Handled(String), // ✅ Command ran and produced output
+    HandledEmpty,    // 🫙 Command ran (or input was blank) with nothing to show
+    Failed(String),  // ❌ Command ran and reported why it failed
+    Unknown,         // ❓ No OmniCommand by that name — route to the shell
+}
+
+impl From<Result<String, String>> for CommandResult {
+    /// 🔁 Maps an `OmniCommand::execute` outcome; empty output is `HandledEmpty`.
+    fn from(output: Result<String, String>) -> Self {
+        match output {
+            Ok(output) if output.is_empty() => CommandResult::HandledEmpty,
+            Ok(output) => CommandResult::Handled(output),
+            Err(reason) => CommandResult::Failed(reason),
         }
     }
 }
@@ -178,8 +282,18 @@ impl OmniCommand for SpeakAiCommand {
 /// to their respective implementations.
 ///
 /// Internally stores commands in a `HashMap` keyed by their invocation name.
+/// `help` is answered by the registry itself from every entry's description.
 pub struct CommandRegistry {
-    commands: HashMap<String, Box<dyn OmniCommand>>, // 🗂️ Registry: command name → command object
+    commands: HashMap<String, CommandEntry>, // 🗂️ Registry: command name → help text + behavior
+}
+
+/// 🧠 Command behavior — receives the words after the command name
+type CommandHandler = Box<dyn Fn(&[&str]) -> CommandResult>;
+
+/// 🧷 One registered command — what `help` says about it and what it runs
+struct CommandEntry {
+    description: String,     // 📖 One-line help text
+    handler: CommandHandler, // 🧠 Receives the parsed arguments
 }
 
 impl CommandRegistry {
@@ -198,8 +312,9 @@ impl CommandRegistry {
         };
 
         // 🧩 Register each built-in OmniCommand here
-        registry.register(Box::new(SpeakCommand));   // 🔌 Echo prototype
-        registry.register(Box::new(SpeakAiCommand)); // 🤖 Basic AI logic prototype
+        registry.register_command(Box::new(SpeakCommand));   // 🔌 Echo prototype
+        registry.register_command(Box::new(SpeakAiCommand)); // 🤖 Basic AI logic prototype
+        registry.register_command(Box::new(InstructionsCommand)); // 📚 Instruction registry table
 
         registry
     }
@@ -208,12 +323,61 @@ impl CommandRegistry {
     // 2️⃣ Register — Add New OmniCommand to Table
     // -----------------------------------------------
 
-    /// 🧬 `register()` — Adds a new OmniCommand to the registry
+    /// 🧬 `register()` — Adds a command built from a name, help text, and handler
+    ///
+    /// - The handler receives the words after the command name.
+    /// - Overwrites any existing entry with the same name (intended behavior).
+    ///
+    /// Example:
+    /// ```rust
+    /// use gate::registry::{CommandRegistry, CommandResult};
+    ///
+    /// let mut registry = CommandRegistry::new();
+    /// registry.register("count", "Counts its arguments", |args| {
+    ///     CommandResult::Handled(args.len().to_string())
+    /// });
+    /// assert_eq!(registry.run("count a b"), CommandResult::Handled("2".to_string()));
+    /// ```
+    pub fn register<F>(&mut self, name: &str, description: &str, handler: F)
+    where
+        F: Fn(&[&str]) -> CommandResult + 'static,
+    {
+        let entry = CommandEntry {
+            description: description.to_string(),
+            handler: Box::new(handler),
+        };
+        self.commands.insert(name.to_string(), entry); // 🧷 Bind name → behavior
+    }
+
+    /// 🧬 `register_command()` — Adds an `OmniCommand` implementation
     ///
-    /// - Inserts command using its `name()` as the key.
+    /// - Inserts command using its `name()` and `description()`.
     /// - Overwrites any existing entry with the same name (intended behavior).
-    pub fn register(&mut self, cmd: Box<dyn OmniCommand>) {
-        self.commands.insert(cmd.name().to_string(), cmd); // 🧷 Bind name → behavior
+    pub fn register_command(&mut self, cmd: Box<dyn OmniCommand>) {
+        let name = cmd.name().to_string();
+        let description = cmd.description().to_string();
+        self.register(&name, &description, move |args| cmd.execute(args).into());
+    }
+
+    /// 📖 `help()` — Lists every command with its description, sorted by name
+    ///
+    /// `help` itself is always listed; a registered `help` replaces it.
+    pub fn help(&self) -> String {
+        let mut rows: Vec<(&str, &str)> = self
+            .commands
+            .iter()
+            .map(|(name, entry)| (name.as_str(), entry.description.as_str()))
+            .collect();
+        if !self.commands.contains_key("help") {
+            rows.push(("help", "Lists commands and what they do"));
+        }
+        rows.sort();
+
+        let mut table = format!("{:<14}{}", "COMMAND", "DESCRIPTION");
+        for (name, description) in rows {
+            table.push_str(&format!("\n{:<14}{}", name, description));
+        }
+        table
     }
 
     // -----------------------------------------------
@@ -223,23 +387,41 @@ impl CommandRegistry {
     /// 🚀 `run()` — Attempts to execute a registered command
     ///
     /// - Parses input into command + arguments.
-    /// - If the command is found, it delegates execution and returns result.
-    /// - If no match is found or input is empty, returns `None`.
+    /// - If the command is found, it delegates execution and reports the outcome.
+    /// - Blank input is `HandledEmpty`; an unregistered command is `Unknown`.
+    /// - `help` lists commands unless a command named `help` was registered.
     ///
     /// Example:
     /// ```rust
-    /// registry.run("speak Hello World"); // Some("Hello World")
+    /// use gate::registry::{CommandRegistry, CommandResult};
+    ///
+    /// let registry = CommandRegistry::new();
+    /// let result = registry.run("speak Hello World");
+    /// assert_eq!(result, CommandResult::Handled("Hello World".to_string()));
     /// ```
-    pub fn run(&self, input: &str) -> Option<String> {
-        let parts: Vec<&str> = input.trim().split_whitespace().collect(); // 🧹 Sanitize input into words
-        let (cmd, args) = parts.split_first()?; // ❓ Handle case where no input was given
-        let output = self.commands.get(*cmd)?.execute(args); // ✅ Dispatch if valid command
+    pub fn run(&self, input: &str) -> CommandResult {
+        let parts: Vec<&str> = input.split_whitespace().collect(); // 🧹 Sanitize input into words
+        let Some((cmd, args)) = parts.split_first() else {
+            return CommandResult::HandledEmpty; // 🫙 Nothing was entered
+        };
+        let Some(command) = self.commands.get(*cmd) else {
+            if *cmd == "help" {
+                return CommandResult::Handled(self.help()); // 📖 Built-in listing
+            }
+            return CommandResult::Unknown; // ❓ Not ours — the shell may know it
+        };
 
         // 🎯 Optional debug integration could go here:
         // let debug_entry = DebugEntry::new(*cmd, &input, &output, &output);
         // let _ = debug_entry.write_scroll("Logs/Debug/scrolls/internal.omni.log");
 
-        Some(output)
+        (command.handler)(args) // ✅ Dispatch to the registered behavior
+    }
+}
+
+impl Default for CommandRegistry {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -249,10 +431,11 @@ impl CommandRegistry {
 //
 // ✅ This module contains no teardown logic by design.
 //    - `CommandRegistry` is self-contained and stateless.
-//    - Commands execute inline and return plain `String` outputs.
+//    - Commands execute inline and return `Result<String, String>` outputs.
 //
 // 🧩 Expansion Strategy:
-//    - Future OmniCommands should implement `OmniCommand` trait.
+//    - Future OmniCommands should implement `OmniCommand` trait,
+//      or be registered as a name + description + handler closure.
 //    - Register all commands in `CommandRegistry::new()`.
 //    - Consider grouping commands by purpose (e.g., shell, dev, AI).
 //
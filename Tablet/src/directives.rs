@@ -0,0 +1,247 @@
+// ===============================================
+// 📜 Metadata — Directives v0.0.1 (Tablet Conditional Preprocessor)
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-15
+// _last updated_:  2025-06-15
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Conditional Preprocessor (`#if` / `#else` / `#endif`)
+// _project_:       OmniCode / Millennium OS
+// _description_:   Keeps or drops scroll sections by phase and bit mode before tokenizing.
+//
+// _notes_:
+// - Pure text pass — runs ahead of the Tokenizer, alongside macro expansion
+// - Directive lines and excluded lines are blanked, not removed, so line numbers stay true
+// - Directives must start their line with no space after `#`; `# if` stays a comment
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+// === Standard Library (alloc-only core) ===
+use alloc::string::{String, ToString}; // 🔤 Filtered source text
+use alloc::vec::Vec; // 📚 Kept lines and the open-`#if` stack
+use core::fmt; // 🧾 Display formatting for `DirectiveError`
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap; // 🗺️ `no_std` symbol table
+#[cfg(feature = "std")]
+use std::collections::HashMap; // 🗺️ Symbol name → value
+
+// === Internal Modules ===
+use crate::instruction_registry::{BitMode, PhaseLevel}; // 🎯 Target configuration
+
+// ===============================================
+// 📦 Foundational Declarations — Symbols & Errors
+// ===============================================
+//
+// 🧭 Syntax:
+//   #if BIT64             → kept when BIT64 is defined and non-zero
+//   #if PHASE >= 3        → kept when the comparison holds
+//   #else                 → flips the innermost `#if`
+//   #endif                → closes the innermost `#if`
+//
+// Comparisons accept `==`, `!=`, `<`, `<=`, `>`, `>=` against an
+// integer. Undefined symbols read as 0.
+
+/// ❌ DirectiveError — Why conditional preprocessing stopped
+#[derive(Debug, Clone, PartialEq)]
+pub enum DirectiveError {
+    /// 🧱 An `#if` condition was not `NAME` or `NAME <op> <integer>`
+    MalformedCondition { line: usize, text: String },
+
+    /// 🔚 An `#else` or `#endif` had no open `#if`
+    Unmatched { line: usize, directive: String },
+
+    /// 🔓 An `#if` was never closed by `#endif`
+    Unclosed { line: usize },
+}
+
+impl fmt::Display for DirectiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DirectiveError::MalformedCondition { line, text } => {
+                write!(f, "line {}: malformed condition '{}'", line, text)
+            }
+            DirectiveError::Unmatched { line, directive } => {
+                write!(f, "line {}: '{}' without a matching '#if'", line, directive)
+            }
+            DirectiveError::Unclosed { line } => {
+                write!(f, "line {}: '#if' is missing '#endif'", line)
+            }
+        }
+    }
+}
+
+impl DirectiveError {
+    /// 📍 Line the error was found on (1-based).
+    pub fn line(&self) -> usize {
+        match self {
+            DirectiveError::MalformedCondition { line, .. }
+            | DirectiveError::Unmatched { line, .. }
+            | DirectiveError::Unclosed { line } => *line,
+        }
+    }
+}
+
+impl core::error::Error for DirectiveError {}
+
+// ===============================================
+// 🔧 Body — SymbolTable & Filtering Pass
+// ===============================================
+
+/// 📚 SymbolTable — Named values that `#if` conditions test
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTable {
+    symbols: HashMap<String, i64>, // 🗺️ Symbol name → value
+}
+
+/// 🧭 One open `#if`, innermost last
+struct Frame {
+    line: usize,         // 📍 Line of the `#if`, for `Unclosed`
+    parent_active: bool, // 🌳 Whether the enclosing section is kept
+    taken: bool,         // ✅ Whether this `#if`'s condition held
+    in_else: bool,       // 🔀 Whether `#else` has been seen
+}
+
+impl SymbolTable {
+    /// 🔨 Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 🎯 Builds the table for a target: `PHASE` (1–6), plus `BIT32` and
+    /// `BIT64` set to 1 when the bit mode supports them and 0 otherwise.
+    pub fn for_target(phase: &PhaseLevel, bit_mode: &BitMode) -> Self {
+        let phase = match phase {
+            PhaseLevel::Phase1 => 1,
+            PhaseLevel::Phase2 => 2,
+            PhaseLevel::Phase3 => 3,
+            PhaseLevel::Phase4 => 4,
+            PhaseLevel::Phase5 => 5,
+            PhaseLevel::Phase6 => 6,
+        };
+        let (bit32, bit64) = match bit_mode {
+            BitMode::Bit32 => (1, 0),
+            BitMode::Bit64 => (0, 1),
+            BitMode::Both => (1, 1),
+        };
+
+        let mut table = Self::new();
+        table.define("PHASE", phase);
+        table.define("BIT32", bit32);
+        table.define("BIT64", bit64);
+        table
+    }
+
+    /// ✒️ Sets (or replaces) a symbol.
+    pub fn define(&mut self, name: &str, value: i64) {
+        self.symbols.insert(name.to_string(), value);
+    }
+
+    /// 🔍 Value of `name`, or 0 when it is undefined.
+    pub fn value(&self, name: &str) -> i64 {
+        self.symbols.get(name).copied().unwrap_or(0)
+    }
+
+    /// 📜 Filters a whole scroll.
+    ///
+    /// Lines inside a section whose condition fails are blanked, as are
+    /// the directive lines themselves.
+    pub fn apply(&self, source: &str) -> Result<String, DirectiveError> {
+        let mut kept = Vec::new();
+        let mut stack: Vec<Frame> = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let number = index + 1;
+            let active = stack.last().is_none_or(|frame| frame.active());
+            let trimmed = line.trim();
+
+            if let Some(condition) = trimmed.strip_prefix("#if ") {
+                let taken =
+                    self.evaluate(condition)
+                        .ok_or_else(|| DirectiveError::MalformedCondition {
+                            line: number,
+                            text: condition.trim().to_string(),
+                        })?;
+                stack.push(Frame {
+                    line: number,
+                    parent_active: active,
+                    taken,
+                    in_else: false,
+                });
+                kept.push("");
+            } else if trimmed == "#else" {
+                match stack.last_mut() {
+                    Some(frame) if !frame.in_else => frame.in_else = true,
+                    _ => return Err(unmatched(number, "#else")),
+                }
+                kept.push("");
+            } else if trimmed == "#endif" {
+                stack.pop().ok_or_else(|| unmatched(number, "#endif"))?;
+                kept.push("");
+            } else {
+                kept.push(if active { line } else { "" });
+            }
+        }
+
+        if let Some(frame) = stack.last() {
+            return Err(DirectiveError::Unclosed { line: frame.line });
+        }
+
+        Ok(kept.join("\n"))
+    }
+
+    /// ⚖️ Evaluates `NAME` or `NAME <op> <integer>`; `None` if malformed.
+    fn evaluate(&self, condition: &str) -> Option<bool> {
+        let parts: Vec<&str> = condition.split_whitespace().collect();
+
+        match parts.as_slice() {
+            [name] => Some(self.value(name) != 0),
+            [name, op, rhs] => {
+                let (left, right) = (self.value(name), rhs.parse::<i64>().ok()?);
+                match *op {
+                    "==" => Some(left == right),
+                    "!=" => Some(left != right),
+                    "<" => Some(left < right),
+                    "<=" => Some(left <= right),
+                    ">" => Some(left > right),
+                    ">=" => Some(left >= right),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Frame {
+    /// 🌿 Whether lines at this point are kept.
+    fn active(&self) -> bool {
+        self.parent_active && (self.taken != self.in_else)
+    }
+}
+
+/// 🔚 Builds an `Unmatched` error for `directive` on `line`.
+fn unmatched(line: usize, directive: &str) -> DirectiveError {
+    DirectiveError::Unmatched {
+        line,
+        directive: directive.to_string(),
+    }
+}
+
+// ===================================================
+// 🔚 Closing — Conditional Preprocessor Notes
+// ===================================================
+//
+// 🪜 Flow:
+//   ⬆️ Upstream:   Raw scroll source + target `SymbolTable`
+//   ⬇️ Downstream: Macro expansion → Tokenizer → Parser (never sees `#if`)
+//
+// 🔮 Future:
+//   - `#elif` chains and `&&` / `||` conditions
+//   - Symbols declared in `.logos` target profiles
+//
+// ---------------------------------------------------
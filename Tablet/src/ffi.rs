@@ -0,0 +1,173 @@
+// ===============================================
+// 📜 Metadata — FFI v0.0.1 (Tablet C Front Door)
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-15
+// _last updated_:  2025-06-15
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     FFI (C string → `.stone` C string)
+// _project_:       OmniCode / Millennium OS
+// _description_:   `extern "C"` surface for embedding the compiler in a C host or kernel.
+//
+// _notes_:
+// - Built only with the `ffi` feature (which brings in `std`)
+// - Every string handed out is owned by Tablet — release it with `omni_free_string`
+// - Return codes are stable; new failures get new codes, old ones never move
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+use std::ffi::{c_char, CStr, CString}; // 🔤 C string borrowing and ownership
+use std::panic::{self, AssertUnwindSafe}; // 💥 Keeping panics out of the C caller
+use std::ptr; // 🕳 Null checks and clearing `out`
+
+use crate::compiler::compile_source; // 🪨 Source → `.stone` pipeline
+use crate::directives::SymbolTable; // 🚦 `#if` symbols, handed to C as an opaque pointer
+
+// ===============================================
+// 📦 Foundational Declarations — Return Codes
+// ===============================================
+
+/// ✅ `.stone` written to `out`; the scroll compiled cleanly.
+pub const OMNI_OK: i32 = 0;
+/// ⚠️ `.stone` written to `out`, but directive, macro, lex, or parse errors were found.
+pub const OMNI_DIAGNOSTICS: i32 = 1;
+/// 🕳 `src` or `out` was null; nothing written.
+pub const OMNI_ERR_NULL: i32 = -1;
+/// 🔤 `src` was not valid UTF-8; nothing written.
+pub const OMNI_ERR_UTF8: i32 = -2;
+/// 🧱 The `.stone` output held an interior NUL; nothing written.
+pub const OMNI_ERR_NUL: i32 = -3;
+/// 💥 The compiler panicked; nothing written.
+pub const OMNI_ERR_PANIC: i32 = -4;
+
+// ===============================================
+// 🔧 Body — Exported Functions
+// ===============================================
+
+/// 🪨 Compiles the NUL-terminated scroll `src` and stores its `.stone`
+/// rendering in `*out`.
+///
+/// `#if` sections are filtered against `symbols`, a table from
+/// `omni_symbols_new`; null reads as an empty table. A directive or macro
+/// error leaves the `.stone` empty.
+///
+/// Returns `OMNI_OK` or `OMNI_DIAGNOSTICS` when `*out` was written (the
+/// latter when the scroll had directive, macro, lex, or parse errors), or a
+/// negative `OMNI_ERR_*` code with `*out` set to null. A written `*out`
+/// belongs to the caller, who must release it with `omni_free_string`.
+/// A compiler panic is caught here rather than unwinding into C.
+///
+/// # Safety
+///
+/// `src` must be null or point to a NUL-terminated string that stays valid
+/// for the call. `symbols` must be null or a live table from
+/// `omni_symbols_new`. `out` must be null or valid for one pointer write.
+#[no_mangle]
+pub unsafe extern "C" fn omni_compile_to_stone(
+    src: *const c_char,
+    symbols: *const SymbolTable,
+    out: *mut *mut c_char,
+) -> i32 {
+    if out.is_null() {
+        return OMNI_ERR_NULL;
+    }
+    *out = ptr::null_mut();
+    if src.is_null() {
+        return OMNI_ERR_NULL;
+    }
+
+    let Ok(source) = CStr::from_ptr(src).to_str() else {
+        return OMNI_ERR_UTF8;
+    };
+
+    let empty = SymbolTable::new();
+    let symbols = symbols.as_ref().unwrap_or(&empty);
+
+    let Ok(report) = panic::catch_unwind(AssertUnwindSafe(|| compile_source(source, symbols)))
+    else {
+        return OMNI_ERR_PANIC;
+    };
+    let Ok(stone) = CString::new(report.output.stone) else {
+        return OMNI_ERR_NUL;
+    };
+
+    *out = stone.into_raw();
+    if report.directive_error.is_none()
+        && report.macro_error.is_none()
+        && report.lex_errors.is_empty()
+        && report.parse_errors.is_empty()
+    {
+        OMNI_OK
+    } else {
+        OMNI_DIAGNOSTICS
+    }
+}
+
+/// 📚 Creates an empty `#if` symbol table for `omni_compile_to_stone`.
+///
+/// The table belongs to the caller, who must release it with
+/// `omni_symbols_free`.
+#[no_mangle]
+pub extern "C" fn omni_symbols_new() -> *mut SymbolTable {
+    Box::into_raw(Box::new(SymbolTable::new()))
+}
+
+/// ✒️ Sets (or replaces) the symbol `name` in `symbols`.
+///
+/// Returns `OMNI_OK`, `OMNI_ERR_NULL` when either pointer is null, or
+/// `OMNI_ERR_UTF8` when `name` is not valid UTF-8.
+///
+/// # Safety
+///
+/// `symbols` must be null or a live table from `omni_symbols_new`. `name`
+/// must be null or point to a NUL-terminated string valid for the call.
+#[no_mangle]
+pub unsafe extern "C" fn omni_symbols_define(
+    symbols: *mut SymbolTable,
+    name: *const c_char,
+    value: i64,
+) -> i32 {
+    let Some(symbols) = symbols.as_mut() else {
+        return OMNI_ERR_NULL;
+    };
+    if name.is_null() {
+        return OMNI_ERR_NULL;
+    }
+    let Ok(name) = CStr::from_ptr(name).to_str() else {
+        return OMNI_ERR_UTF8;
+    };
+
+    symbols.define(name, value);
+    OMNI_OK
+}
+
+/// 🧹 Releases a table from `omni_symbols_new`. Null is ignored.
+///
+/// # Safety
+///
+/// `symbols` must be null or a table from `omni_symbols_new` that has not
+/// already been freed; it must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn omni_symbols_free(symbols: *mut SymbolTable) {
+    if !symbols.is_null() {
+        drop(Box::from_raw(symbols));
+    }
+}
+
+/// 🧹 Releases a string returned by `omni_compile_to_stone`. Null is ignored.
+///
+/// # Safety
+///
+/// `s` must be null or a pointer from `omni_compile_to_stone` that has not
+/// already been freed; it must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn omni_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
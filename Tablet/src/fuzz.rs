@@ -0,0 +1,281 @@
+// ===============================================
+// 📜 Metadata — Fuzz v0.0.1 (Tablet Pipeline Hardening)
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-16
+// _last updated_:  2025-06-16
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Fuzz (mutated scrolls → every pipeline stage)
+// _project_:       OmniCode / Millennium OS
+// _description_:   Corpus-seeded fuzzing of compile, `.stone`, binary cache, and run.
+//
+// _notes_:
+// - Built only with the `fuzzing` feature (which brings in `std`)
+// - Mutation is seeded and self-contained, so a failing seed replays exactly
+// - Each input runs on a watchdog thread; the interpreter runs under a step budget
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+use std::any::Any; // 💥 Panic payloads
+use std::fmt; // 🧾 Display for failures
+use std::panic::{self, AssertUnwindSafe}; // 💥 Catching a stage's panic
+use std::sync::mpsc; // 📨 Watchdog handoff
+use std::thread; // 🧵 Watchdog thread per input
+use std::time::Duration; // ⏳ Watchdog limit
+
+use crate::compiler::compile_source; // 🪨 Source → `.stone` pipeline
+use crate::directives::SymbolTable; // 🚦 No `#if` symbols defined
+use crate::instruction_registry::shared_registry; // 📜 Keywords for the tokenizer and parser
+use crate::interpreter::{Interpreter, MemoryChannel}; // 🏃 Bounded execution
+use crate::parser::{Parser, ScrollTree}; // 🌳 Trees to re-serialize
+use crate::tokenizer::Tokenizer; // 🧩 Source scanner
+
+// ===============================================
+// 📦 Foundational Declarations — Limits & Failures
+// ===============================================
+
+/// 🛑 Nodes the interpreter may execute per input before it stops the run.
+pub const FUZZ_STEP_BUDGET: usize = 10_000;
+
+/// 📞 Calls the interpreter may nest per input before it stops the run.
+pub const FUZZ_CALL_DEPTH: usize = 16;
+
+/// 🧵 Stack for the thread running an input: room for `FUZZ_CALL_DEPTH`
+/// calls whose bodies each nest blocks `MAX_NESTING_DEPTH` deep, with
+/// headroom, in a debug build. A stack overflow aborts the whole fuzzer,
+/// which `catch_unwind` cannot stop.
+pub const FUZZ_STACK_SIZE: usize = 32 * 1024 * 1024;
+
+/// ⏳ Wall time one input may take across every stage before it counts as a hang.
+pub const FUZZ_TIME_LIMIT: Duration = Duration::from_secs(5);
+
+/// 💥 FuzzFailure — An input that broke a pipeline stage
+#[derive(Debug, Clone, PartialEq)]
+pub enum FuzzFailure {
+    /// 💥 `stage` panicked with `message`
+    Panic {
+        input: String,
+        stage: &'static str,
+        message: String,
+    },
+
+    /// ⏳ The input did not finish within `FUZZ_TIME_LIMIT`
+    Hang { input: String },
+}
+
+impl FuzzFailure {
+    /// 📜 The scroll source that caused the failure.
+    pub fn input(&self) -> &str {
+        match self {
+            FuzzFailure::Panic { input, .. } | FuzzFailure::Hang { input } => input,
+        }
+    }
+}
+
+impl fmt::Display for FuzzFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FuzzFailure::Panic {
+                input,
+                stage,
+                message,
+            } => write!(f, "{} panicked on {:?}: {}", stage, input, message),
+            FuzzFailure::Hang { input } => {
+                write!(f, "no result within {:?} on {:?}", FUZZ_TIME_LIMIT, input)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FuzzFailure {}
+
+/// 🎲 FuzzRng — SplitMix64, small and reproducible from one `u64` seed
+#[derive(Debug, Clone)]
+pub struct FuzzRng(u64);
+
+impl FuzzRng {
+    /// 🌱 Starts the sequence at `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// 🎲 Next value in the sequence.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// 🎯 A value in `0..bound`; `bound` must be non-zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+// ===============================================
+// 🔧 Body — Running One Input
+// ===============================================
+
+/// 🧪 Runs `source` through every stage, reporting the first panic or a hang.
+///
+/// Stages, in order: `compile` (`compile_source`), `parse` (the tree the
+/// later stages reuse), `stone` (`to_stone` → `from_stone` → `to_stone`),
+/// `bytes` (`to_bytes` → `from_bytes`), and `run` (the interpreter under
+/// `FUZZ_STEP_BUDGET` and `FUZZ_CALL_DEPTH`). Errors a stage returns are expected; only panics
+/// and hangs fail.
+pub fn fuzz_one(source: &str) -> Result<(), FuzzFailure> {
+    let (sender, receiver) = mpsc::channel();
+    let input = source.to_string();
+
+    // 🧵 A runaway input is abandoned with its thread, not waited on
+    thread::Builder::new()
+        .stack_size(FUZZ_STACK_SIZE)
+        .spawn(move || {
+            let _ = sender.send(run_stages(&input));
+        })
+        .expect("fuzz thread spawns");
+
+    match receiver.recv_timeout(FUZZ_TIME_LIMIT) {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err((stage, message))) => Err(FuzzFailure::Panic {
+            input: source.to_string(),
+            stage,
+            message,
+        }),
+        Err(_) => Err(FuzzFailure::Hang {
+            input: source.to_string(),
+        }),
+    }
+}
+
+/// 🪜 Runs each stage under `catch_unwind`, naming the one that panics.
+fn run_stages(source: &str) -> Result<(), (&'static str, String)> {
+    stage("compile", || {
+        compile_source(source, &SymbolTable::new());
+    })?;
+
+    let tree = stage("parse", || parse(source))?;
+
+    stage("stone", || {
+        let stone = tree.to_stone();
+        ScrollTree::from_stone(&stone).to_stone();
+    })?;
+
+    stage("bytes", || {
+        let _ = ScrollTree::from_bytes(&tree.to_bytes());
+    })?;
+
+    stage("run", || {
+        let mut interpreter = Interpreter::new(MemoryChannel::default())
+            .with_max_steps(FUZZ_STEP_BUDGET)
+            .with_max_call_depth(FUZZ_CALL_DEPTH);
+        let _ = interpreter.run(&tree);
+    })
+}
+
+/// 💥 Runs `f`, turning a panic into `(name, message)`.
+fn stage<T>(name: &'static str, f: impl FnOnce() -> T) -> Result<T, (&'static str, String)> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| (name, panic_message(payload)))
+}
+
+/// 🧾 The text a panic was raised with, when it carried any.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or_else(|| "non-string panic payload".to_string(), |s| s.to_string()),
+    }
+}
+
+/// 🌳 Tokenizes and parses `source` as `compile_source` does.
+fn parse(source: &str) -> ScrollTree {
+    let registry = shared_registry();
+    let tokens = Tokenizer::from_registry(source, &registry).tokenize().tokens;
+    Parser::new(tokens).with_registry(registry).parse()
+}
+
+// ===============================================
+// 🧬 Mutation — Deriving Inputs from the Corpus
+// ===============================================
+
+/// 🔤 Characters inserted by `mutate`: scroll syntax, digits, letters,
+/// line breaks, and multi-byte characters that stress column counting.
+const ALPHABET: &[char] = &[
+    '{', '}', '(', ')', ',', ';', ':', '=', '<', '>', '!', '+', '-', '*', '/', '%', '&', '|',
+    '.', '@', '"', '\'', '`', '#', ' ', '\t', '\n', '\r', '0', '9', 'x', 's', '_', 'é', '🔥',
+    '\u{FEFF}',
+];
+
+/// 🔑 Whole words inserted by `mutate`, so structure forms as well as noise.
+const WORDS: &[&str] = &[
+    "walk ", "let ", "if ", "then ", "else ", "while ", "return ", "speak ", "wait ", "bless ",
+    "curse ", ".byte ", ".org ", "@trusted ", "//! ", "5s ", "0x41 ",
+];
+
+/// 🧬 Applies one to four random edits to `seed`: inserting a character or
+/// word, deleting or duplicating a span, or splicing in part of another
+/// `corpus` entry.
+pub fn mutate(seed: &str, corpus: &[String], rng: &mut FuzzRng) -> String {
+    let mut chars: Vec<char> = seed.chars().collect();
+
+    for _ in 0..=rng.below(4) {
+        let at = rng.below(chars.len() + 1);
+        match rng.below(5) {
+            0 => chars.insert(at, ALPHABET[rng.below(ALPHABET.len())]),
+            1 => {
+                let word = WORDS[rng.below(WORDS.len())];
+                chars.splice(at..at, word.chars());
+            }
+            2 if at < chars.len() => {
+                let end = at + 1 + rng.below((chars.len() - at).min(16));
+                chars.drain(at..end);
+            }
+            3 if at < chars.len() => {
+                let end = at + 1 + rng.below((chars.len() - at).min(16));
+                let span: Vec<char> = chars[at..end].to_vec();
+                chars.splice(at..at, span);
+            }
+            4 if !corpus.is_empty() => {
+                let donor: Vec<char> = corpus[rng.below(corpus.len())].chars().collect();
+                let from = rng.below(donor.len() + 1);
+                let to = from + rng.below(donor.len() - from + 1);
+                chars.splice(at..at, donor[from..to].iter().copied());
+            }
+            _ => {}
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+/// 🌾 Runs every `corpus` entry as written, then `iterations` mutants of
+/// them derived from `seed`, returning every failure found.
+pub fn run_corpus(corpus: &[String], iterations: usize, seed: u64) -> Vec<FuzzFailure> {
+    let mut rng = FuzzRng::new(seed);
+    let mut failures: Vec<FuzzFailure> = corpus
+        .iter()
+        .filter_map(|source| fuzz_one(source).err())
+        .collect();
+
+    if corpus.is_empty() {
+        return failures;
+    }
+
+    for _ in 0..iterations {
+        let parent = &corpus[rng.below(corpus.len())];
+        let input = mutate(parent, corpus, &mut rng);
+        if let Err(failure) = fuzz_one(&input) {
+            failures.push(failure);
+        }
+    }
+
+    failures
+}
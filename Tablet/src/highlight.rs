@@ -0,0 +1,87 @@
+// ===============================================
+// 📜 Metadata — Highlight v0.0.1 (Tablet Editor Export)
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-15
+// _last updated_:  2025-06-15
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Highlight (Token → editor highlight categories)
+// _project_:       OmniCode / Millennium OS
+// _description_:   Classifies tokens into highlight categories with exact source spans.
+//
+// _notes_:
+// - Pure data export — no colors or themes; editors map `HighlightKind` themselves
+// - Reuses the Tokenizer; nothing here re-lexes source
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+use crate::instruction_registry::shared_registry; // 📚 Instruction keywords, built once per process
+use crate::tokenizer::{TokenSpan, TokenType, Tokenizer}; // 🧩 Spanned tokens
+
+// ===============================================
+// 📦 Foundational Declarations — Highlight Kinds
+// ===============================================
+
+/// 🎨 HighlightKind — Editor-facing token category
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighlightKind {
+    Keyword,     // 🔑 Instructions and reserved keywords
+    Literal,     // 🔢 Strings, chars, numbers
+    Comment,     // 💬 Comments and `#!` metadata
+    Operator,    // ➕ Arithmetic, assignment, comparison
+    Identifier,  // ✍️ User-defined names
+    Punctuation, // 🧱 Group markers and separators
+    Error,       // ❌ Unrecognized symbols
+}
+
+impl From<&TokenType> for HighlightKind {
+    fn from(token_type: &TokenType) -> Self {
+        match token_type {
+            TokenType::Keyword | TokenType::Instruction => HighlightKind::Keyword,
+            TokenType::Literal => HighlightKind::Literal,
+            TokenType::Comment | TokenType::Metadata => HighlightKind::Comment,
+            TokenType::Operator => HighlightKind::Operator,
+            TokenType::Identifier => HighlightKind::Identifier,
+            TokenType::GroupMarker | TokenType::Punctuation | TokenType::Whitespace => {
+                HighlightKind::Punctuation
+            }
+            TokenType::Error => HighlightKind::Error,
+        }
+    }
+}
+
+// ===============================================
+// 🔧 Body — Highlight Export
+// ===============================================
+
+/// 🎨 Tokenizes `source` and tags each token with its highlight category.
+///
+/// Spans are char offsets into `source`, in scroll order — counted after
+/// the tokenizer drops a leading BOM and folds CRLF endings to LF.
+/// Instructions are recognised from the compiled-in registry.
+pub fn highlight(source: &str) -> Vec<(TokenSpan, HighlightKind)> {
+    Tokenizer::from_registry(source, &shared_registry())
+        .tokenize_spanned()
+        .into_iter()
+        .map(|(token, span)| (span, HighlightKind::from(&token.token_type)))
+        .collect()
+}
+
+// ===================================================
+// 🔚 Closing — Highlight Notes
+// ===================================================
+//
+// 🪜 Flow:
+//   ⬆️ Upstream:   Tokenizer (spanned pass)
+//   ⬇️ Downstream: Editor integrations, GUI terminal input
+//
+// 🔮 Future:
+//   - Use `.logos` registries for instruction detection
+//   - Distinguish `#!` metadata from comments if editors want it
+//
+// ---------------------------------------------------
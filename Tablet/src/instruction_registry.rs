@@ -48,12 +48,30 @@
 // ===============================================
 // These imports are grouped by origin and function:
 // • Standard: structure mapping and metadata containers
-// • External: (None currently — reserved for byte encoding)
+// • External: serde + toml for `.logos` instruction definition files
 // • Internal: (None yet — may be required for GroupRegistry)
 // • Debugging: (None currently — Phase 6+)
 //
-// === Standard Library ===
+// === Standard Library (alloc-only core) ===
+use alloc::format; // 🧾 Error message formatting
+use alloc::string::{String, ToString}; // 🔤 Error messages
+use alloc::vec; // 📚 Operand schema literals
+use alloc::vec::Vec; // 📚 Operand schemas and encoded bytes
+use core::fmt; // 🧾 Display formatting for registry load errors
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap; // 🗺️ `no_std` keyword map
+#[cfg(feature = "std")]
 use std::collections::HashMap; // 🗺️ Instruction keyword-to-struct registry
+#[cfg(feature = "std")]
+use std::fs; // 📂 Reads `.logos` / TOML definition files from disk
+#[cfg(feature = "std")]
+use std::path::Path; // 🛤 Location of external instruction definitions
+#[cfg(feature = "std")]
+use std::sync::{Arc, OnceLock}; // 🤝 One compiled-in registry shared across threads
+
+// === External Crates ===
+#[cfg(feature = "std")]
+use serde::Deserialize; // 📥 Maps `.logos` / TOML tables into definition structs
 
 
 // ===============================================
@@ -71,7 +89,7 @@ use std::collections::HashMap; // 🗺️ Instruction keyword-to-struct registry
 // === Architecture Targeting ===
 // Specifies which hardware architectures the instruction supports.
 // Used during compilation, emulation, and optimization.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum BitMode {
     Bit32,  // 🧱 32-bit compatible — limited address space
     Bit64,  // 🏗️ 64-bit compatible — modern architecture
@@ -81,7 +99,7 @@ pub enum BitMode {
 // === Debug & Flow Markers ===
 // Specifies side effects or flow alterations caused by an instruction.
 // Used by the Watchtower during execution tracing or scroll validation.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum FlagEffect {
     SetsZero,        // ➖ Sets the zero flag (e.g. result = 0)
     SetsCarry,       // ➕ Arithmetic carry flag set
@@ -95,7 +113,8 @@ pub enum FlagEffect {
 // === Execution Privilege Layers ===
 // Indicates the minimum privilege level required to execute the instruction.
 // Used in interpreters, sandboxing engines, and scroll-protected areas.
-#[derive(Debug)]
+// Variants are ordered lowest to highest, so `Kernel > User`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum PrivilegeLevel {
     User,    // 🧍 Public-level — safe for standard program use
     Kernel,  // 🧪 Internal system calls — modifies protected state
@@ -106,19 +125,180 @@ pub enum PrivilegeLevel {
 
 // === Operand Schema Types ===
 // Used by the parser and operand resolver to validate operand correctness.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum OperandKind {
     Identifier,     // ✍️ Variable or named symbol
     Literal,        // 🔢 Number, string, boolean
     Register,       // 🧾 CPU or virtual register
     Address,        // 🗺️ Memory address or pointer
     Label,          // 🔖 Jump or symbolic target
+    Target,         // 🎯 Destination that receives a write (variable, register, memory)
+    Value,          // 📦 Any readable value — literal or resolved symbol
     Custom(&'static str), // 🎨 Custom operand format (e.g., "duration", "voice")
+    Optional(&'static OperandKind), // ❔ Slot that may be left off — trailing slots only
+    Variadic(&'static OperandKind), // 🔁 Last slot, filled any number of times (none included)
+}
+
+impl OperandKind {
+    /// 🧮 Whether grouped operands filling this slot should share one type.
+    /// `Value` and `Custom` slots accept mixed groups.
+    pub fn expects_uniform(&self) -> bool {
+        !matches!(self.inner(), OperandKind::Value | OperandKind::Custom(_))
+    }
+
+    /// 🎯 The kind an operand must be to fill this slot.
+    /// `Optional` and `Variadic` slots yield the kind they wrap; others yield themselves.
+    pub fn inner(&self) -> &OperandKind {
+        match self {
+            OperandKind::Optional(kind) | OperandKind::Variadic(kind) => kind.inner(),
+            kind => kind,
+        }
+    }
+
+    /// ❔ Whether this slot may be left off the end of an instruction.
+    pub fn is_optional(&self) -> bool {
+        matches!(self, OperandKind::Optional(_) | OperandKind::Variadic(_))
+    }
+
+    /// 🔁 Whether this slot takes every remaining operand.
+    pub fn is_variadic(&self) -> bool {
+        matches!(self, OperandKind::Variadic(_))
+    }
+}
+
+/// 🔢 Number of leading slots in `schema` that must be filled.
+/// Trailing `Optional` slots may be omitted.
+pub fn required_operands(schema: &[OperandKind]) -> usize {
+    let optional = schema
+        .iter()
+        .rev()
+        .take_while(|kind| kind.is_optional())
+        .count();
+    schema.len() - optional
+}
+
+/// 🔢 Most operands `schema` accepts — `None` when it ends in a `Variadic` slot.
+pub fn max_operands(schema: &[OperandKind]) -> Option<usize> {
+    match schema.last() {
+        Some(kind) if kind.is_variadic() => None,
+        _ => Some(schema.len()),
+    }
+}
+
+/// 🧩 Slot filling operand `position`: past the end, a trailing `Variadic`
+/// slot repeats; otherwise there is none.
+pub fn slot_for(schema: &[OperandKind], position: usize) -> Option<&OperandKind> {
+    schema
+        .get(position)
+        .or_else(|| schema.last().filter(|kind| kind.is_variadic()))
+}
+
+/// 🏷️ Lowercase slot name used in operand error messages (e.g., `target`).
+impl fmt::Display for OperandKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OperandKind::Identifier => "identifier",
+            OperandKind::Literal => "literal",
+            OperandKind::Register => "register",
+            OperandKind::Address => "address",
+            OperandKind::Label => "label",
+            OperandKind::Target => "target",
+            OperandKind::Value => "value",
+            OperandKind::Custom(name) => name,
+            OperandKind::Optional(kind) | OperandKind::Variadic(kind) => return kind.fmt(f),
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// 🧩 Operand expectations for one instruction: its arity and the ordered
+/// kinds of its slots (e.g., `store` → arity 2, `[Target, Value]`).
+/// Trailing `Optional` slots count toward `arity`; see `required()` for the minimum.
+/// A trailing `Variadic` slot counts once; see `max()` for the upper bound.
+/// `alternatives` lists further accepted forms (e.g., `bless x` beside
+/// `bless x 5`); operands fit when they match `kinds` or any alternative.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperandSchema {
+    pub arity: usize,                       // 🔢 Number of operand slots, optional ones included
+    pub kinds: Vec<OperandKind>,            // 🧩 Expected kind of each slot, in order
+    pub alternatives: Vec<Vec<OperandKind>>, // 🔀 Other accepted slot lists, tried after `kinds`
+}
+
+impl OperandSchema {
+    /// 🏗️ Builds a schema from ordered slot kinds; arity is their count.
+    pub fn new(kinds: Vec<OperandKind>) -> Self {
+        Self {
+            arity: kinds.len(),
+            kinds,
+            alternatives: Vec::new(),
+        }
+    }
+
+    /// 🔀 Adds further accepted forms alongside the primary `kinds`.
+    pub fn with_alternatives(mut self, alternatives: Vec<Vec<OperandKind>>) -> Self {
+        self.alternatives = alternatives;
+        self
+    }
+
+    /// 📚 Every accepted form — `kinds` first, then each alternative.
+    pub fn forms(&self) -> impl Iterator<Item = &[OperandKind]> {
+        core::iter::once(self.kinds.as_slice()).chain(self.alternatives.iter().map(Vec::as_slice))
+    }
+
+    /// 🔢 Minimum operand count across every form, trailing `Optional` slots dropped.
+    pub fn required(&self) -> usize {
+        self.forms().map(required_operands).min().unwrap_or(0)
+    }
+
+    /// 🔢 Maximum operand count across every form — `None` when any ends `Variadic`.
+    pub fn max(&self) -> Option<usize> {
+        self.forms()
+            .map(max_operands)
+            .try_fold(0, |most, max| max.map(|max| most.max(max)))
+    }
+
+    /// 🧩 First form whose count range admits `count` operands.
+    pub fn form_for(&self, count: usize) -> Option<&[OperandKind]> {
+        self.forms().find(|form| {
+            count >= required_operands(form) && max_operands(form).is_none_or(|max| count <= max)
+        })
+    }
+
+    /// 🧮 Checks operands against every form, accepting the first that fits.
+    ///
+    /// When none fits, a count outside every form's range reports the
+    /// nearer overall bound; otherwise the first form with a fitting count
+    /// reports its first ill-fitting slot.
+    pub fn matches<T: SchemaOperand>(&self, operands: &[T]) -> Result<(), OperandMismatch> {
+        let mut first_kind_mismatch = None;
+
+        for form in self.forms() {
+            match match_form(form, operands) {
+                Ok(()) => return Ok(()),
+                Err(mismatch @ OperandMismatch::Kind { .. }) => {
+                    first_kind_mismatch.get_or_insert(mismatch);
+                }
+                Err(OperandMismatch::Count { .. }) => {}
+            }
+        }
+
+        Err(first_kind_mismatch.unwrap_or_else(|| {
+            let required = self.required();
+            OperandMismatch::Count {
+                expected: if operands.len() < required {
+                    required
+                } else {
+                    self.max().unwrap_or(usize::MAX)
+                },
+                found: operands.len(),
+            }
+        }))
+    }
 }
 
 // === Rollout Phase Level ===
 // Allows phased instruction registration, interpreter versioning, or scroll gating.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum PhaseLevel {
     Phase1, // 🌱 Foundation — First scroll of breath
     Phase2, // 🌿 Growth — Early expansion and testing
@@ -142,7 +322,7 @@ pub enum PhaseLevel {
 // Fields are grouped by implementation phase (1–6) for clarity and future extensibility.
 // ===============================================
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Instruction {
     // === Phase 1 — Mandatory Fields ===
     pub keyword: &'static str,                     // 🔑 NovaScript instruction keyword (e.g., "let", "walk")
@@ -161,6 +341,7 @@ pub struct Instruction {
     // pub operand_format: Option<&'static str>,   // (replaced by operand_schema)
 
     pub operand_schema: Option<Vec<OperandKind>>,  // 🧩 Structured operand kind expectations (e.g., [Identifier, Literal])
+    pub operand_alternatives: Option<Vec<Vec<OperandKind>>>, // 🔀 Other accepted operand forms (e.g., `bless x` beside `bless x 5`)
 
     // === Phase 3 — Execution Effects ===
     pub flags_effects: Option<Vec<FlagEffect>>,    // ⚠️ Registers or flags this instruction sets
@@ -244,6 +425,20 @@ impl Instruction {
         self.operand_schema.as_ref()
     }
 
+    /// Returns the alternative operand forms, if any are declared.
+    pub fn operand_alternatives(&self) -> Option<&Vec<Vec<OperandKind>>> {
+        self.operand_alternatives.as_ref()
+    }
+
+    /// Returns the operand schema as arity plus slot kinds (and any
+    /// alternative forms), if one is declared.
+    pub fn schema(&self) -> Option<OperandSchema> {
+        self.operand_schema.clone().map(|kinds| {
+            OperandSchema::new(kinds)
+                .with_alternatives(self.operand_alternatives.clone().unwrap_or_default())
+        })
+    }
+
     // === Phase 3 — Execution Effects ===
 
     /// Returns the list of flags or flow effects (e.g., AltersFlow).
@@ -313,11 +508,14 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
         description: "Pause or delay execution for a time.",
 
         opcode: 0x00,                             // Unique bytecode
-        machine_code: "00",                       // Encoded representation
+        machine_code: "00 [DD]",                  // DD = duration (a bare `wait` is a minimal pause)
         bit_mode: BitMode::Both,                  // Universal compatibility
 
-        operand_count: Some(0),                   // Explicitly zero operands
-        operand_schema: Some(vec![]),             // No operand schema needed
+        operand_count: Some(1),                   // An optional duration
+        operand_schema: Some(vec![
+            OperandKind::Optional(&OperandKind::Custom("duration")), // ⏳ `5s`, `200ms` — none for a bare pause
+        ]),
+        operand_alternatives: None,
         flags_effects: None,                      // No flags modified
         cycle_cost: Some(1),                      // Lightweight operation
 
@@ -345,6 +543,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
 
         operand_count: Some(1),                        // One operand expected
         operand_schema: Some(vec![OperandKind::Label]),// Label reference required
+        operand_alternatives: None,
         flags_effects: Some(vec![
             FlagEffect::AltersFlow,                    // Alters instruction pointer
         ]),
@@ -378,6 +577,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
 
         operand_count: Some(1),                         // Requires a single label operand
         operand_schema: Some(vec![OperandKind::Label]), // Program or subroutine address
+        operand_alternatives: None,
         flags_effects: Some(vec![
             FlagEffect::AltersFlow,                     // Transfers flow into invoked routine
         ]),
@@ -410,11 +610,15 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
         description: "Output data to terminal or vocal system.",
 
         opcode: 0x20,                                    // Bytecode assignment
-        machine_code: "20 VV",                           // Value to be declared
+        machine_code: "20 VV ..",                        // Values to be declared, in order
         bit_mode: BitMode::Both,
 
-        operand_count: Some(1),                          // One operand: the message/value
-        operand_schema: Some(vec![OperandKind::Literal]),// Literal expression or resolved value
+        operand_count: None,                             // One or more operands, joined on output
+        operand_schema: Some(vec![
+            OperandKind::Literal,                        // 🔢 Leading message
+            OperandKind::Variadic(&OperandKind::Value),  // 📦 Further values — `speak "score:", x`
+        ]),
+        operand_alternatives: None,
         flags_effects: Some(vec![
             FlagEffect::Custom("OutputOperation"),       // Custom logging/instrumentation flag
         ]),
@@ -440,6 +644,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
 
         operand_count: Some(1),
         operand_schema: Some(vec![OperandKind::Identifier]), // Register, symbol, or memory target
+        operand_alternatives: None,
         flags_effects: Some(vec![
             FlagEffect::ModifiesMemory,                  // Input is stored into a memory location
         ]),
@@ -477,6 +682,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
 
         operand_count: Some(0),                          // No operands required
         operand_schema: None,                            // Intention is enough—no payload needed
+        operand_alternatives: None,
         flags_effects: Some(vec![
             FlagEffect::AltersFlow                      // Immediate shift in flow logic
         ]),
@@ -511,6 +717,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
 
         operand_count: Some(0), // ⚙️ No operands — its function is positional
         operand_schema: None, // 🗺 Parser interprets its context — no operand parsing needed
+        operand_alternatives: None,
         flags_effects: None, // 🏳️ Does not modify flags — logical direction only
         cycle_cost: Some(1), // ⏳ Minimal execution time — near-zero cost
 
@@ -533,6 +740,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
 
         operand_count: Some(0), // ⚙️ Like `then`, it stands alone
         operand_schema: None, // 🗺 Operates structurally — no operands
+        operand_alternatives: None,
         flags_effects: None, // 🏳️ No flag effect — its meaning is relational, not mechanical
         cycle_cost: Some(1), // ⏳ Lightweight in cycle impact
 
@@ -569,6 +777,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
             OperandKind::Value,     // 🔍 First value for comparison
             OperandKind::Value,     // 🔍 Second value for comparison
         ]), // 🗺 Structured operand schema for parser & assembler
+        operand_alternatives: None,
 
         flags_effects: Some(vec![
             FlagEffect::SetsCondition // 🧭 Sets condition flag — enables conditional branches
@@ -602,12 +811,16 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
         category: "Math/Logic", // 📂 Quantitative logic
         description: "Increase a value or quantity.", // 🔼 Incrementation as blessing
         opcode: 0x60, // 🧬 Bytecode for upward mutation
-        machine_code: "60 TT", // 💾 TT = target register or memory
+        machine_code: "60 TT [VV]", // 💾 TT = target register or memory, VV = amount (default 1)
         bit_mode: BitMode::Both, // 🔁 Universal operation
 
-        operand_count: Some(1), // 🧮 One operand — simple, pure blessing
+        operand_count: Some(2), // 🧮 Target, then an amount
         operand_schema: Some(vec![
             OperandKind::Target, // 🎯 What receives the multiplication
+            OperandKind::Value, // 📦 How much to add
+        ]),
+        operand_alternatives: Some(vec![
+            vec![OperandKind::Target], // 🔀 `bless x` — increase by 1
         ]),
 
         flags_effects: Some(vec![
@@ -631,13 +844,15 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
         category: "Math/Logic", // 📂 Mirrors `bless` but inverted
         description: "Decrease a value or apply limitation.", // 🔽 Restriction logic
         opcode: 0x61, // 🧬 Opcode for downward mutation
-        machine_code: "61 TT", // 💾 TT = target to curse
+        machine_code: "61 TT [VV]", // 💾 TT = target to curse, VV = amount (default 1)
         bit_mode: BitMode::Both, // 🔁 Same cross-platform compatibility
 
-        operand_count: Some(1), // 🧮 Simple operand
+        operand_count: Some(2), // 🧮 Target, then an optional amount
         operand_schema: Some(vec![
             OperandKind::Target, // 🎯 What is being reduced or judged
+            OperandKind::Optional(&OperandKind::Value), // 📦 How much to take — 1 when left off
         ]),
+        operand_alternatives: None,
 
         flags_effects: Some(vec![
             FlagEffect::ModifiesMemory,       // 🧠 Alters memory or register
@@ -679,6 +894,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
             OperandKind::Target, // 🎯 Where the value will be stored
             OperandKind::Value   // 📦 The value to embed
         ]),
+        operand_alternatives: None,
 
         flags_effects: Some(vec![
             FlagEffect::ModifiesMemory, // 🔧 Alters memory — sacred write
@@ -707,6 +923,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
         operand_schema: Some(vec![
             OperandKind::Target // 🎯 Destination for loaded value
         ]),
+        operand_alternatives: None,
 
         flags_effects: Some(vec![
             FlagEffect::ModifiesMemory, // 🔧 Value is reinserted or restored
@@ -746,6 +963,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
             OperandKind::Target, // 🎯 Where light goes
             OperandKind::Value   // 💡 What light is
         ]),
+        operand_alternatives: None,
 
         flags_effects: Some(vec![
             FlagEffect::ModifiesMemory, // 🧠 Alters the system state
@@ -780,6 +998,7 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
 
         operand_count: Some(0), // ⚙️ None required — it closes everything before it
         operand_schema: None, // 🗝 Pure structure — no input necessary
+        operand_alternatives: None,
 
         flags_effects: Some(vec![
             FlagEffect::EndsFlow // 🔚 Terminates flow — VM or parser halts scope
@@ -795,6 +1014,814 @@ pub fn get_instruction_registry() -> HashMap<&'static str, Instruction> {
     registry
 }
 
+// ===============================================
+// 🗂 InstructionRegistry — Runtime Registry & `.logos` Loading
+// ===============================================
+// `get_instruction_registry()` remains the canonical, compiled-in scroll.
+// `InstructionRegistry` wraps that same map so instructions may also be
+// inscribed from a `.logos` (TOML) file at runtime — allowing contributors
+// who do not write Rust to add opcodes without touching this module.
+//
+// 🧭 Example `.logos` entry:
+// ```toml
+// [[instruction]]
+// keyword = "bless"
+// verse_anchor = "Gen 1:28"
+// traditional = ["INC"]
+// category = "Math/Logic"
+// description = "Increase a value or quantity."
+// opcode = 0x60
+// machine_code = "60 TT [VV]"
+// bit_mode = "Both"
+// operand_count = 2
+// operand_schema = ["Target", "Value"]
+// operand_alternatives = [["Target"]]
+// flags_effects = ["ModifiesMemory", "BlessingFlow"]
+// cycle_cost = 1
+// privilege_level = "User"
+// phase_level = "Phase1"
+// instruction_group_id = 0x60
+// ```
+//
+// Unknown flag or operand names are kept as `Custom(..)` entries.
+// A trailing `?` on an operand name marks it `Optional`.
+// `operand_alternatives` lists further accepted forms, each read the same way.
+// Unknown bit modes, privilege levels, or phases are rejected.
+
+/// 🧯 Errors raised while loading or validating an instruction registry.
+#[derive(Debug)]
+pub enum RegistryError {
+    Io(String),       // 📂 File could not be read
+    Parse(String),    // 📜 File is not valid `.logos` / TOML
+    Invalid(String),  // ❌ Field holds a value outside the schema (e.g., unknown phase)
+    Conflict(String), // ⚔️ Duplicate keyword/opcode or inconsistent operand arity
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::Io(msg) => write!(f, "Registry I/O error: {}", msg),
+            RegistryError::Parse(msg) => write!(f, "Registry parse error: {}", msg),
+            RegistryError::Invalid(msg) => write!(f, "Invalid instruction definition: {}", msg),
+            RegistryError::Conflict(msg) => write!(f, "Registry conflict: {}", msg),
+        }
+    }
+}
+
+/// 📚 `InstructionRegistry` — keyword → `Instruction` lookup table.
+///
+/// Defaults to the compiled-in Phase 1 scroll, or may be loaded
+/// from an external `.logos` / TOML definition file.
+#[derive(Debug)]
+pub struct InstructionRegistry {
+    instructions: HashMap<&'static str, Instruction>, // 🗺️ Keyword-indexed instruction table
+}
+
+impl Default for InstructionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InstructionRegistry {
+    /// 🔨 Builds a registry from the compiled-in instruction scroll.
+    pub fn new() -> Self {
+        Self {
+            instructions: get_instruction_registry(),
+        }
+    }
+
+    /// 📂 Loads instruction definitions from a `.logos` / TOML file.
+    ///
+    /// Every `[[instruction]]` table becomes an `Instruction`.
+    /// Fails on unreadable files, malformed TOML, unknown enum values,
+    /// or conflicts (duplicate keyword, duplicate opcode, arity mismatch).
+    #[cfg(feature = "std")]
+    pub fn from_toml(path: impl AsRef<Path>) -> Result<Self, RegistryError> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)
+            .map_err(|e| RegistryError::Io(format!("{}: {}", path.display(), e)))?;
+
+        Self::from_toml_str(&source)
+    }
+
+    /// 📜 Parses instruction definitions from in-memory `.logos` / TOML text.
+    #[cfg(feature = "std")]
+    pub fn from_toml_str(source: &str) -> Result<Self, RegistryError> {
+        let file: LogosFile =
+            toml::from_str(source).map_err(|e| RegistryError::Parse(e.to_string()))?;
+
+        let mut registry = Self {
+            instructions: HashMap::new(),
+        };
+
+        for def in file.instructions {
+            registry.register(def.into_instruction()?)?;
+        }
+
+        Ok(registry)
+    }
+
+    /// ➕ Registers one more instruction.
+    ///
+    /// Keywords and opcodes must both stay unique across the registry,
+    /// and each opcode must sit inside its group's reserved range.
+    pub fn register(&mut self, instruction: Instruction) -> Result<(), RegistryError> {
+        instruction.check_opcode_range()?;
+
+        if self.instructions.contains_key(instruction.keyword) {
+            return Err(RegistryError::Conflict(format!(
+                "keyword '{}' is defined more than once",
+                instruction.keyword
+            )));
+        }
+
+        if let Some(existing) = self
+            .instructions
+            .values()
+            .find(|other| other.opcode == instruction.opcode)
+        {
+            return Err(RegistryError::Conflict(format!(
+                "opcode {:#04X} is shared by '{}' and '{}'",
+                instruction.opcode, existing.keyword, instruction.keyword
+            )));
+        }
+
+        self.instructions.insert(instruction.keyword, instruction);
+        Ok(())
+    }
+
+    /// 🔍 Returns the instruction registered under `keyword`, if any.
+    pub fn get(&self, keyword: &str) -> Option<&Instruction> {
+        self.instructions.get(keyword)
+    }
+
+    /// ✅ Returns `true` if `keyword` is a registered instruction.
+    pub fn contains(&self, keyword: &str) -> bool {
+        self.instructions.contains_key(keyword)
+    }
+
+    /// 🧩 Returns the operand schema (arity and kinds) for `keyword`, if declared.
+    pub fn get_schema(&self, keyword: &str) -> Option<OperandSchema> {
+        self.get(keyword).and_then(Instruction::schema)
+    }
+
+    /// 🔢 Number of registered instructions.
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    /// 🫙 Returns `true` if no instructions are registered.
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    /// 🔁 Iterates over all `(keyword, instruction)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&&'static str, &Instruction)> {
+        self.instructions.iter()
+    }
+}
+
+/// 🤝 The compiled-in registry, built on first use and shared thereafter.
+///
+/// `get_instruction_registry()` rebuilds its map on every call; this builds
+/// it once and hands out cheap `Arc` clones that may cross threads. The
+/// Bearer and parser use it unless given a registry of their own.
+#[cfg(feature = "std")]
+pub fn shared_registry() -> Arc<InstructionRegistry> {
+    static SHARED: OnceLock<Arc<InstructionRegistry>> = OnceLock::new();
+    Arc::clone(SHARED.get_or_init(|| Arc::new(InstructionRegistry::new())))
+}
+
+// -----------------------------------------------
+// 📖 Verse Anchors — Translation Lookup Hook
+// -----------------------------------------------
+// Instructions carry only a `verse_anchor` reference (e.g., "Gen 1:3").
+// A `VerseProvider` supplies the verse text in its chosen translation
+// so help output can show the Scripture behind each instruction.
+// No verse text is bundled with Tablet.
+
+/// 📚 Bible translations recognised for verse annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Translation {
+    Kjv, // 👑 King James Version
+    Web, // 🌐 World English Bible
+}
+
+impl fmt::Display for Translation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Translation::Kjv => write!(f, "KJV"),
+            Translation::Web => write!(f, "WEB"),
+        }
+    }
+}
+
+/// 📖 Supplies verse text for a `verse_anchor` reference.
+pub trait VerseProvider {
+    /// 📚 Translation this provider quotes from.
+    fn translation(&self) -> Translation;
+
+    /// 🔍 Verse text for `reference`, or `None` if unknown.
+    fn verse(&self, reference: &str) -> Option<String>;
+}
+
+/// 🕯 Annotates an instruction with the text of its verse anchor.
+///
+/// Formats as `"<anchor> (<translation>) — <text>"`.
+/// Returns `None` when the provider has no text for the anchor.
+pub fn annotate_with_verse(instr: &Instruction, provider: &dyn VerseProvider) -> Option<String> {
+    let text = provider.verse(instr.verse_anchor())?;
+
+    Some(format!(
+        "{} ({}) — {}",
+        instr.verse_anchor(),
+        provider.translation(),
+        text
+    ))
+}
+
+// -----------------------------------------------
+// 🧮 Operand Matching — One Schema Check for Every Stage
+// -----------------------------------------------
+// The parser checks raw tokens and the Bearer checks constructed
+// operands; both ask the same question through `matches_operands`.
+// Each operand form decides which `OperandKind` slots it can fill.
+
+/// 🧮 An operand form that can be checked against an `OperandKind` slot.
+pub trait SchemaOperand {
+    /// ✅ Whether this operand may fill a slot of `kind`.
+    fn fits(&self, kind: &OperandKind) -> bool;
+
+    /// 🔤 Source text used in mismatch reports.
+    fn text(&self) -> String;
+}
+
+/// ❌ OperandMismatch — Why operands did not fit an instruction's schema
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperandMismatch {
+    /// 🔢 Wrong number of operands for the schema
+    Count { expected: usize, found: usize },
+
+    /// 🧩 An operand cannot fill its slot
+    Kind {
+        position: usize,
+        expected: OperandKind,
+        found: String,
+    },
+}
+
+impl fmt::Display for OperandMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OperandMismatch::Count { expected, found } => {
+                write!(f, "expected {} operand(s), found {}", expected, found)
+            }
+            OperandMismatch::Kind {
+                position,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {}, found '{}' at position {}",
+                expected, found, position
+            ),
+        }
+    }
+}
+
+impl core::error::Error for OperandMismatch {}
+
+impl Instruction {
+    /// 🧮 Checks operands against this instruction's `operand_schema`.
+    ///
+    /// The count is checked first, then each slot in order; the first
+    /// failure is returned. Trailing `Optional` slots may be left unfilled,
+    /// and any `operand_alternatives` form is accepted as well (see
+    /// `OperandSchema::matches`). Instructions without a schema accept anything.
+    pub fn matches_operands<T: SchemaOperand>(
+        &self,
+        operands: &[T],
+    ) -> Result<(), OperandMismatch> {
+        match self.schema() {
+            Some(schema) => schema.matches(operands),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 🧮 Checks operands against one slot list: count first, then each slot.
+fn match_form<T: SchemaOperand>(
+    schema: &[OperandKind],
+    operands: &[T],
+) -> Result<(), OperandMismatch> {
+    let required = required_operands(schema);
+    let max = max_operands(schema).unwrap_or(usize::MAX);
+    if operands.len() < required || operands.len() > max {
+        return Err(OperandMismatch::Count {
+            expected: if operands.len() < required { required } else { max },
+            found: operands.len(),
+        });
+    }
+
+    let slot = |position| slot_for(schema, position).map_or(&OperandKind::Value, OperandKind::inner);
+    match (0..operands.len()).find(|&position| !operands[position].fits(slot(position))) {
+        Some(position) => Err(OperandMismatch::Kind {
+            position,
+            expected: slot(position).clone(),
+            found: operands[position].text(),
+        }),
+        None => Ok(()),
+    }
+}
+
+// -----------------------------------------------
+// 🗺️ Opcode Ranges — Reserved Bytes per Instruction Group
+// -----------------------------------------------
+// Each `instruction_group_id` owns a block of opcodes. A group's id is
+// the first byte of its usual block, except where history placed an
+// instruction below it (`wait` at 0x00) or a group outgrew one block
+// (`if` at 0x50):
+//
+//   • 0x10 Control    → 0x00–0x1F  (wait, go, walk)
+//   • 0x20 IO         → 0x20–0x2F  (speak, hear)
+//   • 0x30 Interrupt  → 0x30–0x3F  (break)
+//   • 0x40 Logic      → 0x40–0x5F  (then, else, if)
+//   • 0x60 Math       → 0x60–0x6F  (bless, curse)
+//   • 0x70 Memory     → 0x70–0x7F  (store, recall, let)
+//   • 0xFF Structure  → 0xF0–0xFF  (end)
+//
+// 0x80–0xEF stays unreserved for instructions without a group.
+
+/// 📏 One group's reserved opcode block, inclusive at both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpcodeRange {
+    pub group_id: u8,       // 📦 `instruction_group_id` that owns the block
+    pub name: &'static str, // 🏷️ Group name for diagnostics
+    pub first: u8,          // ⬇️ Lowest opcode in the block
+    pub last: u8,           // ⬆️ Highest opcode in the block
+}
+
+impl OpcodeRange {
+    /// ✅ Returns `true` if `opcode` falls inside this block.
+    pub fn contains(&self, opcode: u8) -> bool {
+        (self.first..=self.last).contains(&opcode)
+    }
+}
+
+/// 📚 Every reserved opcode block, ordered by first opcode.
+pub const OPCODE_RANGES: &[OpcodeRange] = &[
+    OpcodeRange {
+        group_id: 0x10,
+        name: "Control",
+        first: 0x00,
+        last: 0x1F,
+    },
+    OpcodeRange {
+        group_id: 0x20,
+        name: "IO",
+        first: 0x20,
+        last: 0x2F,
+    },
+    OpcodeRange {
+        group_id: 0x30,
+        name: "Interrupt",
+        first: 0x30,
+        last: 0x3F,
+    },
+    OpcodeRange {
+        group_id: 0x40,
+        name: "Logic",
+        first: 0x40,
+        last: 0x5F,
+    },
+    OpcodeRange {
+        group_id: 0x60,
+        name: "Math",
+        first: 0x60,
+        last: 0x6F,
+    },
+    OpcodeRange {
+        group_id: 0x70,
+        name: "Memory",
+        first: 0x70,
+        last: 0x7F,
+    },
+    OpcodeRange {
+        group_id: 0xFF,
+        name: "Structure",
+        first: 0xF0,
+        last: 0xFF,
+    },
+];
+
+/// 🔍 Returns the block reserved for `group_id`, if the group is known.
+pub fn opcode_range(group_id: u8) -> Option<&'static OpcodeRange> {
+    OPCODE_RANGES.iter().find(|range| range.group_id == group_id)
+}
+
+impl Instruction {
+    /// 🗺️ Checks that the opcode sits inside its group's reserved block.
+    ///
+    /// Instructions without a group are not checked. A group id with no
+    /// reserved block is rejected as well.
+    pub fn check_opcode_range(&self) -> Result<(), RegistryError> {
+        let Some(group_id) = self.instruction_group_id else {
+            return Ok(());
+        };
+
+        let range = opcode_range(group_id).ok_or_else(|| {
+            RegistryError::Invalid(format!(
+                "'{}' names unknown instruction group {:#04X}",
+                self.keyword, group_id
+            ))
+        })?;
+
+        if range.contains(self.opcode) {
+            Ok(())
+        } else {
+            Err(RegistryError::Invalid(format!(
+                "opcode {:#04X} of '{}' is outside the {} group range {:#04X}–{:#04X}",
+                self.opcode, self.keyword, range.name, range.first, range.last
+            )))
+        }
+    }
+}
+
+// -----------------------------------------------
+// 🧬 Bytecode Encoding — Operand Widths & Byte Order
+// -----------------------------------------------
+// `machine_code` templates (e.g., "70 TT VV") show one placeholder per
+// operand but not its size. `Instruction::encode` emits the opcode byte
+// followed by each operand at a width chosen by the target `BitMode`
+// and the operand's `OperandKind`:
+//
+//   • Register                  → 1 byte (register index)
+//   • Address, Label            → pointer width (4 / 8 bytes)
+//   • Everything else           → word width    (4 / 8 bytes)
+//
+// Operands are little-endian unless the config asks for big-endian.
+// Label operands (`go`, `walk`) are byte offsets — absolute from the start
+// of the output, or relative to the jumping instruction per `JumpMode`.
+
+/// 🔀 Byte order for multi-byte operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endianness {
+    #[default]
+    Little, // ⬅️ Least significant byte first
+    Big,    // ➡️ Most significant byte first
+}
+
+/// 🦘 How a label operand becomes a jump target value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JumpMode {
+    #[default]
+    Absolute, // 📍 Label's byte offset from the start of the output
+    Relative, // ↔️ Label's offset minus the jumping instruction's own offset
+}
+
+/// ⚙️ Target settings for `Instruction::encode`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodeConfig {
+    pub bit_mode: BitMode,       // 🧠 Target architecture — `Bit32` or `Bit64`
+    pub endianness: Endianness,  // 🔀 Operand byte order
+    pub jump_mode: JumpMode,     // 🦘 Absolute or relative label targets
+}
+
+impl EncodeConfig {
+    /// 🛠️ Little-endian, absolute-jump config for the given target mode.
+    pub fn new(bit_mode: BitMode) -> Self {
+        EncodeConfig {
+            bit_mode,
+            endianness: Endianness::default(),
+            jump_mode: JumpMode::default(),
+        }
+    }
+
+    /// 🔀 Overrides the operand byte order.
+    pub fn with_endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
+    /// 🦘 Overrides how label targets are encoded.
+    pub fn with_jump_mode(mut self, jump_mode: JumpMode) -> Self {
+        self.jump_mode = jump_mode;
+        self
+    }
+}
+
+/// 🧯 Errors raised while encoding an instruction to bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncodeError {
+    UnsupportedTarget(String), // 🧠 Target mode is `Both` or not supported by the instruction
+    Arity { expected: usize, found: usize }, // 🔢 Wrong number of operand values
+    OutOfRange { value: i64, width: usize }, // 📏 Value does not fit its operand width
+    UnknownInstruction(String), // ❓ Scroll names an instruction the registry lacks
+    NonNumericOperand(String), // 🔣 Operand is symbolic, not a number
+    OrgBehind { offset: usize, at: usize }, // 📍 `.org` points before bytes already laid out
+    UnknownLabel(String), // 🏷 Jump target names no `.label` in the scroll
+    DuplicateLabel(String), // 🏷 The same `.label` is placed twice
+    ZeroAlignment, // 📐 `.align 0` names no boundary to pad to
+    ImageTooLarge { size: u64, limit: usize }, // 📏 `.org` / `.align` padding past the image cap
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::UnsupportedTarget(msg) => write!(f, "Unsupported encode target: {}", msg),
+            EncodeError::Arity { expected, found } => {
+                write!(f, "Expected {} operand(s), got {}", expected, found)
+            }
+            EncodeError::OutOfRange { value, width } => {
+                write!(f, "Value {} does not fit in {} byte(s)", value, width)
+            }
+            EncodeError::UnknownInstruction(name) => write!(f, "Unknown instruction '{}'", name),
+            EncodeError::NonNumericOperand(text) => {
+                write!(f, "Operand '{}' is not a number", text)
+            }
+            EncodeError::OrgBehind { offset, at } => {
+                write!(
+                    f,
+                    ".org {:#x} is behind the {} byte(s) already emitted",
+                    offset, at
+                )
+            }
+            EncodeError::UnknownLabel(name) => write!(f, "Unknown label '{}'", name),
+            EncodeError::DuplicateLabel(name) => {
+                write!(f, "Label '{}' is defined more than once", name)
+            }
+            EncodeError::ZeroAlignment => write!(f, ".align needs a non-zero boundary"),
+            EncodeError::ImageTooLarge { size, limit } => {
+                write!(
+                    f,
+                    "Padding to {} byte(s) exceeds the {}-byte image limit",
+                    size, limit
+                )
+            }
+        }
+    }
+}
+
+impl Instruction {
+    /// 🧬 Encodes this instruction with resolved operand values.
+    ///
+    /// Output is the opcode byte followed by each operand at the width
+    /// `config.bit_mode` assigns its `OperandKind`. Trailing `Optional`
+    /// operands may be omitted and are then not emitted; a trailing
+    /// `Variadic` slot repeats for every extra operand. The first form
+    /// (`operand_schema`, then `operand_alternatives`) whose count fits
+    /// sets the widths. Without an
+    /// `operand_schema`, `operand_count` word-width operands are expected.
+    pub fn encode(&self, operands: &[i64], config: &EncodeConfig) -> Result<Vec<u8>, EncodeError> {
+        let word = match (&config.bit_mode, &self.bit_mode) {
+            (BitMode::Both, _) => {
+                return Err(EncodeError::UnsupportedTarget(
+                    "target must be Bit32 or Bit64".to_string(),
+                ))
+            }
+            (BitMode::Bit32, BitMode::Bit64) | (BitMode::Bit64, BitMode::Bit32) => {
+                return Err(EncodeError::UnsupportedTarget(format!(
+                    "'{}' does not run in {:?}",
+                    self.keyword, config.bit_mode
+                )))
+            }
+            (BitMode::Bit32, _) => 4,
+            (BitMode::Bit64, _) => 8,
+        };
+
+        let schema = self.schema().unwrap_or_else(|| {
+            OperandSchema::new(vec![OperandKind::Value; self.operand_count.unwrap_or(0) as usize])
+        });
+        let Some(kinds) = schema.form_for(operands.len()) else {
+            let required = schema.required();
+            return Err(EncodeError::Arity {
+                expected: if operands.len() < required {
+                    required
+                } else {
+                    schema.max().unwrap_or(usize::MAX)
+                },
+                found: operands.len(),
+            });
+        };
+
+        let mut bytes = vec![self.opcode];
+        for (position, &value) in operands.iter().enumerate() {
+            let kind = slot_for(kinds, position).unwrap_or(&OperandKind::Value);
+            let width = match kind.inner() {
+                OperandKind::Register => 1,
+                _ => word,
+            };
+            bytes.extend(encode_operand(value, width, config.endianness)?);
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// 📏 Writes `value` as `width` two's-complement bytes in the given order.
+///
+/// Accepts anything representable as either a signed or unsigned
+/// integer of that width.
+fn encode_operand(value: i64, width: usize, endianness: Endianness) -> Result<Vec<u8>, EncodeError> {
+    let bits = width as u32 * 8;
+    let fits = bits >= 64 || (value >= -(1i64 << (bits - 1)) && value < (1i64 << bits));
+    if !fits {
+        return Err(EncodeError::OutOfRange { value, width });
+    }
+
+    let little = &value.to_le_bytes()[..width];
+    Ok(match endianness {
+        Endianness::Little => little.to_vec(),
+        Endianness::Big => little.iter().rev().copied().collect(),
+    })
+}
+
+// -----------------------------------------------
+// 📥 `.logos` Definition Schema — Deserialization Layer
+// -----------------------------------------------
+// Field names mirror `Instruction` so a `.logos` entry reads like
+// the Rust literal it replaces. Enum-valued fields are written as strings.
+
+#[cfg(feature = "std")]
+#[derive(Debug, Deserialize)]
+struct LogosFile {
+    #[serde(default, rename = "instruction")]
+    instructions: Vec<InstructionDef>, // 📜 One entry per `[[instruction]]` table
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Deserialize)]
+struct InstructionDef {
+    keyword: String,
+    verse_anchor: String,
+    #[serde(default)]
+    traditional: Vec<String>,
+    category: String,
+    description: String,
+    opcode: u8,
+    machine_code: String,
+    #[serde(default = "default_bit_mode")]
+    bit_mode: String,
+    operand_count: Option<u8>,
+    operand_schema: Option<Vec<String>>,
+    operand_alternatives: Option<Vec<Vec<String>>>,
+    flags_effects: Option<Vec<String>>,
+    cycle_cost: Option<u16>,
+    privilege_level: Option<String>,
+    instruction_group_id: Option<u8>,
+    phase_level: Option<String>,
+}
+
+#[cfg(feature = "std")]
+fn default_bit_mode() -> String {
+    "Both".to_string()
+}
+
+#[cfg(feature = "std")]
+impl InstructionDef {
+    /// 🔄 Converts a raw definition into a registry-ready `Instruction`.
+    ///
+    /// Strings are leaked into `'static` storage — registries are loaded
+    /// once per session and live for the remainder of the program.
+    fn into_instruction(self) -> Result<Instruction, RegistryError> {
+        if self.keyword.trim().is_empty() {
+            return Err(RegistryError::Invalid("instruction keyword is empty".into()));
+        }
+
+        let operand_schema = self
+            .operand_schema
+            .map(|kinds| kinds.iter().map(|k| parse_operand_kind(k)).collect::<Vec<_>>());
+
+        // 🧮 Declared arity must agree with the operand schema when both are given
+        if let (Some(count), Some(schema)) = (self.operand_count, &operand_schema) {
+            if count as usize != schema.len() {
+                return Err(RegistryError::Conflict(format!(
+                    "'{}' declares {} operands but its schema lists {}",
+                    self.keyword,
+                    count,
+                    schema.len()
+                )));
+            }
+        }
+
+        let traditional: Vec<&'static str> = self.traditional.into_iter().map(leak).collect();
+
+        Ok(Instruction {
+            keyword: leak(self.keyword),
+            verse_anchor: leak(self.verse_anchor),
+            traditional: Box::leak(traditional.into_boxed_slice()),
+            category: leak(self.category),
+            description: leak(self.description),
+            opcode: self.opcode,
+            machine_code: leak(self.machine_code),
+            bit_mode: parse_bit_mode(&self.bit_mode)?,
+
+            operand_count: self.operand_count,
+            operand_schema,
+            operand_alternatives: self.operand_alternatives.map(|forms| {
+                forms
+                    .iter()
+                    .map(|kinds| kinds.iter().map(|k| parse_operand_kind(k)).collect())
+                    .collect()
+            }),
+
+            flags_effects: self
+                .flags_effects
+                .map(|flags| flags.iter().map(|f| parse_flag_effect(f)).collect()),
+            cycle_cost: self.cycle_cost,
+
+            privilege_level: self
+                .privilege_level
+                .as_deref()
+                .map(parse_privilege_level)
+                .transpose()?,
+            instruction_group_id: self.instruction_group_id,
+            phase_level: self.phase_level.as_deref().map(parse_phase_level).transpose()?,
+        })
+    }
+}
+
+// -----------------------------------------------
+// 🔤 Enum Readers — String → Schema Variant
+// -----------------------------------------------
+
+#[cfg(feature = "std")]
+fn leak(value: String) -> &'static str {
+    Box::leak(value.into_boxed_str())
+}
+
+#[cfg(feature = "std")]
+fn parse_bit_mode(value: &str) -> Result<BitMode, RegistryError> {
+    match value {
+        "Bit32" => Ok(BitMode::Bit32),
+        "Bit64" => Ok(BitMode::Bit64),
+        "Both" => Ok(BitMode::Both),
+        other => Err(RegistryError::Invalid(format!("unknown bit_mode '{}'", other))),
+    }
+}
+
+#[cfg(feature = "std")]
+fn parse_flag_effect(value: &str) -> FlagEffect {
+    match value {
+        "SetsZero" => FlagEffect::SetsZero,
+        "SetsCarry" => FlagEffect::SetsCarry,
+        "ModifiesMemory" => FlagEffect::ModifiesMemory,
+        "AltersFlow" => FlagEffect::AltersFlow,
+        "SetsCondition" => FlagEffect::SetsCondition,
+        "EndsFlow" => FlagEffect::EndsFlow,
+        custom => FlagEffect::Custom(leak(custom.to_string())),
+    }
+}
+
+#[cfg(feature = "std")]
+fn parse_operand_kind(value: &str) -> OperandKind {
+    // ❔ `"Value?"` marks a slot that may be left off
+    if let Some(inner) = value.strip_suffix('?') {
+        return OperandKind::Optional(Box::leak(Box::new(parse_operand_kind(inner))));
+    }
+
+    // 🔁 `"Value..."` marks a last slot that takes every remaining operand
+    if let Some(inner) = value.strip_suffix("...") {
+        return OperandKind::Variadic(Box::leak(Box::new(parse_operand_kind(inner))));
+    }
+
+    match value {
+        "Identifier" => OperandKind::Identifier,
+        "Literal" => OperandKind::Literal,
+        "Register" => OperandKind::Register,
+        "Address" => OperandKind::Address,
+        "Label" => OperandKind::Label,
+        "Target" => OperandKind::Target,
+        "Value" => OperandKind::Value,
+        custom => OperandKind::Custom(leak(custom.to_string())),
+    }
+}
+
+#[cfg(feature = "std")]
+fn parse_privilege_level(value: &str) -> Result<PrivilegeLevel, RegistryError> {
+    match value {
+        "User" => Ok(PrivilegeLevel::User),
+        "Kernel" => Ok(PrivilegeLevel::Kernel),
+        "Root" => Ok(PrivilegeLevel::Root),
+        "Divine" => Ok(PrivilegeLevel::Divine),
+        other => Err(RegistryError::Invalid(format!("unknown privilege_level '{}'", other))),
+    }
+}
+
+#[cfg(feature = "std")]
+fn parse_phase_level(value: &str) -> Result<PhaseLevel, RegistryError> {
+    match value {
+        "Phase1" => Ok(PhaseLevel::Phase1),
+        "Phase2" => Ok(PhaseLevel::Phase2),
+        "Phase3" => Ok(PhaseLevel::Phase3),
+        "Phase4" => Ok(PhaseLevel::Phase4),
+        "Phase5" => Ok(PhaseLevel::Phase5),
+        "Phase6" => Ok(PhaseLevel::Phase6),
+        other => Err(RegistryError::Invalid(format!("unknown phase_level '{}'", other))),
+    }
+}
+
 // ===================================================
 // 🔚 Closing Block — Instruction Registry Output & Scroll Integrity
 // ===================================================
@@ -0,0 +1,1109 @@
+// ===============================================
+// 📜 Metadata — Interpreter v0.0.1 (Tablet Scroll Runtime)
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-15
+// _last updated_:  2025-06-15
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Interpreter (ScrollTree execution)
+// _project_:       OmniCode / Millennium OS
+// _description_:   Walks a ScrollTree and executes its instructions against an injected IO channel.
+//
+// _notes_:
+// - All terminal IO flows through `IoChannel` — the interpreter never touches stdio directly
+// - `MemoryChannel` is the scripted double used by tests and automated scrolls
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+use std::cmp::Ordering; // ⚖️ `if` comparison results
+use std::collections::{BTreeMap, HashMap, VecDeque}; // 🧺 Bindings, snapshots, and queued input
+use std::fmt; // 🧾 Display for runtime errors
+use std::io::{self, BufRead, Write}; // 🖥 Stdio channel
+use std::thread; // ⏳ Real sleeps for `wait`
+use std::time::Duration; // ⏳ `wait` durations
+
+use crate::operand_resolver::{is_trust_annotation, parse_duration, BindingScope}; // ⏳ `wait` operands, 🧭 frame scope reporting, 🏷 `@trusted`
+use crate::parser::{split_named_argument, unquote_text, ScrollNode, ScrollTree}; // 🌳 Executable input, 🏷 `name: value` call arguments, 🔤 quoted text
+
+// ===============================================
+// 📦 Foundational Declarations — IO Channels
+// ===============================================
+
+/// 🔌 IoChannel — Where `speak` writes, `hear` reads, and `wait` pauses
+///
+/// `read_line` returns `None` once input is exhausted. `sleep` blocks the
+/// thread by default; doubles may record the pause instead.
+pub trait IoChannel {
+    fn write(&mut self, s: &str);
+    fn read_line(&mut self) -> Option<String>;
+
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// 🖥 StdioChannel — Default channel bound to the process terminal
+#[derive(Debug, Default)]
+pub struct StdioChannel;
+
+impl IoChannel for StdioChannel {
+    fn write(&mut self, s: &str) {
+        print!("{}", s);
+        let _ = io::stdout().flush();
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        let mut line = String::new();
+        match io::stdin().lock().read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line.trim_end_matches(['\r', '\n']).to_string()),
+        }
+    }
+}
+
+/// 🧪 MemoryChannel — Scripted input queue + captured output
+#[derive(Debug, Default, Clone)]
+pub struct MemoryChannel {
+    pub input: VecDeque<String>, // 📥 Lines handed to `hear`, front first
+    pub output: String,          // 📤 Everything written by `speak`
+    pub slept: Duration,         // ⏳ Total time `wait` asked for — simulated, never slept
+}
+
+impl MemoryChannel {
+    /// 🛠️ Creates a channel with the given lines queued for `hear`
+    pub fn with_input<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        MemoryChannel {
+            input: lines.into_iter().map(Into::into).collect(),
+            output: String::new(),
+            slept: Duration::ZERO,
+        }
+    }
+
+    /// 📥 Queues one more line behind any already waiting for `hear`
+    pub fn push_input(&mut self, line: impl Into<String>) {
+        self.input.push_back(line.into());
+    }
+}
+
+impl IoChannel for MemoryChannel {
+    fn write(&mut self, s: &str) {
+        self.output.push_str(s);
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        self.input.pop_front()
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        self.slept += duration;
+    }
+}
+
+// ===============================================
+// 📦 Foundational Declarations — Runtime Values
+// ===============================================
+
+/// 🔢 Value — A runtime binding's current value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(i64),      // 🔢 Whole number — the only type `bless`/`curse` accept
+    Text(String),      // 🔤 Anything else, kept verbatim
+    Tuple(Vec<Value>), // 🧺 `return (a, b)` result; `()` for a bare `return`
+}
+
+impl Value {
+    /// 🧬 Infers a value from raw text: integers parse, everything else is text.
+    pub fn infer(raw: &str) -> Self {
+        raw.parse::<i64>()
+            .map(Value::Integer)
+            .unwrap_or_else(|_| Value::Text(raw.to_string()))
+    }
+
+    /// 🏷 Name of this value's type, for error messages.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Integer(_) => "integer",
+            Value::Text(_) => "text",
+            Value::Tuple(_) => "tuple",
+        }
+    }
+}
+
+/// 🔀 CoercionPolicy — Whether `+` may mix integers and text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoercionPolicy {
+    #[default]
+    Strict,    // 🛑 `"a" + 1` is an error
+    Stringify, // 🔤 `"a" + 1` concatenates as `"a1"`
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Integer(n) => write!(f, "{}", n),
+            Value::Text(text) => write!(f, "{}", text),
+            Value::Tuple(items) => {
+                let items: Vec<String> = items.iter().map(Value::to_string).collect();
+                write!(f, "({})", items.join(", "))
+            }
+        }
+    }
+}
+
+// ===============================================
+// 📦 Foundational Declarations — Conditions
+// ===============================================
+
+/// ⚖️ Comparator — The relation an `if` tests between its operands
+///
+/// Written infix (`if a <= b`); a bare `if a b` tests equality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    Eq, // `==`
+    Ne, // `!=`
+    Lt, // `<`
+    Le, // `<=`
+    Gt, // `>`
+    Ge, // `>=`
+}
+
+impl Comparator {
+    /// 🔣 Maps an operator token to its comparator, if it is one.
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "==" => Some(Comparator::Eq),
+            "!=" => Some(Comparator::Ne),
+            "<" => Some(Comparator::Lt),
+            "<=" => Some(Comparator::Le),
+            ">" => Some(Comparator::Gt),
+            ">=" => Some(Comparator::Ge),
+            _ => None,
+        }
+    }
+
+    /// ✅ Whether an ordering satisfies this comparator.
+    pub fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Comparator::Eq => ordering.is_eq(),
+            Comparator::Ne => ordering.is_ne(),
+            Comparator::Lt => ordering.is_lt(),
+            Comparator::Le => ordering.is_le(),
+            Comparator::Gt => ordering.is_gt(),
+            Comparator::Ge => ordering.is_ge(),
+        }
+    }
+}
+
+/// ⚖️ Orders two values.
+///
+/// Two integers compare numerically. Any other pairing compares the
+/// values' text lexicographically (byte order), so `"10" < "9"` and a
+/// mixed `10` vs `"apple"` is ordered as `"10"` vs `"apple"`.
+pub fn compare(left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+        _ => left.to_string().cmp(&right.to_string()),
+    }
+}
+
+/// 🧭 Evaluates `left op right` under the `compare` ordering rules.
+pub fn eval_condition(left: &Value, op: Comparator, right: &Value) -> bool {
+    op.holds(compare(left, right))
+}
+
+/// ➕ Evaluates `left + right`, dispatching on the operand types.
+///
+/// Two integers add (overflow is an error); two texts concatenate.
+/// An integer and a text concatenate only under `CoercionPolicy::Stringify`;
+/// tuples never combine.
+pub fn add(left: &Value, right: &Value, policy: CoercionPolicy) -> Result<Value, RuntimeError> {
+    match (left, right) {
+        (Value::Integer(a), Value::Integer(b)) => a
+            .checked_add(*b)
+            .map(Value::Integer)
+            .ok_or_else(|| RuntimeError::Overflow {
+                instruction: "+".to_string(),
+            }),
+        (Value::Text(a), Value::Text(b)) => Ok(Value::Text(format!("{}{}", a, b))),
+        (Value::Integer(_), Value::Text(_)) | (Value::Text(_), Value::Integer(_))
+            if policy == CoercionPolicy::Stringify =>
+        {
+            Ok(Value::Text(format!("{}{}", left, right)))
+        }
+        _ => Err(RuntimeError::IncompatibleOperands {
+            operator: "+".to_string(),
+            left: left.clone(),
+            right: right.clone(),
+        }),
+    }
+}
+
+// ===============================================
+// 📦 Foundational Declarations — Runtime Errors
+// ===============================================
+
+/// ❌ RuntimeError — Why execution stopped
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuntimeError {
+    /// 📭 `hear` found no input left on the channel
+    InputExhausted { target: String },
+
+    /// 🕳 An instruction was missing a required operand
+    MissingOperand { instruction: String },
+
+    /// 🔢 Arithmetic on a binding (or amount) that is not an integer
+    NonNumeric {
+        instruction: String,
+        operand: String,
+    },
+
+    /// 🕳 A binding was used before it was given a value
+    Unbound { name: String },
+
+    /// 🌊 Arithmetic overflowed `i64`
+    Overflow { instruction: String },
+
+    /// 📞 A call passed a different number of arguments than the function declares
+    InvalidArgument {
+        function: String,
+        expected: usize,
+        found: usize,
+    },
+
+    /// 🏷 A named argument matched none of the function's parameters
+    UnknownParameter { function: String, name: String },
+
+    /// 🏷 A parameter was given a value twice (named twice, or named and positional)
+    DuplicateArgument { function: String, name: String },
+
+    /// 🧭 `then` / `else` ran before any `if` set the condition flag
+    NoCondition { instruction: String },
+
+    /// ⚖️ An infix `if` used an operator that is not a comparison
+    UnknownComparator {
+        instruction: String,
+        operator: String,
+    },
+
+    /// 🧺 `a, b = value` where the value does not hold that many items
+    TupleMismatch {
+        target: String,
+        expected: usize,
+        found: usize,
+    },
+
+    /// 🛑 The run executed more than `max_steps` nodes (e.g., a runaway loop)
+    StepLimit { limit: usize },
+
+    /// 🛑 A call would nest deeper than `max_call_depth` (e.g., runaway recursion)
+    CallDepth { function: String, limit: usize },
+
+    /// ⏳ `wait` was given something other than a duration like `5s`
+    InvalidDuration {
+        instruction: String,
+        operand: String,
+    },
+
+    /// ➕ An operator was applied to values it cannot combine (e.g., `"a" + 1`)
+    IncompatibleOperands {
+        operator: String,
+        left: Value,
+        right: Value,
+    },
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuntimeError::InputExhausted { target } => {
+                write!(f, "no input available for '{}'", target)
+            }
+            RuntimeError::MissingOperand { instruction } => {
+                write!(f, "'{}' is missing its operand", instruction)
+            }
+            RuntimeError::NonNumeric {
+                instruction,
+                operand,
+            } => write!(
+                f,
+                "'{}' needs an integer, but '{}' is not one",
+                instruction, operand
+            ),
+            RuntimeError::Unbound { name } => write!(f, "'{}' has no value", name),
+            RuntimeError::Overflow { instruction } => {
+                write!(f, "'{}' overflowed the integer range", instruction)
+            }
+            RuntimeError::InvalidArgument {
+                function,
+                expected,
+                found,
+            } => write!(
+                f,
+                "'{}' expects {} argument(s), but {} were given",
+                function, expected, found
+            ),
+            RuntimeError::UnknownParameter { function, name } => {
+                write!(f, "'{}' has no parameter named '{}'", function, name)
+            }
+            RuntimeError::DuplicateArgument { function, name } => {
+                write!(f, "'{}' was given '{}' more than once", function, name)
+            }
+            RuntimeError::NoCondition { instruction } => {
+                write!(f, "'{}' has no preceding 'if' to branch on", instruction)
+            }
+            RuntimeError::UnknownComparator {
+                instruction,
+                operator,
+            } => write!(
+                f,
+                "'{}' cannot compare with '{}' (expected ==, !=, <, <=, >, >=)",
+                instruction, operator
+            ),
+            RuntimeError::TupleMismatch {
+                target,
+                expected,
+                found,
+            } => write!(
+                f,
+                "'{}' binds {} value(s), but {} were given",
+                target, expected, found
+            ),
+            RuntimeError::StepLimit { limit } => {
+                write!(f, "execution exceeded the limit of {} steps", limit)
+            }
+            RuntimeError::CallDepth { function, limit } => write!(
+                f,
+                "calling '{}' would exceed the call depth limit of {}",
+                function, limit
+            ),
+            RuntimeError::InvalidDuration {
+                instruction,
+                operand,
+            } => write!(
+                f,
+                "'{}' needs a duration like 5s or 200ms, but '{}' is not one",
+                instruction, operand
+            ),
+            RuntimeError::IncompatibleOperands {
+                operator,
+                left,
+                right,
+            } => write!(
+                f,
+                "'{}' cannot combine {} '{}' with {} '{}'",
+                operator,
+                left.type_name(),
+                left,
+                right.type_name(),
+                right
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RuntimeError {}
+
+// ===============================================
+// 🔧 Body — Interpreter
+// ===============================================
+
+/// 👣 Step — One top-level statement executed by `Interpreter::step`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub node: ScrollNode,                  // 🧩 The statement just stepped over
+    pub skipped: bool,                     // 🔀 True when a failed `then` / `else` skipped it
+    pub bindings: BTreeMap<String, Value>, // 📸 Visible bindings afterwards, sorted by name
+}
+
+/// 🏷 Named call arguments as `(param, value)` pairs
+type NamedArgs = Vec<(String, String)>;
+
+/// 🛤 A `walk`-defined function as the interpreter stores it
+#[derive(Debug, Clone)]
+struct FunctionDef {
+    params: Vec<String>,   // 🏷️ Parameter names, in call order
+    body: Vec<ScrollNode>, // 🧱 Executed in a fresh `Local` frame per call
+}
+
+/// 🕯 Interpreter — Executes ScrollTrees against an `IoChannel`
+///
+/// Bindings live in a stack of frames mirroring `BindingEnvironment`:
+/// the root frame is `Global`, and each function call pushes a `Local`
+/// frame holding its parameters that is discarded when the call returns.
+/// A `{ ... }` block likewise runs in its own `Local` frame, so names it
+/// declares end with it while assignments to outer names still land.
+///
+/// `if a b` compares its operands into the condition flag (equality), and
+/// `if a <op> b` tests any `Comparator`; a following `then` runs the next
+/// node only when the condition held, `else` only when it did not.
+///
+/// `return` ends the running function with a value (a `Tuple` for
+/// `return (a, b)`), which `x = f()` binds and `a, b = f()` unpacks.
+///
+/// `while` bodies repeat while their condition holds. Every executed node
+/// and loop pass counts toward `max_steps`, and calls may nest at most
+/// `max_call_depth` deep, so runaway scrolls end in an error, not a hang.
+///
+/// `let x = a + b` and `x = a + b` sum their operands with `add`; the
+/// `coercion` policy decides whether integers and text may mix.
+#[derive(Debug)]
+pub struct Interpreter<C: IoChannel = StdioChannel> {
+    io: C,                                   // 🔌 Injected IO channel
+    frames: Vec<HashMap<String, Value>>,     // 🧷 Runtime values, root (Global) first
+    functions: HashMap<String, FunctionDef>, // 🛤 Defined functions by name
+    comparison: Option<Ordering>,            // ⚖️ Ordering found by the latest `if`
+    condition: Option<bool>,                 // 🧭 Condition flag from the latest `if`
+    branch: Option<bool>,                    // 🔀 Pending `then`/`else` verdict for the next node
+    returning: Option<Value>,                // 🔚 Value of a `return` unwinding the current call
+    program: Vec<ScrollNode>,                // 👣 Top-level nodes loaded for stepping
+    cursor: usize,                           // 📍 Index of the next node `step` runs
+    steps: usize,                            // 🧮 Nodes executed since the last `run` / `load`
+    max_steps: usize,                        // 🛑 Ceiling on `steps`
+    call_depth: usize,                       // 📞 Calls currently open (blocks not counted)
+    max_call_depth: usize,                   // 🛑 Ceiling on `call_depth`
+    coercion: CoercionPolicy,                // 🔀 Whether `+` may mix integers and text
+}
+
+/// 🛑 Default `max_steps` — generous for real scrolls, finite for runaway ones
+pub const DEFAULT_MAX_STEPS: usize = 1_000_000;
+
+/// 🛑 Default `max_call_depth` — each call costs several kilobytes of
+/// native stack, and 64 fits a 2 MiB thread stack in a debug build.
+/// Raise it only on a thread spawned with a larger stack.
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 64;
+
+impl<C: IoChannel + Default> Default for Interpreter<C> {
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<C: IoChannel> Interpreter<C> {
+    /// 🛠️ Creates an interpreter bound to the given channel
+    pub fn new(io: C) -> Self {
+        Interpreter {
+            io,
+            frames: vec![HashMap::new()],
+            functions: HashMap::new(),
+            comparison: None,
+            condition: None,
+            branch: None,
+            returning: None,
+            program: Vec::new(),
+            cursor: 0,
+            steps: 0,
+            max_steps: DEFAULT_MAX_STEPS,
+            call_depth: 0,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            coercion: CoercionPolicy::default(),
+        }
+    }
+
+    /// 🛑 Caps how many nodes (and loop passes) one run may execute.
+    pub fn with_max_steps(mut self, limit: usize) -> Self {
+        self.max_steps = limit;
+        self
+    }
+
+    /// 🛑 Caps how deeply function calls may nest.
+    pub fn with_max_call_depth(mut self, limit: usize) -> Self {
+        self.max_call_depth = limit;
+        self
+    }
+
+    /// 🔀 Sets whether `+` may mix integers and text.
+    pub fn with_coercion(mut self, policy: CoercionPolicy) -> Self {
+        self.coercion = policy;
+        self
+    }
+
+    /// 🔌 Borrows the IO channel (e.g. to inspect captured output)
+    pub fn io(&self) -> &C {
+        &self.io
+    }
+
+    /// 🔌 Mutably borrows the IO channel (e.g. to queue more input between runs)
+    pub fn io_mut(&mut self) -> &mut C {
+        &mut self.io
+    }
+
+    /// 🔌 Releases the IO channel
+    pub fn into_io(self) -> C {
+        self.io
+    }
+
+    /// 🔍 Current runtime value of a binding, innermost frame first
+    pub fn value(&self, name: &str) -> Option<&Value> {
+        self.frames.iter().rev().find_map(|frame| frame.get(name))
+    }
+
+    /// ⚖️ How the latest `if` compared its first operand to its second.
+    pub fn comparison(&self) -> Option<Ordering> {
+        self.comparison
+    }
+
+    /// 🧭 The condition flag: `true` when the latest `if` held.
+    pub fn condition(&self) -> Option<bool> {
+        self.condition
+    }
+
+    /// 🧭 Scope of the frame currently holding `name`.
+    pub fn scope_of(&self, name: &str) -> Option<BindingScope> {
+        let depth = self
+            .frames
+            .iter()
+            .rposition(|frame| frame.contains_key(name))?;
+        Some(if depth == 0 {
+            BindingScope::Global
+        } else {
+            BindingScope::Local
+        })
+    }
+
+    /// 📸 Every visible binding by name; inner frames shadow outer ones.
+    pub fn snapshot(&self) -> BTreeMap<String, Value> {
+        self.frames
+            .iter()
+            .flat_map(|frame| frame.iter())
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect()
+    }
+
+    /// ✒️ Assigns `name`, updating the nearest frame that already holds it,
+    /// otherwise declaring it in the innermost frame.
+    fn bind(&mut self, name: &str, value: Value) {
+        let frame = match self
+            .frames
+            .iter()
+            .rposition(|frame| frame.contains_key(name))
+        {
+            Some(depth) => &mut self.frames[depth],
+            None => self.frames.last_mut().expect("root frame is never popped"),
+        };
+        frame.insert(name.to_string(), value);
+    }
+
+    /// ▶️ Executes every node in the tree, stopping at the first runtime error.
+    /// A top-level `return` ends the run early.
+    pub fn run(&mut self, tree: &ScrollTree) -> Result<(), RuntimeError> {
+        self.steps = 0;
+        let result = self.execute_nodes(&tree.nodes);
+        self.returning = None;
+        result
+    }
+
+    /// 👣 Loads a tree for stepping, rewinding to its first statement.
+    /// Bindings and functions from earlier runs are kept.
+    pub fn load(&mut self, tree: &ScrollTree) {
+        self.program = tree.nodes.clone();
+        self.cursor = 0;
+        self.steps = 0;
+        self.returning = None;
+    }
+
+    /// 👣 Executes the next top-level statement of the loaded tree.
+    ///
+    /// Returns the statement with a snapshot of the bindings afterwards, or
+    /// `None` once the tree is finished. A top-level `return` finishes it
+    /// early. Nested bodies and calls run to completion within one step.
+    pub fn step(&mut self) -> Result<Option<Step>, RuntimeError> {
+        let Some(node) = self.program.get(self.cursor).cloned() else {
+            return Ok(None);
+        };
+        self.cursor += 1;
+
+        let skipped = self.branch.take() == Some(false);
+        if !skipped {
+            self.execute_node(&node)?;
+        }
+
+        if self.returning.take().is_some() {
+            self.cursor = self.program.len();
+        }
+
+        Ok(Some(Step {
+            node,
+            skipped,
+            bindings: self.snapshot(),
+        }))
+    }
+
+    /// 🧾 Steps through the whole tree, collecting one `Step` per statement.
+    pub fn run_traced(&mut self, tree: &ScrollTree) -> Result<Vec<Step>, RuntimeError> {
+        self.load(tree);
+
+        let mut trace = Vec::new();
+        while let Some(step) = self.step()? {
+            trace.push(step);
+        }
+        Ok(trace)
+    }
+
+    fn execute_nodes(&mut self, nodes: &[ScrollNode]) -> Result<(), RuntimeError> {
+        for node in nodes {
+            // 🔀 A pending `then` / `else` decides whether this node runs
+            if self.branch.take() == Some(false) {
+                continue;
+            }
+            self.execute_node(node)?;
+
+            // 🔚 A `return` skips the rest of the body, nested blocks included
+            if self.returning.is_some() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// 🧮 Counts one unit of work against `max_steps`.
+    fn tick(&mut self) -> Result<(), RuntimeError> {
+        self.steps += 1;
+        if self.steps > self.max_steps {
+            return Err(RuntimeError::StepLimit {
+                limit: self.max_steps,
+            });
+        }
+        Ok(())
+    }
+
+    fn execute_node(&mut self, node: &ScrollNode) -> Result<(), RuntimeError> {
+        self.tick()?;
+
+        match node {
+            ScrollNode::Instruction { name, args } => {
+                // 🏷 Trust annotations inform the Bearer only; nothing runs them
+                let args: Vec<String> = args
+                    .iter()
+                    .filter(|arg| !is_trust_annotation(arg))
+                    .cloned()
+                    .collect();
+                self.execute_instruction(name, &args)
+            }
+            ScrollNode::Assignment { target, value } => {
+                let value = match self.call_text(value) {
+                    Some((function, args, named)) => self.call(function, &args, &named)?,
+                    None => self.sum(split_top_level(value, " + "))?,
+                };
+                self.bind_targets(target, value)
+            }
+            ScrollNode::Return(value) => {
+                let value = match value.as_str() {
+                    "" => Value::Tuple(Vec::new()),
+                    value => self.evaluate(value),
+                };
+                self.returning = Some(value);
+                Ok(())
+            }
+            ScrollNode::Block(body) => {
+                // 🧱 Block-local names are dropped with the frame
+                self.frames.push(HashMap::new());
+                let result = self.execute_nodes(body);
+                self.frames.pop();
+                result
+            }
+            ScrollNode::Loop { condition, body } => {
+                while self.holds(condition)? {
+                    self.execute_nodes(body)?;
+                    if self.returning.is_some() {
+                        break; // 🔚 A `return` inside the loop ends it
+                    }
+                    self.tick()?; // 🔁 Each pass counts, even with an empty body
+                }
+                Ok(())
+            }
+            ScrollNode::Function { name, params, body } => {
+                // 🛤 Definitions register the function; the body runs only when called
+                self.functions.insert(
+                    name.clone(),
+                    FunctionDef {
+                        params: params.clone(),
+                        body: body.clone(),
+                    },
+                );
+                Ok(())
+            }
+            ScrollNode::Call {
+                function,
+                args,
+                named,
+            } => {
+                if !self.functions.contains_key(function) {
+                    return Err(RuntimeError::Unbound {
+                        name: function.clone(),
+                    });
+                }
+                self.call(function, args, named).map(|_| ())
+            }
+            // 🚧 Declarations, block conditionals, and metadata carry no runtime effect yet
+            _ => Ok(()),
+        }
+    }
+
+    fn execute_instruction(&mut self, name: &str, args: &[String]) -> Result<(), RuntimeError> {
+        match name {
+            "speak" => {
+                // 🗣 `speak "score:", x` — every operand, joined by spaces
+                Self::operand(name, args, 0)?;
+                let text: Vec<String> = args
+                    .iter()
+                    .map(|arg| self.evaluate(arg).to_string())
+                    .collect();
+                self.io.write(&format!("{}\n", text.join(" ")));
+                Ok(())
+            }
+            "let" => {
+                // 📦 `let x = 5` or `let x 5` — the `=` is optional; `let x = a + b` sums
+                let target = Self::operand(name, args, 0)?;
+                let start = match args.get(1).map(String::as_str) {
+                    Some("=") => 2,
+                    _ => 1,
+                };
+                let first = Self::operand(name, args, start)?;
+                let terms = args[start + 1..]
+                    .chunks_exact(2)
+                    .take_while(|pair| pair[0] == "+")
+                    .map(|pair| pair[1].as_str());
+                let value = self.sum(std::iter::once(first.as_str()).chain(terms))?;
+                self.bind(target, value);
+                Ok(())
+            }
+            "walk" => match args.split_first() {
+                // 📞 `walk name args...` calls a defined function; other walks have no effect yet
+                Some((function, rest)) if self.functions.contains_key(function) => {
+                    self.call(function, rest, &[]).map(|_| ())
+                }
+                _ => Ok(()),
+            },
+            "if" => {
+                // ⚖️ `if a b` tests equality; `if a <op> b` names the comparator
+                let (op, right) = match args.len() {
+                    0..=2 => (Comparator::Eq, Self::operand(name, args, 1)?),
+                    _ => {
+                        let op = Comparator::from_symbol(&args[1]).ok_or_else(|| {
+                            RuntimeError::UnknownComparator {
+                                instruction: name.to_string(),
+                                operator: args[1].clone(),
+                            }
+                        })?;
+                        (op, &args[2])
+                    }
+                };
+                let left = self.evaluate(Self::operand(name, args, 0)?);
+                let right = self.evaluate(right);
+                let ordering = compare(&left, &right);
+                self.comparison = Some(ordering);
+                self.condition = Some(op.holds(ordering));
+                Ok(())
+            }
+            "then" | "else" => {
+                let condition = self.condition().ok_or_else(|| RuntimeError::NoCondition {
+                    instruction: name.to_string(),
+                })?;
+                self.branch = Some(condition == (name == "then"));
+                Ok(())
+            }
+            "wait" => {
+                // ⏳ A bare `wait` is a minimal pause; `wait 5s` names its length
+                let duration = match args.first() {
+                    Some(raw) => parse_duration(raw)
+                        .or_else(|| parse_duration(&self.evaluate(raw).to_string()))
+                        .ok_or_else(|| RuntimeError::InvalidDuration {
+                            instruction: name.to_string(),
+                            operand: raw.clone(),
+                        })?,
+                    None => Duration::ZERO,
+                };
+                self.io.sleep(duration);
+                Ok(())
+            }
+            "bless" => self.adjust(name, args, 1),
+            "curse" => self.adjust(name, args, -1),
+            "hear" => {
+                let target = Self::operand(name, args, 0)?;
+                let line = self
+                    .io
+                    .read_line()
+                    .ok_or_else(|| RuntimeError::InputExhausted {
+                        target: target.clone(),
+                    })?;
+                self.bind(target, Value::infer(&line));
+                Ok(())
+            }
+            // 🚧 Remaining instructions are resolved by the Bearer but not yet executed
+            _ => Ok(()),
+        }
+    }
+
+    /// ➕ `bless` / `curse` — shifts an integer binding by `direction` × amount.
+    ///
+    /// The amount is the optional second operand (default 1) and must
+    /// itself evaluate to an integer.
+    fn adjust(&mut self, name: &str, args: &[String], direction: i64) -> Result<(), RuntimeError> {
+        let target = Self::operand(name, args, 0)?;
+        let non_numeric = |operand: &str| RuntimeError::NonNumeric {
+            instruction: name.to_string(),
+            operand: operand.to_string(),
+        };
+
+        let current = match self.value(target) {
+            Some(Value::Integer(n)) => *n,
+            Some(_) => return Err(non_numeric(target)),
+            None => {
+                return Err(RuntimeError::Unbound {
+                    name: target.clone(),
+                })
+            }
+        };
+
+        let amount = match args.get(1) {
+            Some(raw) => match self.evaluate(raw) {
+                Value::Integer(n) => n,
+                _ => return Err(non_numeric(raw)),
+            },
+            None => 1,
+        };
+
+        let updated = amount
+            .checked_mul(direction)
+            .and_then(|delta| current.checked_add(delta))
+            .ok_or_else(|| RuntimeError::Overflow {
+                instruction: name.to_string(),
+            })?;
+
+        self.bind(target, Value::Integer(updated));
+        Ok(())
+    }
+
+    /// 📞 Calls a defined function: checks arity, binds each argument to its
+    /// parameter in a new `Local` frame, runs the body, then drops the frame.
+    /// Named arguments (`amount: 5`) bind by name; positional ones fill the
+    /// remaining parameters in order.
+    /// Yields the `return` value, or `()` if the body never returned one.
+    fn call(
+        &mut self,
+        function: &str,
+        args: &[String],
+        named: &[(String, String)],
+    ) -> Result<Value, RuntimeError> {
+        let def = self.functions[function].clone();
+
+        // 🛑 Blocks push frames too, so calls keep their own count
+        if self.call_depth >= self.max_call_depth {
+            return Err(RuntimeError::CallDepth {
+                function: function.to_string(),
+                limit: self.max_call_depth,
+            });
+        }
+
+        if args.len() + named.len() != def.params.len() {
+            return Err(RuntimeError::InvalidArgument {
+                function: function.to_string(),
+                expected: def.params.len(),
+                found: args.len() + named.len(),
+            });
+        }
+
+        // 🏷 Named arguments claim their parameters first
+        let mut slots: Vec<Option<&str>> = vec![None; def.params.len()];
+        for (name, value) in named {
+            let Some(position) = def.params.iter().position(|param| param == name) else {
+                return Err(RuntimeError::UnknownParameter {
+                    function: function.to_string(),
+                    name: name.to_string(),
+                });
+            };
+            if slots[position].replace(value.as_str()).is_some() {
+                return Err(RuntimeError::DuplicateArgument {
+                    function: function.to_string(),
+                    name: name.to_string(),
+                });
+            }
+        }
+
+        // 📍 Positional arguments fill what is left, in order
+        let mut positional = args.iter();
+        for slot in slots.iter_mut().filter(|slot| slot.is_none()) {
+            *slot = positional.next().map(String::as_str);
+        }
+
+        // 🧮 Arguments are evaluated in the caller's frame before the call opens
+        let frame = def
+            .params
+            .iter()
+            .zip(slots)
+            .map(|(param, arg)| (param.clone(), self.evaluate(arg.unwrap_or_default())))
+            .collect();
+
+        self.frames.push(frame);
+        self.call_depth += 1;
+        let result = self.execute_nodes(&def.body);
+        self.call_depth -= 1;
+        self.frames.pop();
+
+        let returned = self.returning.take();
+        result.map(|()| returned.unwrap_or(Value::Tuple(Vec::new())))
+    }
+
+    /// 📞 Splits `name(a, amount: 5)` call text when `name` is a defined
+    /// function, into positional and named arguments.
+    fn call_text<'a>(&self, value: &'a str) -> Option<(&'a str, Vec<String>, NamedArgs)> {
+        let (function, args) = value.strip_suffix(')')?.split_once('(')?;
+        if !self.functions.contains_key(function) {
+            return None;
+        }
+
+        let (mut positional, mut named) = (Vec::new(), Vec::new());
+        for arg in split_top_level(args, ", ").into_iter().filter(|arg| !arg.is_empty()) {
+            match split_named_argument(arg) {
+                Some((name, value)) => named.push((name.to_string(), value.to_string())),
+                None => positional.push(arg.to_string()),
+            }
+        }
+        Some((function, positional, named))
+    }
+
+    /// 🧺 Binds `value` to `target`, unpacking a tuple across `a, b` targets.
+    fn bind_targets(&mut self, target: &str, value: Value) -> Result<(), RuntimeError> {
+        let targets: Vec<&str> = target.split(", ").collect();
+        if targets.len() == 1 {
+            self.bind(target, value);
+            return Ok(());
+        }
+
+        let items = match value {
+            Value::Tuple(items) if items.len() == targets.len() => items,
+            Value::Tuple(items) => return Err(tuple_mismatch(target, targets.len(), items.len())),
+            _ => return Err(tuple_mismatch(target, targets.len(), 1)),
+        };
+
+        for (name, item) in targets.into_iter().zip(items) {
+            self.bind(name, item);
+        }
+        Ok(())
+    }
+
+    /// 🔍 The operand at `index`, or `MissingOperand` for `instruction`.
+    fn operand<'a>(
+        instruction: &str,
+        args: &'a [String],
+        index: usize,
+    ) -> Result<&'a String, RuntimeError> {
+        args.get(index).ok_or_else(|| RuntimeError::MissingOperand {
+            instruction: instruction.to_string(),
+        })
+    }
+
+    /// 🧭 Tests a `while` condition: `a <op> b` compares like an infix `if`;
+    /// a lone operand holds unless it is `0`, `false`, empty text, or `()`.
+    fn holds(&self, condition: &str) -> Result<bool, RuntimeError> {
+        let parts: Vec<&str> = condition.split_whitespace().collect();
+
+        match parts.as_slice() {
+            [operand] => Ok(match self.evaluate(operand) {
+                Value::Integer(n) => n != 0,
+                Value::Text(text) => !text.is_empty() && text != "false",
+                Value::Tuple(items) => !items.is_empty(),
+            }),
+            [left, op, right] => {
+                let op = Comparator::from_symbol(op).ok_or_else(|| {
+                    RuntimeError::UnknownComparator {
+                        instruction: "while".to_string(),
+                        operator: op.to_string(),
+                    }
+                })?;
+                Ok(eval_condition(&self.evaluate(left), op, &self.evaluate(right)))
+            }
+            _ => Err(RuntimeError::UnknownComparator {
+                instruction: "while".to_string(),
+                operator: condition.to_string(),
+            }),
+        }
+    }
+
+    /// ➕ Evaluates each term and folds them together with `add`, left to right.
+    fn sum<'a>(&self, terms: impl IntoIterator<Item = &'a str>) -> Result<Value, RuntimeError> {
+        let mut terms = terms.into_iter().map(|term| self.evaluate(term));
+        let first = terms.next().unwrap_or(Value::Tuple(Vec::new()));
+        terms.try_fold(first, |total, term| add(&total, &term, self.coercion))
+    }
+
+    /// 🧮 Evaluates a raw operand: quoted strings are unwrapped as text,
+    /// bound names yield their value, anything else is inferred from its shape.
+    fn evaluate(&self, operand: &str) -> Value {
+        if let Some(text) = unquote_text(operand) {
+            Value::Text(text)
+        } else if let Some(inner) = operand.strip_prefix('(').and_then(|o| o.strip_suffix(')')) {
+            // 🧺 `(a, b)` — each item evaluated in turn
+            Value::Tuple(
+                split_top_level(inner, ", ")
+                    .into_iter()
+                    .filter(|item| !item.is_empty())
+                    .map(|item| self.evaluate(item))
+                    .collect(),
+            )
+        } else if let Some(value) = self.value(operand) {
+            value.clone()
+        } else {
+            Value::infer(operand)
+        }
+    }
+}
+
+/// ✂️ Splits `value` at each `separator` outside quotes (escaped `\"`
+/// included) and parentheses, so `a + "b + c" + d` has three terms under
+/// `" + "` and `"hi, there", 2` two arguments under `", "`. Text without a
+/// top-level separator is one piece.
+fn split_top_level<'a>(value: &'a str, separator: &str) -> Vec<&'a str> {
+    let mut pieces = Vec::new();
+    let (mut start, mut depth, mut quoted, mut escaped) = (0, 0usize, false, false);
+
+    for (index, c) in value.char_indices() {
+        match c {
+            _ if index < start => {} // ✂️ Still inside the separator just taken
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth = depth.saturating_sub(1),
+            _ if !quoted && depth == 0 && value[index..].starts_with(separator) => {
+                pieces.push(value[start..index].trim());
+                start = index + separator.len();
+            }
+            _ => {}
+        }
+    }
+
+    pieces.push(value[start..].trim());
+    pieces
+}
+
+/// 🧺 Builds a `TupleMismatch` for `target`.
+fn tuple_mismatch(target: &str, expected: usize, found: usize) -> RuntimeError {
+    RuntimeError::TupleMismatch {
+        target: target.to_string(),
+        expected,
+        found,
+    }
+}
+
+// ===================================================
+// 🔚 Closing — Interpreter Notes
+// ===================================================
+//
+// 🪜 Flow:
+//   ⬆️ Upstream:   Parser → ScrollTree (optionally checked by the Bearer)
+//   ⬇️ Downstream: IoChannel (terminal, GUI, or scripted test double)
+//
+// 🔮 Future:
+//   - Float and boolean runtime values
+//
+// ---------------------------------------------------
@@ -1,10 +1,36 @@
 //! Tablet — The Assembler Engine of OmniCode
 //! This module handles token resolution, operand matching, and scroll-to-bytecode logic.
+//!
+//! The tokenizer, parser, and instruction registry need only `alloc`.
+//! Everything else (file IO, the Bearer, compiler, interpreter, tracing)
+//! sits behind the default `std` feature, so embedded targets can build
+//! with `--no-default-features`. The same core builds for
+//! `wasm32-unknown-unknown`; `wasm::compile_json` is its browser entry.
+//! C hosts enable the `ffi` feature for `ffi::omni_compile_to_stone`.
+//! The `fuzzing` feature adds `fuzz`, a corpus-seeded pipeline fuzzer.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod tokenizer;
 pub mod parser;
 pub mod instruction_registry;
+pub mod macros;
+pub mod directives;
+pub mod wasm;
+#[cfg(feature = "std")]
 pub mod operand_resolver;
+#[cfg(feature = "std")]
+pub mod compiler;
+#[cfg(feature = "std")]
+pub mod interpreter;
+#[cfg(feature = "std")]
+pub mod highlight;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
 // pub mod scroll_form;
 
 pub fn tablet_status() -> &'static str {
@@ -0,0 +1,427 @@
+// ===============================================
+// 📜 Metadata — Macros v0.0.1 (Tablet Scroll Preprocessor)
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-15
+// _last updated_:  2025-06-15
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Macro Preprocessor (define → call-site expansion)
+// _project_:       OmniCode / Millennium OS
+// _description_:   Collects `define` declarations and expands their invocations before tokenizing.
+//
+// _notes_:
+// - Pure text pass — runs ahead of the Tokenizer, so the parser never sees macros
+// - `define` lines are blanked, not removed, so line numbers stay true
+// - Expansion is depth-limited; runaway recursion is an error, not a stack overflow
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+// === Standard Library (alloc-only core) ===
+use alloc::string::{String, ToString}; // 🔤 Expanded source text
+use alloc::vec::Vec; // 📚 Parameter and argument lists
+use core::fmt; // 🧾 Display formatting for `MacroError`
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap; // 🗺️ `no_std` macro table
+#[cfg(feature = "std")]
+use std::collections::HashMap; // 🗺️ Macro name → definition
+
+// === Internal Modules ===
+use crate::directives::{DirectiveError, SymbolTable}; // 🚦 `#if` pass that runs first
+
+// ===============================================
+// 📦 Foundational Declarations — Macro Definitions
+// ===============================================
+//
+// 🧭 Syntax:
+//   define greet(who) = speak who
+//   greet("Nova")              → speak "Nova"
+//
+// A macro is invoked by its name immediately followed by `(`.
+// Arguments are split on top-level commas and substituted for
+// whole-word parameter matches; string literals are left untouched.
+
+/// 📐 Default nesting limit for macro expansion
+pub const DEFAULT_MAX_DEPTH: usize = 16;
+
+/// 🧩 MacroDef — One `define` declaration
+#[derive(Debug, Clone, PartialEq)]
+pub struct MacroDef {
+    pub params: Vec<String>, // 🏷️ Parameter names, in call order
+    pub body: String,        // 📜 Replacement text
+}
+
+/// ❌ MacroError — Why preprocessing stopped
+#[derive(Debug, Clone, PartialEq)]
+pub enum MacroError {
+    /// 🧱 A `define` line did not match `define name(params) = body`
+    MalformedDefine { line: usize, text: String },
+
+    /// 🔢 An invocation passed the wrong number of arguments
+    ArityMismatch {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+
+    /// 🔓 An invocation's `(` was never closed
+    Unclosed { name: String },
+
+    /// 🌀 Expansion nested deeper than the table's limit
+    DepthExceeded { name: String, limit: usize },
+}
+
+impl fmt::Display for MacroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MacroError::MalformedDefine { line, text } => {
+                write!(f, "line {}: malformed define '{}'", line, text)
+            }
+            MacroError::ArityMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "macro '{}' expects {} argument(s), got {}",
+                name, expected, found
+            ),
+            MacroError::Unclosed { name } => {
+                write!(f, "macro '{}' invocation is missing ')'", name)
+            }
+            MacroError::DepthExceeded { name, limit } => write!(
+                f,
+                "macro '{}' exceeded the expansion depth limit of {}",
+                name, limit
+            ),
+        }
+    }
+}
+
+impl MacroError {
+    /// 📍 Line the error was found on (1-based), when it is tied to one.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            MacroError::MalformedDefine { line, .. } => Some(*line),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for MacroError {}
+
+// ===============================================
+// 🔧 Body — MacroTable & Expansion Pass
+// ===============================================
+
+/// 📚 MacroTable — Named macros plus the expansion depth limit
+#[derive(Debug, Clone)]
+pub struct MacroTable {
+    macros: HashMap<String, MacroDef>, // 🗺️ Macro name → definition
+    max_depth: usize,                  // 📐 Deepest allowed nested expansion
+}
+
+impl Default for MacroTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MacroTable {
+    /// 🔨 Creates an empty table with `DEFAULT_MAX_DEPTH`.
+    pub fn new() -> Self {
+        MacroTable {
+            macros: HashMap::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// 📐 Overrides the expansion depth limit.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// ✒️ Registers (or replaces) a macro.
+    pub fn define(&mut self, name: &str, params: Vec<String>, body: &str) {
+        self.macros.insert(
+            name.to_string(),
+            MacroDef {
+                params,
+                body: body.trim().to_string(),
+            },
+        );
+    }
+
+    /// 🔍 Looks up a macro by name.
+    pub fn get(&self, name: &str) -> Option<&MacroDef> {
+        self.macros.get(name)
+    }
+
+    /// 📜 Preprocesses a whole scroll.
+    ///
+    /// Collects every `define` line into the table (blanking the line),
+    /// then expands invocations in the remaining source.
+    pub fn expand_source(&mut self, source: &str) -> Result<String, MacroError> {
+        let mut kept = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            match line.trim_start().strip_prefix("define ") {
+                Some(rest) => {
+                    let (name, params, body) =
+                        parse_define(rest).ok_or_else(|| MacroError::MalformedDefine {
+                            line: index + 1,
+                            text: line.trim().to_string(),
+                        })?;
+                    self.define(&name, params, &body);
+                    kept.push("");
+                }
+                None => kept.push(line),
+            }
+        }
+
+        self.expand(&kept.join("\n"))
+    }
+
+    /// 🔁 Expands every macro invocation in `text`.
+    pub fn expand(&self, text: &str) -> Result<String, MacroError> {
+        self.expand_at(text, 0)
+    }
+
+    /// 🌀 Expansion at a given nesting depth.
+    fn expand_at(&self, text: &str, depth: usize) -> Result<String, MacroError> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut out = String::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+
+            // 🔤 Copy string literals through untouched
+            if c == '"' {
+                let end = closing_quote(&chars, i);
+                out.extend(&chars[i..end]);
+                i = end;
+                continue;
+            }
+
+            if !is_ident_start(c) {
+                out.push(c);
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            while i < chars.len() && is_ident_char(chars[i]) {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+
+            let Some(def) = self.macros.get(&word) else {
+                out.push_str(&word);
+                continue;
+            };
+            if chars.get(i) != Some(&'(') {
+                out.push_str(&word);
+                continue;
+            }
+
+            // 📞 Invocation — gather arguments and check arity
+            let (args, after) =
+                split_args(&chars, i).ok_or_else(|| MacroError::Unclosed { name: word.clone() })?;
+            i = after;
+
+            if args.len() != def.params.len() {
+                return Err(MacroError::ArityMismatch {
+                    name: word,
+                    expected: def.params.len(),
+                    found: args.len(),
+                });
+            }
+            if depth >= self.max_depth {
+                return Err(MacroError::DepthExceeded {
+                    name: word,
+                    limit: self.max_depth,
+                });
+            }
+
+            let substituted = substitute(&def.body, &def.params, &args);
+            out.push_str(&self.expand_at(&substituted, depth + 1)?);
+        }
+
+        Ok(out)
+    }
+}
+
+/// 📜 Preprocesses `source` with a fresh `MacroTable`.
+pub fn preprocess(source: &str) -> Result<String, MacroError> {
+    MacroTable::new().expand_source(source)
+}
+
+/// 🚦 Every text pass ahead of the tokenizer, in order: `#if` sections
+/// filtered against `symbols`, then `define` macros expanded.
+///
+/// `compiler::compile_source` and `wasm::compile_json` both start here so
+/// the two entry points see the same text. A failed pass yields empty
+/// source alongside its error.
+pub(crate) fn prepare_source(
+    source: &str,
+    symbols: &SymbolTable,
+) -> (String, Option<DirectiveError>, Option<MacroError>) {
+    let filtered = match symbols.apply(source) {
+        Ok(filtered) => filtered,
+        Err(error) => return (String::new(), Some(error), None),
+    };
+    match preprocess(&filtered) {
+        Ok(expanded) => (expanded, None, None),
+        Err(error) => (String::new(), None, Some(error)),
+    }
+}
+
+// ===============================================
+// 🧰 Helpers — Define Parsing & Text Scanning
+// ===============================================
+
+/// 🧱 Splits `name(params) = body` (after `define `) into its parts.
+///
+/// The parameter list is optional: `define amen = speak "amen"` declares
+/// a zero-parameter macro invoked as `amen()`.
+fn parse_define(rest: &str) -> Option<(String, Vec<String>, String)> {
+    let (head, body) = rest.split_once('=')?;
+    let head = head.trim();
+
+    let (name, params) = match head.split_once('(') {
+        Some((name, params)) => {
+            let params = params.trim().strip_suffix(')')?;
+            let params: Vec<String> = params
+                .split(',')
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+                .map(str::to_string)
+                .collect();
+            (name.trim(), params)
+        }
+        None => (head, Vec::new()),
+    };
+
+    let valid =
+        |s: &str| s.chars().next().is_some_and(is_ident_start) && s.chars().all(is_ident_char);
+    if !valid(name) || !params.iter().all(|p| valid(p)) {
+        return None;
+    }
+
+    Some((name.to_string(), params, body.trim().to_string()))
+}
+
+/// ✂️ Reads a parenthesised argument list starting at `open` (the `(`).
+///
+/// Returns the trimmed top-level arguments and the index after `)`,
+/// or `None` if the list is never closed.
+fn split_args(chars: &[char], open: usize) -> Option<(Vec<String>, usize)> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut i = open + 1;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '"' => {
+                let end = closing_quote(chars, i);
+                current.extend(&chars[i..end]);
+                i = end;
+                continue;
+            }
+            '(' => depth += 1,
+            ')' if depth == 0 => {
+                if !current.trim().is_empty() || !args.is_empty() {
+                    args.push(current.trim().to_string());
+                }
+                return Some((args, i + 1));
+            }
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(current.trim().to_string());
+                current.clear();
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+        i += 1;
+    }
+
+    None
+}
+
+/// 🔁 Replaces whole-word parameter names in `body` with their arguments.
+fn substitute(body: &str, params: &[String], args: &[String]) -> String {
+    let chars: Vec<char> = body.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '"' {
+            let end = closing_quote(&chars, i);
+            out.extend(&chars[i..end]);
+            i = end;
+        } else if is_ident_start(c) {
+            let start = i;
+            while i < chars.len() && is_ident_char(chars[i]) {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            match params.iter().position(|p| *p == word) {
+                Some(index) => out.push_str(&args[index]),
+                None => out.push_str(&word),
+            }
+        } else {
+            out.push(c);
+            i += 1;
+        }
+    }
+
+    out
+}
+
+/// 🔚 Index just past the string literal opening at `start` (or end of text).
+fn closing_quote(chars: &[char], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// ===================================================
+// 🔚 Closing — Macro Preprocessor Notes
+// ===================================================
+//
+// 🪜 Flow:
+//   ⬆️ Upstream:   Raw scroll source, after the `#if` pass (`prepare_source`)
+//   ⬇️ Downstream: Tokenizer → Parser (never sees `define`)
+//
+// 🔮 Future:
+//   - Source maps from expanded text back to call sites
+//   - `.logos`-declared macro libraries shared across scrolls
+//
+// ---------------------------------------------------
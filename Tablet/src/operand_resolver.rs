@@ -29,21 +29,27 @@
 
 // === Standard Library Imports ===
 
+use std::collections::hash_map::DefaultHasher; // 🗃 Instruction signature hashing
 use std::collections::HashMap; // 📦 Maps symbolic bindings to resolved operands and confidence tiers
+use std::hash::{Hash, Hasher}; // 🗃 Instruction signature hashing
 use std::fmt; // 🧾 Enables custom debug output for operand display
+use std::time::Duration; // ⏳ Structured `wait` durations
 
 // Optionally required for advanced memory or metadata linking across scrolls
-use std::rc::Rc; // 🔗 Shared ownership across single-threaded components
-                 // use std::sync::Arc; // 🔗 Shared ownership in multithreaded context (Uncomment if Watchtower multithreads)
-                 // use std::cell::RefCell; // 🧬 Interior mutability for components like scroll-tree node walkers
+// use std::rc::Rc; // 🔗 Shared ownership across single-threaded components
+use std::sync::Arc; // 🔗 Shared ownership in multithreaded context — the instruction registry
+                    // use std::cell::RefCell; // 🧬 Interior mutability for components like scroll-tree node walkers
                  // use std::sync::Mutex; // 🔐 Needed if multithreaded mutable access becomes required
 
 // === Internal Module Imports ===
 
-use crate::tokenizer::{Token, TokenType};
+use crate::tokenizer::{OperandHint, Token};
 // 🪙 Tokens are the smallest language units — used during literal extraction or pattern matching
 
-use crate::instruction_registry::{Instruction, OperandSchema};
+use crate::instruction_registry::{
+    shared_registry, InstructionRegistry, OperandKind, OperandMismatch, OperandSchema,
+    PrivilegeLevel, SchemaOperand,
+};
 // 📚 Instruction structures and operand expectations — schema validation and resolution targets
 
 use crate::parser::{ScrollNode, ScrollTree};
@@ -134,20 +140,74 @@ pub enum Operand {
     /// Example: `_`, `<insert_here>`, or `{{user_name}}`
     Placeholder(String),
 
+    /// ⏳ A span of time, parsed from a unit-suffixed literal.
+    /// Gives `wait` a structured delay rather than raw text.
+    /// Example: `5s` → `Duration(5s)`, `200ms` → `Duration(200ms)`
+    Duration(Duration),
+
     /// 🌀 A wildcard—an "accept anything" operand used when pattern-matching or abstracting.
     /// It tells the Bearer: “You don’t need to care what this is—just keep going.”
     Wildcard,
 
     /// ❌ An operand that failed to resolve—caught instead of ignored.
-    /// Stored for debugging and Watchtower clarity.
+    /// Stored for debugging and Watchtower clarity, with why it failed and where.
     /// Example: a broken token like `"123abc"` in a numeric field.
-    InvalidOperand(String),
+    InvalidOperand {
+        text: String,   // 🧾 The offending token, verbatim
+        reason: String, // ❓ Why classification rejected it (e.g., "malformed number")
+        line: usize,    // 📍 Source line of the owning instruction (0 if unknown)
+    },
+}
+
+/// 🧮 Constructed operands fill slots by form: bindings take named slots,
+/// literals take `Literal`, and wildcards or placeholders take anything.
+/// An `InvalidOperand` fills no slot.
+impl SchemaOperand for Operand {
+    fn fits(&self, kind: &OperandKind) -> bool {
+        match (self, kind) {
+            (Operand::InvalidOperand { .. }, _) => false,
+            (_, OperandKind::Optional(kind) | OperandKind::Variadic(kind)) => self.fits(kind),
+            (Operand::Wildcard | Operand::Placeholder(_), _) => true,
+            (_, OperandKind::Value | OperandKind::Custom(_)) => true,
+            (
+                Operand::Literal { .. } | Operand::ResolvedValue(_) | Operand::Duration(_),
+                OperandKind::Literal,
+            ) => true,
+            (Operand::Binding { .. }, OperandKind::Identifier | OperandKind::Register) => true,
+            (
+                Operand::Binding { .. },
+                OperandKind::Target | OperandKind::Label | OperandKind::Address,
+            ) => true,
+            (Operand::PathAccess { .. }, OperandKind::Target | OperandKind::Address) => true,
+            (Operand::InstructionRef(_), OperandKind::Label) => true,
+            (Operand::Literal { .. }, OperandKind::Address) => true,
+            _ => false,
+        }
+    }
+
+    fn text(&self) -> String {
+        match self {
+            Operand::Literal { value, .. } => value.clone(),
+            Operand::Binding { name, .. } | Operand::InstructionCall { name, .. } => name.clone(),
+            Operand::Group(items) => {
+                let items: Vec<String> = items.iter().map(SchemaOperand::text).collect();
+                format!("({})", items.join(", "))
+            }
+            Operand::InstructionRef(name) => format!("ref:{}", name),
+            Operand::PathAccess { path } => path.join("."),
+            Operand::ResolvedValue(text)
+            | Operand::Placeholder(text)
+            | Operand::InvalidOperand { text, .. } => text.clone(),
+            Operand::Duration(duration) => format_duration(*duration),
+            Operand::Wildcard => "*".to_string(),
+        }
+    }
 }
 
-/// ===============================================
-/// 📘 OperandType — Resolved Data Classification
-/// ===============================================
-/// Represents the inferred or declared type of an operand.
+// ===============================================
+// 📘 OperandType — Resolved Data Classification
+// ===============================================
+// Represents the inferred or declared type of an operand.
 // Used throughout Bearer logic for resolution, validation,
 // and assembler interpretation.
 //
@@ -170,6 +230,7 @@ pub enum Operand {
 pub enum OperandType {
     Integer,     // 🔢 Whole number value
     Float,       // 🌊 Decimal number value
+    Duration,    // ⏳ Unit-suffixed span of time (`5s`, `200ms`)
     Boolean,     // 🚦 true / false
     String,      // 🔤 Quoted textual data
     Symbol,      // 🪶 Binding or variable name
@@ -182,6 +243,74 @@ pub enum OperandType {
     Unknown,     // ❓ Not yet classified or inferred
 }
 
+impl OperandType {
+    /// 🧬 Reads a declared type name (`Int`, `string`, `Bool`, ...).
+    /// Names outside the literal types return `None` and go unchecked.
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "int" | "integer" => Some(OperandType::Integer),
+            "float" => Some(OperandType::Float),
+            "bool" | "boolean" => Some(OperandType::Boolean),
+            "string" | "str" => Some(OperandType::String),
+            "duration" => Some(OperandType::Duration),
+            _ => None,
+        }
+    }
+
+    /// 🔢 Type of `text` when it reads as a literal: quoted text, `true` /
+    /// `false`, or a number. Names and anything else return `None`.
+    pub fn of_literal(text: &str) -> Option<Self> {
+        if text.len() >= 2 && text.starts_with('"') && text.ends_with('"') {
+            Some(OperandType::String)
+        } else if text == "true" || text == "false" {
+            Some(OperandType::Boolean)
+        } else if text.parse::<i64>().is_ok() {
+            Some(OperandType::Integer)
+        } else if text.parse::<f64>().is_ok() {
+            Some(OperandType::Float)
+        } else if parse_duration(text).is_some() {
+            Some(OperandType::Duration)
+        } else {
+            None
+        }
+    }
+
+    /// ⚖️ True when a value of type `self` may be stored under `declared`.
+    /// Integers widen into `Float`; every other pairing must match.
+    pub fn fits(&self, declared: &OperandType) -> bool {
+        self == declared || (*self == OperandType::Integer && *declared == OperandType::Float)
+    }
+}
+
+/// ⏳ Parses a unit-suffixed duration: `200ms`, `5s`, `2m`, or `1h`.
+/// A bare number, an unknown unit, or an overflowing span returns `None`.
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let split = text.find(|c: char| !c.is_ascii_digit())?;
+    let (digits, unit) = text.split_at(split);
+    let amount = digits.parse::<u64>().ok()?;
+
+    let millis = match unit {
+        "ms" => Some(amount),
+        "s" => amount.checked_mul(1_000),
+        "m" => amount.checked_mul(60_000),
+        "h" => amount.checked_mul(3_600_000),
+        _ => None,
+    }?;
+    Some(Duration::from_millis(millis))
+}
+
+/// 🧾 Writes a duration back in the largest unit that divides it evenly.
+pub fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    match millis {
+        0 => "0ms".to_string(),
+        _ if millis.is_multiple_of(3_600_000) => format!("{}h", millis / 3_600_000),
+        _ if millis.is_multiple_of(60_000) => format!("{}m", millis / 60_000),
+        _ if millis.is_multiple_of(1_000) => format!("{}s", millis / 1_000),
+        _ => format!("{}ms", millis),
+    }
+}
+
 // ===============================================
 // 🧭 BindingScope — Posture or Alignment of a Symbolic Binding
 // ===============================================
@@ -220,6 +349,162 @@ pub enum BindingScope {
     Extern,
 }
 
+// ===============================================
+// 🏛 BindingEnvironment — Scoped Frames of Symbolic Bindings
+// ===============================================
+// A stack of binding frames. The root frame holds `Global` bindings;
+// each `Block`, `Conditional`, or `Loop` body pushes a `Local` frame
+// that is discarded on exit, so inner declarations never leak outward.
+//
+// Lookups walk from the innermost frame outward—outer bindings remain
+// visible inside nested scopes unless a nearer frame redeclares them.
+
+/// 🏛 BindingEnvironment — Stack of scoped binding frames
+#[derive(Debug, Clone)]
+pub struct BindingEnvironment {
+    /// 🧱 Frames from root (index 0) to innermost scope
+    frames: Vec<HashMap<String, Operand>>,
+    /// 🧬 Declared types, one map per frame (`let x: Int`)
+    types: Vec<HashMap<String, OperandType>>,
+    /// 🛰 Imported scrolls by name, each holding its members as `Extern` bindings
+    imports: HashMap<String, HashMap<String, Operand>>,
+}
+
+impl Default for BindingEnvironment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BindingEnvironment {
+    /// 🔨 Creates an environment holding only the root frame.
+    pub fn new() -> Self {
+        Self {
+            frames: vec![HashMap::new()],
+            types: vec![HashMap::new()],
+            imports: HashMap::new(),
+        }
+    }
+
+    /// 🚪 Opens a new innermost scope.
+    pub fn push_scope(&mut self) {
+        self.frames.push(HashMap::new());
+        self.types.push(HashMap::new());
+    }
+
+    /// 🧹 Closes the innermost scope, returning its bindings.
+    /// The root frame is never popped.
+    pub fn pop_scope(&mut self) -> Option<HashMap<String, Operand>> {
+        if self.frames.len() > 1 {
+            self.types.pop();
+            self.frames.pop()
+        } else {
+            None
+        }
+    }
+
+    /// 📏 Number of open frames (root = 1).
+    pub fn depth(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// 🧭 Scope assigned to names declared at the current depth.
+    pub fn current_scope(&self) -> BindingScope {
+        if self.frames.len() == 1 {
+            BindingScope::Global
+        } else {
+            BindingScope::Local
+        }
+    }
+
+    /// ✒️ Declares `name` in the innermost frame as a scoped `Binding`.
+    ///
+    /// Returns the previous binding if `name` was already declared
+    /// in this same frame (outer frames are not consulted). Any type
+    /// the name carried in this frame is dropped.
+    pub fn declare(&mut self, name: &str) -> Option<Operand> {
+        let binding = Operand::Binding {
+            name: name.to_string(),
+            alignment: Some(self.current_scope()),
+        };
+
+        self.types
+            .last_mut()
+            .expect("root frame is never popped")
+            .remove(name);
+        self.frames
+            .last_mut()
+            .expect("root frame is never popped")
+            .insert(name.to_string(), binding)
+    }
+
+    /// 🧬 Declares `name` like `declare`, recording `dtype` as its type.
+    pub fn declare_typed(&mut self, name: &str, dtype: OperandType) -> Option<Operand> {
+        let previous = self.declare(name);
+        self.types
+            .last_mut()
+            .expect("root frame is never popped")
+            .insert(name.to_string(), dtype);
+        previous
+    }
+
+    /// 🔎 Declared type of the nearest visible `name`, if it was given one.
+    /// An untyped redeclaration in a nearer frame hides outer types.
+    pub fn declared_type(&self, name: &str) -> Option<&OperandType> {
+        let depth = self.frames.iter().rposition(|frame| frame.contains_key(name))?;
+        self.types[depth].get(name)
+    }
+
+    /// 🔍 Finds the nearest visible binding for `name`, innermost first.
+    pub fn lookup(&self, name: &str) -> Option<&Operand> {
+        self.frames.iter().rev().find_map(|frame| frame.get(name))
+    }
+
+    /// 👁 True when `name` is visible from the current scope.
+    pub fn is_visible(&self, name: &str) -> bool {
+        self.lookup(name).is_some()
+    }
+
+    /// 🛰 Makes each of `members` reachable as `scroll.member`, bound with
+    /// `BindingScope::Extern`. Importing `scroll` again replaces its members.
+    /// Imported members are reached only through their path, never by bare name.
+    pub fn import_scroll<'a>(&mut self, scroll: &str, members: impl IntoIterator<Item = &'a str>) {
+        let members = members
+            .into_iter()
+            .map(|name| {
+                let binding = Operand::Binding {
+                    name: name.to_string(),
+                    alignment: Some(BindingScope::Extern),
+                };
+                (name.to_string(), binding)
+            })
+            .collect();
+        self.imports.insert(scroll.to_string(), members);
+    }
+
+    /// 🛰 True when `scroll` has been imported.
+    pub fn is_imported(&self, scroll: &str) -> bool {
+        self.imports.contains_key(scroll)
+    }
+
+    /// 🛤 Resolves `scroll.member` (or `scroll::member`) to the member's
+    /// `Extern` binding. Segments past the member are not checked.
+    /// `None` when the head is not an imported scroll or lacks that member.
+    pub fn lookup_path(&self, path: &str) -> Option<&Operand> {
+        let mut segments = path.split("::").flat_map(|s| s.split('.'));
+        let scroll = segments.next()?;
+        let member = segments.next()?;
+        self.imports.get(scroll)?.get(member)
+    }
+
+    /// 📜 Every name declared in any open frame (duplicates possible).
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.frames
+            .iter()
+            .flat_map(|frame| frame.keys().map(String::as_str))
+    }
+}
+
 // ===============================================
 // 🔐 TrustTier — Operand Resolution Confidence Tier
 // ===============================================
@@ -266,6 +551,29 @@ pub enum TrustTier {
     Invalid, // Score: 0
 }
 
+impl TrustTier {
+    /// 🏷 Reads an operand annotation — `@certain`, `@trusted`, `@ambiguous`,
+    /// `@shadowed`, or `@invalid` — as the tier it asserts.
+    pub fn from_annotation(text: &str) -> Option<Self> {
+        match text.strip_prefix('@')? {
+            "certain" => Some(TrustTier::Certain),
+            "trusted" => Some(TrustTier::Trusted),
+            "ambiguous" => Some(TrustTier::Ambiguous),
+            "shadowed" => Some(TrustTier::Shadowed),
+            "invalid" => Some(TrustTier::Invalid),
+            _ => None,
+        }
+    }
+}
+
+/// 🏷 Whether `arg` is a trust annotation rather than an operand.
+///
+/// The parser writes annotation keywords bare and string literals quoted,
+/// so `"@trusted"` — text — is never mistaken for `@trusted`.
+pub fn is_trust_annotation(arg: &str) -> bool {
+    TrustTier::from_annotation(arg).is_some()
+}
+
 // ===============================================
 // 🧾 OperandMetadata — Scroll Provenance & Diagnostic Tags
 // ===============================================
@@ -300,6 +608,28 @@ pub enum OperandError {
     InvalidForm(String),
 }
 
+/// 🔤 Most edits a known identifier may be from an unknown one to be suggested
+pub const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// 🚧 ResolutionError — Why `begin_resolution` could not walk a tree
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolutionError {
+    /// 📚 An instruction in the tree has no registry entry, so no schema can load
+    UnknownInstruction { name: String },
+}
+
+impl fmt::Display for ResolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolutionError::UnknownInstruction { name } => {
+                write!(f, "no schema for unknown instruction '{}'", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResolutionError {}
+
 // ===============================================
 // 🧱 Struct Definition — Operand Bearer (Tablet Cog)
 // ===============================================
@@ -318,7 +648,8 @@ pub enum OperandError {
 #[derive(Debug)]
 pub struct Bearer {
     /// 📚 Instruction registry reference — for schema lookup and instruction arity rules
-    pub instruction_registry: InstructionRegistry,
+    /// (shared via `Arc`; defaults to `shared_registry()`)
+    pub instruction_registry: Arc<InstructionRegistry>,
 
     /// 🧩 Token stream from the scroll being interpreted
     pub tokens: Vec<Token>,
@@ -345,9 +676,15 @@ pub struct Bearer {
     /// 🧷 Local operand bindings by symbolic name (used in context tracking)
     pub operand_bindings: HashMap<String, Operand>,
 
+    /// 🏛 Scoped declaration frames — block-local bindings are dropped on scope exit
+    pub environment: BindingEnvironment,
+
     /// 🛡️ Per-symbol trust tier flag — helps Watchtower understand alignment confidence
     pub trust_flags: HashMap<String, TrustTier>,
 
+    /// 🔗 Resolved `ref:` targets — referenced keyword mapped to its registry opcode
+    pub instruction_refs: HashMap<String, u8>,
+
     /// ❗ Collection of resolution issues that require developer attention
     pub errors: Vec<DebugEntry>,
 
@@ -355,6 +692,88 @@ pub struct Bearer {
 
     /// 🔌 Optional hook for live Watchtower feedback — planned for real-time resolution streaming.
     pub watchtower_hook: Option<fn(DebugEntry) -> DebugResponse>,
+
+    /// 🫙 When true, empty `{}` / loop / conditional bodies are not flagged.
+    pub allow_empty_blocks: bool,
+
+    /// 🕊️ Explicit unlock for `Divine`-privilege instructions — without it they are Fatal.
+    pub divine_unlock: bool,
+
+    /// 📍 Source line of the node being resolved, taken from the tree's spans —
+    /// stamped onto `InvalidOperand`s (0 for hand-built trees)
+    pub current_line: usize,
+
+    /// 📶 Called with `(resolved, total)` after each instruction is resolved — drives progress bars.
+    pub on_progress: Option<fn(usize, usize)>,
+
+    /// 📶 Instructions resolved so far and in total for the current walk.
+    pub progress: (usize, usize),
+
+    /// 🗃 Operands already built, keyed by `signature_hash` of verb and operands.
+    pub resolution_cache: HashMap<u64, CachedResolution>,
+
+    /// 🗃 Registry the cache was filled against — resolving under any other empties it.
+    pub cache_registry: Arc<InstructionRegistry>,
+
+    /// 🗃 Instructions answered from `resolution_cache` instead of re-resolved.
+    pub cache_hits: usize,
+}
+
+/// 🗃 CachedResolution — One instruction signature's resolution, kept for reuse
+///
+/// Holds what depends only on the verb, its operand text, and the registry:
+/// the constructed operands and any schema mismatch. Trust marks, trace
+/// entries, and progress are still produced for every occurrence.
+/// Instructions with a `Binding` operand are never cached — its alignment
+/// comes from the scoped environment, which the key does not capture.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedResolution {
+    pub name: String,                       // 🏷 Instruction verb
+    pub args: Vec<String>,                  // 🧩 Operand text, as written
+    pub operands: Vec<Operand>,             // 🪙 Constructed operands
+    pub mismatch: Option<OperandMismatch>,  // ❌ Schema finding, if any
+}
+
+/// 🧾 ResolutionStatus — How one instruction fared in `resolve_all`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolutionStatus {
+    Resolved,        // ✅ Every operand built and fit the schema
+    Invalid(String), // ❌ Why it could not resolve
+}
+
+/// 🧾 InstructionResolution — One instruction's outcome, in tree order
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstructionResolution {
+    pub name: String,             // 🏷 Instruction verb
+    pub args: Vec<String>,        // 🧩 Operand text, as written
+    pub status: ResolutionStatus, // 🧾 Resolved, or why not
+}
+
+/// 📋 ResolutionReport — Every instruction's status from `resolve_all`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResolutionReport {
+    pub instructions: Vec<InstructionResolution>, // 📚 One entry per instruction, in tree order
+}
+
+impl ResolutionReport {
+    /// ✅ Instructions that resolved cleanly.
+    pub fn resolved(&self) -> impl Iterator<Item = &InstructionResolution> {
+        self.instructions
+            .iter()
+            .filter(|entry| entry.status == ResolutionStatus::Resolved)
+    }
+
+    /// ❌ Instructions that did not, each with its reason.
+    pub fn invalid(&self) -> impl Iterator<Item = &InstructionResolution> {
+        self.instructions
+            .iter()
+            .filter(|entry| entry.status != ResolutionStatus::Resolved)
+    }
+
+    /// 🧼 Whether every instruction resolved.
+    pub fn is_clean(&self) -> bool {
+        self.invalid().next().is_none()
+    }
 }
 
 // ===============================================
@@ -374,7 +793,7 @@ impl Bearer {
     /// This prepares the resolver with fresh state and optional configuration scaffolding.
     pub fn new() -> Self {
         Self {
-            instruction_registry: InstructionRegistry::default(),
+            instruction_registry: shared_registry(),
             tokens: Vec::new(),
             current_instruction: None,
             resolved_operands: Vec::new(),
@@ -384,697 +803,55 @@ impl Bearer {
             current_node: None,
             instruction_schema: None,
             operand_bindings: HashMap::new(),
+            environment: BindingEnvironment::new(),
             trust_flags: HashMap::new(),
+            instruction_refs: HashMap::new(),
             errors: Vec::new(),
             context_id: None,
             watchtower_hook: None,
+            allow_empty_blocks: false,
+            divine_unlock: false,
+            current_line: 0,
+            on_progress: None,
+            progress: (0, 0),
+            resolution_cache: HashMap::new(),
+            cache_registry: shared_registry(),
+            cache_hits: 0,
         }
     }
 
-    /// 🪪 Identifies the component as the Operand Resolver.
-    /// Useful for debug, scaffolding, or internal CLI description.
-    pub fn identity() -> &'static str {
-        "Bearer (Operand Resolver)"
-    }
-}
-
-// ===============================================
-// 🛠️ Body — Operand Resolution System (Bearer)
-// ===============================================
-// This body houses the Bearer's operand resolution pipeline,
-// translating parsed instructions into structured operand forms.
-//
-// Dev Log 7 identifies this role as:
-// "The Priest of Operand Meaning" — tasked with discerning,
-// validating, and preparing operands for assembler readiness.
-//
-// Each phase is scaffolded and wired to match the mental flow
-// of execution while awaiting deeper logic integration.
-
-impl Bearer {
-    /// 🎯 `resolve_operands` — Bearer’s primary operand interpretation entry point
-    ///
-    /// This function orchestrates the entire resolution process for scroll-based instructions.
-    /// It performs operand extraction, classification, construction, metadata tagging,
-    /// trust assessment, and Watchtower trace emission.
-    ///
-    /// As defined in Dev Log 7, this is where the Bearer acts as the
-    /// **Priest of Operand Meaning** — discerning each operand's true role and trust.
-    ///
-    /// This function aligns with Dev Log 7’s principle that all operands
-    /// must pass through clarity, construction, and confidence before
-    /// being released to the assembler.
-    fn stub_scroll_form(object: &str) -> Operand {
-        Operand::Placeholder(format!("ScrollFormStub({})", object))
-    }
-
-    pub fn resolve_operands(instruction: &mut Instruction) {
-        // ===============================================
-        // 🪧 Phase 1 — Operand Field Extraction
-        // ===============================================
-        // Gathers subject, verb, and object for pattern-based classification.
-        let (subject, verb, object) = Self::extract_fields(instruction);
-
-        // ➕ Phase 1A — Structural Validation (scaffolded)
-        if subject.is_empty() || verb.is_empty() || object.is_empty() {
-            instruction.status = InstructionStatus::Invalid;
-            return; // Cannot resolve if key fields are missing.
-        }
-
-        // ===============================================
-        // 🧠 Phase 2 — Pattern Recognition & Classification
-        // ===============================================
-        // Determines operand type based on subject/verb/object symbolic mapping.
-        let operand_type = Self::classify_pattern(&subject, &verb, &object);
-
-        // ➕ Phase 2A — Verb Taxonomy Matching (scaffold)
-        let _verb_role_hint = match verb.to_lowercase().as_str() {
-            "let" | "set" | "define" => Some("Assignment"),
-            "return" | "yield" => Some("Control"),
-            "push" | "append" => Some("Mutation"),
-            _ => None,
-        };
-
-        // ➕ Phase 2B — AI-Based Deduction
-        if matches!(operand_type, OperandType::Unknown) {
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: format!("Unrecognized operand form — flagged for AI-based deduction."),
-                severity: Severity::Drifted,
-            });
-        }
-
-        // ===============================================
-        // 🧱 Phase 3 — Operand Construction
-        // ===============================================
-        // Builds concrete Operand structure from pattern insight and object value.
-        let operand = match Self::build_operand(&object, operand_type.clone()) {
-            Ok(op) => op,
-            Err(err) => {
-                instruction.debug_trace.push(DebugEntry {
-                    line: instruction.line,
-                    message: format!("Operand resolution failed: {:?}", err),
-                    severity: Severity::Broken,
-                });
-                instruction.status = InstructionStatus::Invalid;
-                return;
-            }
-        };
-
-        // ➕ Phase 3A — Operand Refinement (partial logic)
-        // For now, just a basic trust tag and binding fallback if unknown.
-        let trust_tier = match operand_type {
-            OperandType::Unknown => TrustTier::Shadowed,
-            OperandType::Symbol => TrustTier::Trusted,
-            OperandType::Literal => TrustTier::Certain,
-            _ => TrustTier::Ambiguous,
-        };
-
-        // Update bindings and trust flags
-        if let Operand::Binding { name, .. } = &operand {
-            instruction
-                .operand_bindings
-                .insert(name.clone(), operand.clone());
-            instruction.trust_flags.insert(name.clone(), trust_tier);
-        }
-
-        // ➕ Phase 3B — ScrollForm Stub Injection (pre-tablet)
-        if matches!(operand_type, OperandType::Symbol) && object.to_lowercase() == "scroll" {
-            let scroll_stub = Self::stub_scroll_form(&object);
-            instruction.resolved_operands.push(scroll_stub);
-
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: "ScrollForm operand stub injected — downstream implementation required."
-                    .to_string(),
-                severity: Severity::Valid,
-            });
-        }
-
-        // ===============================================
-        // 🎯 Phase 4 — Instruction State Update
-        // ===============================================
-        // Updates instruction readiness for assembler or re-resolution.
-        let is_resolved = !matches!(operand_type, OperandType::Unknown);
-        Self::update_instruction_state(instruction, is_resolved);
-
-        // ===============================================
-        // 🛡 Phase 5 — Debug Trace to Watchtower
-        // ===============================================
-        // Emits trace status from resolution to Watchtower or logs.
-        Self::emit_watchtower_log(instruction);
-
-        // ➕ Phase 5A — TrustTier Cascade
-        Self::cascade_trust_summary(instruction);
-
-        // ===============================================
-        // 🌿 Phase 6 — Reconciliation & Operand Rewalk (future)
-        // ===============================================
-        if instruction
-            .resolved_operands
-            .iter()
-            .any(|op| matches!(op, Operand::Placeholder(_)))
-        {
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: "Instruction contains placeholders — rewalk may be required.".to_string(),
-                severity: Severity::Shadowed,
-            });
-        }
-
-        // ===============================================
-        // 📎 Phase 7 — Operand Metadata Tagging (future)
-        // ===============================================
-        let meta_note = format!("Origin line: {}", instruction.line);
-        instruction
-            .metadata_tags
-            .insert("operand_origin".to_string(), meta_note);
-
-        // ===============================================
-        // 🪞 Phase 8 — MetaOperand & Reflective Operand Support (future)
-        // ===============================================
-        if matches!(
-            operand,
-            Operand::Wildcard | Operand::InstructionRef(_) | Operand::Placeholder(_)
-        ) {
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: "MetaOperand or reflective operand form detected.".to_string(),
-                severity: Severity::Valid,
-            });
-        }
-
-        // Finally, push the resolved operand (for now, only one) into instruction context
-        instruction.resolved_operands.push(operand);
-    }
-
-    let operand = match operand_type {
-        OperandType::Symbol => {
-            Self::resolve_symbol(instruction, &object).unwrap_or_else(|| {
-                Operand::Binding {
-                    name: object.clone(),
-                    trust: TrustTier::Ambiguous,
-                }
-            })
-        }
-        _ => Self::build_operand(&object, operand_type.clone()),
-    };
-
-    fn resolve_operand_slot(&self, node: &ScrollNode, schema_slot: &str) -> Operand {
-        /// Resolves an individual operand slot from a ScrollNode based on a schema hint.
-        /// Currently scaffolds symbolic logic for future schema-slot alignment.
-        match node {
-            ScrollNode::Literal(value) => Operand::Literal(value.clone()),
-            ScrollNode::Symbol(name) => Operand::Binding {
-                name: name.clone(),
-                trust: TrustTier::Ambiguous,
-            },
-            _ => Operand::Placeholder(format!("SlotStub({})", schema_slot)),
-        }
-    }
-
-    /// 📚 resolve_symbol — Looks up a symbol name in the instruction’s operand_bindings map.
-    /// Returns a cloned Operand if the symbol is known.
-    fn resolve_symbol(instruction: &Instruction, symbol: &str) -> Option<Operand> {
-        instruction.operand_bindings.get(symbol).cloned()
-    }
-
-
-    // ===============================================
-    // 🧩 Phase 1 — Field Extraction Logic
-    // ===============================================
-    /// Extracts operand-relevant fields from a parsed instruction scroll.
-    /// Performs basic cleaning and emits trace warnings if fields are malformed.
-    /// This phase breathes structure into the scroll — the first clarity pass.
-    fn extract_fields(instruction: &mut Instruction) -> (String, String, String) {
-        // 🪶 Clean whitespace from each field
-        let subject = instruction.subject.trim().to_string();
-        let verb = instruction.verb.trim().to_string();
-        let object = instruction.object.trim().to_string();
-
-        // 🧭 Field validation — emit to debug trace if any are missing
-        if subject.is_empty() {
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: "Subject field is empty — malformed instruction detected.".to_string(),
-                severity: Severity::Broken,
-            });
-        }
-
-        if verb.is_empty() {
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: "Verb field is empty — intent of instruction unclear.".to_string(),
-                severity: Severity::Drifted,
-            });
-        }
-
-        if object.is_empty() {
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: "Object field is empty — operand construction may fail.".to_string(),
-                severity: Severity::Shadowed,
-            });
-        }
-
-        // Return structured tuple for classification
-        (subject, verb, object)
-    }
-
-    // ===============================================
-    // 🔍 Phase 2 — Pattern Recognition Logic
-    // ===============================================
-    /// Analyzes the subject-verb-object pattern to determine operand intent.
-    /// Uses verb taxonomy to infer operand type; defaults to Unknown if ambiguous.
-    fn classify_pattern(subject: &str, verb: &str, object: &str) -> Result<OperandType, OperandError> {
-        // ➕ Phase 2A — Verb Taxonomy Matching
-        let verb_role = match_verb_taxonomy(verb);
-
-        match verb_role {
-            Some("Assignment") => OperandType::Symbol,
-            Some("Control") => OperandType::Unknown, // Will later map to control-type operands
-            Some("Mutation") => OperandType::Unknown, // Mutation logic deferred
-            _ => OperandType::Unknown,
-        }
-    }
-
-    /// ➕ Phase 2A — Verb Taxonomy Matching
-    fn match_verb_taxonomy(verb: &str) -> Option<&'static str> {
-        match verb.to_lowercase().as_str() {
-            "let" | "set" | "define" => Some("Assignment"),
-            "return" | "yield" => Some("Control"),
-            "push" | "append" => Some("Mutation"),
-            _ => None,
-        }
-    }
-
-    /// ➕ Phase 2B — AI-Based Deduction (scaffolded)
-    fn flag_for_ai_deduction(instruction: &mut Instruction) {
-        instruction.debug_trace.push(DebugEntry {
-            line: instruction.line,
-            message: "Unrecognized operand form — flagged for AI-based deduction.".to_string(),
-            severity: Severity::Drifted,
-        });
-    }
-
-    // ===============================================
-    // 🧱 Phase 3 — Operand Construction Logic
-    // ===============================================
-    fn build_operand(object: &str, operand_type: OperandType) -> Result<Operand, OperandError> {
-        match operand_type {
-            OperandType::Symbol => Operand::Binding {
-                name: object.to_string(),
-                alignment: None,
-            },
-            OperandType::Literal => Operand::Literal {
-                value: object.to_string(),
-                dtype: None,
-            },
-            _ => Operand::InvalidOperand(object.to_string()),
-        }
-    }
-
-    // ➕ Phase 3A — Operand Refinement
-    fn refine_operand(
-        instruction: &mut Instruction,
-        operand: &Operand,
-        operand_type: &OperandType,
-    ) -> TrustTier {
-        let trust = match operand_type {
-            OperandType::Unknown => TrustTier::Shadowed,
-            OperandType::Symbol => TrustTier::Trusted,
-            OperandType::Literal => TrustTier::Certain,
-            _ => TrustTier::Ambiguous,
-        };
-
-        if let Operand::Binding { name, .. } = operand {
-            instruction
-                .operand_bindings
-                .insert(name.clone(), operand.clone());
-            instruction.trust_flags.insert(name.clone(), trust.clone());
-        }
-
-        trust
-    }
-
-    // ===============================================
-    // 🛠 Phase 4 — Instruction State Resolution Logic
-    // ===============================================
-    /// Updates the instruction status based on operand resolution outcome.
-    /// Also prepares trace feedback and triggers rewalk logic for low-trust states.
-    fn update_instruction_state(instruction: &mut Instruction, resolved: bool) {
-        if resolved {
-            // ✅ All operands resolved clearly — instruction is now ready for assembly.
-            instruction.status = InstructionStatus::ReadyToAssemble;
-
-            // 🗒️ Log resolution success for Watchtower or internal debug tracing.
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: "Operands resolved — instruction marked ReadyToAssemble.".to_string(),
-                severity: Severity::Valid,
-            });
-        } else {
-            // ⚠️ Operand resolution incomplete or ambiguous — mark for further review.
-            instruction.status = InstructionStatus::RequiresResolution;
-
-            // 🗒️ Log resolution failure for Watchtower and trace output.
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: "Operands incomplete — instruction marked RequiresResolution.".to_string(),
-                severity: Severity::Drifted,
-            });
-
-            // 🧠 Trust rating may trigger retry/reprocess logic.
-            if let Some(ref tier) = instruction.trust_summary {
-                match tier {
-                    TrustTier::Shadowed | TrustTier::Ambiguous => {
-                        // 🛠️ Instruction may need another pass — set rewalk flag and retry count.
-                        instruction.rewalk_flag = true;
-                        instruction.retry_count += 1;
-
-                        instruction.debug_trace.push(DebugEntry {
-                            line: instruction.line,
-                            message: "Low trust tier — rewalk triggered on this instruction."
-                                .to_string(),
-                            severity: Severity::Shadowed,
-                        });
-
-                        // 🤝 Defer resolution to NovaAI or Watchtower agent in next pass.
-                        instruction.defer_to_watchtower = true;
-                    }
-
-                    _ => {
-                        // 🧘 Trust level sufficient — no rewalk needed yet.
-                        instruction.debug_trace.push(DebugEntry {
-                            line: instruction.line,
-                            message: "Trust sufficient — no rewalk triggered.".to_string(),
-                            severity: Severity::Valid,
-                        });
-                    }
-                }
-            }
-        }
-    }
-
-    // ===============================================
-    // 📡 Phase 5 — Debug Emission to Watchtower
-    // ===============================================
-    /// Emits instruction resolution results and trace history to Watchtower.
-    /// This phase closes the scroll’s breath, exposing all alignment states.
-    /// Outputs every DebugEntry — not just status — to support full traceability.
-    fn emit_watchtower_log(instruction: &Instruction) {
-        // 📡 Emit each debug trace entry individually
-        for entry in &instruction.debug_trace {
-            // 🛰️ Primary output: Console trace for local development
-            println!("{:?}", entry);
-
-            // 🛸 Secondary output: Forward to Watchtower hook if present
-            if let Some(ref hook) = instruction.watchtower_hook {
-                hook(entry.clone());
-            }
-
-            // 🔭 Future: Integrate with NovaAI debug channel or persistent scroll logger
-            // e.g., NovaBridge::send_log(entry.clone());
-        }
-
-        // 📜 Emit final resolution status as a capstone event
-        let status_log = DebugEntry {
-            line: instruction.line,
-            message: format!("Bearer resolution status: {:?}", instruction.status),
-            severity: match instruction.status {
-                InstructionStatus::ReadyToAssemble => Severity::Valid,
-                InstructionStatus::RequiresResolution => Severity::Drifted,
-                InstructionStatus::RequiresRewalk => Severity::Shadowed,
-                InstructionStatus::Invalid => Severity::Broken,
-            },
-        };
-
-        // Console + hook broadcast
-        println!("{:?}", status_log);
-        if let Some(ref hook) = instruction.watchtower_hook {
-            hook(status_log);
-        }
-
-        // ===============================================
-        // ➕ Phase 5A — TrustTier Cascade
-        // ===============================================
-        /// Analyzes all operand-level trust flags and sets a single trust summary.
-        /// This helps reflect confidence level in the instruction as a whole.
-        /// Trust cascades upward: the weakest link defines the spiritual posture of the instruction.
-        fn cascade_trust_summary(instruction: &mut Instruction) {
-            // 🎚️ Start with strongest trust tier and downgrade as needed
-            let mut highest = TrustTier::Certain;
-
-            // 🔎 Examine each operand trust flag
-            for tier in instruction.trust_flags.values() {
-                if tier < &highest {
-                    highest = tier.clone();
-                }
-            }
-
-            // 🏷️ Attach the final trust score to instruction for future reconciliation checks
-            instruction.trust_summary = Some(highest.clone());
-
-            // 📝 Echo to debug trace for post-run audit
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: format!("TrustTier summary cascaded: {:?}", highest),
-                severity: Severity::Valid,
-            });
-        }
-    }
-
-    // ===============================================
-    // 🌿 Phase 6 — Reconciliation & Operand Rewalk
-    // ===============================================
-    /// This phase inspects the resolved operands for structural gaps or
-    /// low-confidence patterns. It flags instructions for reprocessing
-    /// if unresolved or invalid elements are found.
-    ///
-    /// It also prepares the instruction for later passes by setting:
-    /// - `rewalk_flag` for recursive resolution
-    /// - `retry_count` for tracking attempt cycles
-    /// - `defer_to_watchtower` for NovaAI/agent handoff if necessary
-    ///
-    /// Phase 6 ensures instructions that drifted from alignment get another
-    /// chance at clarity, without breaking assembly flow prematurely.
-    fn check_for_rewalk(instruction: &mut Instruction) {
-        let mut requires_rewalk = false;
-
-        for operand in &instruction.resolved_operands {
-            match operand {
-                Operand::Placeholder(_) => {
-                    // 🧩 A placeholder means something wasn't recognized — we should retry.
-                    instruction.debug_trace.push(DebugEntry {
-                        line: instruction.line,
-                        message: "Operand placeholder detected — rewalk recommended.".to_string(),
-                        severity: Severity::Shadowed,
-                    });
-
-                    requires_rewalk = true;
-                }
-
-                Operand::InvalidOperand(_) => {
-                    // ❌ Invalid operands indicate parsing or logic failure.
-                    instruction.debug_trace.push(DebugEntry {
-                        line: instruction.line,
-                        message: "Invalid operand encountered — flagged for operand rewalk."
-                            .to_string(),
-                        severity: Severity::Broken,
-                    });
-
-                    requires_rewalk = true;
-
-                    // 🔁 Escalate unresolved issues to Watchtower agent or NovaAI support.
-                    instruction.defer_to_watchtower = true;
-                }
-
-                _ => {
-                    // ✅ Operand is valid and trustworthy — no need to rewalk.
-                }
-            }
-        }
-
-        if requires_rewalk {
-            // 🔁 Enable retry flow and mark for multi-pass resolution strategies.
-            instruction.rewalk_flag = true;
-            instruction.retry_count += 1;
-
-            // 🚧 (Optional future): mark status for scroll rewalker system or agent triggers.
-            instruction.status = InstructionStatus::RequiresRewalk;
-
-            // 🗒️ Echo resolution intent for Watchtower trace.
-            instruction.debug_trace.push(DebugEntry {
-                line: instruction.line,
-                message: "Instruction flagged for rewalk cycle and deeper reconciliation."
-                    .to_string(),
-                severity: Severity::Drifted,
-            });
-        }
+    /// 📚 Resolves against `registry` instead of the shared compiled-in one.
+    pub fn with_registry(mut self, registry: Arc<InstructionRegistry>) -> Self {
+        self.instruction_registry = registry;
+        self.invalidate_cache();
+        self
     }
 
-    // ===============================================
-    // 🛠️ Metadata Helper — Optional Utility
-    // ===============================================
-    /// ✨ Utility helper to insert metadata if value is present.
-    /// Used to reduce redundancy and improve Phase 7 clarity.
-    fn insert_metadata(instruction: &mut Instruction, key: &str, value: Option<String>) {
-        if let Some(val) = value {
-            instruction.metadata_tags.insert(key.to_string(), val);
-        }
+    /// 📶 Reports `(resolved, total)` to `callback` as each instruction resolves.
+    pub fn with_progress(mut self, callback: fn(usize, usize)) -> Self {
+        self.on_progress = Some(callback);
+        self
     }
 
-    // ===============================================
-    // 📎 Phase 7 — Operand Metadata Tagging
-    // ===============================================
-    /// Assigns contextual metadata to the instruction’s scroll.
-    /// Tracks operand origin, trust state, operand role, source, and hint.
-    /// Now uses a helper to insert values cleanly.
-    fn tag_operand_metadata(instruction: &mut Instruction) {
-        // 🏷️ Line of origin — always recorded.
-        insert_metadata(
-            instruction,
-            "operand_origin",
-            Some(format!("Origin line: {}", instruction.line)),
-        );
-
-        // 🔐 Trust tier — if determined.
-        insert_metadata(
-            instruction,
-            "trust_tier",
-            instruction
-                .trust_summary
-                .as_ref()
-                .map(|tier| format!("Trust tier: {:?}", tier)),
-        );
-
-        // 📌 Operand role — if first resolved operand exists.
-        insert_metadata(
-            instruction,
-            "operand_role",
-            instruction.resolved_operands.first().map(|op| {
-                match op {
-                    Operand::Binding { .. } => "Binding",
-                    Operand::Literal { .. } => "Literal",
-                    Operand::InstructionRef(_) => "InstructionRef",
-                    Operand::Placeholder(_) => "Placeholder",
-                    Operand::Wildcard => "Wildcard",
-                    Operand::InvalidOperand(_) => "Invalid",
-                    Operand::Group(_) => "Group",
-                    Operand::InstructionCall(_) => "InstructionCall",
-                }
-                .to_string()
-            }),
-        );
-
-        // 🛠️ Resolution state — assembler readiness.
-        insert_metadata(
-            instruction,
-            "resolution_state",
-            Some(
-                match instruction.status {
-                    InstructionStatus::ReadyToAssemble => "Final",
-                    InstructionStatus::RequiresResolution => "Pending",
-                    InstructionStatus::RequiresRewalk => "Rewalk",
-                    InstructionStatus::Invalid => "Invalid",
-                }
-                .to_string(),
-            ),
-        );
-
-        // 📜 Source scroll — if assigned.
-        insert_metadata(
-            instruction,
-            "source_scroll",
-            instruction.source_scroll.clone(),
-        );
-
-        // 🧠 Operand hint — if annotated.
-        insert_metadata(
-            instruction,
-            "operand_hint",
-            instruction.operand_hint.clone(),
-        );
-
-        // 💡 Notes:
-        // - These metadata tags are read by Watchtower logs, NovaAI overlays, and system validators.
-        // - All fields are optional but encouraged for scroll-based clarity and debugging.
+    /// 🌳 Constructs a Bearer and immediately resolves the given scroll tree.
+    /// Shorthand for `Bearer::new()` followed by `begin_resolution(tree)`;
+    /// a refused tree is still visible through `debug_trace`.
+    pub fn for_tree(tree: ScrollTree) -> Self {
+        let mut bearer = Self::new();
+        let _ = bearer.begin_resolution(tree);
+        bearer
     }
 
-    // ===============================================
-    // 🪞 Phase 8 — MetaOperand & Reflective Operand Support
-    // ===============================================
-    /// Identifies and handles operand forms that represent indirect,
-    /// symbolic, or reflective references rather than direct values.
-    /// This includes placeholders, wildcards, and instruction references,
-    /// which require special treatment in advanced assembler phases.
-    fn handle_meta_operand(instruction: &mut Instruction, operand: &Operand) {
-        match operand {
-            Operand::Wildcard => {
-                // 🌌 A wildcard is an open operand — accepted but marked as symbolic.
-                instruction.debug_trace.push(DebugEntry {
-                    line: instruction.line,
-                    message: "Wildcard operand detected — symbolic binding accepted.".to_string(),
-                    severity: Severity::Valid,
-                });
-
-                instruction
-                    .metadata_tags
-                    .insert("meta_operand_type".to_string(), "Wildcard".to_string());
-            }
-
-            Operand::InstructionRef(_) => {
-                // 🔁 A reference to another instruction — denotes relational operand form.
-                instruction.debug_trace.push(DebugEntry {
-                    line: instruction.line,
-                    message: "InstructionRef operand detected — reflective context required."
-                        .to_string(),
-                    severity: Severity::Valid,
-                });
-
-                instruction.metadata_tags.insert(
-                    "meta_operand_type".to_string(),
-                    "InstructionRef".to_string(),
-                );
-
-                // ⛓️ Optionally mark the instruction as needing reflective evaluation.
-                instruction.defer_to_watchtower = true;
-            }
-
-            Operand::Placeholder(_) => {
-                // 🕳️ Placeholder detected — symbolic and unresolved.
-                instruction.debug_trace.push(DebugEntry {
-                    line: instruction.line,
-                    message: "Placeholder operand detected — operand remains unresolved."
-                        .to_string(),
-                    severity: Severity::Shadowed,
-                });
-
-                instruction
-                    .metadata_tags
-                    .insert("meta_operand_type".to_string(), "Placeholder".to_string());
-
-                // ⚠️ Signal potential rewalk if not already triggered.
-                instruction.rewalk_flag = true;
-            }
-
-            _ => {
-                // ✅ Not a meta operand — nothing to handle here.
-            }
-        }
+    /// 🪪 Identifies the component as the Operand Resolver.
+    /// Useful for debug, scaffolding, or internal CLI description.
+    pub fn identity() -> &'static str {
+        "Bearer (Operand Resolver)"
     }
+}
 
-    /// Binds a resolved operand into a ScrollFormNode for execution tree construction.
-    pub fn bind_scrollform(
-        &self,
-        node: &ScrollNode,
-        resolved_operands: Vec<Operand>,
-    ) -> ScrollFormNode {
-        ScrollFormNode {
-            instruction_name: node.instruction.clone(),
-            operands: resolved_operands,
-            line_number: node.line_number,
-        }
+impl Default for Bearer {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -1133,114 +910,239 @@ impl Bearer {
 
 impl Bearer {
     // ===================================================
-    // ✅ POST-RESOLUTION CONFIRMATION
+    // 🪞 SCOPE INTEGRITY — SHADOWED DECLARATIONS
     // ===================================================
 
-    /// ✅ Final confirmation that all operand fields have been classified and constructed.
-    ///
-    /// This method walks the operands assigned to an instruction and
-    /// checks if all have been resolved to valid types. It ensures no
-    /// placeholders, invalid stubs, or unresolved entries remain.
+    /// 🪞 Flags identifiers declared more than once within the same scope.
     ///
-    /// This is a **post-pass sanity check** to confirm that all operands
-    /// are spiritually and structurally aligned before proceeding to assembly.
+    /// Declarations are recorded into a fresh `BindingEnvironment` as the tree
+    /// is walked, so the pass leaves `self.environment` untouched and can be
+    /// rerun on the same tree. Each `Block`, `Conditional`, `Loop`, or `Function` body opens a fresh `Local` scope
+    /// that is popped on exit, so re-declaring an outer name inside a nested block
+    /// is permitted and block-local names do not remain visible afterward.
+    /// Re-declaring a name in the *same* scope is pushed into `errors`
+    /// as a `Severity::Drift` entry — likely a mistake, but not fatal.
     ///
-    /// Returns `true` if all operands are valid and ready.
-    pub fn validate_operands(instruction: &Instruction) -> bool {
-        for operand in &instruction.resolved_operands {
-            match operand {
-                Operand::InvalidOperand(_) | Operand::Placeholder(_) => {
-                    // 🧾 Record warning trace (optional in later Watchtower logging)
-                    #[cfg(feature = "debug_mode")]
-                    println!(
-                        "⚠️ [Validate] Operand not fully resolved: {:?} (line {})",
-                        operand, instruction.line
-                    );
+    /// Returns the number of redeclarations found.
+    pub fn detect_shadowed_declarations(&mut self, scroll_tree: &ScrollTree) -> usize {
+        let before = self.errors.len();
+
+        self.walk_declaration_scope(&scroll_tree.nodes, &mut BindingEnvironment::new());
 
-                    // 🚨 If any operand is incomplete, resolution is not valid
-                    return false;
+        self.errors.len() - before
+    }
+
+    /// 🧱 Walks one scope level, recording declarations and descending into nested bodies.
+    fn walk_declaration_scope(
+        &mut self,
+        nodes: &[ScrollNode],
+        environment: &mut BindingEnvironment,
+    ) {
+        for node in nodes {
+            match node {
+                ScrollNode::Declaration { name, .. } if environment.declare(name).is_some() => {
+                    let entry = DebugEntry::new(
+                        "detect_shadowed_declarations",
+                        name,
+                        "unique declaration within scope",
+                        &format!("'{}' redeclared within the same scope", name),
+                    )
+                    .with_location("Bearer::detect_shadowed_declarations")
+                    .with_suggestion("Rename the binding, or move it into a nested block")
+                    .with_severity(Severity::Drift);
+
+                    self.errors.push(entry);
                 }
-                _ => {
-                    // ✅ Operand is valid — continue checking others
+
+                // 🧭 Nested bodies open a fresh local scope
+                ScrollNode::Block(body)
+                | ScrollNode::Conditional { body, .. }
+                | ScrollNode::Loop { body, .. }
+                | ScrollNode::Function { body, .. } => {
+                    environment.push_scope();
+                    self.walk_declaration_scope(body, environment);
+                    environment.pop_scope();
                 }
+
+                _ => {}
             }
         }
-
-        // 🎯 All operands passed validation
-        true
     }
 
     // ===================================================
-    // 📡 WATCHTOWER & TRACE EMISSION
+    // 🔚 FLOW INTEGRITY — STRAY RETURNS
     // ===================================================
 
-    /// 🛰 Emit debug snapshot to the Watchtower after operand resolution.
+    /// 🔚 Flags `return` statements that sit outside any function body.
     ///
-    /// This function creates a diagnostic payload from the instruction state
-    /// and emits it to the central Watchtower system. It allows deeper
-    /// system introspection and alignment checks across components.
-    pub fn report_to_watchtower(instruction: &Instruction) {
-        // Construct a basic debug payload based on the current instruction state
-        let payload = DebugEntry {
-            line: instruction.line,
-            message: format!(
-                "Resolution status: {:?} | Trust summary: {:?}",
-                instruction.status,
-                instruction
-                    .trust_summary
-                    .as_ref()
-                    .unwrap_or(&TrustTier::Shadowed)
-            ),
-            severity: match instruction.status {
-                InstructionStatus::ReadyToAssemble => Severity::Valid,
-                InstructionStatus::RequiresResolution => Severity::Drifted,
-                InstructionStatus::Invalid => Severity::Broken,
-                InstructionStatus::RequiresRewalk => Severity::Shadowed,
-            },
-        };
+    /// Only a `Function` body is a callable context; plain blocks,
+    /// conditionals, and loops inherit whatever context encloses them.
+    /// Each stray `return` is pushed into `errors` as a `Severity::Drift` entry.
+    ///
+    /// Returns the number of stray returns found.
+    pub fn detect_stray_returns(&mut self, scroll_tree: &ScrollTree) -> usize {
+        let before = self.errors.len();
+
+        self.walk_return_context(&scroll_tree.nodes, false);
+
+        self.errors.len() - before
+    }
+
+    /// 🧱 Walks nodes, tracking whether they sit inside a callable body.
+    fn walk_return_context(&mut self, nodes: &[ScrollNode], in_callable: bool) {
+        for node in nodes {
+            match node {
+                ScrollNode::Return(value) if !in_callable => {
+                    let entry = DebugEntry::new(
+                        "detect_stray_returns",
+                        &format!("return {}", value),
+                        "return inside a function body",
+                        "return outside any function",
+                    )
+                    .with_location("Bearer::detect_stray_returns")
+                    .with_suggestion("Move the return into a `walk` definition, or remove it")
+                    .with_severity(Severity::Drift);
+
+                    self.errors.push(entry);
+                }
+
+                ScrollNode::Function { body, .. } => self.walk_return_context(body, true),
 
-        // Send the payload to the Watchtower if a hook exists
-        if let Some(ref hook) = instruction.watchtower_hook {
-            hook(payload.clone()); // Pass a clone if ownership is taken
+                ScrollNode::Block(body)
+                | ScrollNode::Conditional { body, .. }
+                | ScrollNode::Loop { body, .. } => self.walk_return_context(body, in_callable),
+
+                _ => {}
+            }
         }
+    }
 
-        // Always emit to CLI trace in debug mode for local inspection
-        #[cfg(feature = "debug_mode")]
-        {
-            println!("📡 [Watchtower Emission] {:?}", payload);
+    // ===================================================
+    // 🫙 FLOW INTEGRITY — EMPTY BODIES
+    // ===================================================
+
+    /// 🫙 Flags `Block`, `Loop`, and `Conditional` bodies with no nodes.
+    ///
+    /// A body holding only an empty `{}` block counts as empty and is
+    /// reported once, on the enclosing loop or conditional. Each empty body
+    /// is pushed into `errors` as a `Severity::Drift` entry, unless
+    /// `allow_empty_blocks` is set.
+    ///
+    /// Returns the number of empty bodies found.
+    pub fn detect_empty_blocks(&mut self, scroll_tree: &ScrollTree) -> usize {
+        if self.allow_empty_blocks {
+            return 0;
         }
 
-        // 📬 Future: Relay to NovaBridge (for AI-assisted commentary or remote logging)
-        // if let Some(bridge) = NovaBridge::current() {
-        //     let signature = Self::export_operand_signature(instruction);
-        //     let nova_payload = NovaPayload::from_debug_entry(payload, signature);
-        //     bridge.send(nova_payload);
-        // }
+        let before = self.errors.len();
+
+        self.walk_empty_bodies(&scroll_tree.nodes);
+
+        self.errors.len() - before
     }
 
-    /// 🧾 Optional serializer for logging or assembly review.
-    ///
-    /// Converts the resolved operand set into a readable signature format,
-    /// useful for trace logs, scroll metadata, or assembler inspection.
-    /// This acts as a compressed summary of operand resolution results.
-    pub fn export_operand_signature(instruction: &Instruction) -> String {
-        let mut signature = vec![];
-
-        for operand in &instruction.resolved_operands {
-            let kind = match operand {
-                Operand::Literal { .. } => "Literal",
-                Operand::Binding { .. } => "Symbol",
-                Operand::Wildcard => "Wildcard",
-                Operand::InstructionRef(_) => "InstructionRef",
-                Operand::Placeholder(_) => "Placeholder",
-                Operand::InvalidOperand(_) => "Invalid",
+    /// 🧱 Walks nodes, reporting each empty body and descending into the rest.
+    fn walk_empty_bodies(&mut self, nodes: &[ScrollNode]) {
+        for node in nodes {
+            let (input, body) = match node {
+                ScrollNode::Block(body) => ("{}".to_string(), body),
+                ScrollNode::Conditional { condition, body } => (format!("if {}", condition), body),
+                ScrollNode::Loop { condition, body } => (format!("loop {}", condition), body),
+                ScrollNode::Function { body, .. } => {
+                    self.walk_empty_bodies(body);
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let is_empty = match body.as_slice() {
+                [] => true,
+                [ScrollNode::Block(inner)] => inner.is_empty(),
+                _ => false,
             };
 
-            let value = format!("{:?}", operand);
-            signature.push(format!("{}: {}", kind, value));
+            if is_empty {
+                let entry = DebugEntry::new(
+                    "detect_empty_blocks",
+                    &input,
+                    "body with at least one node",
+                    "empty body",
+                )
+                .with_location("Bearer::detect_empty_blocks")
+                .with_suggestion("Fill in the body or remove the dead construct")
+                .with_severity(Severity::Drift);
+
+                self.errors.push(entry);
+            } else {
+                self.walk_empty_bodies(body);
+            }
         }
+    }
 
-        format!("[{}]", signature.join(" | "))
+    // ===================================================
+    // 🔁 GROUP INTEGRITY — MIXED OPERAND KINDS
+    // ===================================================
+
+    /// 🔁 Checks that a `Group` operand carries a single `OperandType`.
+    ///
+    /// Only enforced when `slot` expects uniformity (see
+    /// `OperandKind::expects_uniform`). Wildcards match any type and are
+    /// skipped. A mixed group is pushed into `errors` as a
+    /// `Severity::Fault` entry naming the types found.
+    ///
+    /// Returns true when the group passes (or the check does not apply).
+    pub fn validate_group_homogeneity(&mut self, group: &Operand, slot: &OperandKind) -> bool {
+        let Operand::Group(members) = group else {
+            return true;
+        };
+        if !slot.expects_uniform() {
+            return true;
+        }
+
+        let mut kinds: Vec<OperandType> = Vec::new();
+        for member in members {
+            if let Some(kind) = Self::operand_type_of(member) {
+                if !kinds.contains(&kind) {
+                    kinds.push(kind);
+                }
+            }
+        }
+
+        if kinds.len() <= 1 {
+            return true;
+        }
+
+        let entry = DebugEntry::new(
+            "validate_group_homogeneity",
+            &format!("{:?}", group),
+            &format!("uniform {:?} group", slot),
+            &format!("mixed group of {:?}", kinds),
+        )
+        .with_location("Bearer::validate_group_homogeneity")
+        .with_suggestion("Split the group, or use a `Value` slot that accepts mixed operands")
+        .with_severity(Severity::Fault);
+
+        self.errors.push(entry);
+        false
+    }
+
+    /// 🧬 The `OperandType` an operand carries, for homogeneity checks.
+    /// Wildcards return `None` — they match any type.
+    fn operand_type_of(operand: &Operand) -> Option<OperandType> {
+        match operand {
+            Operand::Literal { dtype, .. } => Some(dtype.clone().unwrap_or(OperandType::Unknown)),
+            Operand::Binding { .. } => Some(OperandType::Symbol),
+            Operand::Group(_) => Some(OperandType::Scroll),
+            Operand::InstructionCall { .. } | Operand::InstructionRef(_) => {
+                Some(OperandType::Instruction)
+            }
+            Operand::PathAccess { .. } => Some(OperandType::Path),
+            Operand::Duration(_) => Some(OperandType::Duration),
+            Operand::ResolvedValue(_) => Some(OperandType::PreFolded),
+            Operand::Placeholder(_) => Some(OperandType::Placeholder),
+            Operand::Wildcard => None,
+            Operand::InvalidOperand { .. } => Some(OperandType::Unknown),
+        }
     }
 
     // ===================================================
@@ -1251,27 +1153,139 @@ impl Bearer {
     ///
     /// This method plants the scroll tree into the Bearer and
     /// immediately initiates tree traversal to extract and classify operands.
-    pub fn begin_resolution(&mut self, scroll_tree: ScrollTree) {
+    /// Same-scope redeclarations and stray returns are flagged before traversal begins.
+    ///
+    /// Every instruction in the tree must be registered; the first one's
+    /// schema is loaded before the walk. An unknown instruction is recorded
+    /// as an `Error` entry and returned without walking.
+    pub fn begin_resolution(&mut self, scroll_tree: ScrollTree) -> Result<(), ResolutionError> {
+        // 🪞 Surface shadowed declarations, stray returns, and empty bodies before operand discovery
+        self.detect_shadowed_declarations(&scroll_tree);
+        self.detect_stray_returns(&scroll_tree);
+        self.detect_empty_blocks(&scroll_tree);
+
+        // 📚 Require a registry entry for every instruction before walking
+        let unknown = scroll_tree
+            .instructions()
+            .map(|(name, _)| name)
+            .find(|name| self.instruction_registry.get(name).is_none())
+            .map(str::to_string);
+        let first = scroll_tree
+            .instructions()
+            .next()
+            .map(|(name, _)| name.to_string());
+
         self.scroll_tree = Some(scroll_tree);
 
+        if let Some(name) = unknown {
+            self.record_unknown_instruction(&name, "begin_resolution");
+            return Err(ResolutionError::UnknownInstruction { name });
+        }
+
+        if let Some(name) = first {
+            self.load_instruction_schema(&name);
+        }
+
         // 🌿 Begin operand discovery immediately
         self.walk_scroll_tree();
+        Ok(())
+    }
+
+    /// 🧺 Resolves every instruction in the tree on its own, so one bad
+    /// instruction does not stop the rest.
+    ///
+    /// Unlike `begin_resolution`, an unknown instruction does not refuse
+    /// the tree — it is reported and the walk moves on. An instruction is
+    /// `Invalid` when its own resolution recorded an `Error`-or-worse entry
+    /// (in `debug_trace` or `errors`, as an unknown `ref:` does) or built an
+    /// `InvalidOperand`; the first such finding is its reason.
+    /// Scope checks run first, as in `begin_resolution`, but only findings
+    /// from an instruction's own resolution count against it.
+    pub fn resolve_all(&mut self, tree: &ScrollTree) -> ResolutionReport {
+        self.detect_shadowed_declarations(tree);
+        self.detect_stray_returns(tree);
+        self.detect_empty_blocks(tree);
+
+        let mut report = ResolutionReport::default();
+        self.progress = (0, tree.instructions().count());
+
+        for (node, span) in tree.descendants_with_spans() {
+            let ScrollNode::Instruction { name, args } = node else {
+                continue;
+            };
+            self.current_line = span.map_or(0, |span| span.start.0);
+            let trace_start = self.debug_trace.len();
+            let error_start = self.errors.len();
+            let operand_start = self.resolved_operands.len();
+
+            if self.instruction_registry.get(name).is_some() {
+                self.resolve_instruction_node(node, name, args);
+            } else {
+                self.record_unknown_instruction(name, "resolve_all");
+            }
+
+            // 🔎 Judge this instruction by what it alone produced
+            let failed_entry = self.debug_trace[trace_start..]
+                .iter()
+                .chain(&self.errors[error_start..])
+                .find(|entry| entry.severity.ceiling() <= Severity::Error.ceiling())
+                .map(|entry| format!("expected {}, got {}", entry.expected, entry.actual));
+            let invalid_operand = self.resolved_operands[operand_start..]
+                .iter()
+                .find_map(|operand| match operand {
+                    Operand::InvalidOperand { text, reason, .. } => {
+                        Some(format!("'{}': {}", text, reason))
+                    }
+                    _ => None,
+                });
+            let status = match failed_entry.or(invalid_operand) {
+                Some(reason) => ResolutionStatus::Invalid(reason),
+                None => ResolutionStatus::Resolved,
+            };
+
+            report.instructions.push(InstructionResolution {
+                name: name.clone(),
+                args: args.clone(),
+                status,
+            });
+
+            // 📶 One more instruction through the Bearer, resolved or not
+            self.progress.0 += 1;
+            if let Some(callback) = self.on_progress {
+                callback(self.progress.0, self.progress.1);
+            }
+        }
+
+        report
+    }
+
+    /// ❓ Records `name` as an unregistered instruction, with a near-keyword hint if one exists.
+    fn record_unknown_instruction(&mut self, name: &str, command: &str) {
+        let mut entry = DebugEntry::new(command, name, "registered instruction", "unknown")
+            .with_location(&format!("Bearer::{}", command))
+            .with_suggestion("Register the instruction or correct its keyword");
+        if let Some(known) = self.suggest_identifier(name) {
+            entry = entry.with_suggestion(&format!("did you mean '{}'?", known));
+        }
+
+        self.record_debug_entry(entry.with_severity(Severity::Error));
     }
 
     /// 📚 Loads the operand schema for a specific instruction.
     ///
-    /// Retrieves the operand schema (arity and expected operand structure)
-    /// from the instruction registry based on the instruction’s name.
-    /// Logs a warning if the schema is missing, malformed, or mismatched.
-    pub fn load_instruction_schema(&mut self, instruction: &Instruction) {
-        self.instruction_schema = self.instruction_registry.get_schema(&instruction.name);
+    /// Retrieves the operand schema (arity and ordered operand kinds)
+    /// from the instruction registry based on the instruction’s keyword.
+    /// Logs a trace entry if the instruction is unknown or declares no schema.
+    pub fn load_instruction_schema(&mut self, keyword: &str) {
+        self.current_instruction = Some(keyword.to_string());
+        self.instruction_schema = self.instruction_registry.get_schema(keyword);
 
         if self.instruction_schema.is_none() {
-            self.record_debug_entry(DebugEntry {
-                line: instruction.line,
-                message: format!("Missing schema for instruction '{}'", instruction.name),
-                severity: Severity::Broken,
-            });
+            self.record_debug_entry(
+                DebugEntry::new("load_instruction_schema", keyword, "operand schema", "none")
+                    .with_location("Bearer::load_instruction_schema")
+                    .with_severity(Severity::Info),
+            );
         }
     }
 
@@ -1281,114 +1295,478 @@ impl Bearer {
 
     /// 🌿 Walks the scroll tree and processes operand nodes.
     ///
-    /// This function iterates through the children of the scroll tree root,
-    /// classifies operand types, validates arity, and constructs resolved operands.
-    /// It assumes a schema has been loaded prior to invocation.
+    /// Each `Instruction` node loads its schema from the registry; its
+    /// arguments are classified and constructed, checked with
+    /// `Instruction::matches_operands`, then trust-marked and pushed into
+    /// `resolved_operands`. A `Divine`-privilege instruction is recorded as
+    /// `Severity::Fatal` and left unresolved unless `divine_unlock` is set.
+    /// Nested `Block`, `Conditional`, and `Loop` bodies are walked in order,
+    /// and each instruction's span sets `current_line` before it resolves.
+    /// `progress` counts resolved instructions against the tree's total,
+    /// and `on_progress` hears each step.
     pub fn walk_scroll_tree(&mut self) {
-        if self.scroll_tree.is_none() || self.instruction_schema.is_none() {
-            eprintln!("⚠️ Cannot walk tree — scroll or schema missing.");
+        let Some(tree) = self.scroll_tree.take() else {
+            eprintln!("⚠️ Cannot walk tree — scroll missing.");
             return;
+        };
+
+        self.progress = (0, tree.instructions().count());
+        for (node, span) in tree.descendants_with_spans() {
+            let ScrollNode::Instruction { name, args } = node else {
+                continue;
+            };
+            self.current_line = span.map_or(0, |span| span.start.0);
+            self.resolve_instruction_node(node, name, args);
+
+            // 📶 One more instruction through the Bearer
+            self.progress.0 += 1;
+            if let Some(report) = self.on_progress {
+                report(self.progress.0, self.progress.1);
+            }
         }
 
-        let tree = self.scroll_tree.as_ref().unwrap();
-        let schema = self.instruction_schema.as_ref().unwrap();
-
-        // Only process top-level children for now
-        let operand_nodes = &tree.root.children;
-
-        // 🔍 Validate operand count (arity)
-        if !self.validate_arity(&tree.root, schema) {
-            self.record_debug_entry(DebugEntry {
-                line: 0,
-                message: format!(
-                    "Arity mismatch: expected {}, found {}.",
-                    schema.arity,
-                    operand_nodes.len()
-                ),
-                severity: Severity::Broken,
-            });
+        self.scroll_tree = Some(tree);
+    }
+
+    /// 🪙 Resolves every argument of a single instruction node.
+    fn resolve_instruction_node(&mut self, node: &ScrollNode, name: &str, args: &[String]) {
+        self.current_node = Some(node.clone());
+        self.load_instruction_schema(name);
+
+        // 🕊️ Irreversible instructions resolve only under an explicit unlock
+        if !self.divine_unlock && self.is_divine(name) {
+            self.record_debug_entry(
+                DebugEntry::new("walk_scroll_tree", name, "divine unlock", "locked")
+                    .with_location("Bearer::walk_scroll_tree")
+                    .with_suggestion("Set `divine_unlock` to resolve Divine-privilege instructions")
+                    .with_severity(Severity::Fatal),
+            );
+            return;
+        }
+
+        // 🧾 `let x = 1` — the `=` is syntax, not an operand, as in the parser's count
+        let args: Vec<String> = args.iter().filter(|arg| *arg != "=").cloned().collect();
+
+        // 🏷 `@trusted x` — annotations are not operands; each asserts the next one's tier
+        let (args, overrides) = Self::split_trust_annotations(&args);
+        let args = args.as_slice();
+
+        // 🗃 An identical instruction seen before reuses its operands
+        let (operands, mismatch) = match self.cached_resolution(name, args) {
+            Some(cached) => cached,
+            None => {
+                // 🌱 Classify and construct each operand
+                let operands: Vec<Operand> = args
+                    .iter()
+                    .map(|arg| {
+                        let operand_type = self.classify_operand_type(arg);
+                        self.construct_operand(arg, operand_type)
+                    })
+                    .collect();
+
+                // 🔍 Validate operands against the schema (count, then kind)
+                let mismatch = self
+                    .instruction_registry
+                    .get(name)
+                    .and_then(|instruction| instruction.matches_operands(&operands).err());
+
+                // 🧭 Bindings read the current scope, so only scope-free operands are kept
+                let scoped = operands.iter().any(|operand| matches!(operand, Operand::Binding { .. }));
+                if !scoped {
+                    self.resolution_cache.insert(
+                        Self::signature_hash(name, args),
+                        CachedResolution {
+                            name: name.to_string(),
+                            args: args.to_vec(),
+                            operands: operands.clone(),
+                            mismatch: mismatch.clone(),
+                        },
+                    );
+                }
+                (operands, mismatch)
+            }
+        };
+
+        // ⛔ A count mismatch stops resolution outright
+        if let Some(OperandMismatch::Count { expected, found }) = mismatch {
+            self.record_schema_mismatch(
+                name,
+                &format!("{} operands", expected),
+                &format!("{} operands", found),
+            );
             return;
         }
 
-        // 🌱 Walk each operand node, classify, construct, and store
-        for node in operand_nodes {
-            let operand_type = self.classify_operand_type(node);
-            let operand = self.construct_operand(node, operand_type);
-            let trust = self.mark_trust_level(&operand);
+        // 🪙 Mark trust for and store each operand
+        for ((arg, operand), annotated) in args.iter().zip(operands).zip(overrides) {
+            let inferred = match operand {
+                Operand::InstructionRef(_) => self.resolve_instruction_ref(arg),
+                _ => self.mark_trust_level(&operand),
+            };
+            let trust = match annotated {
+                Some(tier) => {
+                    self.record_debug_entry(
+                        DebugEntry::new(
+                            "walk_scroll_tree",
+                            arg,
+                            &format!("{:?} (inferred)", inferred),
+                            &format!("{:?} (annotated)", tier),
+                        )
+                        .with_location("Bearer::walk_scroll_tree")
+                        .with_suggestion("Trust was asserted by annotation, not verified")
+                        .with_severity(Severity::Info),
+                    );
+                    tier
+                }
+                None => inferred,
+            };
+            self.trust_flags.insert(arg.clone(), trust.clone());
+
+            self.record_debug_entry(
+                DebugEntry::new(
+                    "walk_scroll_tree",
+                    arg,
+                    "resolved operand",
+                    &format!("{:?} with trust {:?}", operand, trust),
+                )
+                .with_location("Bearer::walk_scroll_tree")
+                .with_severity(Severity::Pass),
+            );
+
+            self.resolved_operands.push(operand);
+        }
 
-            self.operands.push(operand.clone());
+        // 🧩 A kind mismatch is reported after the operands' own findings
+        if let Some(OperandMismatch::Kind {
+            position,
+            expected,
+            found,
+        }) = mismatch
+        {
+            self.record_schema_mismatch(
+                name,
+                &format!("{} at position {}", expected, position),
+                &found,
+            );
+        }
+    }
 
-            self.record_debug_entry(DebugEntry {
-                line: node.line,
-                message: format!("Resolved operand: {:?} with trust {:?}", operand, trust),
-                severity: Severity::Valid,
-            });
+    /// 🏷 Separates trust annotations from operands.
+    ///
+    /// Returns the operands alone, each paired with the tier asserted by
+    /// the annotation written before it. An annotation with no operand
+    /// after it asserts nothing.
+    pub fn split_trust_annotations(args: &[String]) -> (Vec<String>, Vec<Option<TrustTier>>) {
+        let mut operands = Vec::new();
+        let mut overrides = Vec::new();
+        let mut pending = None;
+
+        for arg in args {
+            match TrustTier::from_annotation(arg) {
+                Some(tier) => pending = Some(tier),
+                None => {
+                    operands.push(arg.clone());
+                    overrides.push(pending.take());
+                }
+            }
         }
+
+        (operands, overrides)
+    }
+
+    /// 🗃 Hashes an instruction's verb and operand text into its cache key.
+    pub fn signature_hash(name: &str, args: &[String]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        args.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// 🧹 Empties `resolution_cache` and binds it to the current registry.
+    pub fn invalidate_cache(&mut self) {
+        self.resolution_cache.clear();
+        self.cache_registry = Arc::clone(&self.instruction_registry);
     }
 
-    /// 🪞 Validates operand count against expected arity.
+    /// 🗃 Looks up a prior resolution of `name args`, counting a hit.
     ///
-    /// Returns true if the number of operand nodes matches the schema arity.
-    pub fn validate_arity(&self, node: &ScrollNode, schema: &OperandSchema) -> bool {
-        node.children.len() == schema.arity
+    /// A registry swapped in since the cache was filled empties it first.
+    /// Invalid operands are re-stamped with the current line.
+    fn cached_resolution(
+        &mut self,
+        name: &str,
+        args: &[String],
+    ) -> Option<(Vec<Operand>, Option<OperandMismatch>)> {
+        if !Arc::ptr_eq(&self.cache_registry, &self.instruction_registry) {
+            self.invalidate_cache();
+        }
+
+        let cached = self
+            .resolution_cache
+            .get(&Self::signature_hash(name, args))
+            .filter(|cached| cached.name == name && cached.args == args)?;
+
+        let operands = cached
+            .operands
+            .iter()
+            .cloned()
+            .map(|operand| match operand {
+                Operand::InvalidOperand { text, reason, .. } => Operand::InvalidOperand {
+                    text,
+                    reason,
+                    line: self.current_line,
+                },
+                operand => operand,
+            })
+            .collect();
+        let mismatch = cached.mismatch.clone();
+
+        self.cache_hits += 1;
+        Some((operands, mismatch))
+    }
+
+    /// 🕊️ Returns `true` if `name` is registered with `PrivilegeLevel::Divine`.
+    fn is_divine(&self, name: &str) -> bool {
+        self.instruction_registry
+            .get(name)
+            .and_then(|instruction| instruction.privilege_level())
+            == Some(&PrivilegeLevel::Divine)
+    }
+
+    /// 🔤 Closest known instruction keyword or visible binding to `name`.
+    ///
+    /// Candidates within `MAX_SUGGESTION_DISTANCE` edits (and fewer edits
+    /// than `name` has characters) qualify; the nearest wins, ties broken
+    /// alphabetically. `None` when nothing is close enough.
+    fn suggest_identifier(&self, name: &str) -> Option<String> {
+        let limit = MAX_SUGGESTION_DISTANCE.min(name.chars().count().saturating_sub(1));
+
+        self.instruction_registry
+            .iter()
+            .map(|(keyword, _)| *keyword)
+            .chain(self.environment.names())
+            .filter(|candidate| *candidate != name)
+            .map(|candidate| (Self::edit_distance(name, candidate), candidate))
+            .filter(|(distance, _)| *distance <= limit)
+            .min()
+            .map(|(_, candidate)| candidate.to_string())
+    }
+
+    /// 📏 Levenshtein distance between `a` and `b`, counted in chars.
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+        for (i, ca) in a.chars().enumerate() {
+            let mut current = vec![i + 1];
+            for (j, cb) in b.iter().enumerate() {
+                let substitution = previous[j] + usize::from(ca != *cb);
+                current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+            }
+            previous = current;
+        }
+
+        previous[b.len()]
+    }
+
+    /// ❌ Records an operand-schema mismatch for `name` as an `Error` entry.
+    fn record_schema_mismatch(&mut self, name: &str, expected: &str, actual: &str) {
+        self.record_debug_entry(
+            DebugEntry::new("walk_scroll_tree", name, expected, actual)
+                .with_location("Bearer::walk_scroll_tree")
+                .with_suggestion("Match the instruction's operand schema")
+                .with_severity(Severity::Error),
+        );
     }
 
     // ===================================================
     // 🛠 OPERAND CONSTRUCTION & TYPE LOGIC
     // ===================================================
 
-    /// 🪶 Determines the operand type based on the node.
+    /// 🪶 Determines the operand type based on the raw token.
     ///
-    /// This logic checks the structure and token contents of a scroll node
-    /// to determine if it’s a literal, binding, or symbolic reference.
+    /// This logic checks the token's shape to determine if it’s a literal,
+    /// binding, path, instruction reference, or structural marker.
     /// For now, it's simple — but it's structured for evolution.
-    pub fn classify_operand_type(&self, node: &ScrollNode) -> OperandType {
-        if node.token.starts_with('"') && node.token.ends_with('"') {
-            OperandType::Literal
-        } else if node.token.starts_with('$') {
-            OperandType::Binding
-        } else if node.token == "*" {
+    pub fn classify_operand_type(&self, token: &str) -> OperandType {
+        let is_ident = |s: &str| {
+            s.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+                && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+        };
+
+        if let Some(literal) = OperandType::of_literal(token) {
+            literal
+        } else if token.starts_with("ref:") {
+            OperandType::Instruction
+        } else if token == "*" {
             OperandType::Wildcard
-        } else if node.token.starts_with("ref:") {
-            OperandType::InstructionRef
-        } else if node.token == "_" {
+        } else if token == "_" {
             OperandType::Placeholder
+        } else if is_ident(token.trim_start_matches('$')) {
+            OperandType::Symbol
+        } else if token.split("::").flat_map(|s| s.split('.')).all(is_ident) {
+            OperandType::Path
         } else {
             OperandType::Unknown
         }
     }
 
-    /// 🏗️ Constructs the operand from a scroll node and type.
+    /// 🧠 Classifies a token, preferring the tokenizer's `OperandHint`.
+    ///
+    /// Labels and registers are named slots, so both resolve as `Symbol`;
+    /// unhinted tokens fall back to `classify_operand_type` inference.
+    pub fn classify_token(&self, token: &Token) -> OperandType {
+        match token.hint {
+            Some(OperandHint::Label | OperandHint::Register) => OperandType::Symbol,
+            None => self.classify_operand_type(&token.value),
+        }
+    }
+
+    /// 🏗️ Constructs the operand from a raw token and its type.
     ///
     /// This function builds the appropriate operand variant
-    /// based on parsed operand type and the node's token contents.
-    pub fn construct_operand(&self, node: &ScrollNode, operand_type: OperandType) -> Operand {
+    /// based on parsed operand type and the token's contents.
+    /// Symbols pick up their alignment from the scoped environment.
+    pub fn construct_operand(&self, token: &str, operand_type: OperandType) -> Operand {
         match operand_type {
-            OperandType::Literal => Operand::Literal {
-                value: node.token.trim_matches('"').to_string(),
+            OperandType::String => Operand::Literal {
+                value: token.trim_matches('"').to_string(),
+                dtype: Some(OperandType::String),
             },
-            OperandType::Binding => Operand::Binding {
-                symbol: node.token.trim_start_matches('$').to_string(),
+            OperandType::Integer | OperandType::Float | OperandType::Boolean => Operand::Literal {
+                value: token.to_string(),
+                dtype: Some(operand_type),
             },
-            OperandType::Wildcard => Operand::Wildcard,
-            OperandType::InstructionRef => {
-                Operand::InstructionRef(node.token.trim_start_matches("ref:").to_string())
+            OperandType::Symbol => {
+                let name = token.trim_start_matches('$').to_string();
+                let alignment = match self.environment.lookup(&name) {
+                    Some(Operand::Binding { alignment, .. }) => alignment.clone(),
+                    _ => None,
+                };
+
+                Operand::Binding { name, alignment }
+            }
+            OperandType::Path => Operand::PathAccess {
+                path: token
+                    .split("::")
+                    .flat_map(|s| s.split('.'))
+                    .map(str::to_string)
+                    .collect(),
+            },
+            OperandType::Instruction => {
+                Operand::InstructionRef(token.trim_start_matches("ref:").to_string())
             }
-            OperandType::Placeholder => Operand::Placeholder("_".to_string()),
-            OperandType::Unknown => Operand::InvalidOperand(node.token.clone()),
+            OperandType::Duration => match parse_duration(token) {
+                Some(duration) => Operand::Duration(duration),
+                None => Operand::InvalidOperand {
+                    text: token.to_string(),
+                    reason: "expected a duration like `5s` or `200ms`".to_string(),
+                    line: self.current_line,
+                },
+            },
+            OperandType::Wildcard => Operand::Wildcard,
+            OperandType::Placeholder => Operand::Placeholder(token.to_string()),
+            OperandType::PreFolded => Operand::ResolvedValue(token.to_string()),
+            OperandType::Scroll | OperandType::Unknown => Operand::InvalidOperand {
+                text: token.to_string(),
+                reason: Self::invalid_reason(token, &operand_type),
+                line: self.current_line,
+            },
         }
     }
 
+    /// 🔚 Constructs the operand a `ScrollNode::Return` carries.
+    ///
+    /// A tuple `(a, b)` becomes a `Group` of its constructed elements;
+    /// any other value is constructed like a single operand.
+    pub fn construct_return(&self, value: &str) -> Operand {
+        match value.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+            Some(inner) => Operand::Group(
+                inner
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|element| !element.is_empty())
+                    .map(|element| {
+                        let operand_type = self.classify_operand_type(element);
+                        self.construct_operand(element, operand_type)
+                    })
+                    .collect(),
+            ),
+            None => self.construct_operand(value, self.classify_operand_type(value)),
+        }
+    }
+
+    /// ❓ Explains why a token could not be classified as an operand.
+    fn invalid_reason(token: &str, operand_type: &OperandType) -> String {
+        if matches!(operand_type, OperandType::Scroll) {
+            "expected a single operand, got a scroll group".to_string()
+        } else if token.is_empty() {
+            "expected an operand, got nothing".to_string()
+        } else if token.starts_with('"') {
+            "expected a closed string literal, got an unterminated one".to_string()
+        } else if token.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+            "expected numeric, got a malformed number".to_string()
+        } else {
+            "expected literal, symbol, or path, got an unrecognized form".to_string()
+        }
+    }
+
+    /// 🔗 Resolves a `ref:`-prefixed token against the instruction registry.
+    ///
+    /// Known keywords store their opcode in `instruction_refs` and are marked
+    /// `Certain`. Unknown keywords are downgraded to `Invalid` and reported
+    /// into `errors`. Either way the tier is recorded in `trust_flags`
+    /// under the full token (e.g. `ref:let`).
+    pub fn resolve_instruction_ref(&mut self, token: &str) -> TrustTier {
+        let keyword = token.trim_start_matches("ref:");
+
+        let trust = match self.instruction_registry.get(keyword) {
+            Some(instruction) => {
+                self.instruction_refs
+                    .insert(keyword.to_string(), instruction.opcode());
+                TrustTier::Certain
+            }
+            None => {
+                let mut entry = DebugEntry::new(
+                    "resolve_instruction_ref",
+                    token,
+                    "registered instruction keyword",
+                    &format!("no instruction named '{}'", keyword),
+                )
+                .with_location("Bearer::resolve_instruction_ref")
+                .with_suggestion("Check the referenced keyword against the instruction registry");
+                if let Some(known) = self.suggest_identifier(keyword) {
+                    entry = entry.with_suggestion(&format!("did you mean '{}'?", known));
+                }
+
+                self.errors.push(entry.with_severity(Severity::Error));
+                TrustTier::Invalid
+            }
+        };
+
+        self.trust_flags.insert(token.to_string(), trust.clone());
+        trust
+    }
+
     /// 🕊️ Assigns a trust tier to a resolved operand.
     ///
     /// This scoring system is temporary. It provides a rudimentary
     /// mapping of operand clarity for now — designed for future depth.
     pub fn mark_trust_level(&self, operand: &Operand) -> TrustTier {
         match operand {
-            Operand::Literal { .. } | Operand::Binding { .. } => TrustTier::Sealed,
-            Operand::Wildcard | Operand::InstructionRef(_) => TrustTier::Ambiguous,
-            Operand::Placeholder(_) => TrustTier::Shadowed,
-            Operand::InvalidOperand(_) => TrustTier::Broken,
+            Operand::Literal { .. } | Operand::ResolvedValue(_) | Operand::Duration(_) => {
+                TrustTier::Certain
+            }
+            Operand::Binding { alignment: Some(_), .. } => TrustTier::Trusted,
+            Operand::Binding { alignment: None, .. } | Operand::Placeholder(_) => {
+                TrustTier::Shadowed
+            }
+            Operand::Group(_)
+            | Operand::InstructionCall { .. }
+            | Operand::InstructionRef(_)
+            | Operand::PathAccess { .. }
+            | Operand::Wildcard => TrustTier::Ambiguous,
+            Operand::InvalidOperand { .. } => TrustTier::Invalid,
         }
     }
 
@@ -1404,52 +1782,4 @@ impl Bearer {
     pub fn record_debug_entry(&mut self, entry: DebugEntry) {
         self.debug_trace.push(entry);
     }
-
-    /// 📖 emit_operand_trace — Returns a string representation of the resolved operands.
-    /// Useful for CLI debug view, Watchtower snapshots, or postmortem analysis.
-    pub fn emit_operand_trace(instruction: &Instruction) -> String {
-        if instruction.resolved_operands.is_empty() {
-            return "[no operands resolved]".to_string();
-        }
-
-        instruction
-            .resolved_operands
-            .iter()
-            .map(|op| format!("{:?}", op))
-            .collect::<Vec<_>>()
-            .join(" | ")
-    }
-
-    /// 📦 Finalizes all resolved operands for handoff.
-    ///
-    /// This step marks the Bearer's resolution phase as complete.
-    /// It verifies that all operands are resolved and adjusts the
-    /// instruction status accordingly.
-    ///
-    /// Future hooks may emit diagnostics to `.logos` or Watchtower overlays.
-    pub fn finalize_operands(&mut self) {
-        if let Some(ref mut instruction) = self.current_instruction {
-            let all_resolved = instruction
-                .resolved_operands
-                .iter()
-                .all(|op| !matches!(op, Operand::InvalidOperand(_) | Operand::Placeholder(_)));
-
-            if all_resolved {
-                instruction.status = InstructionStatus::ReadyToAssemble;
-            } else {
-                instruction.status = InstructionStatus::RequiresResolution;
-
-                // 🧾 Push debug trace for post-resolution awareness
-                instruction.debug_trace.push(DebugEntry {
-                    line: instruction.line,
-                    message: "Finalization failed — unresolved or invalid operand detected."
-                        .to_string(),
-                    severity: Severity::Broken,
-                });
-
-                // 🚨 Optional: Emit Watchtower trace
-                Self::report_to_watchtower(instruction);
-            }
-        }
-    }
 }
@@ -46,22 +46,41 @@
 // • Internal: tokenizer, instruction metadata, operand resolver
 // • Debugging: Watchtower trace scaffolding
 
-// === Standard Library ===
-use std::collections::VecDeque; // 🔁 Token queue for recursive descent parsing
+// === Standard Library (alloc-only core) ===
+use alloc::boxed::Box; // 📦 Type-erased token sources
+use alloc::collections::BTreeMap; // 📜 Unrecognized header keys, in key order
+use alloc::collections::VecDeque; // 🔁 Token queue for recursive descent parsing
+use alloc::format; // 🧾 Error and `.stone` message formatting
+use alloc::string::{String, ToString}; // 🔤 Node names and values
+use alloc::sync::Arc; // 🤝 Registry shared between parsers and threads
+use alloc::vec; // 📚 Node list literals
+use alloc::vec::Vec; // 📚 Node and error lists
+
+use serde::{Deserialize, Serialize}; // 🧾 JSON snapshots of ParseError shapes
 #[allow(unused_imports)]
-use std::fmt; // 🧾 Enables custom Display / Debug formatting for ScrollTree or error logs
+use core::fmt; // 🧾 Enables custom Display / Debug formatting for ScrollTree or error logs
 
 // === External Crates ===
 #[allow(unused_imports)]
+#[cfg(feature = "std")]
 use chrono::Utc; // 🕰 Timestamps parse events for trace diagnostics and scroll lineage
 
 // === Internal Modules ===
-use super::instruction_registry::get_instruction_registry; // 📚 Instruction schema registry — validates opcodes and operand expectations
-use crate::operand_resolver::Bearer;
+use super::instruction_registry::InstructionRegistry; // 📚 Instruction schema registry — validates opcodes and operand expectations
+use super::instruction_registry::{OperandKind, OperandMismatch, SchemaOperand}; // 🧮 Operand slots checked by `check_operand_schema`
+use super::instruction_registry::PrivilegeLevel; // 🔐 Privilege ceiling for `ValidateOptions`
+use super::instruction_registry::{slot_for, EncodeConfig, EncodeError, JumpMode}; // 🧬 Bytecode layout in `assemble`
+#[cfg(feature = "std")]
+use super::instruction_registry::shared_registry; // 🤝 Default registry for new parsers
+#[cfg(feature = "std")]
+use crate::operand_resolver::{is_trust_annotation, BindingEnvironment, OperandType}; // 🏛 Scoped, typed bindings for the symbol pass, 🏷 `@trusted` annotations
+#[cfg(feature = "std")]
+use crate::operand_resolver::Bearer; // 🪙 Argument classification for enriched `.stone`
 use crate::tokenizer::{Token, TokenType}; // 🧱 Core units of NovaScript — value, type, and source position // 🧱 Operand Resolver — performs operand classification after parsing
 
 // === Watchtower Integration ===
 #[allow(unused_imports)]
+#[cfg(feature = "std")]
 use watchtower::debugger::{
     DebugEntry,    // 📋 Individual trace record — includes line, source, and severity
     DebugResponse, // 🔧 Feedback object for system-level debugging or confirmation
@@ -89,7 +108,7 @@ use watchtower::debugger::{
 /// 🧩 Enum representing all valid node types produced by the parser.
 /// These nodes are not yet operands or bindings—they are raw structures,
 /// capturing grammatical meaning and scroll intent in intermediate form.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ScrollNode {
     Instruction {
         name: String,
@@ -144,10 +163,241 @@ pub enum ScrollNode {
     Call {
         function: String,
         args: Vec<String>,
+        named: Vec<(String, String)>,
     },
     // 📞 A function call node (used in nested or procedural expressions)
+    //     → positional `args` in order, `named` as `(param, value)` pairs
     Comment(String),
     // 💬 A non-evaluated annotation (inline or floating comment)
+    Function {
+        name: String,
+        params: Vec<String>,
+        body: Vec<ScrollNode>,
+    },
+    // 🛤 A callable subroutine definition — the only context where `return` belongs
+    //     → e.g., `walk bless_all(flock) { ... }`
+    Directive(AsmDirective),
+    // 🧷 An assembler directive shaping bytecode layout in `ScrollTree::assemble`
+    //     → e.g., `.byte 0x41, 0x42`
+}
+
+// ------------------------------------------------
+// 🔨 ScrollNode Constructors — Borrowed-Text Shorthand
+// ------------------------------------------------
+// One constructor per struct-like variant, taking `&str` and copying it in,
+// so trees can be written without spelling out every field and `to_string`.
+// Tuple variants (`Literal`, `Comment`, `Block`, ...) are already one call.
+
+impl ScrollNode {
+    /// ⚙️ `Instruction` — `wait`, `speak "truth"`
+    pub fn instruction(name: &str, args: &[&str]) -> Self {
+        ScrollNode::Instruction {
+            name: name.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    /// 🧾 `ScrollSentence` — `Let flame be 5`
+    pub fn sentence(subject: &str, verb: &str, object: &str) -> Self {
+        ScrollNode::ScrollSentence {
+            subject: subject.to_string(),
+            verb: verb.to_string(),
+            object: object.to_string(),
+        }
+    }
+
+    /// 📦 `Assignment` — `holiness = 100`, or `a, b = pair()`
+    pub fn assignment(target: &str, value: &str) -> Self {
+        ScrollNode::Assignment {
+            target: target.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// ✒️ `Declaration` — `let x`, or `let x: Int` with a `dtype`
+    pub fn declaration(name: &str, dtype: Option<&str>) -> Self {
+        ScrollNode::Declaration {
+            name: name.to_string(),
+            dtype: dtype.map(str::to_string),
+        }
+    }
+
+    /// 🧭 `Conditional` — `if x { ... }`
+    pub fn conditional(condition: &str, body: Vec<ScrollNode>) -> Self {
+        ScrollNode::Conditional {
+            condition: condition.to_string(),
+            body,
+        }
+    }
+
+    /// 🔁 `Loop` — `while x { ... }`
+    pub fn while_loop(condition: &str, body: Vec<ScrollNode>) -> Self {
+        ScrollNode::Loop {
+            condition: condition.to_string(),
+            body,
+        }
+    }
+
+    /// 📞 `Call` — `invoke("grace", "mercy")`
+    pub fn call(function: &str, args: &[&str]) -> Self {
+        Self::named_call(function, args, &[])
+    }
+
+    /// 📞 `Call` with named arguments — `bless(x, amount: 5)`
+    pub fn named_call(function: &str, args: &[&str], named: &[(&str, &str)]) -> Self {
+        ScrollNode::Call {
+            function: function.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            named: named
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    /// 🛤 `Function` — `walk bless_all(flock) { ... }`
+    pub fn function(name: &str, params: &[&str], body: Vec<ScrollNode>) -> Self {
+        ScrollNode::Function {
+            name: name.to_string(),
+            params: params.iter().map(|param| param.to_string()).collect(),
+            body,
+        }
+    }
+}
+
+/// 🏷 Splits one argument of call text (`pair(x, amount: 5)`) into parameter
+/// name and value. Call nodes keep named arguments apart already; this reads
+/// the text form, where string literals are quoted (see `operand_text`), so
+/// `"note: this"` and `{key: value}` give `None`.
+pub fn split_named_argument(arg: &str) -> Option<(&str, &str)> {
+    arg.split_once(": ").filter(|(name, _)| is_name(name))
+}
+
+/// 📞 Writes call arguments as text — positional first, then `name: value`.
+pub fn call_arguments_text(args: &[String], named: &[(String, String)]) -> String {
+    args.iter()
+        .cloned()
+        .chain(named.iter().map(|(name, value)| format!("{}: {}", name, value)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 🔤 Writes `value` as a `"quoted"` string, escaping `\`, `"`, and line
+/// breaks so the text keeps to one line and reads back unchanged.
+pub fn quote_text(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// 🔤 An operand token's text as a node argument: string literals keep their
+/// quotes (`"@trusted"`, `"amount: 5"`) so they never read as syntax.
+fn operand_text(token: Token) -> String {
+    if token.quoted {
+        quote_text(&token.value)
+    } else {
+        token.value
+    }
+}
+
+/// 🔤 Reads text written by `quote_text`, or `None` when `text` is not quoted.
+pub fn unquote_text(text: &str) -> Option<String> {
+    let inner = text.strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut value = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('r') => value.push('\r'),
+            Some('t') => value.push('\t'),
+            Some(escaped) => value.push(escaped),
+            None => value.push('\\'),
+        }
+    }
+    Some(value)
+}
+
+// ------------------------------------------------
+// 🧷 AsmDirective — Bytecode Layout Directives
+// ------------------------------------------------
+/// 🧷 Assembler directives a scroll may place between instructions.
+/// Written on their own line, dot first; values are decimal or `0x` hex,
+/// except `.label`, which takes one name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsmDirective {
+    Org(u64),      // 📍 `.org 0x100` — zero-pad up to this byte offset
+    Byte(Vec<u8>), // 🔢 `.byte 0x41, 0x42` — emit these bytes verbatim
+    Align(u64),    // 📐 `.align 4` — zero-pad to a multiple of this many bytes
+    Label(String), // 🏷 `.label top` — name the current byte offset as a jump target
+}
+
+impl AsmDirective {
+    /// 🧷 Builds the directive `name` (dot included) from its values,
+    /// or explains why they do not fit it.
+    pub fn new(name: &str, values: &[u64]) -> Result<Self, String> {
+        match (name, values) {
+            (".org", [offset]) => Ok(AsmDirective::Org(*offset)),
+            (".align", [0]) => Err("'.align' needs a non-zero boundary".to_string()),
+            (".align", [boundary]) => Ok(AsmDirective::Align(*boundary)),
+            (".byte", [_, ..]) => values
+                .iter()
+                .map(|&value| u8::try_from(value))
+                .collect::<Result<Vec<u8>, _>>()
+                .map(AsmDirective::Byte)
+                .map_err(|_| "'.byte' values must fit in 0..=255".to_string()),
+            (".org" | ".align", _) => Err(format!("'{}' takes exactly one value", name)),
+            (".label", _) => Err("'.label' takes a name, not a value".to_string()),
+            (".byte", _) => Err("'.byte' needs at least one value".to_string()),
+            _ => Err(format!("Unknown assembler directive '{}'", name)),
+        }
+    }
+
+    /// 🏷 Builds `.label name`, or explains why `name` cannot be a label.
+    pub fn label(name: &str) -> Result<Self, String> {
+        if is_name(name) {
+            Ok(AsmDirective::Label(name.to_string()))
+        } else {
+            Err(format!("'.label' needs a name, found '{}'", name))
+        }
+    }
+
+    /// 🔢 Reads a directive value: decimal, or hex written `0x41`.
+    pub fn parse_value(text: &str) -> Option<u64> {
+        match text.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => text.parse().ok(),
+        }
+    }
+}
+
+/// 🪨 The `.stone` form, which the parser reads back unchanged.
+impl fmt::Display for AsmDirective {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsmDirective::Org(offset) => write!(f, ".org {:#x}", offset),
+            AsmDirective::Byte(bytes) => {
+                let bytes: Vec<String> = bytes.iter().map(|b| format!("{:#04x}", b)).collect();
+                write!(f, ".byte {}", bytes.join(", "))
+            }
+            AsmDirective::Align(boundary) => write!(f, ".align {}", boundary),
+            AsmDirective::Label(name) => write!(f, ".label {}", name),
+        }
+    }
 }
 
 // ------------------------------------------------
@@ -155,9 +405,213 @@ pub enum ScrollNode {
 // ------------------------------------------------
 /// 📚 Represents a fully parsed NovaScript scroll.
 /// Functions as the top-level AST, ordered by sequence of declarations.
+/// Structurally comparable — two trees are equal when their nodes match in order.
+#[derive(Debug, Clone, Default)]
 pub struct ScrollTree {
     pub nodes: Vec<ScrollNode>,
     // 🔗 All top-level nodes in order of appearance (execution flow matters)
+    pub spans: Vec<NodeSpan>,
+    // 📍 Source extents parallel to `nodes` — empty for hand-built trees
+    pub meta: ScrollMeta,
+    // 📜 Header fields gathered from the leading metadata nodes
+}
+
+/// ⚖️ Spans and header fields are derived, not structure — equality ignores them.
+impl PartialEq for ScrollTree {
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes == other.nodes
+    }
+}
+
+// ------------------------------------------------
+// 📜 ScrollMeta — Structured Scroll Header
+// ------------------------------------------------
+/// 📜 The `_key_: value` header a scroll opens with, one field per line:
+///
+/// ```text
+/// #! _author_:       Nova Dawn
+/// #! _version_:      0.0.1
+/// #! _last updated_: 2025-06-15
+/// ```
+///
+/// Only metadata before the first non-comment node counts as header;
+/// a `_cfg_` feature gate belongs to the body and ends it too.
+/// Keys are matched case-insensitively with spaces read as `_`; keys
+/// without a field of their own are kept in `extra`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScrollMeta {
+    pub author: Option<String>,          // ✍️ `_author_`
+    pub version: Option<String>,         // 🔢 `_version_`
+    pub status: Option<String>,          // 🚦 `_status_` (e.g., "Dev")
+    pub created: Option<String>,         // 🌱 `_created_`
+    pub last_updated: Option<String>,    // 🕰 `_last updated_`
+    pub license: Option<String>,         // 📄 `_license_`
+    pub component: Option<String>,       // 🧩 `_component_`
+    pub project: Option<String>,         // 🏛 `_project_`
+    pub description: Option<String>,     // 📝 `_description_`
+    pub extra: BTreeMap<String, String>, // 🗃 Any other `_key_`, by normalized key
+}
+
+impl ScrollMeta {
+    /// 🔍 Collects the header from the metadata nodes that open `nodes`.
+    /// Comments may sit between header lines; any other node ends the header.
+    pub fn from_nodes(nodes: &[ScrollNode]) -> Self {
+        let mut meta = Self::default();
+
+        for node in nodes {
+            match node {
+                ScrollNode::Metadata(line) if Self::feature_gate(line).is_some() => break,
+                ScrollNode::Metadata(line) => {
+                    if let Some((key, value)) = Self::parse_line(line) {
+                        meta.set(&key, value);
+                    }
+                }
+                ScrollNode::Comment(_) => {}
+                _ => break, // 🛑 The body has begun
+            }
+        }
+
+        meta
+    }
+
+    /// 🚦 The feature a `#! _cfg_: name` line requires, or `None` for any other line.
+    pub fn feature_gate(line: &str) -> Option<String> {
+        Self::parse_line(line)
+            .filter(|(key, _)| key == "cfg")
+            .map(|(_, feature)| feature)
+    }
+
+    /// 🔍 Splits one `#! _key_: value` (or `//! _key_: value`) line into a
+    /// normalized key and its value.
+    fn parse_line(line: &str) -> Option<(String, String)> {
+        let text = line.trim_start();
+        let text = text
+            .strip_prefix("#!")
+            .or_else(|| text.strip_prefix("//!"))
+            .unwrap_or(text);
+        let (key, value) = text.split_once(':')?;
+        let key = key.trim().strip_prefix('_')?.strip_suffix('_')?;
+
+        let key = key.trim().to_lowercase().replace(' ', "_");
+        (!key.is_empty()).then(|| (key, value.trim().to_string()))
+    }
+
+    /// ✍️ Stores `value` under its field, or in `extra` for unknown keys.
+    fn set(&mut self, key: &str, value: String) {
+        let field = match key {
+            "author" => &mut self.author,
+            "version" => &mut self.version,
+            "status" => &mut self.status,
+            "created" => &mut self.created,
+            "last_updated" => &mut self.last_updated,
+            "license" => &mut self.license,
+            "component" => &mut self.component,
+            "project" => &mut self.project,
+            "description" => &mut self.description,
+            _ => {
+                self.extra.insert(key.to_string(), value);
+                return;
+            }
+        };
+        *field = Some(value);
+    }
+}
+
+// ------------------------------------------------
+// 📍 NodeSpan — Source Extent of a Parsed Node
+// ------------------------------------------------
+/// 📍 Where a node sits in the scroll, as `(line, column)` token positions.
+/// `children` mirrors the node's body, so spans walk in lockstep with the tree.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NodeSpan {
+    pub start: (usize, usize),   // 🟢 Position of the node's first token
+    pub end: (usize, usize),     // 🔴 Position of the node's last token
+    pub children: Vec<NodeSpan>, // 🧱 Spans of body nodes, in body order
+    pub instruction: Option<InstructionMeta>, // 🧬 Registry metadata, set on instruction nodes
+}
+
+// ------------------------------------------------
+// 🧬 InstructionMeta — Decoded Registry Metadata
+// ------------------------------------------------
+/// 🧬 The registry entry facts `parse_instruction` resolved for a node,
+/// kept so later stages need not look the keyword up again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstructionMeta {
+    pub opcode: u8,             // 🧬 Byte-level opcode (e.g., `0x72` for `let`)
+    pub category: &'static str, // 📂 Registry category (e.g., "Memory/Data")
+}
+
+impl NodeSpan {
+    /// 🎯 True when `(line, column)` falls within this span (inclusive).
+    pub fn contains(&self, line: usize, column: usize) -> bool {
+        self.start <= (line, column) && (line, column) <= self.end
+    }
+}
+
+// ------------------------------------------------
+// 🪨 StoneOptions — What `.stone` Output Carries
+// ------------------------------------------------
+/// 🪨 Flags for `ScrollTree::to_stone_opts`: raw parser output or
+/// Bearer-enriched output with resolved operand types and trust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StoneOptions {
+    pub include_types: bool,    // 🔣 Suffix instruction args with `:Type`
+    pub include_trust: bool,    // 🛡️ Suffix instruction args with `#Tier`
+    pub include_comments: bool, // 💬 Keep `;;` comment lines
+}
+
+impl StoneOptions {
+    /// 🪨 Exactly what `to_stone` writes: comments kept, no enrichment.
+    pub fn raw() -> Self {
+        Self {
+            include_types: false,
+            include_trust: false,
+            include_comments: true,
+        }
+    }
+
+    /// 🧬 Everything on: types, trust, and comments.
+    pub fn enriched() -> Self {
+        Self {
+            include_types: true,
+            include_trust: true,
+            include_comments: true,
+        }
+    }
+}
+
+impl Default for StoneOptions {
+    fn default() -> Self {
+        Self::raw()
+    }
+}
+
+// ------------------------------------------------
+// 🩺 ValidateOptions — Which Passes `validate` Runs
+// ------------------------------------------------
+/// 🩺 Toggles for `ScrollTree::validate`. Every pass is on by default,
+/// and only `User`-privilege instructions are allowed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidateOptions {
+    pub symbols: bool,                 // 🕳 Names read before any binding
+    pub unreachable: bool,             // 🪦 Nodes after a `return` or `break`
+    pub arity: bool,                   // 🧮 Operand counts against the registry schema
+    pub privilege: bool,               // 🔐 Instructions above `max_privilege`
+    pub scripture: bool,               // 📖 `validate_with_scripture` grammar checks
+    pub max_privilege: PrivilegeLevel, // 🧍 Highest privilege the scroll may use
+}
+
+impl Default for ValidateOptions {
+    fn default() -> Self {
+        Self {
+            symbols: true,
+            unreachable: true,
+            arity: true,
+            privilege: true,
+            scripture: true,
+            max_privilege: PrivilegeLevel::User,
+        }
+    }
 }
 // ------------------------------------------------
 // 🌀 ScrollParser — Legacy Non-Resolving Parser
@@ -165,6 +619,7 @@ pub struct ScrollTree {
 /// 🌀 Legacy parser implementation — retained for test scaffolding.
 /// Uses a token queue and does **not** perform operand resolution.
 pub struct ScrollParser {
+    #[allow(dead_code)]
     tokens: VecDeque<Token>,
     // 🪙 Token queue (ordered consumption during basic parsing)
     scroll: Vec<ScrollNode>,
@@ -178,12 +633,41 @@ pub struct ScrollParser {
 /// Parses a linear stream of tokens into `ScrollNode`s and prepares for operand resolution.
 /// Does not build operand structures directly, but enables schema-ready flow into the Bearer.
 pub struct Parser {
-    tokens: Vec<Token>,
-    // 📜 Flat token stream (from tokenizer output)
-    position: usize,
-    // 🔍 Cursor within token stream for ordered access
+    tokens: Box<dyn Iterator<Item = Token>>,
+    // 📜 Token source — a buffered Vec or a lazy `TokenIter`
+    lookahead: VecDeque<Token>,
+    // 👁 Tokens pulled from the source by `peek_n` but not yet consumed
+    errors: Vec<ParseError>,
+    // 🩺 Structured errors recorded alongside emitted `ScrollNode::Error` nodes
+    spans: Vec<Vec<NodeSpan>>,
+    // 📍 Span frames — the last frame collects spans for the body being parsed
+    last_end: (usize, usize),
+    // 🔴 Position of the most recently consumed token
+    case_sensitive_keywords: bool,
+    // 🔠 When false, `decode_instruction` matches keywords in any casing
+    pending_meta: Option<InstructionMeta>,
+    // 🧬 Metadata of the instruction just built, claimed by its span
+    registry: Arc<InstructionRegistry>,
+    // 📚 Keyword lookups — `shared_registry()` unless replaced by `with_registry`
+    reserved_keywords: bool,
+    // 🔒 When true, instruction keywords cannot name variables
+    consumed: usize,
+    // 🔢 Tokens consumed so far — the cursor `checkpoint` hands out
+    history: Vec<Token>,
+    // ⏪ Tokens consumed since the earliest checkpoint, replayed by `restore`
+    history_origin: Option<(usize, (usize, usize))>,
+    // ⏪ Cursor and `last_end` when `history` began recording
+    open_checkpoints: usize,
+    // ⏪ Checkpoints not yet restored or released — recording stops at zero
+    depth: usize,
+    // 🪜 Blocks currently open around the cursor, capped at `MAX_NESTING_DEPTH`
 }
 
+/// 🪜 Deepest a `{ ... }` body may nest. Parsing, cloning, and dropping a
+/// tree all recurse per level, so a runaway scroll would otherwise exhaust
+/// the stack; deeper bodies are skipped and reported instead.
+pub const MAX_NESTING_DEPTH: usize = 128;
+
 // ===============================================
 // 🛠 Constructors & Initializers
 // ===============================================
@@ -219,13 +703,143 @@ impl ScrollParser {
 
 impl Parser {
     /// 🎬 Constructs a new `Parser` from a linear token stream.
-    /// Sets internal cursor to the starting position (0).
+    /// Tokens are consumed from the front, in order.
     pub fn new(tokens: Vec<Token>) -> Self {
+        Self::from_stream(tokens.into_iter())
+    }
+
+    /// 🌊 Constructs a `Parser` that pulls tokens lazily from any iterator.
+    /// Pair with `Tokenizer::stream().recovering()` to parse without
+    /// materializing the full token vector.
+    pub fn from_stream<I>(tokens: I) -> Self
+    where
+        I: Iterator<Item = Token> + 'static,
+    {
+        let source: Box<dyn Iterator<Item = Token>> = Box::new(tokens);
+        #[cfg(feature = "std")]
+        let registry = shared_registry();
+        #[cfg(not(feature = "std"))]
+        let registry = Arc::new(InstructionRegistry::new());
+
         Self {
-            tokens,      // 📜 Token list sourced from tokenizer
-            position: 0, // 🧭 Begin at the first token in the stream
+            tokens: source,                // 📜 Pulled on demand
+            lookahead: VecDeque::new(),    // 👁 Nothing peeked yet
+            errors: Vec::new(),            // 🩺 No errors before parsing begins
+            spans: vec![Vec::new()],       // 📍 Root frame for top-level spans
+            last_end: (0, 0),              // 🔴 Nothing consumed yet
+            case_sensitive_keywords: true, // 🔠 Exact keyword matching
+            pending_meta: None,            // 🧬 No instruction decoded yet
+            registry,                      // 📚 Compiled-in instructions
+            reserved_keywords: true,       // 🔒 `let if = 5` is an error
+            consumed: 0,                   // 🔢 Cursor at the first token
+            history: Vec::new(),           // ⏪ Nothing to replay yet
+            history_origin: None,          // ⏪ Recording starts at the first checkpoint
+            open_checkpoints: 0,           // ⏪ No speculation under way
+            depth: 0,                      // 🪜 At the top level
+        }
+    }
+
+    /// 🔒 Sets whether instruction keywords are reserved as names.
+    /// With `false`, `let if = 5` binds a variable named `if`.
+    pub fn with_reserved_keywords(mut self, reserved: bool) -> Self {
+        self.reserved_keywords = reserved;
+        self
+    }
+
+    /// 📚 Looks instructions up in `registry` instead of the compiled-in one.
+    /// Share one `Arc` with the Bearer so both stages agree.
+    pub fn with_registry(mut self, registry: Arc<InstructionRegistry>) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// 🔠 Sets whether instruction keywords must match the registry's casing.
+    /// Pair with `Tokenizer::with_case_sensitive_keywords` so both stages agree.
+    pub fn with_case_sensitive_keywords(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive_keywords = case_sensitive;
+        self
+    }
+
+    /// 🩺 Structured errors recorded so far, in the order they were found.
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    /// 🔭 Tokens not yet consumed, for inspecting parse state.
+    ///
+    /// Parsing continues from the same point afterwards. A lazy source is
+    /// drained into a buffer on first call, so its tokens are no longer
+    /// pulled on demand.
+    pub fn remaining_tokens(&mut self) -> Vec<Token> {
+        let rest: Vec<Token> = self.tokens.by_ref().collect();
+        self.lookahead.extend(rest);
+        self.lookahead.iter().cloned().collect()
+    }
+
+    /// 🧮 Records a standardized `InvalidArgument` for `at` and returns the error node.
+    fn report_invalid_argument(
+        &mut self,
+        position: usize,
+        expected: &str,
+        at: &Token,
+    ) -> ScrollNode {
+        let error = ParseError::invalid_argument(position, expected, &at.value, at.line, at.column);
+        let node = ScrollNode::Error(error.message.clone());
+        self.errors.push(error);
+        node
+    }
+
+    /// 🩺 Records a `ParseError` at `at`'s position and returns the matching error node.
+    fn report(&mut self, kind: ParseErrorType, message: String, at: &Token) -> ScrollNode {
+        self.record(ParseError::new(kind, message, at.line, at.column))
+    }
+
+    /// 🩺 Records an already-built `ParseError` and returns the matching error node.
+    fn record(&mut self, error: ParseError) -> ScrollNode {
+        let node = ScrollNode::Error(error.message.clone());
+        self.errors.push(error);
+        node
+    }
+}
+
+/// 🧾 The token source is opaque, so `Debug` shows parse state, not tokens.
+impl fmt::Debug for Parser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Parser")
+            .field("errors", &self.errors)
+            .field("span_depth", &self.spans.len())
+            .field("lookahead", &self.lookahead.len())
+            .field("last_end", &self.last_end)
+            .field("case_sensitive_keywords", &self.case_sensitive_keywords)
+            .field("pending_meta", &self.pending_meta)
+            .finish_non_exhaustive()
+    }
+}
+
+// ===============================================
+// 🧮 Token as Schema Operand
+// ===============================================
+
+/// 🧮 Raw tokens fill slots by token type: named slots take identifiers,
+/// `Literal` takes literals, and open slots take either.
+impl SchemaOperand for Token {
+    fn fits(&self, kind: &OperandKind) -> bool {
+        match kind {
+            OperandKind::Identifier
+            | OperandKind::Target
+            | OperandKind::Label
+            | OperandKind::Register => self.token_type == TokenType::Identifier,
+            OperandKind::Literal => self.token_type == TokenType::Literal,
+            OperandKind::Address | OperandKind::Value | OperandKind::Custom(_) => {
+                matches!(self.token_type, TokenType::Identifier | TokenType::Literal)
+            }
+            OperandKind::Optional(kind) | OperandKind::Variadic(kind) => self.fits(kind),
         }
     }
+
+    fn text(&self) -> String {
+        self.value.clone()
+    }
 }
 
 // ===============================================
@@ -245,7 +859,7 @@ impl Parser {
 
 /// 🧯 Enum representing categories of parser failure.
 /// Each variant defines a unique class of misalignment between scroll syntax and expected sentence logic.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ParseErrorType {
     UnexpectedEOF,           // 📉 Ran out of tokens mid-expression or sentence
     InvalidArgument(String), // ❌ Argument found but doesn't match expected type or structure
@@ -254,6 +868,7 @@ pub enum ParseErrorType {
     InvalidInstruction,      // 📚 Instruction not found in registry
     InvalidGrammar,          // 🪓 Sentence structure broke grammatical covenant
     UnknownSymbol,           // 🕳 Reference used but not declared or defined
+    ReservedKeyword,         // 🔒 Instruction keyword used as a variable name
 }
 
 // ===============================================
@@ -262,12 +877,13 @@ pub enum ParseErrorType {
 
 /// 🩺 Represents a single error encountered while parsing a scroll.
 /// Contains type, readable message, and positional metadata for traceability.
-#[derive(Debug)]
+/// Serializable so error shapes can be snapshotted as JSON fixtures.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParseError {
     pub kind: ParseErrorType, // 🧭 What kind of misalignment occurred
     pub message: String,      // 📜 Human-readable explanation
     pub line: usize,          // 📍 Where in the scroll the error emerged (line number)
-    pub column: usize,        // 📏 Specific character offset in the line
+    pub column: usize,        // 📏 Char (not byte) offset in the line
 }
 
 impl ParseError {
@@ -287,6 +903,28 @@ impl ParseError {
         }
     }
 
+    /// 🧮 Standardized `InvalidArgument` for an operand that does not fit its slot.
+    ///
+    /// Message format: `expected <kind>, found '<value>' at position <n>`
+    /// (positions are 0-based operand indexes).
+    pub fn invalid_argument(
+        position: usize,
+        expected: &str,
+        found: &str,
+        line: usize,
+        column: usize,
+    ) -> Self {
+        Self::new(
+            ParseErrorType::InvalidArgument(found.to_string()),
+            format!(
+                "expected {}, found '{}' at position {}",
+                expected, found, position
+            ),
+            line,
+            column,
+        )
+    }
+
     /// 📃 Lightweight builder for structural errors without location.
     /// Used in early failure stages or when positional data is unavailable.
     pub fn basic(kind: ParseErrorType) -> Self {
@@ -352,13 +990,61 @@ impl Parser {
         // 🔁 Loop until all tokens have been read
         while self.peek().is_some() {
             // ✏️ Attempt to parse next scroll sentence
-            if let Some(node) = self.parse_node() {
+            if let Some(node) = self.parse_spanned_node() {
                 nodes.push(node); // ✅ If valid, add to scroll
             }
         }
 
+        // 📍 Root frame holds one span per top-level node
+        let spans = self
+            .spans
+            .first_mut()
+            .map(core::mem::take)
+            .unwrap_or_default();
+
+        // 📜 Gather the `_key_: value` header, if the scroll opens with one
+        let meta = ScrollMeta::from_nodes(&nodes);
+
         // 🌳 Return structured tree of interpreted sentences
-        ScrollTree { nodes }
+        ScrollTree { nodes, spans, meta }
+    }
+
+    /// 📍 Parses one node via `parse_node()` and records its span.
+    ///
+    /// Spans of nodes parsed inside its body land in a fresh frame,
+    /// which becomes the span's `children`.
+    fn parse_spanned_node(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia(); // 🧹 The span starts at the node, not the space before it
+        let start = self.peek().map(|t| (t.line, t.column))?;
+
+        self.spans.push(Vec::new());
+        self.pending_meta = None;
+        let node = self.parse_node();
+        let children = self.spans.pop().unwrap_or_default();
+
+        // 🧬 Only instruction nodes claim the metadata `finish_instruction` left behind
+        let instruction = match &node {
+            Some(ScrollNode::Instruction { .. }) => self.pending_meta.take(),
+            _ => None,
+        };
+
+        let span = match &node {
+            // 🧱 Bare blocks already recorded their own span in `parse_block()`
+            Some(ScrollNode::Block(_)) if children.len() == 1 => children.into_iter().next(),
+            Some(_) => Some(NodeSpan {
+                start,
+                end: self.last_end,
+                children,
+                instruction,
+            }),
+            None => None,
+        };
+
+        if let (Some(span), Some(frame)) = (span, self.spans.last_mut()) {
+            frame.push(span);
+        }
+
+        node
     }
 
     /// 🔍 Node dispatcher — determines how to interpret each token.
@@ -367,37 +1053,91 @@ impl Parser {
     /// based on its token type and value. Acts as a scroll sentence router.
     ///
     /// 🧩 Token Routing:
+    /// • `walk`        → `parse_walk()`         (e.g., `walk shepherd(flock) { ... }`)
+    /// • `let name :`  → `parse_declaration()`  (e.g., `let x: int`, via `peek_n`)
+    /// • `let keyword` → `parse_keyword_binding()` (e.g., `let if = 5`)
+    /// • `keyword =`   → `parse_keyword_assignment()` (e.g., `if = 5`)
     /// • `Instruction` → `parse_instruction()`  (e.g., `invoke("flame")`)
+    /// • `while`       → `parse_loop()`         (e.g., `while x < 3 { ... }`)
+    /// • `return`      → `parse_return()`       (e.g., `return (a, b)`)
     /// • `Literal`     → `parse_literal()`      (e.g., `"Holy Fire"`)
     /// • `Identifier`  → `parse_assignment_or_call()` (e.g., `x = 3`)
     /// • `Metadata`    → `parse_metadata()`     (e.g., `// system info`)
     /// • `Comment`     → `parse_comment()`      (e.g., `# speak only truth`)
+    /// • `.directive`  → `parse_directive()`    (e.g., `.align 4`)
+    /// • `{ key: value }` → `parse_map()`      (e.g., `{ name: "Ruth", age: 30 }`)
     /// • `GroupMarker` → `parse_block()`        (e.g., `{ let x = 5 }`)
     ///
-    /// ❗ Any unknown or invalid token yields a `ScrollNode::Error`
+    /// 🧹 Trivia starts no sentence and is stepped over, returning `None`:
+    /// whitespace, a stray `;` or `,` between sentences, and the empty
+    /// end-of-stream markers some external token sources append.
+    ///
+    /// ❗ Any other unknown or invalid token yields a `ScrollNode::Error`
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_node(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
         let token = self.peek()?.clone(); // 👁 Preview current token without consuming it
 
         match token.token_type {
+            // 🧹 Separators and empty markers between sentences carry no meaning
+            TokenType::Punctuation if matches!(token.value.as_str(), ";" | ",") => {
+                self.advance();
+                None
+            }
+            _ if token.value.is_empty() && token.token_type != TokenType::Literal => {
+                self.advance();
+                None
+            }
+
+            TokenType::Instruction if token.value == "walk" => self.parse_walk(), // 🛤 Call or definition
+            TokenType::Instruction if token.value == "let" && self.peek_value_n(2) == Some(":") => {
+                self.parse_declaration() // ✒️ `let name: Type`
+            }
+            TokenType::Instruction
+                if token.value == "let"
+                    && self
+                        .peek_n(1)
+                        .is_some_and(|next| next.token_type == TokenType::Instruction) =>
+            {
+                self.parse_keyword_binding() // 🔒 `let if = 5`
+            }
+            TokenType::Instruction if self.peek_value_n(1) == Some("=") => {
+                self.parse_keyword_assignment() // 🔒 `if = 5`
+            }
             TokenType::Instruction => self.parse_instruction(), // ⚙️ Scroll instruction
+            TokenType::Identifier if token.value == "while" => self.parse_loop(), // 🔁 Loop construct
+            TokenType::Identifier if token.value == "return" => self.parse_return(), // 🔚 Return value(s)
             TokenType::Literal => self.parse_literal(),         // 🔢 Raw literal value
             TokenType::Identifier => self.parse_assignment_or_call(), // 🪶 Variable or call logic
             TokenType::Metadata => self.parse_metadata(),       // 📘 Metadata directives
             TokenType::Comment => self.parse_comment(),         // 💬 Human-facing notes
-
-            // 🧱 Start of scroll block (e.g., loop, function body)
-            TokenType::GroupMarker if token.value == "{" => self.parse_block(),
+            TokenType::Keyword if token.value.starts_with('.') => self.parse_directive(), // 🧷 `.byte`
+
+            // 🗃 `{ key: value }` map, else a scroll block (e.g., loop, function body)
+            TokenType::GroupMarker if token.value == "{" => {
+                let start = self.checkpoint();
+                match self.parse_map() {
+                    Some(map) => {
+                        self.release(); // 🔓 A map after all — keep what it read
+                        Some(map)
+                    }
+                    None => {
+                        self.restore(start); // ⏪ Not a map — reread as a block
+                        self.parse_block()
+                    }
+                }
+            }
 
             _ => {
                 // 🚨 Token does not match known sentence starters
                 self.advance(); // ⏭ Skip token to avoid infinite loop
 
                 // ❌ Return error node with embedded token context for debugging
-                Some(ScrollNode::Error(format!(
-                    "Unrecognized token: {}",
-                    token.value
-                )))
+                Some(self.report(
+                    ParseErrorType::UnexpectedToken,
+                    format!("Unrecognized token: {}", token.value),
+                    &token,
+                ))
             }
         }
     }
@@ -423,19 +1163,90 @@ impl Parser {
 
     /// 📌 Advance the token stream — move cursor forward and consume token.
     ///
-    /// Retrieves and returns the token at the current position,
-    /// then increments the parser's position index by one.
+    /// Pulls and returns the next token from the underlying source.
     ///
     /// 🔁 Returns:
-    /// • `Some(Token)` if a token exists at the current index
+    /// • `Some(Token)` if the source yields another token
     /// • `None` if the end of the token stream has been reached
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn advance(&mut self) -> Option<Token> {
-        let tok = self.tokens.get(self.position).cloned(); // 🧤 Clone ensures original token remains intact
-        if tok.is_some() {
-            self.position += 1; // ➡️ Move parser cursor to next token
+        let token = match self.lookahead.pop_front() {
+            Some(token) => token,        // 👁 Already peeked
+            None => self.tokens.next()?, // 🎯 Pull from the source (or None if at EOF)
+        };
+        self.last_end = (token.line, token.column); // 🔴 Span end tracking
+        self.consumed += 1;
+        if self.history_origin.is_some() {
+            self.history.push(token.clone()); // ⏪ Kept for `restore`
+        }
+        Some(token)
+    }
+
+    /// 🔖 Marks the current cursor position for a later `restore`.
+    ///
+    /// Walkers take a checkpoint, attempt a parse, and restore on failure or
+    /// `release` on success. Consumed tokens are kept from the earliest open
+    /// checkpoint on, so any open checkpoint can be restored; once every
+    /// checkpoint is resolved the kept tokens are dropped.
+    pub fn checkpoint(&mut self) -> usize {
+        if self.history_origin.is_none() {
+            self.history_origin = Some((self.consumed, self.last_end));
+        }
+        self.open_checkpoints += 1;
+        self.consumed
+    }
+
+    /// 🔓 Resolves the latest checkpoint without rewinding — the attempt
+    /// succeeded, so its tokens stay consumed.
+    pub fn release(&mut self) {
+        self.open_checkpoints = self.open_checkpoints.saturating_sub(1);
+        if self.open_checkpoints == 0 {
+            self.history.clear();
+            self.history_origin = None;
+        }
+    }
+
+    /// ⏪ Rewinds the cursor to `pos`, a value returned by `checkpoint`.
+    ///
+    /// Tokens consumed since `pos` are pushed back to be read again, in
+    /// order, and the checkpoint is resolved as by `release`. Only the
+    /// cursor rewinds: errors and spans recorded by the abandoned attempt
+    /// stay. A position that was never checkpointed, was already resolved,
+    /// or lies ahead of the cursor, is ignored.
+    pub fn restore(&mut self, pos: usize) {
+        let Some((origin, origin_end)) = self.history_origin else {
+            return;
+        };
+        if pos < origin || pos > self.consumed {
+            return;
+        }
+
+        let replay = self.history.split_off(pos - origin);
+        for token in replay.into_iter().rev() {
+            self.lookahead.push_front(token);
+        }
+        self.consumed = pos;
+        self.last_end = self
+            .history
+            .last()
+            .map_or(origin_end, |token| (token.line, token.column));
+        self.release();
+    }
+
+    /// 🧹 Skips `Whitespace` tokens so spacing never becomes a subject,
+    /// operand, or argument.
+    ///
+    /// The tokenizer tracks whitespace in `LineMeta` rather than emitting
+    /// it, but hand-built or external token streams may carry it. Every
+    /// walker calls this first. Comments are not trivia: `parse_comment`
+    /// keeps them as `ScrollNode::Comment`.
+    pub fn skip_trivia(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|t| t.token_type == TokenType::Whitespace)
+        {
+            self.advance();
         }
-        tok // 🎯 Return the consumed token (or None if at EOF)
     }
 
     /// 🔍 Peek at the current token without consuming it.
@@ -446,7 +1257,76 @@ impl Parser {
     /// 🔭 This is essential for grammar branching (e.g., assignment vs call)
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn peek(&mut self) -> Option<&Token> {
-        self.tokens.get(self.position) // 🧿 Non-consuming view of current token
+        self.peek_n(0) // 🧿 Non-consuming view of current token
+    }
+
+    /// 🔭 Peek `n` tokens past the current one without consuming any.
+    ///
+    /// `peek_n(0)` is `peek()`. Takes `&mut self` because a lazy source is
+    /// pulled into the lookahead buffer as far as `n` reaches.
+    ///
+    /// 🔭 Used where one token cannot decide the route, e.g. `let name :`
+    /// (declaration) vs `let name value` (instruction).
+    pub fn peek_n(&mut self, n: usize) -> Option<&Token> {
+        while self.lookahead.len() <= n {
+            let token = self.tokens.next()?;
+            self.lookahead.push_back(token);
+        }
+        self.lookahead.get(n)
+    }
+
+    /// 🔤 Text of the token `n` past the current one, if any.
+    pub fn peek_value_n(&mut self, n: usize) -> Option<&str> {
+        self.peek_n(n).map(|token| token.value.as_str())
+    }
+
+    /// 🎯 Consumes the next token, requiring its type to be `ty`.
+    ///
+    /// 🔁 Returns:
+    /// • `Ok(Token)` when the type matches
+    /// • `Err(MissingToken)` at the offending token, which is still consumed
+    /// • `Err(UnexpectedEOF)` at the last consumed position when the stream is empty
+    ///
+    /// Walkers turn the error into a node with `record()`.
+    pub fn expect(&mut self, ty: TokenType) -> Result<Token, ParseError> {
+        let expected = format!("{:?}", ty);
+        self.expect_where(&expected, |token| token.token_type == ty)
+    }
+
+    /// 🎯 Consumes the next token, requiring its text to be `value`.
+    ///
+    /// Same consumption and error rules as `expect()`.
+    pub fn expect_value(&mut self, value: &str) -> Result<Token, ParseError> {
+        let expected = format!("'{}'", value);
+        self.expect_where(&expected, |token| token.value == value)
+    }
+
+    /// 🎯 Shared body of `expect()` / `expect_value()`.
+    fn expect_where(
+        &mut self,
+        expected: &str,
+        matches: impl FnOnce(&Token) -> bool,
+    ) -> Result<Token, ParseError> {
+        let Some(token) = self.advance() else {
+            let (line, column) = self.last_end;
+            return Err(ParseError::new(
+                ParseErrorType::UnexpectedEOF,
+                format!("Expected {}, found end of scroll", expected),
+                line,
+                column,
+            ));
+        };
+
+        if matches(&token) {
+            return Ok(token);
+        }
+
+        Err(ParseError::new(
+            ParseErrorType::MissingToken,
+            format!("Expected {}, found '{}'", expected, token.value),
+            token.line,
+            token.column,
+        ))
     }
 
     // -----------------------------------------------
@@ -476,27 +1356,85 @@ impl Parser {
     /// - Emits log of instruction name and number of args parsed
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_instruction(&mut self) -> Option<ScrollNode> {
-        let token = self.advance()?; // 🎯 Step forward to consume the instruction keyword
+        self.skip_trivia();
+        let mut token = self.advance()?; // 🎯 Step forward to consume the instruction keyword
 
         // 🚨 Validate instruction name against registry before parsing args
-        if self.decode_instruction(&token).is_none() {
-            return Some(ScrollNode::Error(format!(
-                "Unknown instruction '{}'",
-                token.value
-            )));
-        }
+        let Some(keyword) = self.decode_instruction(&token) else {
+            return Some(self.report(
+                ParseErrorType::InvalidInstruction,
+                format!("Unknown instruction '{}'", token.value),
+                &token,
+            ));
+        };
+        token.value = keyword; // 🔠 Registered spelling, whatever the source casing
 
-        let mut args = Vec::new(); // 📦 Collector for parsed arguments
+        // 🔢 Declared operand count, used to flag surplus arguments
+        let limit = self
+            .registry
+            .get(token.value.as_str())
+            .and_then(|instruction| instruction.operand_count());
+
+        self.finish_instruction(token, Vec::new(), limit)
+    }
 
-        // 🔁 Walk forward through valid argument tokens
-        while let Some(tok) = self.peek() {
+    /// 📦 Collects the remaining argument tokens for an already-consumed instruction.
+    ///
+    /// `args` holds any arguments the caller has already taken off the stream
+    /// (e.g. the subroutine name consumed by `parse_walk`).
+    ///
+    /// With a `limit` (the registry's `operand_count`), the first operand past
+    /// it is recorded as an `UnexpectedToken` error; the node keeps every
+    /// argument. Operator tokens (`=`, `<=`, …) are syntax, not operands, and
+    /// are not counted; `,` separators (`speak "score:", x`) are dropped.
+    /// Operand annotations (`@trusted x`) are kept for the Bearer but not
+    /// counted either. String literals keep their quotes (see `operand_text`),
+    /// so `"@trusted"` stays an operand.
+    ///
+    /// Arguments end with the keyword's line, so `let x 0` followed by a
+    /// `while` line stays two sentences.
+    ///
+    /// "Logic Structure" instructions (`then`, `else`) take nothing from the
+    /// line: they gate the node after them, so `then x = 3` is two nodes.
+    fn finish_instruction(
+        &mut self,
+        token: Token,
+        mut args: Vec<String>,
+        limit: Option<u8>,
+    ) -> Option<ScrollNode> {
+        let mut operands = args.len();
+        let mut surplus: Option<Token> = None;
+        let mut joined = false; // ➕ Next operand continues an `a + b` sum
+        let structural = self
+            .registry
+            .get(token.value.as_str())
+            .is_some_and(|instruction| instruction.category() == "Logic Structure");
+
+        // 🔁 Walk forward through valid argument tokens on the keyword's line
+        let line = token.line;
+        while let Some(tok) = self.peek().filter(|tok| !structural && tok.line == line) {
             match tok.token_type {
                 TokenType::Literal | TokenType::Identifier | TokenType::Operator => {
-                    args.push(tok.value.clone()); // ✍️ Add to argument list
-                    self.advance(); // ➡️ Step forward
+                    let tok = self.advance()?; // ➡️ Step forward
+                    if tok.token_type == TokenType::Operator {
+                        joined = tok.value == "+";
+                    } else if joined {
+                        joined = false; // ➕ `2 + 3` fills one operand slot
+                    } else {
+                        operands += 1;
+                        if surplus.is_none() && limit.is_some_and(|max| operands > max as usize) {
+                            surplus = Some(tok.clone());
+                        }
+                    }
+                    args.push(operand_text(tok)); // ✍️ Add to argument list
                 }
-                TokenType::Whitespace => {
-                    self.advance(); // 🧹 Ignore blank space
+                TokenType::Whitespace => self.skip_trivia(), // 🧹 Ignore blank space
+                TokenType::Punctuation if tok.value == "," => {
+                    self.advance(); // ➡️ Skip the ',' separator
+                }
+                TokenType::Keyword if tok.value.starts_with('@') => {
+                    let tok = self.advance()?; // 🏷 `@trusted` — kept, but not an operand
+                    args.push(tok.value);
                 }
                 _ => break, // ⛔ Stop on block, newline, or invalid type
             }
@@ -505,7 +1443,7 @@ impl Parser {
         // 🧪 Optional debug trace (prints instruction structure)
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, DebugResponse, Severity};
+            use watchtower::debugger::DebugEntry;
 
             let entry = DebugEntry::new(
                 "parse_instruction",
@@ -516,7 +1454,29 @@ impl Parser {
             .with_location("Parser::parse_instruction")
             .with_suggestion("Ensure argument types align with instruction schema.");
 
-            println!("{entry:#?}"); // 🪵 Emit structured debug report
+            entry.emit(); // 🪵 Emit structured debug report
+        }
+
+        // 🧬 Keep the decoded registry facts for this node's span
+        self.pending_meta = self
+            .registry
+            .get(token.value.as_str())
+            .map(|entry| InstructionMeta {
+                opcode: entry.opcode,
+                category: entry.category,
+            });
+
+        // 🔢 Too many operands — recorded, but the node is still emitted
+        if let (Some(extra), Some(max)) = (surplus, limit) {
+            self.errors.push(ParseError::new(
+                ParseErrorType::UnexpectedToken,
+                format!(
+                    "'{}' takes {} operand(s), found {} (first extra: '{}')",
+                    token.value, max, operands, extra.value
+                ),
+                extra.line,
+                extra.column,
+            ));
         }
 
         // 🧱 Emit constructed instruction node
@@ -549,12 +1509,13 @@ impl Parser {
     /// - Emits trace showing token value and node capture
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_literal(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
         let token = self.advance()?; // 📥 Step forward and consume literal token
 
         // 🧪 Optional: emit debug trace of literal interpretation
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, DebugResponse, Severity};
+            use watchtower::debugger::DebugEntry;
 
             let entry = DebugEntry::new(
                 "parse_literal",
@@ -564,7 +1525,7 @@ impl Parser {
             )
             .with_location("Parser::parse_literal");
 
-            println!("{entry:#?}"); // 🪵 Emit debug info
+            entry.emit(); // 🪵 Emit debug info
         }
 
         // 📦 Construct and return literal node directly
@@ -598,13 +1559,23 @@ impl Parser {
     /// - Logs expected pattern and actual token encountered
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_assignment_or_call(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
         let identifier = self.advance()?; // 🔑 Consume the symbol name (variable or callable)
+
+        // 📉 Scroll ended right after the identifier
+        if self.peek().is_none() {
+            return Some(self.report(
+                ParseErrorType::UnexpectedEOF,
+                format!("Expected '=' or '(' after '{}', found end of scroll", identifier.value),
+                &identifier,
+            ));
+        }
         let next = self.peek()?; // 👁️ Peek at the next token to determine intent
 
         // 🧪 Emit trace for branching decision
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, DebugResponse, Severity};
+            use watchtower::debugger::DebugEntry;
 
             let expected = "`=` or `(`";
             let actual = next.value.clone();
@@ -618,35 +1589,126 @@ impl Parser {
             .with_location("Parser::parse_assignment_or_call")
             .with_suggestion("Check next token to distinguish assignment or call.");
 
-            println!("{entry:#?}"); // 🪵 Log the branching context
+            entry.emit(); // 🪵 Log the branching context
         }
 
         match next.value.as_str() {
-            // 🧾 Assignment pattern: identifier = value
-            "=" => {
-                self.advance(); // ➡️ Skip the '=' token
-                let value_token = self.advance()?; // 📥 Capture right-hand side value
-
-                Some(ScrollNode::Assignment {
-                    target: identifier.value, // 🧱 Variable name
-                    value: value_token.value, // 🔢 Bound value
-                })
-            }
+            // 🧾 Assignment pattern: identifier = value, or a, b = value
+            "=" | "," => match self.reject_reserved_name(&identifier) {
+                Some(error) => {
+                    self.skip_line(identifier.line);
+                    Some(error)
+                }
+                None => self.parse_assignment_tail(identifier),
+            },
 
             // 📞 Invocation pattern: identifier(...)
             "(" => self.parse_call(identifier.value.clone()),
 
+            // 📚 `word arg` shape — reads as an instruction the registry doesn't know
+            _ if matches!(next.token_type, TokenType::Literal | TokenType::Identifier) => {
+                Some(self.report(
+                    ParseErrorType::InvalidInstruction,
+                    format!("Unknown instruction '{}'", identifier.value),
+                    &identifier,
+                ))
+            }
+
             // ❌ Invalid pattern — identifier used ambiguously
-            _ => Some(ScrollNode::Error(format!(
-                "Ambiguous identifier usage near '{}'",
-                identifier.value
-            ))),
+            _ => Some(self.report(
+                ParseErrorType::UnexpectedToken,
+                format!("Ambiguous identifier usage near '{}'", identifier.value),
+                &identifier,
+            )),
         }
     }
 
+    /// 🧾 Finishes an assignment whose first target was just consumed.
+    ///
+    /// Further `, name` targets are collected before `=` (`a, b = pair()`)
+    /// and stored comma-joined. A `name(args)` value is kept as call text
+    /// (`pair(1, 2)`) so the interpreter can bind what the call returns;
+    /// any other value may continue as a sum (`"a" + name + 3`).
+    fn parse_assignment_tail(&mut self, identifier: Token) -> Option<ScrollNode> {
+        let mut targets = vec![identifier.value];
+
+        // 🧺 Tuple targets: `a, b, c =`
+        while self.peek().is_some_and(|t| t.value == ",") {
+            self.advance(); // ➡️ Skip the ',' separator
+            match self.expect(TokenType::Identifier) {
+                Ok(target) => targets.push(target.value),
+                Err(error) => return Some(self.record(error)),
+            }
+        }
+        let target = targets.join(", ");
+
+        let equals = match self.expect_value("=") {
+            Ok(equals) => equals, // ➡️ Skip the '=' token
+            Err(error) => return Some(self.record(error)),
+        };
+
+        // 📥 Capture right-hand side value
+        let Some(value_token) = self.advance() else {
+            return Some(self.report(
+                ParseErrorType::UnexpectedEOF,
+                format!("Expected value after '=' in assignment to '{}'", target),
+                &equals,
+            ));
+        };
+
+        // 📞 `name = f(args)` — keep the call whole
+        let mut value = value_token.value;
+        if value_token.quoted {
+            // 🔤 The tokenizer unquotes strings; restore them so `"5"` stays text
+            value = quote_text(&value);
+        } else if value_token.token_type == TokenType::Identifier
+            && self.peek().is_some_and(|t| t.value == "(")
+        {
+            match self.parse_call(value)? {
+                ScrollNode::Call {
+                    function,
+                    args,
+                    named,
+                } => {
+                    value = format!("{}({})", function, call_arguments_text(&args, &named));
+                }
+                error => return Some(error),
+            }
+            return Some(ScrollNode::Assignment { target, value });
+        }
+
+        // ➕ `name = a + b` — further terms on the line join the sum text
+        while self
+            .peek()
+            .is_some_and(|t| t.token_type == TokenType::Operator && t.value == "+" && t.line == equals.line)
+        {
+            let plus = self.advance()?; // ➡️ Skip the '+' operator
+            match self.advance() {
+                Some(term)
+                    if matches!(term.token_type, TokenType::Literal | TokenType::Identifier) =>
+                {
+                    value.push_str(" + ");
+                    value.push_str(&operand_text(term));
+                }
+                _ => {
+                    return Some(self.report(
+                        ParseErrorType::UnexpectedToken,
+                        format!("Expected a term after '+' in assignment to '{}'", target),
+                        &plus,
+                    ))
+                }
+            }
+        }
+
+        Some(ScrollNode::Assignment {
+            target, // 🧱 Variable name(s)
+            value,  // 🔢 Bound value or call text
+        })
+    }
+
+    // -----------------------------------------------
+    // 🧾 Metadata & Comment Parsers
     // -----------------------------------------------
-    // 🧾 Metadata & Comment Parsers
-    // -----------------------------------------------
     // These walkers capture non-executing elements in the scroll:
     //
     // • `parse_metadata` gathers system-aligned framing tokens like
@@ -667,11 +1729,12 @@ impl Parser {
     /// - `// governs the Gate subsystem` → `ScrollNode::Metadata(...)`
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_metadata(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
         let token = self.advance()?; // 🧾 Consume metadata token from token stream
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, DebugResponse, Severity};
+            use watchtower::debugger::DebugEntry;
 
             let entry = DebugEntry::new(
                 "parse_metadata",
@@ -681,7 +1744,7 @@ impl Parser {
             )
             .with_location("Parser::parse_metadata");
 
-            println!("{entry:#?}"); // 🪵 Emit debug log for metadata
+            entry.emit(); // 🪵 Emit debug log for metadata
         }
 
         Some(ScrollNode::Metadata(token.value)) // 🧱 Return node containing directive content
@@ -697,11 +1760,12 @@ impl Parser {
     /// - `# This section guards NovaGate` → `ScrollNode::Comment(...)`
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_comment(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
         let token = self.advance()?; // ✏️ Consume comment token from stream
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, DebugResponse, Severity};
+            use watchtower::debugger::DebugEntry;
 
             let entry = DebugEntry::new(
                 "parse_comment",
@@ -711,7 +1775,7 @@ impl Parser {
             )
             .with_location("Parser::parse_comment");
 
-            println!("{entry:#?}"); // 📜 Log for dev traceability
+            entry.emit(); // 📜 Log for dev traceability
         }
 
         Some(ScrollNode::Comment(token.value)) // 🧱 Return node preserving the voice
@@ -763,7 +1827,7 @@ impl Parser {
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
 
             let entry = DebugEntry::new(
                 "walk_condition",
@@ -774,7 +1838,7 @@ impl Parser {
             .with_location("Parser::walk_condition")
             .with_suggestion("Ensure block follows valid grammar");
 
-            println!("{entry:#?}"); // 🪵 Emit trace log for visual feedback
+            entry.emit(); // 🪵 Emit trace log for visual feedback
         }
 
         if condition.is_empty() {
@@ -840,6 +1904,7 @@ impl Parser {
     /// it only collects argument **tokens** for later evaluation.
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_argument_list(&mut self) -> Result<Vec<String>, ParseError> {
+        self.skip_trivia();
         let mut args = vec![];
 
         // 🔍 Verify that an argument group is starting with `(`
@@ -852,6 +1917,7 @@ impl Parser {
         // 🔁 Continue gathering until closing `)`
         while let Some(token) = self.peek() {
             match token.value.as_str() {
+                _ if token.token_type == TokenType::Whitespace => self.skip_trivia(), // 🧹 Spacing
                 ")" => {
                     self.advance(); // ✅ End of group — consume `)` and stop
                     break;
@@ -869,7 +1935,7 @@ impl Parser {
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let joined = args.join(", ");
             let entry = DebugEntry::new(
                 "parse_argument_list",
@@ -879,7 +1945,7 @@ impl Parser {
             )
             .with_location("Parser::parse_argument_list")
             .with_suggestion("Validate argument arity if required");
-            println!("{entry:#?}"); // 🪵 Emit debug trace
+            entry.emit(); // 🪵 Emit debug trace
         }
 
         Ok(args)
@@ -897,9 +1963,11 @@ impl Parser {
     /// 🧾 Examples:
     /// - `"The priest speaks truth"`
     /// - `"scroll invokes clarity"`
+    /// - `"The priest speaks 5 + 3"`
     ///
     /// 🧠 Behavior:
-    /// • Assumes 3 consecutive tokens = subject, verb, object
+    /// • Subject (with any leading article) and verb are one token each
+    /// • The object spans the rest of the verb's line, up to a `.` or `;`
     /// • Captures only raw strings — no operand resolution
     /// • Used for declarations, prophetic patterns, or natural scroll grammars
     ///
@@ -908,13 +1976,36 @@ impl Parser {
     /// • No type-checking or verb-object agreement (for now)
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_scroll_sentence(&mut self) -> Option<ScrollNode> {
-        let subject = self.advance()?.value; // 🙋 Subject — who is acting
-        let verb = self.advance()?.value; // 🗣️ Verb — what they do
-        let object = self.advance()?.value; // 🎯 Object — what is acted upon
+        self.skip_trivia();
+        let mut subject = self.advance()?.value; // 🙋 Subject — who is acting
+
+        // 📰 A leading article belongs to its noun: `The priest`
+        if ["the", "a", "an"].contains(&subject.to_lowercase().as_str()) {
+            self.skip_trivia();
+            subject = format!("{} {}", subject, self.advance()?.value);
+        }
+
+        self.skip_trivia();
+        let verb = self.advance()?; // 🗣️ Verb — what they do
+        let line = verb.line;
+        self.skip_trivia();
+        let mut object = self.advance()?.value; // 🎯 Object — what is acted upon
+
+        // 🧵 The object runs on to the sentence's end: `speaks 5 + 3`
+        self.skip_trivia();
+        while let Some(next) = self.peek().filter(|t| t.line == line) {
+            if matches!(next.value.as_str(), "." | ";") {
+                self.advance(); // 🛑 Terminator closes the sentence
+                break;
+            }
+            object = format!("{} {}", object, self.advance()?.value);
+            self.skip_trivia();
+        }
+        let verb = verb.value;
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
 
             let phrase = format!("{subject} {verb} {object}"); // 📖 Full sentence preview
             let entry = DebugEntry::new(
@@ -926,7 +2017,7 @@ impl Parser {
             .with_location("Parser::parse_scroll_sentence")
             .with_suggestion("Validate grammar structure with schema");
 
-            println!("{entry:#?}"); // 🪵 Debug trace output
+            entry.emit(); // 🪵 Debug trace output
         }
 
         Some(ScrollNode::ScrollSentence {
@@ -936,9 +2027,9 @@ impl Parser {
         }) // ✅ Output raw SVO node
     }
 
-    /// ===============================================
-    /// 📘 Extended Scroll Parsers — Declarations & Blocks
-    /// ===============================================
+    // ===============================================
+    // 📘 Extended Scroll Parsers — Declarations & Blocks
+    // ===============================================
 
     // -------------------------------
     // 📝 Variable Declaration Parser
@@ -951,20 +2042,41 @@ impl Parser {
     ///
     /// Flow:
     /// - Consumes `let` keyword
-    /// - Grabs the identifier
+    /// - Grabs the identifier (must be `TokenType::Identifier`)
     /// - Optionally parses a `: Type` suffix
     ///
     /// Returns:
     /// - `ScrollNode::Declaration { name, dtype }`
+    /// - `ScrollNode::Error` on a missing (`MissingToken`) or
+    ///   non-identifier (`InvalidArgument`) name
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_declaration(&mut self) -> Option<ScrollNode> {
-        let _keyword = self.advance()?; // 🔑 Expect `let`
-        let name_token = self.advance()?; // 🧾 Capture variable name
+        self.skip_trivia();
+        let keyword = self.advance()?; // 🔑 Expect `let`
+
+        // 🧾 Capture variable name — must be an identifier
+        let Some(name_token) = self.advance() else {
+            return Some(self.report(
+                ParseErrorType::MissingToken,
+                format!("Expected a name after '{}'", keyword.value),
+                &keyword,
+            ));
+        };
+
+        if let Some(error) = self.reject_reserved_name(&name_token) {
+            return Some(error);
+        }
+
+        let keyword_name = name_token.token_type == TokenType::Instruction; // 🔓 Allowed only when unreserved
+        if name_token.token_type != TokenType::Identifier && !keyword_name {
+            return Some(self.report_invalid_argument(0, "identifier", &name_token));
+        }
+
         let dtype = self.walk_type_annotation(); // 🧬 Optional type suffix (e.g., `: Int`)
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let display = format!(
                 "{}{}",
                 name_token.value,
@@ -979,7 +2091,7 @@ impl Parser {
             )
             .with_location("Parser::parse_declaration")
             .with_suggestion("Ensure name is a valid identifier and type is registered");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         Some(ScrollNode::Declaration {
@@ -988,6 +2100,85 @@ impl Parser {
         })
     }
 
+    /// 🔒 Parses `let` followed by an instruction keyword, like `let if = 5`.
+    ///
+    /// With reserved keywords (the default) the name is rejected as a
+    /// `ReservedKeyword` error and the rest of its line is skipped, so the
+    /// keyword never starts a sentence of its own. Otherwise the keyword is
+    /// taken as the first operand of an ordinary `let` instruction.
+    fn parse_keyword_binding(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
+        let keyword = self.advance()?; // 🔑 `let`
+        let name = self.advance()?; // 🔒 Instruction keyword in the name slot
+
+        if let Some(error) = self.reject_reserved_name(&name) {
+            self.skip_line(name.line);
+            return Some(error);
+        }
+
+        let limit = self
+            .registry
+            .get(keyword.value.as_str())
+            .and_then(|instruction| instruction.operand_count());
+
+        self.finish_instruction(keyword, vec![name.value], limit)
+    }
+
+    /// 🔒 Parses an assignment whose target is an instruction keyword (`if = 5`).
+    ///
+    /// Rejected like `parse_keyword_binding` unless keywords are unreserved,
+    /// in which case it finishes as a normal assignment.
+    fn parse_keyword_assignment(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
+        let target = self.advance()?; // 🔒 Instruction keyword as target
+
+        if let Some(error) = self.reject_reserved_name(&target) {
+            self.skip_line(target.line);
+            return Some(error);
+        }
+
+        self.parse_assignment_tail(target)
+    }
+
+    /// 🔒 Records a `ReservedKeyword` error when `name` is a registered
+    /// instruction keyword and keywords are reserved.
+    fn reject_reserved_name(&mut self, name: &Token) -> Option<ScrollNode> {
+        if !self.reserved_keywords {
+            return None;
+        }
+        let keyword = self.decode_instruction(name)?;
+
+        Some(self.report(
+            ParseErrorType::ReservedKeyword,
+            format!(
+                "'{}' is an instruction keyword and cannot name a variable",
+                keyword
+            ),
+            name,
+        ))
+    }
+
+    /// ⏭ Consumes tokens through the `}` that closes an already-opened block,
+    /// counting nested braces without recursing; stops at end of input.
+    fn skip_block_body(&mut self) {
+        let mut open = 1;
+        while let Some(token) = self.advance() {
+            match (&token.token_type, token.value.as_str()) {
+                (TokenType::GroupMarker, "{") => open += 1,
+                (TokenType::GroupMarker, "}") if open == 1 => break,
+                (TokenType::GroupMarker, "}") => open -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    /// ⏭ Consumes every remaining token on `line`.
+    fn skip_line(&mut self, line: usize) {
+        while self.peek().is_some_and(|token| token.line == line) {
+            self.advance();
+        }
+    }
+
     // -------------------------------
     // 🧠 Conditional Parser
     // -------------------------------
@@ -1008,15 +2199,21 @@ impl Parser {
     ///
     /// Returns:
     /// - `ScrollNode::Conditional { condition, body }`
+    ///
+    /// 🧭 Keyword style — `if a b then { ... } else { ... }` — is not folded
+    /// into a `Conditional`. It parses as flat nodes (`if`, `then`, block,
+    /// `else`, block), which the interpreter runs by letting `then` / `else`
+    /// gate the node that follows each.
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_conditional(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
         let _keyword = self.advance()?; // 🧭 Expect conditional keyword
         let condition = self.walk_condition()?; // 🧠 Extract raw condition string (for later operand resolution)
         let body = self.parse_block()?; // 📦 Parse block under condition
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let entry = DebugEntry::new(
                 "parse_conditional",
                 &condition,
@@ -1025,7 +2222,7 @@ impl Parser {
             )
             .with_location("Parser::parse_conditional")
             .with_suggestion("Ensure condition is valid and block is non-empty");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         Some(ScrollNode::Conditional {
@@ -1059,13 +2256,14 @@ impl Parser {
     /// - `ScrollNode::Loop { condition, body }`
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_loop(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
         let _keyword = self.advance()?; // 🧭 Expect loop keyword
         let condition = self.walk_condition()?; // 🧠 Capture loop condition string (raw)
         let body = self.parse_block()?; // 📦 Parse the loop body block
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let entry = DebugEntry::new(
                 "parse_loop",
                 &condition,
@@ -1074,7 +2272,7 @@ impl Parser {
             )
             .with_location("Parser::parse_loop")
             .with_suggestion("Ensure loop condition and body are syntactically aligned");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         Some(ScrollNode::Loop {
@@ -1110,7 +2308,10 @@ impl Parser {
     /// - Each child node may contain operand expressions that must be resolved later
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_instruction_group(&mut self) -> Option<ScrollNode> {
-        let _open = self.advance()?; // 🔓 Consume `[`
+        self.skip_trivia();
+        if let Err(error) = self.expect_value("[") {
+            return Some(self.record(error)); // 🔓 Group must open with `[`
+        }
         let mut group_nodes = vec![];
 
         while let Some(token) = self.peek() {
@@ -1120,16 +2321,17 @@ impl Parser {
             }
 
             // ✨ Recursively parse nested instructions
+            let before = self.consumed;
             if let Some(node) = self.parse_node() {
                 group_nodes.push(node);
-            } else {
+            } else if self.consumed == before {
                 break; // 🚧 Stop on invalid node
-            }
+            } // 🧹 Otherwise trivia (a stray `;`) was skipped — keep walking
         }
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let entry = DebugEntry::new(
                 "parse_instruction_group",
                 "[ ... ]",
@@ -1138,7 +2340,7 @@ impl Parser {
             )
             .with_location("Parser::parse_instruction_group")
             .with_suggestion("Ensure all instructions inside brackets are valid scroll nodes");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         Some(ScrollNode::Block(group_nodes))
@@ -1166,19 +2368,18 @@ impl Parser {
     /// Emits a `ScrollNode::Import` if successful.
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_import(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
         let _keyword = self.advance()?; // 📥 Consume `import`
         let path_token = self.advance()?; // 📦 Expect string literal path
 
         // ⚠️ Validate that the token is a properly quoted string
         if !path_token.value.starts_with('"') || !path_token.value.ends_with('"') {
-            return Some(ScrollNode::Error(
-                "Import path must be a quoted string literal.".into(),
-            ));
+            return Some(self.report_invalid_argument(0, "quoted string literal", &path_token));
         }
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let entry = DebugEntry::new(
                 "parse_import",
                 &path_token.value,
@@ -1187,7 +2388,7 @@ impl Parser {
             )
             .with_location("Parser::parse_import")
             .with_suggestion("Validate path is a literal and properly quoted");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         Some(ScrollNode::Import(path_token.value)) // 🔗 Emit import node
@@ -1199,23 +2400,43 @@ impl Parser {
 
     /// 🔚 Parses a return statement into `ScrollNode::Return`.
     ///
-    /// 🚧 Currently supports single resolved operand only.
-    /// Full expression and block return support planned.
+    /// 🚧 Full expression and block return support planned.
     ///
     /// Pattern:
     /// - `return value`
+    /// - `return (a, b)` — a tuple, stored as `"(a, b)"`
+    /// - `return` — nothing else on its line, stored as `""`
     ///
     /// Emits:
     /// - `ScrollNode::Return(Operand)`
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_return(&mut self) -> Option<ScrollNode> {
-        let _keyword = self.advance()?; // ⏎ Consume `return`
+        self.skip_trivia();
+        let keyword = self.advance()?; // ⏎ Consume `return`
+
+        // 🫙 Bare `return` — the value would have to share its line
+        let bare = self
+            .peek()
+            .is_none_or(|t| t.line != keyword.line || t.value == "}");
+        if bare {
+            return Some(ScrollNode::Return(String::new()));
+        }
 
-        let operand = self.walk_operand()?; // 🧠 Resolve value into Operand
+        // 🧺 Tuple return — read the group like call arguments
+        let operand = if self.peek().is_some_and(|t| t.value == "(") {
+            match self.parse_call(keyword.value)? {
+                ScrollNode::Call { args, named, .. } => {
+                    format!("({})", call_arguments_text(&args, &named))
+                }
+                error => return Some(error),
+            }
+        } else {
+            self.walk_operand()? // 🧠 Resolve value into Operand
+        };
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let entry = DebugEntry::new(
                 "parse_return",
                 &format!("{operand:?}"),
@@ -1224,12 +2445,105 @@ impl Parser {
             )
             .with_location("Parser::parse_return")
             .with_suggestion("Support expression trees and multi-token operands in future");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         Some(ScrollNode::Return(operand)) // 📤 Emit full return node
     }
 
+    // -------------------------------
+    // 🛤 Function Definition Parser
+    // -------------------------------
+
+    /// 🛤 Routes a `walk` statement to a definition or an instruction.
+    ///
+    /// - `walk name(params) { ... }` → `parse_function`
+    /// - `walk name ...` / `walk "north"` → `ScrollNode::Instruction`
+    #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
+    pub fn parse_walk(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
+        let names_target = self
+            .peek_n(1)
+            .is_some_and(|t| t.token_type == TokenType::Identifier);
+        let defines = names_target && self.peek_value_n(2) == Some("(");
+
+        let keyword = self.advance()?; // 🔑 Consume `walk`
+        if !names_target {
+            return self.finish_instruction(keyword, Vec::new(), None);
+        }
+
+        let name = self.advance()?; // 🏷️ Subroutine name
+        if defines {
+            return self.parse_function(name);
+        }
+
+        self.finish_instruction(keyword, vec![name.value], None)
+    }
+
+    /// 🛤 Parses a subroutine definition into `ScrollNode::Function`.
+    ///
+    /// Pattern:
+    /// - `walk name(param1, param2) { ... }` (after `walk name` is consumed)
+    ///
+    /// Logic Flow:
+    /// - Consumes `(`, identifier parameters separated by `,`, and `)`
+    /// - Parses the `{ ... }` body via `parse_block()`
+    ///
+    /// Returns:
+    /// - `ScrollNode::Function { name, params, body }`
+    /// - `ScrollNode::Error` on a missing delimiter or non-identifier parameter
+    #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
+    pub fn parse_function(&mut self, name_token: Token) -> Option<ScrollNode> {
+        self.skip_trivia();
+        let name = name_token.value.clone();
+
+        let open_paren = match self.expect_value("(") {
+            Ok(open_paren) => open_paren, // 🔓 Parameter list opens
+            Err(error) => return Some(self.record(error)),
+        };
+
+        let mut params = vec![];
+        loop {
+            let Some(token) = self.advance() else {
+                return Some(self.report(
+                    ParseErrorType::UnexpectedEOF,
+                    format!("Unclosed parameter list for '{}'", name),
+                    &open_paren,
+                ));
+            };
+
+            match token.value.as_str() {
+                ")" => break,    // ✅ Close the parameter list
+                "," => continue, // ➖ Separator
+                _ if token.token_type == TokenType::Identifier => params.push(token.value),
+                _ => return Some(self.report_invalid_argument(params.len(), "identifier", &token)),
+            }
+        }
+
+        // 🧱 Body must follow immediately
+        if self.peek().is_none_or(|t| t.value != "{") {
+            return Some(self.report(
+                ParseErrorType::MissingToken,
+                format!("Expected '{{' to open the body of '{}'", name),
+                &name_token,
+            ));
+        }
+
+        let body = match self.parse_block()? {
+            ScrollNode::Block(body) => body,
+            other => return Some(other),
+        };
+
+        // 📍 The body is unwrapped from its block, so its span is too
+        if let Some(frame) = self.spans.last_mut() {
+            if let Some(block) = frame.pop() {
+                frame.extend(block.children);
+            }
+        }
+
+        Some(ScrollNode::Function { name, params, body })
+    }
+
     // -------------------------------
     // 📞 Function Call Parser
     // -------------------------------
@@ -1238,37 +2552,59 @@ impl Parser {
     ///
     /// Pattern:
     /// - `function(arg1, arg2, ...)`
+    /// - `function(param: arg1, param: arg2)` — named, in any order
     ///
     /// Logic Flow:
     /// - Consumes function name and `(`
     /// - Resolves each argument using `walk_operand()`
+    /// - Keeps a named argument apart, as a `(param, value)` pair in `named`
     /// - Emits `ScrollNode::Call`
     ///
     /// Notes:
     /// - Supports flat arguments only (for now)
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_call(&mut self, function_token: String) -> Option<ScrollNode> {
-        let open_paren = self.advance()?; // 🔓 Expect '('
-
-        if open_paren.value != "(" {
-            return Some(ScrollNode::Error(
-                "Expected '(' after function name.".into(),
-            ));
-        }
+        self.skip_trivia();
+        let open_paren = match self.expect_value("(") {
+            Ok(open_paren) => open_paren, // 🔓 Argument list opens
+            Err(error) => return Some(self.record(error)),
+        };
 
         let mut args = vec![];
+        let mut named = vec![];
 
         while let Some(token) = self.peek() {
+            if token.token_type == TokenType::Whitespace {
+                self.skip_trivia(); // 🧹 Spacing between arguments
+                continue;
+            }
             if token.value == ")" {
                 self.advance(); // ✅ Close the argument list
                 break;
             }
 
+            // 🏷 `amount: 5` — the parameter name is kept beside its value
+            let is_identifier = token.token_type == TokenType::Identifier;
+            let name = if is_identifier && self.peek_value_n(1) == Some(":") {
+                let name = self.advance().map(|token| token.value);
+                self.advance(); // ➡️ Skip the ':'
+                self.skip_trivia();
+                name
+            } else {
+                None
+            };
+
             if let Some(arg) = self.walk_operand() {
-                args.push(arg); // 🎯 Resolve argument via operand logic
+                // 🎯 Resolve argument via operand logic
+                match name {
+                    Some(name) => named.push((name, arg)),
+                    None => args.push(arg),
+                }
             } else {
-                return Some(ScrollNode::Error(
+                return Some(self.report(
+                    ParseErrorType::InvalidArgument(function_token.clone()),
                     "Invalid argument in function call.".into(),
+                    &open_paren,
                 ));
             }
 
@@ -1281,24 +2617,40 @@ impl Parser {
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let entry = DebugEntry::new(
                 "parse_call",
                 &function_token,
                 "call(function, args...)",
-                &format!("{} args parsed", args.len()),
+                &format!("{} args parsed", args.len() + named.len()),
             )
             .with_location("Parser::parse_call")
             .with_suggestion("Consider supporting nested expressions in arguments");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         Some(ScrollNode::Call {
             function: function_token,
             args,
+            named,
         })
     }
 
+    /// 🧠 Reads the next value token as an operand's text.
+    ///
+    /// Returns `None` — leaving the token in place — when the next token
+    /// cannot stand as a value (a separator, group marker, or end of input).
+    fn walk_operand(&mut self) -> Option<String> {
+        self.skip_trivia();
+        self.peek().filter(|token| {
+            matches!(
+                token.token_type,
+                TokenType::Literal | TokenType::Identifier | TokenType::Keyword
+            )
+        })?;
+        self.advance().map(operand_text)
+    }
+
     // -------------------------------
     // 🧾 Assignment Parser
     // -------------------------------
@@ -1316,20 +2668,16 @@ impl Parser {
     /// - `ScrollNode::Assignment { target, value }`
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_assignment(&mut self, target: String) -> Option<ScrollNode> {
-        let next = self.advance()?; // 🔍 Expect '='
-
-        if next.value != "=" {
-            return Some(ScrollNode::Error(format!(
-                "Expected '=' after '{}', got '{}'",
-                target, next.value
-            )));
+        self.skip_trivia();
+        if let Err(error) = self.expect_value("=") {
+            return Some(self.record(error)); // 🔍 `=` must follow the target
         }
 
         let value = self.walk_operand()?; // 🎯 Parse right-hand side as operand
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let display = format!("{target} = {value}");
             let entry = DebugEntry::new(
                 "parse_assignment",
@@ -1339,12 +2687,121 @@ impl Parser {
             )
             .with_location("Parser::parse_assignment")
             .with_suggestion("Ensure variable exists and value is valid expression");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         Some(ScrollNode::Assignment { target, value })
     }
 
+    // -------------------------------
+    // 🧷 Assembler Directive Parser
+    // -------------------------------
+
+    /// 🧷 Parses `.org`, `.byte`, `.align`, or `.label` and the values on
+    /// its line into a `ScrollNode::Directive`.
+    ///
+    /// Values are decimal or `0x` hex, optionally comma-separated. A value
+    /// that is not a number, or a set that does not fit the directive,
+    /// records an `InvalidArgument` error for the whole line. `.label`
+    /// instead takes exactly one name.
+    pub fn parse_directive(&mut self) -> Option<ScrollNode> {
+        self.skip_trivia();
+        let keyword = self.advance()?; // 🧷 `.name`
+
+        if keyword.value == ".label" {
+            let mut words = vec![];
+            while let Some(token) = self.peek().filter(|t| t.line == keyword.line) {
+                words.push(token.value.clone());
+                self.advance();
+            }
+            return match AsmDirective::label(&words.join(" ")) {
+                Ok(directive) => Some(ScrollNode::Directive(directive)),
+                Err(message) => Some(self.report(
+                    ParseErrorType::InvalidArgument(keyword.value.clone()),
+                    message,
+                    &keyword,
+                )),
+            };
+        }
+
+        let mut values = vec![];
+        let mut bad = None;
+        while let Some(token) = self.peek().filter(|t| t.line == keyword.line) {
+            let token = token.clone();
+            self.advance();
+            if token.token_type == TokenType::Punctuation && token.value == "," {
+                continue;
+            }
+            match AsmDirective::parse_value(&token.value) {
+                Some(value) => values.push(value),
+                None => bad = bad.or(Some(token)),
+            }
+        }
+
+        if let Some(token) = bad {
+            return Some(self.report(
+                ParseErrorType::InvalidArgument(token.value.clone()),
+                format!("'{}' expects numeric values, found '{}'", keyword.value, token.value),
+                &token,
+            ));
+        }
+
+        match AsmDirective::new(&keyword.value, &values) {
+            Ok(directive) => Some(ScrollNode::Directive(directive)),
+            Err(message) => Some(self.report(
+                ParseErrorType::InvalidArgument(keyword.value.clone()),
+                message,
+                &keyword,
+            )),
+        }
+    }
+
+    // -------------------------------
+    // 🗃 Map Literal Parser
+    // -------------------------------
+
+    /// 🗃 Parses a `{ key: value, ... }` map into a `ScrollNode::Literal`.
+    ///
+    /// Keys and values are single identifiers or literals; a trailing comma
+    /// is allowed. Returns `None` without recording an error as soon as the
+    /// tokens stop looking like a map — `parse_node` takes a `checkpoint`
+    /// first, releases it on a map, and restores it to reread the braces as
+    /// a block.
+    ///
+    /// Returns:
+    /// - `ScrollNode::Literal("{key: value, ...}")`
+    pub fn parse_map(&mut self) -> Option<ScrollNode> {
+        let is_term =
+            |token: &Token| matches!(token.token_type, TokenType::Identifier | TokenType::Literal);
+
+        self.skip_trivia();
+        self.advance().filter(|t| t.value == "{")?; // 🧩 Opening `{`
+
+        let mut entries = vec![];
+        loop {
+            self.skip_trivia();
+            let key = self.advance().filter(is_term)?;
+            self.skip_trivia();
+            self.advance().filter(|t| t.value == ":")?;
+            self.skip_trivia();
+            let value = self.advance().filter(is_term)?;
+            entries.push(format!("{}: {}", key.value, value.value));
+
+            self.skip_trivia();
+            match self.advance()?.value.as_str() {
+                "}" => break,
+                "," if self.peek_value_n(0) == Some("}") => {
+                    self.advance(); // ✅ Trailing comma, then `}`
+                    break;
+                }
+                "," => continue,
+                _ => return None,
+            }
+        }
+
+        Some(ScrollNode::Literal(format!("{{{}}}", entries.join(", "))))
+    }
+
     // -------------------------------
     // 🧱 Logic Block Parser
     // -------------------------------
@@ -1362,38 +2819,76 @@ impl Parser {
     /// Notes:
     /// - Gracefully halts if malformed or EOF is encountered mid-block
     /// - Used for conditional bodies, loops, and nested scroll logic
+    /// - A block past `MAX_NESTING_DEPTH` is skipped whole and reported
     ///
     /// Returns:
     /// - `ScrollNode::Block(Vec<ScrollNode>)`
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn parse_block(&mut self) -> Option<ScrollNode> {
-        let open = self.advance()?; // 🧩 Expect opening `{`
-        if open.value != "{" {
-            return Some(ScrollNode::Error(format!(
-                "Expected '{{' to open block, found '{}'",
-                open.value
-            )));
+        self.skip_trivia();
+        let open = match self.expect_value("{") {
+            Ok(open) => open, // 🧩 Opening `{`
+            Err(error) => return Some(self.record(error)),
+        };
+
+        // 🪜 Too deep — step over the whole body rather than recurse into it
+        if self.depth >= MAX_NESTING_DEPTH {
+            self.skip_block_body();
+            return Some(self.report(
+                ParseErrorType::InvalidGrammar,
+                format!("Blocks nest deeper than {} levels", MAX_NESTING_DEPTH),
+                &open,
+            ));
         }
 
         let mut nodes = vec![];
+        let mut closed = false;
+        self.depth += 1;
+        self.spans.push(Vec::new()); // 📍 Frame for the inner nodes' spans
 
         // 🌀 Walk through each inner node until `}` is found
         while let Some(token) = self.peek() {
             if token.token_type == TokenType::GroupMarker && token.value == "}" {
                 self.advance(); // ✅ Close the block
+                closed = true;
                 break;
             }
 
-            if let Some(node) = self.parse_node() {
+            let before = self.consumed;
+            if let Some(node) = self.parse_spanned_node() {
                 nodes.push(node); // 🧱 Push parsed scroll node
-            } else {
+            } else if self.consumed == before {
                 break; // 🚨 Exit on invalid node
-            }
+            } // 🧹 Otherwise trivia (a stray `;`) was skipped — keep walking
+        }
+
+        self.depth -= 1;
+
+        // 📍 The block's own span runs from `{` to its closing token
+        let children = self.spans.pop().unwrap_or_default();
+        let span = NodeSpan {
+            start: (open.line, open.column),
+            end: self.last_end,
+            children,
+            instruction: None,
+        };
+        if let Some(frame) = self.spans.last_mut() {
+            frame.push(span);
+        }
+
+        // ⛔ Scroll ended inside the block — keep what was parsed, but record it
+        if !closed {
+            self.errors.push(ParseError::new(
+                ParseErrorType::MissingToken,
+                "Unclosed block — expected '}'",
+                open.line,
+                open.column,
+            ));
         }
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let entry = DebugEntry::new(
                 "parse_block",
                 "{...}",
@@ -1402,7 +2897,7 @@ impl Parser {
             )
             .with_location("Parser::parse_block")
             .with_suggestion("Ensure matching braces and valid scroll logic inside block");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         Some(ScrollNode::Block(nodes))
@@ -1418,7 +2913,7 @@ impl Parser {
 
     /// 🧩 Attempts to decode a raw instruction token using the instruction registry.
     ///
-    /// Looks up a token against the global `InstructionRegistry`, which houses
+    /// Looks up a token against the parser's `InstructionRegistry`, which houses
     /// all recognized opcode-like commands and sacred instruction keywords.
     ///
     /// Outcome:
@@ -1434,14 +2929,17 @@ impl Parser {
     /// - Suggests registry check or update if unrecognized
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
     pub fn decode_instruction(&self, token: &Token) -> Option<String> {
-        use super::instruction_registry::get_instruction_registry;
-
-        let instruction = token.value.clone(); // 🧽 Normalize for consistent lookup
+        // 🧽 Normalize for consistent lookup
+        let instruction = if self.case_sensitive_keywords {
+            token.value.clone()
+        } else {
+            token.value.to_lowercase()
+        };
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
-            let found = InstructionRegistry::contains(&instruction);
+            use watchtower::debugger::DebugEntry;
+            let found = self.registry.contains(instruction.as_str());
             let entry = DebugEntry::new(
                 "decode_instruction",
                 &instruction,
@@ -1450,14 +2948,74 @@ impl Parser {
             )
             .with_location("Parser::decode_instruction")
             .with_suggestion("Verify token is a valid instruction or update registry");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
-        get_instruction_registry()
-            .contains_key(instruction.as_str())
+        self.registry
+            .contains(instruction.as_str())
             .then_some(instruction)
     }
 
+    // -------------------------------
+    // 🧮 Operand Schema Checker
+    // -------------------------------
+
+    /// 🧮 Checks operand tokens against the instruction's registry schema.
+    ///
+    /// Delegates to `Instruction::matches_operands`, the same check the
+    /// Bearer runs on constructed operands. A kind mismatch comes back as a
+    /// standardized `InvalidArgument` (see `ParseError::invalid_argument`);
+    /// too few operands as `MissingToken` at the keyword, too many as
+    /// `UnexpectedToken` at the first extra operand. Instructions without a
+    /// schema are not checked.
+    pub fn check_operand_schema(
+        &self,
+        keyword: &Token,
+        operands: &[Token],
+    ) -> Result<(), ParseError> {
+        let Some(instruction) = self
+            .decode_instruction(keyword)
+            .and_then(|name| self.registry.get(name.as_str()))
+        else {
+            return Ok(());
+        };
+
+        let Err(mismatch) = instruction.matches_operands(operands) else {
+            return Ok(());
+        };
+
+        Err(match &mismatch {
+            OperandMismatch::Kind {
+                position,
+                expected,
+                found,
+            } => {
+                let at = &operands[*position];
+                ParseError::invalid_argument(
+                    *position,
+                    &expected.to_string(),
+                    found,
+                    at.line,
+                    at.column,
+                )
+            }
+            OperandMismatch::Count { expected, .. } => match operands.get(*expected) {
+                Some(extra) => ParseError::new(
+                    ParseErrorType::UnexpectedToken,
+                    mismatch.to_string(),
+                    extra.line,
+                    extra.column,
+                ),
+                None => ParseError::new(
+                    ParseErrorType::MissingToken,
+                    mismatch.to_string(),
+                    keyword.line,
+                    keyword.column,
+                ),
+            },
+        })
+    }
+
     // -------------------------------
     // 🧪 Scroll Sentence Grammar Validator
     // -------------------------------
@@ -1480,7 +3038,7 @@ impl Parser {
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let actual = format!("s='{}', v='{}', o='{:?}'", subject, verb, object);
             let entry = DebugEntry::new(
                 "is_valid_sentence",
@@ -1490,7 +3048,7 @@ impl Parser {
             )
             .with_location("Parser::is_valid_sentence")
             .with_suggestion("Improve validation using verb-object grammar matrix");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         has_subject && has_verb && has_valid_object
@@ -1549,7 +3107,7 @@ impl Parser {
 // ---------------------------------------------------
 // - Extend `to_stone()` to handle nested indentation and metadata fields
 // - Implement `.logos` validator and trust-based audit tagging
-// - Mirror `.stone` and `.logos` divergence points for truth scoring
+// - Feed `.logos` output to `watchtower::alignment_score::divergence_report` for truth scoring
 // - Start schema propagation through grammar roles and call assignments
 //
 // ---------------------------------------------------
@@ -1559,6 +3117,222 @@ impl Parser {
 // ===============================================
 
 impl ScrollTree {
+    // -------------------------------
+    // 🛤 Function Lookup
+    // -------------------------------
+
+    /// 🔍 Finds the `Function` definition named `name`, searching nested bodies too.
+    pub fn find_function(&self, name: &str) -> Option<&ScrollNode> {
+        fn search<'a>(nodes: &'a [ScrollNode], name: &str) -> Option<&'a ScrollNode> {
+            nodes.iter().find_map(|node| match node {
+                ScrollNode::Function { name: defined, .. } if defined == name => Some(node),
+                ScrollNode::Block(body)
+                | ScrollNode::Conditional { body, .. }
+                | ScrollNode::Loop { body, .. }
+                | ScrollNode::Function { body, .. } => search(body, name),
+                _ => None,
+            })
+        }
+
+        search(&self.nodes, name)
+    }
+
+    /// 📞 Resolves a call site to its `Function` definition.
+    ///
+    /// Accepts `Call { function, .. }` and `walk name ...` instructions.
+    pub fn resolve_call(&self, call: &ScrollNode) -> Option<&ScrollNode> {
+        match call {
+            ScrollNode::Call { function, .. } => self.find_function(function),
+            ScrollNode::Instruction { name, args } if name == "walk" => {
+                self.find_function(args.first()?)
+            }
+            _ => None,
+        }
+    }
+
+    // -------------------------------
+    // 📍 Cursor Lookup
+    // -------------------------------
+
+    /// 🎯 Returns the innermost node whose span contains `(line, column)`.
+    ///
+    /// Uses the spans recorded by `Parser::parse()`; hand-built trees
+    /// without spans never match.
+    pub fn find_node_at(&self, line: usize, column: usize) -> Option<&ScrollNode> {
+        fn search<'a>(
+            nodes: &'a [ScrollNode],
+            spans: &[NodeSpan],
+            line: usize,
+            column: usize,
+        ) -> Option<&'a ScrollNode> {
+            let (node, span) = nodes
+                .iter()
+                .zip(spans)
+                .find(|(_, span)| span.contains(line, column))?;
+
+            let body: &[ScrollNode] = match node {
+                ScrollNode::Block(body)
+                | ScrollNode::Conditional { body, .. }
+                | ScrollNode::Loop { body, .. }
+                | ScrollNode::Function { body, .. } => body,
+                _ => &[],
+            };
+
+            search(body, &span.children, line, column).or(Some(node))
+        }
+
+        search(&self.nodes, &self.spans, line, column)
+    }
+
+    /// 📍 Every node in the tree, depth-first in source order, each paired
+    /// with its span — `None` throughout a hand-built tree, which carries no spans.
+    pub fn descendants_with_spans(&self) -> impl Iterator<Item = (&ScrollNode, Option<&NodeSpan>)> {
+        let mut stack = vec![(self.nodes.iter(), self.spans.iter())];
+
+        core::iter::from_fn(move || loop {
+            let (nodes, spans) = stack.last_mut()?;
+            let Some(node) = nodes.next() else {
+                stack.pop(); // ⬆️ Body exhausted — resume the parent
+                continue;
+            };
+            let span = spans.next();
+
+            if let ScrollNode::Block(body)
+            | ScrollNode::Conditional { body, .. }
+            | ScrollNode::Loop { body, .. }
+            | ScrollNode::Function { body, .. } = node
+            {
+                let children = span.map_or(&[][..], |span| span.children.as_slice());
+                stack.push((body.iter(), children.iter())); // ⬇️ Visit the body next
+            }
+
+            return Some((node, span));
+        })
+    }
+
+    // -------------------------------
+    // 🔎 Filtered Node Accessors
+    // -------------------------------
+
+    /// 🌲 Every node in the tree, depth-first in scroll order, including
+    /// nodes nested in block, conditional, loop, and function bodies.
+    pub fn descendants(&self) -> impl Iterator<Item = &ScrollNode> {
+        let mut stack = vec![self.nodes.iter()];
+
+        core::iter::from_fn(move || loop {
+            let Some(node) = stack.last_mut()?.next() else {
+                stack.pop(); // ⬆️ Body exhausted — resume the parent
+                continue;
+            };
+
+            if let ScrollNode::Block(body)
+            | ScrollNode::Conditional { body, .. }
+            | ScrollNode::Loop { body, .. }
+            | ScrollNode::Function { body, .. } = node
+            {
+                stack.push(body.iter()); // ⬇️ Visit the body next
+            }
+
+            return Some(node);
+        })
+    }
+
+    /// 🪜 Deepest nesting in the tree: top-level nodes sit at 1, a block's
+    /// children at 2, and so on. An empty tree has depth 0.
+    pub fn max_depth(&self) -> usize {
+        let mut stack = vec![(self.nodes.iter(), 1)];
+        let mut deepest = 0;
+
+        while let Some((nodes, depth)) = stack.last_mut() {
+            let depth = *depth;
+            let Some(node) = nodes.next() else {
+                stack.pop(); // ⬆️ Body exhausted — resume the parent
+                continue;
+            };
+
+            deepest = deepest.max(depth);
+            if let ScrollNode::Block(body)
+            | ScrollNode::Conditional { body, .. }
+            | ScrollNode::Loop { body, .. }
+            | ScrollNode::Function { body, .. } = node
+            {
+                stack.push((body.iter(), depth + 1)); // ⬇️ Visit the body next
+            }
+        }
+
+        deepest
+    }
+
+    /// 🛰 Names this scroll binds at its top level, in first-bound order —
+    /// what an importing scroll reaches as `scroll.name`. Declarations,
+    /// function names, assignment targets, and the first operand of `let`,
+    /// `hear`, `store`, and `recall` count; this scroll's own imports do not.
+    /// Like the symbol pass that reads it, this needs the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn exports(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        let mut bind = |name: &str| {
+            if !names.iter().any(|bound| bound == name) {
+                names.push(name.to_string());
+            }
+        };
+
+        for node in &self.nodes {
+            match node {
+                ScrollNode::Declaration { name, .. } | ScrollNode::Function { name, .. } => {
+                    bind(name)
+                }
+                ScrollNode::Assignment { target, .. } => {
+                    target.split(',').map(str::trim).for_each(&mut bind)
+                }
+                ScrollNode::Instruction { name, args }
+                    if BINDING_INSTRUCTIONS.contains(&name.as_str()) =>
+                {
+                    if let Some(first) = args.iter().find(|arg| !is_trust_annotation(arg)) {
+                        bind(first);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        names
+    }
+
+    /// ⚙️ Every `Instruction` in the tree as `(name, args)`.
+    pub fn instructions(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.descendants().filter_map(|node| match node {
+            ScrollNode::Instruction { name, args } => Some((name.as_str(), args.as_slice())),
+            _ => None,
+        })
+    }
+
+    /// ✒️ Every `Declaration` in the tree as `(name, dtype)`.
+    pub fn declarations(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.descendants().filter_map(|node| match node {
+            ScrollNode::Declaration { name, dtype } => Some((name.as_str(), dtype.as_deref())),
+            _ => None,
+        })
+    }
+
+    /// 📞 Every `Call` in the tree as `(function, args)`, positional arguments only.
+    pub fn calls(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.descendants().filter_map(|node| match node {
+            ScrollNode::Call { function, args, .. } => Some((function.as_str(), args.as_slice())),
+            _ => None,
+        })
+    }
+
+    /// 🧮 Accumulates a value over every node, in `descendants` order.
+    ///
+    /// For custom metrics the filtered accessors don't cover, e.g.
+    /// `tree.fold(0, |n, node| n + matches!(node, ScrollNode::Call { .. }) as usize)`.
+    /// Nodes borrow from the tree, so the accumulator may hold references
+    /// into it.
+    pub fn fold<'a, B>(&'a self, init: B, f: impl FnMut(B, &'a ScrollNode) -> B) -> B {
+        self.descendants().fold(init, f)
+    }
+
     // -------------------------------
     // 🪨 Stone Format Serializer
     // -------------------------------
@@ -1568,20 +3342,78 @@ impl ScrollTree {
     /// Serializes all top-level nodes into `.stone`—a linear, readable
     /// intermediate representation for debugging, inspection, or transport.
     ///
+    /// 🏷️ Annotations: comments are written as `;; text` and metadata as
+    /// `;;! text`, each on its own line at the indentation of the node that
+    /// follows it. Block children are emitted one per line, indented two
+    /// spaces per level, so annotations inside a block stay inside it.
+    ///
     /// 🔮 Future upgrades:
-    /// - Prettify block formatting
-    /// - Add nested indentation
+    /// - Emit conditional, loop, and function bodies
     /// - Integrate schema-aware emitters
     /// - Resolve operands using `.logos` or grammar walker
     pub fn to_stone(&self) -> String {
+        self.to_stone_opts(StoneOptions::raw())
+    }
+
+    /// 🪨 Converts `ScrollTree` into `.stone`, enriched as `opts` asks.
+    ///
+    /// With `include_types` / `include_trust`, each instruction argument is
+    /// classified by the Bearer and suffixed `:Type` and/or `#Tier`
+    /// (`speak "truth":String#Certain`). Without `include_comments`, `;;`
+    /// comment lines are dropped. Enriched output is for reading, not for
+    /// `from_stone`. Without the `std` feature there is no Bearer, so the
+    /// type and trust flags have no effect.
+    pub fn to_stone_opts(&self, opts: StoneOptions) -> String {
+        #[cfg(feature = "std")]
+        let bearer = (opts.include_types || opts.include_trust).then(Bearer::new);
+
+        let annotate = |arg: &str| -> String {
+            #[allow(unused_mut)]
+            let mut annotated = arg.to_string();
+
+            // 🏷 `@trusted` annotations are written as-is, unclassified
+            #[cfg(feature = "std")]
+            if let Some(bearer) = bearer.as_ref().filter(|_| !is_trust_annotation(arg)) {
+                let operand_type = bearer.classify_operand_type(arg);
+                if opts.include_types {
+                    annotated += &format!(":{:?}", operand_type);
+                }
+                if opts.include_trust {
+                    let operand = bearer.construct_operand(arg, operand_type);
+                    annotated += &format!("#{:?}", bearer.mark_trust_level(&operand));
+                }
+            }
+
+            annotated
+        };
+
         let mut output = String::new();
+        Self::write_stone(&self.nodes, 0, &opts, &annotate, &mut output);
+        output
+    }
+
+    /// 🪨 Writes `nodes` as `.stone` lines at `depth` levels of indentation.
+    fn write_stone(
+        nodes: &[ScrollNode],
+        depth: usize,
+        opts: &StoneOptions,
+        annotate: &dyn Fn(&str) -> String,
+        output: &mut String,
+    ) {
+        let indent = "  ".repeat(depth);
+
+        for node in nodes {
+            if !opts.include_comments && matches!(node, ScrollNode::Comment(_)) {
+                continue;
+            }
+
+            output.push_str(&indent);
 
-        for node in &self.nodes {
             match node {
                 // ✨ Basic instruction: verb and arguments flattened
                 ScrollNode::Instruction { name, args } => {
-                    output += &format!("{} {}\n", name, args.join(" "));
-                    // 🔍 If operand resolver enriches args in future, update format here
+                    let args: Vec<String> = args.iter().map(|arg| annotate(arg)).collect();
+                    *output += &format!("{} {}\n", name, args.join(" "));
                 }
 
                 // 📖 Scroll-style sentence: subject–verb–object grammar
@@ -1590,100 +3422,274 @@ impl ScrollTree {
                     verb,
                     object,
                 } => {
-                    output += &format!("{} {} {}\n", subject, verb, object);
+                    *output += &format!("{} {} {}\n", subject, verb, object);
                     // 🧠 Could later enrich with operand role types or tags
                 }
 
                 // 🧷 Assignment: `x = value`
                 ScrollNode::Assignment { target, value } => {
-                    output += &format!("{} = {}\n", target, value);
+                    *output += &format!("{} = {}\n", target, value);
                     // ⚙️ Operand-aware value? Ensure proper spacing or quotes if literal
                 }
 
-                // 🔢 Literal node: raw value capture
+                // 🔢 Literal node: strings quoted and escaped, other values bare
                 ScrollNode::Literal(val) => {
-                    output += &format!("literal {}\n", val);
+                    *output += &format!("literal {}\n", Self::stone_literal(val));
                 }
 
-                // 🏷️ Metadata: for tags, titles, or attributes
+                // 🏷️ Metadata annotation: for tags, titles, or attributes
                 ScrollNode::Metadata(data) => {
-                    output += &format!("meta {}\n", data);
+                    *output += &format!(";;! {}\n", data);
                 }
 
-                // 🧱 Block: nested child nodes, displayed as internal lines
+                // 🧱 Block: nested child nodes, one indented line each
                 ScrollNode::Block(inner) => {
-                    output += "{\n";
-                    for child in inner {
-                        // 🚧 TEMP: Debug output — replace with `child.to_stone()` or similar
-                        output += &format!("  {:?}\n", child);
-                    }
-                    output += "}\n";
+                    output.push_str("{\n");
+                    Self::write_stone(inner, depth + 1, opts, annotate, output);
+                    *output += &format!("{}}}\n", indent);
                 }
 
                 // 🚨 Error display
                 ScrollNode::Error(err) => {
-                    output += &format!("!error {}\n", err);
+                    *output += &format!("!error {}\n", err);
                 }
 
                 // 📝 Declaration: `let name: Type`
                 ScrollNode::Declaration { name, dtype } => {
                     let dtype_display = dtype.clone().unwrap_or_else(|| "Unknown".into());
-                    output += &format!("let {}: {}\n", name, dtype_display);
+                    *output += &format!("let {}: {}\n", name, dtype_display);
                 }
 
                 // 🔀 Conditional: just show condition inline
                 ScrollNode::Conditional { condition, .. } => {
-                    output += &format!("if {}\n", condition);
+                    *output += &format!("if {}\n", condition);
                     // 🌿 Future: emit body as well (nested blocks)
                 }
 
                 // 🔁 Loop: emit as `loop <cond>`
                 ScrollNode::Loop { condition, .. } => {
-                    output += &format!("loop {}\n", condition);
+                    *output += &format!("loop {}\n", condition);
                     // 🌱 Similar: body emission later
                 }
 
                 // 📥 Import statements
                 ScrollNode::Import(path) => {
-                    output += &format!("import {}\n", path);
+                    *output += &format!("import {}\n", path);
                 }
 
                 // 🔚 Return value — potentially operand-wrapped
                 ScrollNode::Return(value) => {
-                    output += &format!("return {}\n", value);
+                    *output += &format!("return {}\n", value);
                     // 🧩 Future: value may come from operand tree
                 }
 
                 // 📞 Function call
-                ScrollNode::Call { function, args } => {
-                    // 💡 Function call emits like: `func(arg1, arg2)`
-                    output += &format!("{}({})\n", function, args.join(", "));
+                ScrollNode::Call {
+                    function,
+                    args,
+                    named,
+                } => {
+                    // 💡 Function call emits like: `func(arg1, arg2, name: value)`
+                    *output += &format!("{}({})\n", function, call_arguments_text(args, named));
                     // 🧠 Operand resolver may later format args differently
                 }
 
-                // 💬 Comments in scroll
+                // 💬 Comment annotation
                 ScrollNode::Comment(text) => {
-                    output += &format!("// {}\n", text);
+                    *output += &format!(";; {}\n", text);
+                }
+
+                // 🛤 Function definition header
+                ScrollNode::Function { name, params, .. } => {
+                    *output += &format!("walk {}({})\n", name, params.join(", "));
+                    // 🌱 Body emission later, alongside conditionals and loops
+                }
+
+                // 🧷 Assembler directive — `.byte 0x41, 0x42`
+                ScrollNode::Directive(directive) => {
+                    *output += &format!("{}\n", directive);
                 }
             }
         }
-
-        output
     }
 
     // -------------------------------
-    // 📖 Scroll Validation (.logos-Aligned)
+    // 🪨 Stone Format Reader
     // -------------------------------
 
-    /// 📖 Validates the `ScrollTree` against .logos grammar and Scripture alignment.
-    ///
-    /// Early validation logic now includes:
-    /// - Subject–Verb–Object sentence checks
-    /// - Instruction name registry checks
-    /// - Return statement validity
+    /// 🔁 Rebuilds a `ScrollTree` from `.stone` text written by `to_stone`.
     ///
-    /// 🛐 Future integration:
-    /// - Full `.logos` spiritual schema
+    /// Annotations (`;;` / `;;!`), blocks, errors, literals, imports,
+    /// returns, assignments, and calls are recognized by their line form;
+    /// any other line reads back as an instruction. Forms `to_stone` writes
+    /// lossily (conditional, loop, and function bodies; declarations) read
+    /// back as their header instruction only. The result carries no spans.
+    pub fn from_stone(stone: &str) -> ScrollTree {
+        let mut frames: Vec<Vec<ScrollNode>> = vec![Vec::new()];
+
+        for raw in stone.lines() {
+            let line = raw.trim_start();
+
+            let node = if let Some(data) = line.strip_prefix(";;!") {
+                ScrollNode::Metadata(data.strip_prefix(' ').unwrap_or(data).to_string())
+            } else if let Some(text) = line.strip_prefix(";;") {
+                ScrollNode::Comment(text.strip_prefix(' ').unwrap_or(text).to_string())
+            } else if line.trim_end() == "{" {
+                frames.push(Vec::new());
+                continue;
+            } else if line.trim_end() == "}" && frames.len() > 1 {
+                let inner = frames.pop().unwrap_or_default();
+                ScrollNode::Block(inner)
+            } else {
+                match Self::stone_line(line.trim_end()) {
+                    Some(node) => node,
+                    None => continue, // 🫧 Blank line
+                }
+            };
+
+            if let Some(frame) = frames.last_mut() {
+                frame.push(node);
+            }
+        }
+
+        // 🧱 Close any block left open at end of input
+        while frames.len() > 1 {
+            let inner = frames.pop().unwrap_or_default();
+            if let Some(frame) = frames.last_mut() {
+                frame.push(ScrollNode::Block(inner));
+            }
+        }
+
+        let nodes = frames.pop().unwrap_or_default();
+        ScrollTree {
+            meta: ScrollMeta::from_nodes(&nodes),
+            nodes,
+            spans: Vec::new(),
+        }
+    }
+
+    /// 🪨 Reads one non-annotation, non-brace `.stone` line.
+    fn stone_line(line: &str) -> Option<ScrollNode> {
+        if line.is_empty() {
+            return None;
+        }
+
+        // 🧷 `.byte 0x41, 0x42` — assembler directives start with a dot
+        if line.starts_with('.') {
+            let (name, rest) = line.split_once(' ').unwrap_or((line, ""));
+            if name == ".label" {
+                if let Ok(directive) = AsmDirective::label(rest) {
+                    return Some(ScrollNode::Directive(directive));
+                }
+            }
+            let values: Option<Vec<u64>> = rest
+                .split([',', ' '])
+                .filter(|value| !value.is_empty())
+                .map(AsmDirective::parse_value)
+                .collect();
+            if let Some(Ok(directive)) = values.map(|values| AsmDirective::new(name, &values)) {
+                return Some(ScrollNode::Directive(directive));
+            }
+        }
+
+        // 📞 `func(arg1, arg2)` — a call has no space before its parenthesis
+        if let Some((function, args)) = line.strip_suffix(')').and_then(|l| l.split_once('(')) {
+            if !function.is_empty() && !function.contains(' ') {
+                // 🏷 Quoted text never splits, so `name: value` is a named argument
+                let (named, args): (Vec<&str>, Vec<&str>) = args
+                    .split(", ")
+                    .filter(|arg| !arg.is_empty())
+                    .partition(|arg| split_named_argument(arg).is_some());
+                let named: Vec<(&str, &str)> =
+                    named.into_iter().filter_map(split_named_argument).collect();
+                return Some(ScrollNode::named_call(function, &args, &named));
+            }
+        }
+
+        // 🧺 `a, b = value` — tuple targets start with `name,`
+        if let Some((target, value)) = line.split_once(" = ") {
+            if target.contains(", ") && !target.contains('(') {
+                return Some(ScrollNode::assignment(target, value));
+            }
+        }
+
+        let (head, rest) = line.split_once(' ').unwrap_or((line, ""));
+
+        Some(match head {
+            "!error" => ScrollNode::Error(rest.to_string()),
+            "literal" => ScrollNode::Literal(Self::read_stone_literal(rest)),
+            "import" => ScrollNode::Import(rest.to_string()),
+            "return" => ScrollNode::Return(rest.to_string()),
+            _ if rest.starts_with("= ") => ScrollNode::assignment(head, &rest[2..]),
+            _ => ScrollNode::instruction(head, &Self::stone_args(rest)),
+        })
+    }
+
+    /// 🔢 Writes a literal for `.stone`: numbers, booleans, and `{...}` maps
+    /// bare; anything else as a `"quoted"` string with `\\`, `"`, and line
+    /// breaks escaped, so spaces and newlines cannot split the line.
+    fn stone_literal(val: &str) -> String {
+        let bare = val == "true"
+            || val == "false"
+            || val.parse::<f64>().is_ok()
+            || (val.starts_with('{') && val.ends_with('}'));
+        if bare && !val.contains(['\n', '\r']) {
+            return val.to_string();
+        }
+        quote_text(val)
+    }
+
+    /// 🔢 Reads a literal written by `stone_literal`, unescaping a quoted one.
+    fn read_stone_literal(rest: &str) -> String {
+        unquote_text(rest).unwrap_or_else(|| rest.to_string())
+    }
+
+    /// ✂️ Splits instruction arguments on spaces, keeping `"quoted text"`
+    /// (escaped `\"` included) whole.
+    fn stone_args(rest: &str) -> Vec<&str> {
+        let mut args = Vec::new();
+        let mut start = None; // 📍 Byte offset where the current argument began
+        let (mut quoted, mut escaped) = (false, false);
+
+        for (i, c) in rest.char_indices() {
+            match c {
+                ' ' if !quoted => {
+                    if let Some(start) = start.take() {
+                        args.push(&rest[start..i]);
+                    }
+                }
+                _ => {
+                    match c {
+                        _ if escaped => escaped = false,
+                        '\\' if quoted => escaped = true,
+                        '"' => quoted = !quoted,
+                        _ => {}
+                    }
+                    start.get_or_insert(i);
+                }
+            }
+        }
+
+        if let Some(start) = start {
+            args.push(&rest[start..]);
+        }
+
+        args
+    }
+
+    // -------------------------------
+    // 📖 Scroll Validation (.logos-Aligned)
+    // -------------------------------
+
+    /// 📖 Validates the `ScrollTree` against .logos grammar and Scripture alignment.
+    ///
+    /// Early validation logic now includes:
+    /// - Subject–Verb–Object sentence checks
+    /// - Instruction name registry checks
+    /// - Return statement validity
+    ///
+    /// 🛐 Future integration:
+    /// - Full `.logos` spiritual schema
     /// - Verse-backed alignment walkers
     /// - Drift diagnostics and audit score
     #[cfg_attr(not(any(test, feature = "debug_mode")), allow(dead_code))]
@@ -1705,7 +3711,7 @@ impl ScrollTree {
                     if !is_valid {
                         #[cfg(feature = "debug_mode")]
                         {
-                            use crate::debugger::{DebugEntry, Severity};
+                            use watchtower::debugger::{DebugEntry, Severity};
                             let entry = DebugEntry::new(
                                 "validate_with_scripture",
                                 &format!("{} {} {}", subject, verb, object),
@@ -1713,59 +3719,56 @@ impl ScrollTree {
                                 "Failed validation",
                             )
                             .with_location("ScrollTree::validate_with_scripture")
-                            .with_severity(Severity::Warning)
+                            .with_severity(Severity::Weakness)
                             .with_suggestion("Review sentence structure or verb roles");
-                            println!("{entry:#?}");
+                            entry.emit();
                         }
                         return false; // 🚨 Fatal alignment failure
                     }
                 }
 
                 // 🔍 Validate instruction name against registry
-                ScrollNode::Instruction { name, .. } => {
+                ScrollNode::Instruction { name, .. }
                     if validator
                         .decode_instruction(&Token::from_value(name))
-                        .is_none()
+                        .is_none() =>
+                {
+                    #[cfg(feature = "debug_mode")]
                     {
-                        #[cfg(feature = "debug_mode")]
-                        {
-                            use crate::debugger::{DebugEntry, Severity};
-                            let entry = DebugEntry::new(
-                                "validate_with_scripture",
-                                name,
-                                "Known instruction",
-                                "Unknown instruction",
-                            )
-                            .with_location("ScrollTree::validate_with_scripture")
-                            .with_severity(Severity::Warning)
-                            .with_suggestion("Verify instruction name is part of the registry");
-                            println!("{entry:#?}");
-                        }
-                        return false; // 🚨 Invalid instruction
+                        use watchtower::debugger::{DebugEntry, Severity};
+                        let entry = DebugEntry::new(
+                            "validate_with_scripture",
+                            name,
+                            "Known instruction",
+                            "Unknown instruction",
+                        )
+                        .with_location("ScrollTree::validate_with_scripture")
+                        .with_severity(Severity::Weakness)
+                        .with_suggestion("Verify instruction name is part of the registry");
+                        entry.emit();
                     }
+                    return false; // 🚨 Invalid instruction
                 }
 
                 // ⚠️ Return with empty or suspicious value
-                ScrollNode::Return(value) => {
-                    if value.trim().is_empty() || value == "None" {
-                        #[cfg(feature = "debug_mode")]
-                        {
-                            use crate::debugger::{DebugEntry, Severity};
-                            let entry = DebugEntry::new(
-                                "validate_with_scripture",
-                                value,
-                                "Non-empty return",
-                                "Empty or invalid return value",
-                            )
-                            .with_location("ScrollTree::validate_with_scripture")
-                            .with_severity(Severity::Warning)
-                            .with_suggestion(
-                                "Ensure return carries actual meaning or operand value",
-                            );
-                            println!("{entry:#?}");
-                        }
-                        return false;
+                ScrollNode::Return(value) if value.trim().is_empty() || value == "None" => {
+                    #[cfg(feature = "debug_mode")]
+                    {
+                        use watchtower::debugger::{DebugEntry, Severity};
+                        let entry = DebugEntry::new(
+                            "validate_with_scripture",
+                            value,
+                            "Non-empty return",
+                            "Empty or invalid return value",
+                        )
+                        .with_location("ScrollTree::validate_with_scripture")
+                        .with_severity(Severity::Weakness)
+                        .with_suggestion(
+                            "Ensure return carries actual meaning or operand value",
+                        );
+                        entry.emit();
                     }
+                    return false;
                 }
 
                 _ => {
@@ -1777,7 +3780,7 @@ impl ScrollTree {
 
         #[cfg(feature = "debug_mode")]
         {
-            use crate::debugger::{DebugEntry, Severity};
+            use watchtower::debugger::DebugEntry;
             let entry = DebugEntry::new(
                 "validate_with_scripture",
                 "ScrollTree",
@@ -1786,9 +3789,1100 @@ impl ScrollTree {
             )
             .with_location("ScrollTree::validate_with_scripture")
             .with_suggestion("Integrate .logos validator hooks");
-            println!("{entry:#?}");
+            entry.emit();
         }
 
         true // ✅ Passed all checks
     }
+
+    // -------------------------------
+    // 🩺 Combined Validation
+    // -------------------------------
+
+    /// 🩺 Runs every pass enabled in `opts` and gathers their findings.
+    ///
+    /// Passes run in a fixed order — symbols, unreachable code, arity,
+    /// privilege, scripture — each appending its own entries, so one scroll
+    /// can report several kinds of problem at once. Arity and privilege are
+    /// read from `registry`; instructions it does not know are left to the
+    /// scripture pass. An empty result means nothing was found.
+    #[cfg(feature = "std")]
+    pub fn validate(
+        &self,
+        registry: &InstructionRegistry,
+        opts: ValidateOptions,
+    ) -> Vec<DebugEntry> {
+        self.validate_with_imports(registry, opts, &BTreeMap::new())
+    }
+
+    /// 🛰 Runs `validate` with `imports` — parsed scrolls keyed by scroll
+    /// name — available to this scroll's `import` lines.
+    ///
+    /// An `import "psalms.ns"` finding `psalms` in `imports` makes that
+    /// scroll's `exports()` reachable as `psalms.member` (bound `Extern`).
+    /// The symbol pass flags paths into a scroll that was never imported
+    /// and paths naming a member the imported scroll does not bind.
+    #[cfg(feature = "std")]
+    pub fn validate_with_imports(
+        &self,
+        registry: &InstructionRegistry,
+        opts: ValidateOptions,
+        imports: &BTreeMap<String, ScrollTree>,
+    ) -> Vec<DebugEntry> {
+        let mut entries = Vec::new();
+
+        if opts.symbols {
+            let mut environment = BindingEnvironment::new();
+            Self::check_symbols(&self.nodes, registry, imports, &mut environment, &mut entries);
+        }
+
+        if opts.unreachable {
+            Self::check_unreachable(&self.nodes, &mut entries);
+        }
+
+        for (name, args) in self.instructions() {
+            let Some(instruction) = registry.get(name) else {
+                continue;
+            };
+
+            // 🧮 `let x = 5` — the `=` separator and `@trusted` annotations are not operands
+            let found = args
+                .iter()
+                .filter(|arg| arg.as_str() != "=" && !is_trust_annotation(arg))
+                .count();
+            if let Some(schema) = instruction.schema().filter(|_| opts.arity) {
+                let required = schema.required();
+                let max = schema.max();
+                if found < required || max.is_some_and(|max| found > max) {
+                    let expected = match max {
+                        Some(max) if max == required => format!("{} operands", required),
+                        Some(max) => format!("{}–{} operands", required, max),
+                        None => format!("at least {} operands", required),
+                    };
+                    entries.push(
+                        DebugEntry::new(
+                            "validate",
+                            &format!("{} {}", name, args.join(" ")),
+                            &expected,
+                            &format!("{} operands", found),
+                        )
+                        .with_location("ScrollTree::validate")
+                        .with_suggestion("Match the instruction's operand schema")
+                        .with_severity(Severity::Error),
+                    );
+                }
+            }
+
+            if let Some(level) = instruction.privilege_level().filter(|_| opts.privilege) {
+                if *level > opts.max_privilege {
+                    entries.push(
+                        DebugEntry::new(
+                            "validate",
+                            name,
+                            &format!("{:?} privilege or lower", opts.max_privilege),
+                            &format!("{:?} privilege", level),
+                        )
+                        .with_location("ScrollTree::validate")
+                        .with_suggestion("Raise `max_privilege` or remove the instruction")
+                        .with_severity(Severity::Error),
+                    );
+                }
+            }
+        }
+
+        if opts.scripture && !self.validate_with_scripture() {
+            entries.push(
+                DebugEntry::new(
+                    "validate",
+                    "ScrollTree",
+                    "Spiritual grammar",
+                    "Validation failed",
+                )
+                .with_location("ScrollTree::validate_with_scripture")
+                .with_suggestion("Check sentences, instruction names, and return values")
+                .with_severity(Severity::Weakness),
+            );
+        }
+
+        entries
+    }
+
+    /// 🕳 Symbol pass — flags names read before any visible binding.
+    ///
+    /// `Declaration`, `Assignment` targets, function names and parameters,
+    /// and the first operand of `let`, `hear`, `store`, and `recall` bind a
+    /// name. `Target` and `Value` operands, call arguments, assigned names,
+    /// and returned names are reads. Nested bodies open a fresh scope.
+    ///
+    /// A `Declaration` with a known type (`let x: Int`) also records it;
+    /// later assignments of a literal or typed name that does not fit that
+    /// type are flagged as conflicts.
+    ///
+    /// An `Import` found in `imports` brings that scroll's members into
+    /// scope as `scroll.member`. A dotted path read is checked against them
+    /// unless its head is a local binding, whose fields are not tracked.
+    ///
+    /// Only bare identifiers and identifier paths are reads: string literals
+    /// keep their quotes in node arguments, so `store x "notes.txt"` reads `x`
+    /// alone.
+    #[cfg(feature = "std")]
+    fn check_symbols(
+        nodes: &[ScrollNode],
+        registry: &InstructionRegistry,
+        imports: &BTreeMap<String, ScrollTree>,
+        environment: &mut BindingEnvironment,
+        entries: &mut Vec<DebugEntry>,
+    ) {
+        let read_path = |path: &str, environment: &BindingEnvironment, entries: &mut Vec<DebugEntry>| {
+            let mut segments = path.split("::").flat_map(|s| s.split('.'));
+            let scroll = segments.next().unwrap_or(path);
+            if environment.lookup_path(path).is_some() || environment.is_visible(scroll) {
+                return;
+            }
+
+            let found = match segments.next() {
+                Some(member) if environment.is_imported(scroll) => {
+                    format!("'{}' has no member '{}'", scroll, member)
+                }
+                _ => format!("'{}' is not an imported scroll", scroll),
+            };
+            entries.push(
+                DebugEntry::new("validate", path, "member of an imported scroll", &found)
+                    .with_location("ScrollTree::validate")
+                    .with_suggestion("Import the scroll, and bind the member at its top level")
+                    .with_severity(Severity::Error),
+            );
+        };
+
+        let read = |name: &str, environment: &BindingEnvironment, entries: &mut Vec<DebugEntry>| {
+            if !environment.is_visible(name) {
+                entries.push(
+                    DebugEntry::new(
+                        "validate",
+                        name,
+                        "declared before use",
+                        &format!("'{}' is not declared", name),
+                    )
+                    .with_location("ScrollTree::validate")
+                    .with_suggestion("Bind it with `let` (or declare it) before this line")
+                    .with_severity(Severity::Error),
+                );
+            }
+        };
+
+        // 📖 A bare name or `scroll.member` path; literals and quoted text read nothing
+        let read_operand = |arg: &str, environment: &BindingEnvironment, entries: &mut Vec<DebugEntry>| {
+            if is_name(arg) {
+                read(arg, environment, entries);
+            } else if is_path(arg) {
+                read_path(arg, environment, entries);
+            }
+        };
+
+        for node in nodes {
+            match node {
+                ScrollNode::Instruction { name, args } => {
+                    let Some(schema) = registry.get(name).and_then(|i| i.schema()) else {
+                        continue;
+                    };
+                    let operands: Vec<&String> = args
+                        .iter()
+                        .filter(|arg| arg.as_str() != "=" && !is_trust_annotation(arg))
+                        .collect();
+                    let kinds = schema.form_for(operands.len()).unwrap_or(&schema.kinds);
+
+                    for (position, (arg, kind)) in operands.into_iter().zip(kinds).enumerate() {
+                        if position == 0 && BINDING_INSTRUCTIONS.contains(&name.as_str()) {
+                            environment.declare(arg);
+                        } else if matches!(kind.inner(), OperandKind::Target | OperandKind::Value) {
+                            read_operand(arg, environment, entries);
+                        }
+                    }
+                }
+
+                ScrollNode::Import(path) => {
+                    let scroll = scroll_name(path);
+                    if let Some(tree) = imports.get(scroll) {
+                        environment.import_scroll(scroll, tree.exports().iter().map(String::as_str));
+                    }
+                }
+
+                ScrollNode::Declaration { name, dtype } => {
+                    match dtype.as_deref().and_then(OperandType::from_type_name) {
+                        Some(dtype) => environment.declare_typed(name, dtype),
+                        None => environment.declare(name),
+                    };
+                }
+
+                ScrollNode::Assignment { target, value } => {
+                    names_in(value).for_each(|name| read(name, environment, entries));
+                    if is_path(value) {
+                        read_path(value, environment, entries);
+                    }
+
+                    let declared = environment.declared_type(target).cloned();
+                    let found = OperandType::of_literal(value)
+                        .or_else(|| environment.declared_type(value).cloned());
+                    if let (Some(declared), Some(found)) = (declared, found) {
+                        if !found.fits(&declared) {
+                            entries.push(
+                                DebugEntry::new(
+                                    "validate",
+                                    &format!("{} = {}", target, value),
+                                    &format!("{:?}", declared),
+                                    &format!("{:?}", found),
+                                )
+                                .with_location("ScrollTree::validate")
+                                .with_suggestion("Assign a value of the declared type")
+                                .with_severity(Severity::Error),
+                            );
+                        }
+                    }
+
+                    // ♻️ Reassignments keep the existing binding and its type
+                    for name in target.split(',').map(str::trim) {
+                        if !environment.is_visible(name) {
+                            environment.declare(name);
+                        }
+                    }
+                }
+
+                ScrollNode::Call { args, named, .. } => {
+                    let values = named.iter().map(|(_, value)| value);
+                    for arg in args.iter().chain(values) {
+                        read_operand(arg, environment, entries);
+                    }
+                }
+
+                ScrollNode::Return(value) => {
+                    names_in(value).for_each(|name| read(name, environment, entries));
+                }
+
+                ScrollNode::Function { name, params, body } => {
+                    environment.declare(name);
+                    environment.push_scope();
+                    for param in params {
+                        environment.declare(param);
+                    }
+                    Self::check_symbols(body, registry, imports, environment, entries);
+                    environment.pop_scope();
+                }
+
+                ScrollNode::Block(body)
+                | ScrollNode::Conditional { body, .. }
+                | ScrollNode::Loop { body, .. } => {
+                    environment.push_scope();
+                    Self::check_symbols(body, registry, imports, environment, entries);
+                    environment.pop_scope();
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    /// 🪦 Unreachable pass — flags the first node after a `return` or
+    /// `break` in each body. Comments and metadata are skipped over.
+    #[cfg(feature = "std")]
+    fn check_unreachable(nodes: &[ScrollNode], entries: &mut Vec<DebugEntry>) {
+        let mut exited = false;
+
+        for node in nodes {
+            match node {
+                ScrollNode::Comment(_) | ScrollNode::Metadata(_) => {}
+
+                _ if exited => {
+                    entries.push(
+                        DebugEntry::new(
+                            "validate",
+                            &format!("{:?}", node),
+                            "reachable node",
+                            "node after return or break",
+                        )
+                        .with_location("ScrollTree::validate")
+                        .with_suggestion("Remove the dead code or move the exit below it")
+                        .with_severity(Severity::Drift),
+                    );
+                    break;
+                }
+
+                ScrollNode::Return(_) => exited = true,
+                ScrollNode::Instruction { name, .. } if name == "break" => exited = true,
+
+                ScrollNode::Block(body)
+                | ScrollNode::Conditional { body, .. }
+                | ScrollNode::Loop { body, .. }
+                | ScrollNode::Function { body, .. } => Self::check_unreachable(body, entries),
+
+                _ => {}
+            }
+        }
+    }
+}
+
+/// ✒️ Instructions whose first operand binds a name rather than reading one.
+#[cfg(feature = "std")]
+const BINDING_INSTRUCTIONS: [&str; 4] = ["let", "hear", "store", "recall"];
+
+/// 🛤 Whether `text` is a dotted or `::` path of names (`psalms.shepherd`).
+#[cfg(feature = "std")]
+fn is_path(text: &str) -> bool {
+    let mut segments = text.split("::").flat_map(|s| s.split('.'));
+    (text.contains('.') || text.contains("::")) && segments.all(is_name)
+}
+
+/// 📥 Scroll name an import path refers to: `"lib/psalms.ns"` → `psalms`.
+#[cfg(feature = "std")]
+fn scroll_name(path: &str) -> &str {
+    let path = path.trim_matches('"');
+    let file = path.rsplit('/').next().unwrap_or(path);
+    file.strip_suffix(".ns").unwrap_or(file)
+}
+
+/// 🔤 Whether `text` reads as a binding name (`flock`, `_count`), not a literal.
+fn is_name(text: &str) -> bool {
+    text.chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && text.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !matches!(text, "true" | "false")
+}
+
+/// 🧺 Names read by a value: `x`, or each member of a tuple `(a, b)`.
+/// Call text such as `f(x)` yields nothing.
+#[cfg(feature = "std")]
+fn names_in(value: &str) -> impl Iterator<Item = &str> {
+    let inner = value
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(value);
+
+    inner.split(',').map(str::trim).filter(|name| is_name(name))
+}
+
+// ===============================================
+// 🚦 Feature Gating — `#! _cfg_: feature`
+// ===============================================
+// Like Rust's `#[cfg(feature = "...")]`: a `_cfg_` metadata line gates
+// the node after it, which is parsed either way but only kept when its
+// feature is enabled.
+//
+//   #! _cfg_: phase2
+//   {
+//       speak "only in phase 2"
+//   }
+
+impl ScrollTree {
+    /// 🚦 Drops every node gated on a feature missing from `features`.
+    ///
+    /// A gate applies to the next non-comment node in its body; gates
+    /// inside a kept body are honored too. An excluded node leaves with
+    /// its gate line, an included one keeps it. Spans stay in step.
+    pub fn configure(mut self, features: &[&str]) -> Self {
+        Self::configure_nodes(&mut self.nodes, &mut self.spans, features);
+        self
+    }
+
+    /// 🌱 Recursive gate walker behind `configure`.
+    fn configure_nodes(
+        nodes: &mut Vec<ScrollNode>,
+        spans: &mut Vec<NodeSpan>,
+        features: &[&str],
+    ) {
+        let mut keep = vec![true; nodes.len()];
+        let mut gate: Option<(usize, bool)> = None; // 🚦 Pending gate line and whether it passes
+
+        for (index, node) in nodes.iter().enumerate() {
+            match node {
+                ScrollNode::Comment(_) => continue,
+                ScrollNode::Metadata(line) => {
+                    if let Some(feature) = ScrollMeta::feature_gate(line) {
+                        gate = Some((index, features.contains(&feature.as_str())));
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+
+            if let Some((at, false)) = gate.take() {
+                keep[at] = false;
+                keep[index] = false;
+            }
+        }
+
+        // 📍 Hand-built trees carry no spans — only trim ones that line up
+        if spans.len() == nodes.len() {
+            let mut kept = keep.iter();
+            spans.retain(|_| *kept.next().unwrap_or(&true));
+        }
+        let mut kept = keep.iter();
+        nodes.retain(|_| *kept.next().unwrap_or(&true));
+
+        for (index, node) in nodes.iter_mut().enumerate() {
+            if let ScrollNode::Block(body)
+            | ScrollNode::Conditional { body, .. }
+            | ScrollNode::Loop { body, .. }
+            | ScrollNode::Function { body, .. } = node
+            {
+                let mut none = Vec::new();
+                let children = spans.get_mut(index).map_or(&mut none, |span| &mut span.children);
+                Self::configure_nodes(body, children, features);
+            }
+        }
+    }
+}
+
+// ===============================================
+// 🧬 Bytecode Assembly — ScrollTree → Machine Bytes
+// ===============================================
+// Lays instructions out back to back with `Instruction::encode`, letting
+// `AsmDirective`s pad, place, or insert raw bytes between them. Only
+// top-level nodes and plain `Block` bodies are laid out; other nodes carry
+// no bytes of their own.
+//
+// Layout runs twice: the first pass records every `.label` offset with
+// placeholder jump targets (operand widths do not depend on the value),
+// the second fills in each target per the config's `JumpMode`.
+
+/// 📏 Largest image `.org` or `.align` may pad out to (16 MiB)
+pub const MAX_IMAGE_SIZE: usize = 16 * 1024 * 1024;
+
+impl ScrollTree {
+    /// 🧬 Assembles the scroll into bytecode for `config`'s target.
+    ///
+    /// Instruction operands must be numeric (decimal or `0x` hex), or —
+    /// in a `Label` slot — the name of a `.label` placed anywhere in the
+    /// scroll. Other symbolic operands are left to the Bearer and fail here.
+    pub fn assemble(
+        &self,
+        registry: &InstructionRegistry,
+        config: &EncodeConfig,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let mut labels = BTreeMap::new();
+        let mut out = Vec::new();
+        Self::assemble_nodes(&self.nodes, registry, config, &mut labels, false, &mut out)?;
+
+        out.clear();
+        Self::assemble_nodes(&self.nodes, registry, config, &mut labels, true, &mut out)?;
+        Ok(out)
+    }
+
+    /// 🌱 Recursive layout walker behind `assemble`.
+    ///
+    /// Without `resolve`, `.label`s are recorded into `labels` and label
+    /// operands encode as `0`; with it, they encode as their target.
+    fn assemble_nodes(
+        nodes: &[ScrollNode],
+        registry: &InstructionRegistry,
+        config: &EncodeConfig,
+        labels: &mut BTreeMap<String, usize>,
+        resolve: bool,
+        out: &mut Vec<u8>,
+    ) -> Result<(), EncodeError> {
+        for node in nodes {
+            match node {
+                ScrollNode::Instruction { name, args } => {
+                    let instruction = registry
+                        .get(name)
+                        .ok_or_else(|| EncodeError::UnknownInstruction(name.clone()))?;
+                    let schema = instruction.schema();
+                    let kinds = schema.as_ref().and_then(|schema| schema.form_for(args.len()));
+                    let operands = args
+                        .iter()
+                        .enumerate()
+                        .map(|(position, arg)| {
+                            let (sign, digits) = match arg.strip_prefix('-') {
+                                Some(digits) => (-1, digits),
+                                None => (1, arg.as_str()),
+                            };
+                            if let Some(value) = AsmDirective::parse_value(digits)
+                                .and_then(|value| i64::try_from(value).ok())
+                            {
+                                return Ok(sign * value);
+                            }
+
+                            // 🏷 A name in a `Label` slot is a jump target
+                            let is_label = kinds
+                                .and_then(|kinds| slot_for(kinds, position))
+                                .is_some_and(|kind| *kind.inner() == OperandKind::Label);
+                            if !is_label || !is_name(arg) {
+                                return Err(EncodeError::NonNumericOperand(arg.clone()));
+                            }
+                            if !resolve {
+                                return Ok(0);
+                            }
+                            let target = *labels
+                                .get(arg)
+                                .ok_or_else(|| EncodeError::UnknownLabel(arg.clone()))?;
+                            Ok(match config.jump_mode {
+                                JumpMode::Absolute => target as i64,
+                                JumpMode::Relative => target as i64 - out.len() as i64,
+                            })
+                        })
+                        .collect::<Result<Vec<i64>, _>>()?;
+                    out.extend(instruction.encode(&operands, config)?);
+                }
+                ScrollNode::Block(body) => {
+                    Self::assemble_nodes(body, registry, config, labels, resolve, out)?
+                }
+                ScrollNode::Directive(AsmDirective::Org(offset)) => {
+                    if *offset > MAX_IMAGE_SIZE as u64 {
+                        return Err(EncodeError::ImageTooLarge {
+                            size: *offset,
+                            limit: MAX_IMAGE_SIZE,
+                        });
+                    }
+                    let offset = *offset as usize;
+                    if offset < out.len() {
+                        return Err(EncodeError::OrgBehind {
+                            offset,
+                            at: out.len(),
+                        });
+                    }
+                    out.resize(offset, 0);
+                }
+                ScrollNode::Directive(AsmDirective::Byte(bytes)) => out.extend(bytes),
+                ScrollNode::Directive(AsmDirective::Align(0)) => {
+                    return Err(EncodeError::ZeroAlignment);
+                }
+                ScrollNode::Directive(AsmDirective::Align(boundary)) => {
+                    let size = (out.len() as u64)
+                        .div_ceil(*boundary)
+                        .saturating_mul(*boundary);
+                    if size > MAX_IMAGE_SIZE as u64 {
+                        return Err(EncodeError::ImageTooLarge {
+                            size,
+                            limit: MAX_IMAGE_SIZE,
+                        });
+                    }
+                    out.resize(size as usize, 0);
+                }
+                ScrollNode::Directive(AsmDirective::Label(label))
+                    if !resolve && labels.insert(label.clone(), out.len()).is_some() =>
+                {
+                    return Err(EncodeError::DuplicateLabel(label.clone()));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+// ===============================================
+// 🧱 Binary Tree Cache — ScrollTree ⇄ Bytes
+// ===============================================
+// A compact, versioned encoding so tooling can cache parsed trees
+// instead of re-parsing (or re-reading text `.stone`) on every load.
+//
+// 🧭 Layout (all integers little-endian):
+//   magic `STNB` · u16 version · u32 node count · nodes
+//   node   = u8 tag · fields in declaration order
+//   string = u32 byte length · UTF-8 bytes
+//   list   = u32 count · items
+//   pair   = string · string (named `Call` arguments, as a list)
+//
+// Spans are positional metadata and are not cached.
+
+/// 🪨 Leading bytes of every binary-cached `ScrollTree`
+pub const TREE_MAGIC: &[u8; 4] = b"STNB";
+
+/// 🔢 Encoding version written by `to_bytes` and required by `from_bytes`
+/// (2 added `Directive` nodes, tag 15; 3 added named `Call` arguments)
+pub const TREE_FORMAT_VERSION: u16 = 3;
+
+/// ❌ Why a byte buffer could not be read back as a `ScrollTree`
+#[derive(Debug, Clone, PartialEq)]
+pub enum TreeBytesError {
+    /// 🪨 The buffer does not start with `TREE_MAGIC`
+    BadMagic,
+
+    /// 🔢 The buffer was written by a different encoding version
+    VersionMismatch { found: u16, expected: u16 },
+
+    /// 📉 The buffer ended mid-value
+    Truncated,
+
+    /// 🏷️ A node tag not produced by `to_bytes`
+    UnknownTag(u8),
+
+    /// 🔤 A string field was not valid UTF-8
+    InvalidUtf8,
+
+    /// 🧹 Bytes remained after the last node
+    TrailingBytes(usize),
+
+    /// 🧷 A directive whose values `AsmDirective::new` would reject
+    InvalidDirective(String),
+
+    /// 🪜 Blocks nest deeper than the parser's `MAX_NESTING_DEPTH`
+    NestingTooDeep,
+}
+
+impl fmt::Display for TreeBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeBytesError::BadMagic => write!(f, "not a binary ScrollTree (bad magic)"),
+            TreeBytesError::VersionMismatch { found, expected } => write!(
+                f,
+                "binary ScrollTree version {} is not supported (expected {})",
+                found, expected
+            ),
+            TreeBytesError::Truncated => write!(f, "binary ScrollTree ended unexpectedly"),
+            TreeBytesError::UnknownTag(tag) => write!(f, "unknown node tag {:#04x}", tag),
+            TreeBytesError::InvalidUtf8 => write!(f, "string field is not valid UTF-8"),
+            TreeBytesError::TrailingBytes(count) => {
+                write!(f, "{} unexpected byte(s) after the last node", count)
+            }
+            TreeBytesError::InvalidDirective(reason) => write!(f, "invalid directive: {}", reason),
+            TreeBytesError::NestingTooDeep => {
+                write!(f, "blocks nest deeper than {} levels", MAX_NESTING_DEPTH)
+            }
+        }
+    }
+}
+
+impl core::error::Error for TreeBytesError {}
+
+impl ScrollTree {
+    /// 📦 Encodes the tree's nodes into the versioned binary cache format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(TREE_MAGIC);
+        out.extend_from_slice(&TREE_FORMAT_VERSION.to_le_bytes());
+        write_nodes(&mut out, &self.nodes);
+        out
+    }
+
+    /// 📂 Decodes a tree written by `to_bytes`, rejecting other versions.
+    ///
+    /// The result carries no spans.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ScrollTree, TreeBytesError> {
+        let mut reader = ByteReader {
+            bytes,
+            pos: 0,
+            depth: 0,
+        };
+
+        if reader.take(TREE_MAGIC.len())? != TREE_MAGIC {
+            return Err(TreeBytesError::BadMagic);
+        }
+
+        let version = u16::from_le_bytes([reader.byte()?, reader.byte()?]);
+        if version != TREE_FORMAT_VERSION {
+            return Err(TreeBytesError::VersionMismatch {
+                found: version,
+                expected: TREE_FORMAT_VERSION,
+            });
+        }
+
+        let nodes = reader.nodes()?;
+        match bytes.len() - reader.pos {
+            0 => Ok(ScrollTree {
+                meta: ScrollMeta::from_nodes(&nodes),
+                nodes,
+                spans: Vec::new(),
+            }),
+            rest => Err(TreeBytesError::TrailingBytes(rest)),
+        }
+    }
+}
+
+// === Encoding ===
+
+fn write_len(out: &mut Vec<u8>, len: usize) {
+    out.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn write_str(out: &mut Vec<u8>, s: &str) {
+    write_len(out, s.len());
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn write_strs(out: &mut Vec<u8>, items: &[String]) {
+    write_len(out, items.len());
+    for item in items {
+        write_str(out, item);
+    }
+}
+
+fn write_nodes(out: &mut Vec<u8>, nodes: &[ScrollNode]) {
+    write_len(out, nodes.len());
+    for node in nodes {
+        write_node(out, node);
+    }
+}
+
+/// 🏷️ One tag per `ScrollNode` variant, in declaration order.
+fn write_node(out: &mut Vec<u8>, node: &ScrollNode) {
+    match node {
+        ScrollNode::Instruction { name, args } => {
+            out.push(0);
+            write_str(out, name);
+            write_strs(out, args);
+        }
+        ScrollNode::ScrollSentence {
+            subject,
+            verb,
+            object,
+        } => {
+            out.push(1);
+            write_str(out, subject);
+            write_str(out, verb);
+            write_str(out, object);
+        }
+        ScrollNode::Assignment { target, value } => {
+            out.push(2);
+            write_str(out, target);
+            write_str(out, value);
+        }
+        ScrollNode::Literal(text) => {
+            out.push(3);
+            write_str(out, text);
+        }
+        ScrollNode::Metadata(text) => {
+            out.push(4);
+            write_str(out, text);
+        }
+        ScrollNode::Block(body) => {
+            out.push(5);
+            write_nodes(out, body);
+        }
+        ScrollNode::Error(text) => {
+            out.push(6);
+            write_str(out, text);
+        }
+        ScrollNode::Declaration { name, dtype } => {
+            out.push(7);
+            write_str(out, name);
+            match dtype {
+                Some(dtype) => {
+                    out.push(1);
+                    write_str(out, dtype);
+                }
+                None => out.push(0),
+            }
+        }
+        ScrollNode::Conditional { condition, body } => {
+            out.push(8);
+            write_str(out, condition);
+            write_nodes(out, body);
+        }
+        ScrollNode::Loop { condition, body } => {
+            out.push(9);
+            write_str(out, condition);
+            write_nodes(out, body);
+        }
+        ScrollNode::Import(path) => {
+            out.push(10);
+            write_str(out, path);
+        }
+        ScrollNode::Return(value) => {
+            out.push(11);
+            write_str(out, value);
+        }
+        ScrollNode::Call {
+            function,
+            args,
+            named,
+        } => {
+            out.push(12);
+            write_str(out, function);
+            write_strs(out, args);
+            write_len(out, named.len());
+            for (name, value) in named {
+                write_str(out, name);
+                write_str(out, value);
+            }
+        }
+        ScrollNode::Comment(text) => {
+            out.push(13);
+            write_str(out, text);
+        }
+        ScrollNode::Function { name, params, body } => {
+            out.push(14);
+            write_str(out, name);
+            write_strs(out, params);
+            write_nodes(out, body);
+        }
+        ScrollNode::Directive(directive) => {
+            out.push(15);
+            match directive {
+                AsmDirective::Org(offset) => {
+                    out.push(0);
+                    out.extend_from_slice(&offset.to_le_bytes());
+                }
+                AsmDirective::Byte(bytes) => {
+                    out.push(1);
+                    write_len(out, bytes.len());
+                    out.extend_from_slice(bytes);
+                }
+                AsmDirective::Align(boundary) => {
+                    out.push(2);
+                    out.extend_from_slice(&boundary.to_le_bytes());
+                }
+                AsmDirective::Label(name) => {
+                    out.push(3);
+                    write_str(out, name);
+                }
+            }
+        }
+    }
+}
+
+// === Decoding ===
+
+/// 📖 Cursor over a binary tree buffer
+struct ByteReader<'a> {
+    bytes: &'a [u8], // 📦 Whole buffer
+    pos: usize,      // 📍 Next unread byte
+    depth: usize,    // 🪜 Node lists open around the cursor
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], TreeBytesError> {
+        let end = self
+            .pos
+            .checked_add(count)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(TreeBytesError::Truncated)?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn byte(&mut self) -> Result<u8, TreeBytesError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn len(&mut self) -> Result<usize, TreeBytesError> {
+        let raw = self.take(4)?;
+        Ok(u32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]) as usize)
+    }
+
+    fn u64(&mut self) -> Result<u64, TreeBytesError> {
+        let raw = self.take(8)?;
+        let mut word = [0u8; 8];
+        word.copy_from_slice(raw);
+        Ok(u64::from_le_bytes(word))
+    }
+
+    fn string(&mut self) -> Result<String, TreeBytesError> {
+        let len = self.len()?;
+        let raw = self.take(len)?;
+        core::str::from_utf8(raw)
+            .map(ToString::to_string)
+            .map_err(|_| TreeBytesError::InvalidUtf8)
+    }
+
+    fn strings(&mut self) -> Result<Vec<String>, TreeBytesError> {
+        let count = self.len()?;
+        (0..count).map(|_| self.string()).collect()
+    }
+
+    fn pairs(&mut self) -> Result<Vec<(String, String)>, TreeBytesError> {
+        let count = self.len()?;
+        (0..count)
+            .map(|_| Ok((self.string()?, self.string()?)))
+            .collect()
+    }
+
+    /// Bodies may nest as deep as the parser allows and no deeper, so a
+    /// hostile buffer cannot recurse the decoder off the stack.
+    fn nodes(&mut self) -> Result<Vec<ScrollNode>, TreeBytesError> {
+        if self.depth > MAX_NESTING_DEPTH {
+            return Err(TreeBytesError::NestingTooDeep);
+        }
+        self.depth += 1;
+        let count = self.len()?;
+        let nodes = (0..count).map(|_| self.node()).collect();
+        self.depth -= 1;
+        nodes
+    }
+
+    fn node(&mut self) -> Result<ScrollNode, TreeBytesError> {
+        Ok(match self.byte()? {
+            0 => ScrollNode::Instruction {
+                name: self.string()?,
+                args: self.strings()?,
+            },
+            1 => ScrollNode::ScrollSentence {
+                subject: self.string()?,
+                verb: self.string()?,
+                object: self.string()?,
+            },
+            2 => ScrollNode::Assignment {
+                target: self.string()?,
+                value: self.string()?,
+            },
+            3 => ScrollNode::Literal(self.string()?),
+            4 => ScrollNode::Metadata(self.string()?),
+            5 => ScrollNode::Block(self.nodes()?),
+            6 => ScrollNode::Error(self.string()?),
+            7 => ScrollNode::Declaration {
+                name: self.string()?,
+                dtype: match self.byte()? {
+                    0 => None,
+                    1 => Some(self.string()?),
+                    tag => return Err(TreeBytesError::UnknownTag(tag)),
+                },
+            },
+            8 => ScrollNode::Conditional {
+                condition: self.string()?,
+                body: self.nodes()?,
+            },
+            9 => ScrollNode::Loop {
+                condition: self.string()?,
+                body: self.nodes()?,
+            },
+            10 => ScrollNode::Import(self.string()?),
+            11 => ScrollNode::Return(self.string()?),
+            12 => ScrollNode::Call {
+                function: self.string()?,
+                args: self.strings()?,
+                named: self.pairs()?,
+            },
+            13 => ScrollNode::Comment(self.string()?),
+            14 => ScrollNode::Function {
+                name: self.string()?,
+                params: self.strings()?,
+                body: self.nodes()?,
+            },
+            15 => ScrollNode::Directive(match self.byte()? {
+                0 => AsmDirective::Org(self.u64()?),
+                1 => {
+                    let count = self.len()?;
+                    AsmDirective::Byte(self.take(count)?.to_vec())
+                }
+                2 => AsmDirective::new(".align", &[self.u64()?])
+                    .map_err(TreeBytesError::InvalidDirective)?,
+                3 => AsmDirective::Label(self.string()?),
+                tag => return Err(TreeBytesError::UnknownTag(tag)),
+            }),
+            tag => return Err(TreeBytesError::UnknownTag(tag)),
+        })
+    }
+}
+
+// ===============================================
+// 🔀 Tree Diff — Regression Comparison
+// ===============================================
+// Compares two parses of the same corpus so parser refactors can show
+// exactly which nodes moved. Nodes are paired by position; a node whose
+// header matches but whose body differs is descended into, so a change
+// deep in a block reports that one child rather than the whole block.
+//
+// Spans and header fields are ignored, as in `ScrollTree` equality.
+
+/// 🔀 One difference between two trees, located by a path of node indices
+/// (`[2, 0]` is the first child of the third top-level node).
+#[derive(Debug, Clone, PartialEq)]
+pub enum TreeDiff {
+    /// ➕ Present only in the second tree
+    Added { path: Vec<usize>, node: ScrollNode },
+
+    /// ➖ Present only in the first tree
+    Removed { path: Vec<usize>, node: ScrollNode },
+
+    /// ✏️ Present in both, but not equal
+    Changed {
+        path: Vec<usize>,
+        before: ScrollNode,
+        after: ScrollNode,
+    },
+}
+
+impl TreeDiff {
+    /// 📍 Index path of the node this difference is about.
+    pub fn path(&self) -> &[usize] {
+        match self {
+            TreeDiff::Added { path, .. }
+            | TreeDiff::Removed { path, .. }
+            | TreeDiff::Changed { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for TreeDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self
+            .path()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(".");
+        match self {
+            TreeDiff::Added { node, .. } => write!(f, "+ [{}] {:?}", path, node),
+            TreeDiff::Removed { node, .. } => write!(f, "- [{}] {:?}", path, node),
+            TreeDiff::Changed { before, after, .. } => {
+                write!(f, "~ [{}] {:?} → {:?}", path, before, after)
+            }
+        }
+    }
+}
+
+/// 🔀 Lists every node added, removed, or changed going from `a` to `b`.
+///
+/// An empty result means the trees are equal.
+pub fn tree_diff(a: &ScrollTree, b: &ScrollTree) -> Vec<TreeDiff> {
+    let mut diffs = Vec::new();
+    diff_nodes(&a.nodes, &b.nodes, &mut Vec::new(), &mut diffs);
+    diffs
+}
+
+/// 🌱 Recursive pairing walker behind `tree_diff`.
+fn diff_nodes(
+    before: &[ScrollNode],
+    after: &[ScrollNode],
+    path: &mut Vec<usize>,
+    diffs: &mut Vec<TreeDiff>,
+) {
+    for index in 0..before.len().max(after.len()) {
+        path.push(index);
+        match (before.get(index), after.get(index)) {
+            (Some(old), Some(new)) if old != new => match (split_body(old), split_body(new)) {
+                // 🧱 Same header — only the body changed, so look inside
+                (Some((old_head, old_body)), Some((new_head, new_body)))
+                    if old_head == new_head =>
+                {
+                    diff_nodes(old_body, new_body, path, diffs)
+                }
+                _ => diffs.push(TreeDiff::Changed {
+                    path: path.clone(),
+                    before: old.clone(),
+                    after: new.clone(),
+                }),
+            },
+            (Some(old), None) => diffs.push(TreeDiff::Removed {
+                path: path.clone(),
+                node: old.clone(),
+            }),
+            (None, Some(new)) => diffs.push(TreeDiff::Added {
+                path: path.clone(),
+                node: new.clone(),
+            }),
+            _ => {}
+        }
+        path.pop();
+    }
+}
+
+/// 🪓 Splits a body-bearing node into its header (body emptied) and body.
+fn split_body(node: &ScrollNode) -> Option<(ScrollNode, &[ScrollNode])> {
+    Some(match node {
+        ScrollNode::Block(body) => (ScrollNode::Block(Vec::new()), body),
+        ScrollNode::Conditional { condition, body } => {
+            (ScrollNode::conditional(condition, Vec::new()), body)
+        }
+        ScrollNode::Loop { condition, body } => (ScrollNode::while_loop(condition, Vec::new()), body),
+        ScrollNode::Function { name, params, body } => (
+            ScrollNode::Function {
+                name: name.clone(),
+                params: params.clone(),
+                body: Vec::new(),
+            },
+            body,
+        ),
+        _ => return None,
+    })
 }
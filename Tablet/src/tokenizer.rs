@@ -42,7 +42,6 @@
 //
 // Future expansion includes:
 // - `.logos` registry syncing
-// - Operand hint tagging for literal disambiguation
 // - Integration with the Watchtower token stream for drift detection
 //
 // ===============================================
@@ -51,15 +50,21 @@
 // These imports are grouped by origin and function:
 // • Standard: character-level input processing and keyword maps
 // • External: (None currently — reserved for macro or symbol maps)
-// • Internal: Operand hint typing for forward compatibility
+// • Internal: Instruction registry for keyword classification
 // • Debugging: (None yet — may expand for Watchtower token logs)
 
-// === Standard Library ===
+// === Standard Library (alloc-only core) ===
+use alloc::string::{String, ToString}; // 🔤 Token values
+use alloc::vec; // 📚 Token stream literals
+use alloc::vec::Vec; // 📚 Token streams
+use core::fmt; // 🧾 Display formatting for `TokenizeError`
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap; // 🔑 `no_std` keyword map
+#[cfg(feature = "std")]
 use std::collections::HashMap; // 🔑 Fast lookup for instruction keyword classification
 
 // === Internal Modules ===
-#[allow(unused_imports)]
-use crate::operand_resolver::OperandHint; // 🧠 Future hook: tag tokens with operand meaning (e.g., Label, Register)
+use crate::instruction_registry::InstructionRegistry; // 📚 Source of instruction keywords
 
 // ===============================================
 // 📦 Foundational Declarations — Core Structures
@@ -77,7 +82,7 @@ use crate::operand_resolver::OperandHint; // 🧠 Future hook: tag tokens with o
 /// 2. 🔑 Symbol Semantics — Names, values, and opcodes
 /// 3. 🗒 Line Modifiers — Metadata and developer comments
 /// 4. ⚠ Fallback Catch — Invalid or malformed sequences
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum TokenType {
     // === 📚 Structural Markers ===
     Whitespace,    // Not emitted; tracked in `LineMeta` for indentation/audit
@@ -106,12 +111,42 @@ pub enum TokenType {
 /// 🧱 Token — A Symbol in the Scroll
 /// --------------------------------
 /// Holds the type, value, and location of each token parsed.
-#[derive(Debug, Clone)]
+/// Columns count Unicode scalar values (`char`s), never bytes, so an
+/// emoji earlier on the line shifts later columns by one, not four.
+///
+/// Equality and hashing cover every field, position included, so two
+/// `let` tokens on different lines stay distinct in a set. Use
+/// `value_eq` to compare what a token says, not where it says it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Token {
-    pub token_type: TokenType, // Category of token behavior
-    pub value: String,         // Source string matched
-    pub line: usize,           // Line number in source (1-based)
-    pub column: usize,         // Column offset (0-based)
+    pub token_type: TokenType,     // Category of token behavior
+    pub value: String,             // Source string matched
+    pub line: usize,               // Line number in source (1-based)
+    pub column: usize,             // Column offset (0-based)
+    pub hint: Option<OperandHint>, // Operand role, when the tokenizer can disambiguate
+    pub quoted: bool,              // Literal written in quotes (`"5"` is text, `5` is not)
+}
+
+/// 🧠 OperandHint — Tokenizer's Read on an Ambiguous Operand
+/// ---------------------------------------------------------
+/// Set only where the surface form alone leaves the operand's role open,
+/// e.g. a bare number written as `10:` names a label, not a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperandHint {
+    Label,    // 🏷 Jump target or marker name
+    Register, // 🗃 Register slot
+}
+
+/// 📏 TokenSpan — Where a Token Sits in Source
+/// -------------------------------------------
+/// Char offsets `start..end` cover the token's full source text
+/// (quotes and escapes included), starting at `line`/`column`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenSpan {
+    pub start: usize,  // First char offset (inclusive)
+    pub end: usize,    // Last char offset (exclusive)
+    pub line: usize,   // Line of the first char (1-based)
+    pub column: usize, // Column of the first char (0-based)
 }
 
 /// 🧾 LineMeta — Per-Line Formatting & Indentation
@@ -133,6 +168,49 @@ pub struct TokenStream {
     pub tokens: Vec<Token>,       // All valid tokens in scroll order
     pub line_meta: Vec<LineMeta>, // Per-line formatting context
     pub errors: Vec<Token>,       // Any malformed or rejected tokens
+    pub normalization: SourceNormalization, // What `Tokenizer::new` cleaned from the input
+}
+
+/// 🧼 SourceNormalization — Windows Artifacts Removed on Entry
+/// ----------------------------------------------------------
+/// `Tokenizer::new` strips a leading UTF-8 BOM and rewrites `\r\n` as `\n`,
+/// so neither leaks into the first token or a column count. Line numbers
+/// are unchanged; spans index the cleaned text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SourceNormalization {
+    pub bom_stripped: bool, // A leading U+FEFF was dropped
+    pub crlf_lines: usize,  // Line endings rewritten from `\r\n` to `\n`
+}
+
+impl SourceNormalization {
+    /// 🧼 Cleans `source` into walkable chars, reporting what was removed.
+    pub fn apply(source: &str) -> (Vec<char>, Self) {
+        let mut report = SourceNormalization::default();
+        let text = match source.strip_prefix('\u{feff}') {
+            Some(rest) => {
+                report.bom_stripped = true;
+                rest
+            }
+            None => source,
+        };
+
+        let mut chars = Vec::with_capacity(text.len());
+        let mut walk = text.chars().peekable();
+        while let Some(c) = walk.next() {
+            if c == '\r' && walk.peek() == Some(&'\n') {
+                report.crlf_lines += 1;
+                continue; // ↩️ The `\n` that follows ends the line
+            }
+            chars.push(c);
+        }
+
+        (chars, report)
+    }
+
+    /// ❔ True when the input needed no cleaning.
+    pub fn is_clean(&self) -> bool {
+        !self.bom_stripped && self.crlf_lines == 0
+    }
 }
 
 // ===============================================
@@ -156,14 +234,22 @@ pub struct Tokenizer {
     pub instruction_registry: HashMap<String, TokenType>, // Classifies opcodes, schema-backed
 
     // === 🎯 Cursor State Tracking ===
-    source: Vec<char>,     // Char-level walkable source
+    source: Vec<char>,     // Char-level walkable source (BOM-free, LF endings)
+    normalization: SourceNormalization, // What was cleaned from the raw input
     position: usize,       // Current absolute cursor in `source`
     line: usize,           // Current line (1-based for reporting)
-    column: usize,         // Current column (0-based offset)
+    column: usize,         // Current column (0-based, in chars)
+    #[allow(dead_code)] // ↔️ Reserved for indentation-aware tokenizing
     current_indent: usize, // Whitespace depth before active token
 
     // === 🧱 Structural Block Parsing ===
     group_stack: Vec<TokenType>, // Tracks open `{` / `(` until matched
+
+    // === 🔠 Keyword Matching ===
+    case_sensitive_keywords: bool, // When false, `LET` classifies (and normalizes) as `let`
+
+    // === 📐 Block Grammar ===
+    indentation_blocks: bool, // When true, indent/dedent open and close blocks instead of `{}`
 }
 
 // ===============================================
@@ -189,24 +275,77 @@ impl Tokenizer {
     /// - `instruction_map`: the instruction schema registry for keyword detection
     ///
     /// Initializes:
-    /// - character vector (`source`) for precise char-by-char traversal
+    /// - character vector (`source`) for precise char-by-char traversal,
+    ///   with a leading BOM stripped and CRLF endings folded to LF
     /// - positional state (line, column, indent)
     /// - empty grouping stack for structural balance tracking
     ///
     /// This constructor does not emit tokens. It prepares the engine
     /// to begin its pass via `.tokenize()`, preserving scroll integrity.
     pub fn new(source_code: &str, instruction_map: HashMap<String, TokenType>) -> Self {
+        let (source, normalization) = SourceNormalization::apply(source_code);
         Self {
             instruction_registry: instruction_map,             // 📚 Known keywords & instructions
-            source: source_code.chars().collect(),             // 🔡 Raw scroll input → Vec<char>
+            source,                                            // 🔡 Cleaned scroll input → Vec<char>
+            normalization,                                     // 🧼 BOM / CRLF cleanup report
             position: 0,                                       // 🧭 Cursor in source stream
             line: 1,                                           // 🔢 Starting at first line
             column: 0,                                         // 📍 Column tracker for position
             current_indent: 0,                                 // ↔️ Indentation tracking
             group_stack: vec![],                               // 📦 Stack for (, {, etc.
+            case_sensitive_keywords: true,                     // 🔠 Exact keyword matching
+            indentation_blocks: false,                         // 📐 Braces delimit blocks
         }
     }
 
+    // ===============================================
+    // 🧼 Report — Source Normalization
+    // ===============================================
+    /// What `new` cleaned from the raw input: a leading BOM and any
+    /// CRLF line endings. Also carried on every `TokenStream`.
+    pub fn normalization(&self) -> SourceNormalization {
+        self.normalization
+    }
+
+    // ===============================================
+    // 🔠 Option — Keyword Case Sensitivity
+    // ===============================================
+    /// Sets whether instruction keywords must match the registry's casing.
+    /// When `false`, any casing of a keyword is classified as an `Instruction`
+    /// and its token value is normalized to the registered spelling.
+    pub fn with_case_sensitive_keywords(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive_keywords = case_sensitive;
+        self
+    }
+
+    // ===============================================
+    // 📐 Option — Indentation-Based Blocks
+    // ===============================================
+    /// Sets whether significant indentation delimits blocks. When `true`,
+    /// `tokenize()` uses `LineMeta` to emit a `{` marker wherever a line is
+    /// indented deeper than the line before it, and a `}` marker for each
+    /// level a later line dedents past. Scrolls in this mode should not also
+    /// use braces. Applies to the eager `tokenize()` pass only.
+    pub fn with_indentation_blocks(mut self, enabled: bool) -> Self {
+        self.indentation_blocks = enabled;
+        self
+    }
+
+    // ===============================================
+    // 📚 Constructor — Tokenizer::from_registry
+    // ===============================================
+    /// Builds a Tokenizer whose instruction keywords come straight from
+    /// `registry`, so newly registered instructions are recognised without
+    /// a separately maintained keyword map.
+    pub fn from_registry(source_code: &str, registry: &InstructionRegistry) -> Self {
+        let instruction_map = registry
+            .iter()
+            .map(|(keyword, _)| (keyword.to_string(), TokenType::Instruction))
+            .collect();
+
+        Self::new(source_code, instruction_map)
+    }
+
     // ===============================================
     // 🚧 Entry Point — Tokenizer::tokenize
     // ===============================================
@@ -217,87 +356,172 @@ impl Tokenizer {
     pub fn tokenize(&mut self) -> TokenStream {
         let mut tokens = vec![];      // All successfully parsed tokens
         let mut line_meta = vec![];   // Indentation and blank-line data
-        let mut errors = vec![];      // Malformed or unknown token captures
+        let errors = vec![];          // Malformed or unknown token captures
+
+        // 🔁 Main tokenizing loop — one token per step
+        while let Some(token) = self.next_token() {
+            tokens.push(token);
+        }
 
-        // 🔁 Main tokenizing loop — character-by-character
+        // ===============================================
+        // 🧾 Line Formatting Metadata — Indentation Map
+        // ===============================================
+        // After token collection, analyze source lines for formatting metadata:
+        // • Tracks indentation depth (leading whitespace count)
+        // • Flags blank lines for structure alignment and spiritual whitespace
+        for (index, line) in self.source.iter().collect::<String>().lines().enumerate() {
+            let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+
+            line_meta.push(LineMeta {
+                line_number: index + 1,
+                indentation: indent,
+                is_blank: line.trim().is_empty(),
+            });
+        }
+
+        // 📐 Indentation mode: turn indent/dedent into block markers
+        if self.indentation_blocks {
+            tokens = Self::indentation_to_braces(tokens, &line_meta);
+        }
+
+        // Emit the full TokenStream scroll:
+        TokenStream {
+            tokens,
+            line_meta,
+            errors,
+            normalization: self.normalization,
+        }
+    }
+
+    // ===============================================
+    // 🪶 Single Step — Tokenizer::next_token
+    // ===============================================
+    /// Emits the next token from the current cursor:
+    /// • Skips whitespace and newlines (tracking line/column)
+    /// • Dispatches on the leading character
+    /// • Returns `None` once the source is exhausted
+    ///
+    /// Shared by the eager `tokenize()` pass and the lazy `TokenIter`.
+    fn next_token(&mut self) -> Option<Token> {
+        self.next_spanned().map(|(token, _)| token)
+    }
+
+    /// Emits the next token together with the source span it was read from.
+    fn next_spanned(&mut self) -> Option<(Token, TokenSpan)> {
         while let Some(ch) = self.peek() {
-            match ch {
+            let (start, line, column) = (self.position, self.line, self.column);
+
+            let token = match ch {
                 // --- Whitespace (not tokenized, but tracked) ---
-                ' ' | '\t' => self.consume_whitespace(),
+                ' ' | '\t' => {
+                    self.consume_whitespace();
+                    continue;
+                }
 
                 // --- Newline (line break tracking only) ---
                 '\n' => {
                     self.advance();    // Skip newline
                     self.line += 1;    // Next line
                     self.column = 0;   // Reset column
+                    continue;
                 }
 
                 // --- Comments or Metadata (prefixed with `#`) ---
-                '#' => tokens.push(self.tokenize_comment_or_meta()),
+                '#' => self.tokenize_comment_or_meta(),
 
                 // --- Literal: String (`"..."`) ---
-                '"' => tokens.push(self.tokenize_string()),
+                '"' => self.tokenize_string(),
 
                 // --- Literal: Char (`'c'`) ---
-                '\'' => tokens.push(self.tokenize_char()),
+                '\'' => self.tokenize_char(),
+
+                // --- Quoted Identifier (`` `my var` ``) ---
+                '`' => self.tokenize_quoted_identifier(),
+
+                // --- Assembler Directive (`.org`, `.byte`, `.align`) opening a line ---
+                '.' if self.at_line_start() && self.peek_at(1).is_some_and(char::is_alphabetic) => {
+                    self.tokenize_marked_word()
+                }
+
+                // --- Operand Annotation (`@trusted`) ---
+                '@' if self.peek_at(1).is_some_and(char::is_alphabetic) => self.tokenize_marked_word(),
 
                 // --- Operator Tokens ---
-                ':' | '=' | '+' | '-' | '*' | '/' | '%' | '&' | '|' | '<' | '>' => {
-                    tokens.push(self.tokenize_operator());
+                ':' | '=' | '!' | '+' | '-' | '*' | '/' | '%' | '&' | '|' | '<' | '>' => {
+                    self.tokenize_operator()
                 }
 
-                // --- Grouping Symbols ( ) ---
-                '(' => {
+                // --- Grouping Symbols ( ) { } ---
+                '(' | '{' => {
                     self.group_stack.push(TokenType::GroupMarker);
-                    tokens.push(self.make_token(TokenType::GroupMarker, "("));
+                    let token = self.make_token(TokenType::GroupMarker, &ch.to_string());
                     self.advance();
+                    token
                 }
-                ')' => {
+                ')' | '}' => {
                     self.group_stack.pop();
-                    tokens.push(self.make_token(TokenType::GroupMarker, ")"));
+                    let token = self.make_token(TokenType::GroupMarker, &ch.to_string());
                     self.advance();
+                    token
+                }
+
+                // --- Separators `,` `;` ---
+                ',' | ';' => {
+                    let token = self.make_token(TokenType::Punctuation, &ch.to_string());
+                    self.advance();
+                    token
                 }
 
                 // --- Alphabetic Word (could be identifier or instruction) ---
-                c if c.is_alphabetic() => tokens.push(self.tokenize_word()),
+                c if c.is_alphabetic() => self.tokenize_word(),
 
                 // --- Numeric Literal ---
-                c if c.is_numeric() => tokens.push(self.tokenize_number()),
+                c if c.is_numeric() => self.tokenize_number(),
 
                 // --- Unknown Symbol (fallback to Error token) ---
                 _ => {
-                    tokens.push(self.make_token(TokenType::Error, &ch.to_string()));
+                    let token = self.make_token(TokenType::Error, &ch.to_string());
                     self.advance();
+                    token
                 }
-            }
-        }
+            };
 
-        // ===============================================
-        // 🧾 Line Formatting Metadata — Indentation Map
-        // ===============================================
-        // After token collection, analyze source lines for formatting metadata:
-        // • Tracks indentation depth (leading whitespace count)
-        // • Flags blank lines for structure alignment and spiritual whitespace
-        let mut line_number = 1;
+            let span = TokenSpan {
+                start,
+                end: self.position,
+                line,
+                column,
+            };
 
-        for line in self.source.iter().collect::<String>().lines() {
-            let indent = line.chars().take_while(|c| c.is_whitespace()).count();
+            return Some((token, span));
+        }
 
-            line_meta.push(LineMeta {
-                line_number,
-                indentation: indent,
-                is_blank: line.trim().is_empty(),
-            });
+        None
+    }
 
-            line_number += 1;
-        }
+    // ===============================================
+    // 📏 Spanned Entry — Tokenizer::tokenize_spanned
+    // ===============================================
+    /// Tokenizes the full source, pairing each token with its `TokenSpan`.
+    /// Used by editor tooling (e.g. highlighting) that needs exact ranges.
+    pub fn tokenize_spanned(&mut self) -> Vec<(Token, TokenSpan)> {
+        let mut spanned = vec![];
 
-        // Emit the full TokenStream scroll:
-        TokenStream {
-            tokens,
-            line_meta,
-            errors,
+        while let Some(pair) = self.next_spanned() {
+            spanned.push(pair);
         }
+
+        spanned
+    }
+
+    // ===============================================
+    // 🌊 Lazy Entry — Tokenizer::stream
+    // ===============================================
+    /// Consumes the tokenizer into a `TokenIter` that lexes on demand.
+    /// Yields the same tokens as `tokenize()` without materializing the
+    /// full vector; `Error` tokens surface as `Err(TokenizeError)`.
+    pub fn stream(self) -> TokenIter {
+        TokenIter { tokenizer: self }
     }
 
     // ===============================================
@@ -325,6 +549,20 @@ impl Tokenizer {
         self.source.get(self.position).copied()
     }
 
+    /// Looks `n` characters past the cursor without consuming.
+    fn peek_at(&self, n: usize) -> Option<char> {
+        self.source.get(self.position + n).copied()
+    }
+
+    /// True when only spaces or tabs precede the cursor on its line.
+    fn at_line_start(&self) -> bool {
+        self.source[..self.position]
+            .iter()
+            .rev()
+            .take_while(|&&c| c != '\n')
+            .all(|&c| c == ' ' || c == '\t')
+    }
+
     // -----------------------------------------------
     // 🎯 make_token — Construct a Token from current position
     // -----------------------------------------------
@@ -335,6 +573,8 @@ impl Tokenizer {
             value: value.to_string(),
             line: self.line,
             column: self.column,
+            hint: None,
+            quoted: false,
         }
     }
 
@@ -432,7 +672,9 @@ impl Tokenizer {
             }
         }
 
-        self.make_token(TokenType::Literal, &content)
+        let mut token = self.make_token(TokenType::Literal, &content);
+        token.quoted = true; // 🔤 Text, however it reads — `"42"`, `"true"`
+        token
     }
 
     // -----------------------------------------------
@@ -463,7 +705,65 @@ impl Tokenizer {
         };
         self.advance(); // Consume closing `'` or next char regardless
 
-        self.make_token(TokenType::Literal, &value.to_string())
+        let mut token = self.make_token(TokenType::Literal, &value.to_string());
+        token.quoted = true;
+        token
+    }
+
+    // -----------------------------------------------
+    // 🪪 Quoted Identifier — e.g., `my var`, `let`
+    // -----------------------------------------------
+    /// Parses a backtick-quoted identifier. The content is one `Identifier`
+    /// token whatever it holds — spaces and keywords included.
+    /// `` \` `` and `\\` escape a backtick and a backslash. A quote left open
+    /// at the end of its line becomes an `Error` token.
+    fn tokenize_quoted_identifier(&mut self) -> Token {
+        let mut name = String::new();
+        self.advance(); // Consume opening `` ` ``
+
+        while let Some(c) = self.peek() {
+            match c {
+                '`' => {
+                    self.advance(); // Closing backtick
+                    return self.make_token(TokenType::Identifier, &name);
+                }
+                '\n' => break,
+                '\\' => {
+                    self.advance(); // Consume backslash
+                    if let Some(escaped) = self.peek().filter(|&e| e != '\n') {
+                        name.push(escaped);
+                        self.advance();
+                    }
+                }
+                _ => {
+                    name.push(c);
+                    self.advance();
+                }
+            }
+        }
+
+        name.insert(0, '`'); // Keep the stray quote visible in the error
+        self.make_token(TokenType::Error, &name)
+    }
+
+    // -----------------------------------------------
+    // 🧷 Marked Words — .org directives, @trusted annotations
+    // -----------------------------------------------
+    /// Parses a marked word into a `Keyword` token, mark included: a
+    /// `.name` directive opening its line, or an `@name` operand annotation.
+    /// A `.` elsewhere (e.g. ending a sentence) is not routed here.
+    fn tokenize_marked_word(&mut self) -> Token {
+        let mut word = self.peek().map(String::from).unwrap_or_default();
+        self.advance(); // Consume `.` or `@`
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() || c == '_' {
+                word.push(c);
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        self.make_token(TokenType::Keyword, &word)
     }
 
     // -----------------------------------------------
@@ -473,7 +773,7 @@ impl Tokenizer {
     fn tokenize_operator(&mut self) -> Token {
         let mut content = String::new();
         while let Some(c) = self.peek() {
-            if ":=+-*/%&|<>".contains(c) {
+            if ":=!+-*/%&|<>".contains(c) {
                 content.push(c);
                 self.advance();
             } else {
@@ -486,19 +786,51 @@ impl Tokenizer {
     // -----------------------------------------------
     // 🔢 Numeric Literal — e.g., 42
     // -----------------------------------------------
-    /// Parses decimal integer literals.
-    /// Extended formats (hex, float) will be supported in future revisions.
+    /// Parses decimal integer literals, and hex ones written `0x41`.
+    /// Extended formats (float) will be supported in future revisions.
+    /// A number written directly before `:` (e.g. `10:`) is hinted as a label.
+    /// A decimal followed by a time unit (`5s`, `200ms`, `2m`, `1h`) stays
+    /// one literal, so `wait` receives its duration whole.
     fn tokenize_number(&mut self) -> Token {
         let mut num = String::new();
+        let hex = self.peek() == Some('0')
+            && self.peek_at(1) == Some('x')
+            && self.peek_at(2).is_some_and(|c| c.is_ascii_hexdigit());
+        if hex {
+            num.push_str("0x");
+            self.advance();
+            self.advance();
+        }
         while let Some(c) = self.peek() {
-            if c.is_ascii_digit() {
+            if c.is_ascii_digit() || (hex && c.is_ascii_hexdigit()) {
                 num.push(c);
                 self.advance();
             } else {
                 break;
             }
         }
-        self.make_token(TokenType::Literal, &num)
+
+        if !hex {
+            let unit = ["ms", "s", "m", "h"].into_iter().find(|unit| {
+                let len = unit.chars().count();
+                unit.chars()
+                    .enumerate()
+                    .all(|(i, c)| self.peek_at(i) == Some(c))
+                    && !self
+                        .peek_at(len)
+                        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+            });
+            for c in unit.unwrap_or_default().chars() {
+                num.push(c);
+                self.advance();
+            }
+        }
+
+        let mut token = self.make_token(TokenType::Literal, &num);
+        if self.peek() == Some(':') {
+            token.hint = Some(OperandHint::Label); // 🏷 `10:` names a label
+        }
+        token
     }
 
     // -----------------------------------------------
@@ -517,6 +849,14 @@ impl Tokenizer {
             }
         }
 
+        // 🔠 Case-insensitive matching normalizes to the registered keyword
+        if !self.case_sensitive_keywords {
+            let lowered = word.to_lowercase();
+            if self.instruction_registry.contains_key(&lowered) {
+                word = lowered;
+            }
+        }
+
         let token_type = if self.instruction_registry.contains_key(&word) {
             TokenType::Instruction
         } else {
@@ -549,6 +889,7 @@ impl Tokenizer {
     /// - enforce operand types
     /// - permit optional / variadic args
     /// - match formatting rules
+    #[allow(dead_code)] // 📏 Superseded by the registry's `operand_count`
     fn validate_instruction_syntax(keyword: &str, operands: &[&str]) -> bool {
         // Early schema stub: expected arity map (to be moved into instruction_registry in future)
         let expected_operands: HashMap<&str, usize> = HashMap::from([
@@ -574,35 +915,164 @@ impl Tokenizer {
     /// - Verifies grouping marker balance
     /// - Flags unclosed or orphaned brackets
     /// - Preps structure for AST nesting (future)
-    fn post_process_tokens(mut tokens: Vec<Token>) -> Vec<Token> {
+    #[allow(dead_code)] // 🌀 Not yet wired into `tokenize`
+    fn post_process_tokens(tokens: Vec<Token>) -> Vec<Token> {
         let mut group_stack: Vec<(TokenType, Token)> = Vec::new();
 
-        for token in &tokens {
-            match token.token_type {
-                TokenType::GroupMarker => {
-                    match token.value.as_str() {
-                        "(" | "{" => group_stack.push((TokenType::GroupMarker, token.clone())),
-                        ")" | "}" => {
-                            if group_stack.pop().is_none() {
-                                // Insert virtual open if we pop nothing
-                                // This could also push an Error token instead in future
-                            }
+        for token in tokens.iter().filter(|t| t.token_type == TokenType::GroupMarker) {
+            match token.value.as_str() {
+                "(" | "{" => group_stack.push((TokenType::GroupMarker, token.clone())),
+                ")" | "}" if group_stack.pop().is_none() => {
+                    // Insert virtual open if we pop nothing
+                    // This could also push an Error token instead in future
+                }
+                _ => {}
+            }
+        }
+
+        for (_, _token) in group_stack {
+            // In future: add diagnostic or append unclosed group marker to errors
+            // tokens.push(Token { ...error for unclosed group... })
+        }
+
+        tokens
+    }
+
+    // ------------------------------------------------
+    // 📐 Indentation Blocks — Indent/Dedent → `{` / `}`
+    // ------------------------------------------------
+    /// Inserts `GroupMarker` braces so an indentation-structured scroll
+    /// reaches the parser in the same shape as its braced form:
+    /// - A line indented past the enclosing level opens a block (`{` placed
+    ///   at the last token of the line before)
+    /// - A line dedented below a level closes it (`}` placed at column 0)
+    /// - Levels still open at end of scroll are closed after the last token
+    fn indentation_to_braces(tokens: Vec<Token>, line_meta: &[LineMeta]) -> Vec<Token> {
+        let marker = |value: &str, line: usize, column: usize| Token {
+            token_type: TokenType::GroupMarker,
+            value: value.to_string(),
+            line,
+            column,
+            hint: None,
+            quoted: false,
+        };
+
+        let mut output: Vec<Token> = Vec::with_capacity(tokens.len());
+        let mut levels = vec![0]; // 📏 Open indentation levels, outermost first
+        let mut current_line = 0;
+
+        for token in tokens {
+            if token.line != current_line {
+                current_line = token.line;
+                let indent = line_meta
+                    .get(token.line.saturating_sub(1))
+                    .map_or(0, |meta| meta.indentation);
+
+                match output.last() {
+                    // ➡️ Indent: open a block
+                    Some(previous) if indent > *levels.last().unwrap_or(&0) => {
+                        let open = marker("{", previous.line, previous.column);
+                        output.push(open);
+                        levels.push(indent);
+                    }
+                    // ⬅️ Dedent: close every level deeper than this line
+                    _ => {
+                        while levels.len() > 1 && indent < *levels.last().unwrap_or(&0) {
+                            levels.pop();
+                            output.push(marker("}", token.line, 0));
                         }
-                        _ => {}
                     }
                 }
-                _ => {}
             }
+
+            output.push(token);
         }
 
-        if !group_stack.is_empty() {
-            for (_, token) in group_stack {
-                // In future: add diagnostic or append unclosed group marker to errors
-                // tokens.push(Token { ...error for unclosed group... })
+        // 🔚 Close levels left open at end of scroll
+        if let Some((line, column)) = output.last().map(|t| (t.line, t.column)) {
+            for _ in 1..levels.len() {
+                output.push(marker("}", line, column));
             }
         }
 
-        tokens
+        output
+    }
+}
+
+// ===============================================
+// 🌊 Lazy Tokenization — TokenIter & TokenizeError
+// ===============================================
+// A pull-based alternative to `TokenStream`. Each call to `next()`
+// lexes exactly one token, so large scrolls never hold a full token
+// vector in memory. Formatting metadata (`LineMeta`) is eager-only.
+
+/// ⚠️ TokenizeError — An unrecognized symbol surfaced by `TokenIter`
+/// ----------------------------------------------------------------
+/// Mirrors the `TokenType::Error` token the eager pass would emit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenizeError {
+    pub value: String, // Offending source text
+    pub line: usize,   // Line number in source (1-based)
+    pub column: usize, // Column offset (0-based)
+}
+
+impl TokenizeError {
+    /// 🔁 Converts back into the `Error` token the eager pass would emit.
+    pub fn into_token(self) -> Token {
+        Token {
+            token_type: TokenType::Error,
+            value: self.value,
+            line: self.line,
+            column: self.column,
+            hint: None,
+            quoted: false,
+        }
+    }
+}
+
+impl fmt::Display for TokenizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unrecognized symbol '{}' at line {}, column {}",
+            self.value, self.line, self.column
+        )
+    }
+}
+
+impl core::error::Error for TokenizeError {}
+
+/// 🌊 TokenIter — Lazy Token Source
+/// --------------------------------
+/// Owns a `Tokenizer` and lexes one token per `next()` call.
+/// Created via `Tokenizer::stream()`.
+pub struct TokenIter {
+    tokenizer: Tokenizer, // Cursor state advanced on each pull
+}
+
+impl TokenIter {
+    /// 🩹 Recovers errors back into `Error` tokens.
+    /// Produces exactly the sequence `tokenize()` would, for parser consumption.
+    pub fn recovering(self) -> impl Iterator<Item = Token> {
+        self.map(|result| result.unwrap_or_else(TokenizeError::into_token))
+    }
+}
+
+impl Iterator for TokenIter {
+    type Item = Result<Token, TokenizeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.tokenizer.next_token()?;
+
+        if token.token_type == TokenType::Error {
+            Some(Err(TokenizeError {
+                value: token.value,
+                line: token.line,
+                column: token.column,
+            }))
+        } else {
+            Some(Ok(token))
+        }
     }
 }
 
@@ -630,10 +1100,120 @@ impl Token {
             value: value.to_string(),          // Raw symbolic name
             line: 0,                            // Default, parser may overwrite
             column: 0,                          // Default, parser may overwrite
+            hint: None,                         // No operand role implied
+            quoted: false,                      // Names are never quoted
+        }
+    }
+
+    /// ⚖️ value_eq — Position-Blind Comparison
+    /// ----------------------------------------
+    /// True when both tokens share a type and source text, wherever they
+    /// sit. The operand hint is ignored too, as it is a read on position.
+    /// Quoting is not: `"5"` and `5` say different things.
+    pub fn value_eq(&self, other: &Token) -> bool {
+        self.token_type == other.token_type
+            && self.value == other.value
+            && self.quoted == other.quoted
+    }
+}
+
+// ===============================================
+// 🧹 Scroll Hygiene — Whitespace Lint
+// ===============================================
+// Formatting checks that run over raw source, independent of tokens.
+// Findings are informational: they never block tokenizing or parsing.
+
+/// 🧹 WhitespaceLint — Which hygiene checks run
+/// --------------------------------------------
+/// Both checks are on by default; a run of more than
+/// `max_blank_lines` consecutive blank lines is flagged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WhitespaceLint {
+    pub trailing: bool,         // Flag spaces/tabs at the end of a line
+    pub blank_runs: bool,       // Flag runs of consecutive blank lines
+    pub max_blank_lines: usize, // Longest blank run allowed before flagging
+}
+
+impl Default for WhitespaceLint {
+    fn default() -> Self {
+        Self {
+            trailing: true,
+            blank_runs: true,
+            max_blank_lines: 1,
         }
     }
 }
 
+/// 🪶 HygieneIssue — One whitespace finding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HygieneIssue {
+    /// Line `line` ends in whitespace
+    TrailingWhitespace { line: usize },
+
+    /// `count` consecutive blank lines starting at `line`
+    BlankRun { line: usize, count: usize },
+}
+
+/// 🧹 Runs the enabled whitespace checks over `source` (lines are 1-based).
+pub fn lint_whitespace(source: &str, lint: &WhitespaceLint) -> Vec<HygieneIssue> {
+    let mut issues = vec![];
+    let mut run_start = 0;
+    let mut run_len = 0;
+
+    let flush = |issues: &mut Vec<HygieneIssue>, start: usize, len: usize| {
+        if lint.blank_runs && len > lint.max_blank_lines {
+            issues.push(HygieneIssue::BlankRun { line: start, count: len });
+        }
+    };
+
+    for (index, line) in source.lines().enumerate() {
+        let number = index + 1;
+
+        if line.trim().is_empty() {
+            if run_len == 0 {
+                run_start = number;
+            }
+            run_len += 1;
+        } else {
+            flush(&mut issues, run_start, run_len);
+            run_len = 0;
+        }
+
+        if lint.trailing && line.ends_with([' ', '\t']) {
+            issues.push(HygieneIssue::TrailingWhitespace { line: number });
+        }
+    }
+    flush(&mut issues, run_start, run_len);
+
+    issues
+}
+
+#[cfg(feature = "std")]
+impl HygieneIssue {
+    /// 🪛 Converts the finding into an informational Watchtower entry.
+    pub fn to_entry(&self) -> watchtower::debugger::DebugEntry {
+        use watchtower::debugger::{DebugEntry, Severity};
+
+        let (input, actual, suggestion) = match self {
+            HygieneIssue::TrailingWhitespace { line } => (
+                format!("line {}", line),
+                "trailing whitespace".to_string(),
+                "Trim whitespace at the end of the line",
+            ),
+            HygieneIssue::BlankRun { line, count } => (
+                format!("line {}", line),
+                format!("{} consecutive blank lines", count),
+                "Collapse the run to a single blank line",
+            ),
+        };
+
+        DebugEntry::new("lint_whitespace", &input, "clean whitespace", &actual)
+            .with_location("Tokenizer::lint_whitespace")
+            .with_suggestion(suggestion)
+            .with_severity(Severity::Info)
+    }
+}
+
 // ===================================================
 // 🔚 Closing Block — Tokenizer Output & Expansion Path
 // ===================================================
@@ -695,15 +1275,5 @@ impl Token {
 // 🔒 Closing — Final Diagnostics & Stack Cleanup
 // ===============================================
 
-// 🔹 Group Marker Check — Unmatched open parens/braces
-while let Some(unmatched) = self.group_stack.pop() {
-    errors.push(Token {
-        token_type: TokenType::Error,
-        value: format!("Unclosed group marker: {:?}", unmatched),
-        line: self.line,
-        column: self.column,
-    });
-}
-
 // 🔹 End-of-File Token Hooks (optional)
 // Could emit EOF token or special scroll-seal marker later
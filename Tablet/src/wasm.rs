@@ -0,0 +1,130 @@
+// ===============================================
+// 📜 Metadata — Wasm Entry v0.0.1 (Tablet Browser Front Door)
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-15
+// _last updated_:  2025-06-15
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Wasm Entry (source → JSON diagnostics)
+// _project_:       OmniCode / Millennium OS
+// _description_:   Lexes and parses a scroll with the alloc-only core and reports findings as JSON.
+//
+// _notes_:
+// - Builds for `wasm32-unknown-unknown` with `--no-default-features`
+// - No file IO, threads, or Watchtower — the Bearer and compiler stages stay std-only
+// - Strings in, strings out, so a JS binding layer needs no shared types
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+// === Standard Library (alloc-only core) ===
+use alloc::string::{String, ToString}; // 🔤 Messages and the JSON report
+use alloc::vec::Vec; // 📚 Token and diagnostic lists
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc; // 🤝 `no_std` registry handle for the tokenizer and parser
+
+// === External Crates ===
+use serde::Serialize; // 🧾 JSON shape of the report
+
+// === Internal Modules ===
+use crate::directives::SymbolTable; // 🚦 `#if` pass ahead of the tokenizer
+#[cfg(not(feature = "std"))]
+use crate::instruction_registry::InstructionRegistry; // 🧭 `no_std` keywords and schemas
+#[cfg(feature = "std")]
+use crate::instruction_registry::shared_registry; // 🧭 Keywords and schemas, built once per process
+use crate::macros::prepare_source; // 🧩 `#if` and `define` passes, shared with `compile_source`
+use crate::parser::Parser; // 🌳 Parsing pass
+use crate::tokenizer::Tokenizer; // 🧩 Lexing pass
+
+// ===============================================
+// 📦 Foundational Declarations — Report Shapes
+// ===============================================
+
+/// 🩺 One finding, flattened so every stage shares a shape.
+#[derive(Debug, Clone, Serialize)]
+struct Diagnostic {
+    stage: &'static str, // 🧩 "directive", "macro", "lex", or "parse"
+    message: String,     // 📜 Human-readable explanation
+    line: usize,         // 📍 Line in the scroll (1-based; 0 when unknown)
+    column: usize,       // 📏 Char offset in the line
+}
+
+/// 📋 Everything `compile_json` reports about one scroll.
+#[derive(Debug, Clone, Serialize)]
+struct Report {
+    ok: bool,                     // ✅ No diagnostics at all
+    nodes: usize,                 // 🌳 Top-level nodes parsed
+    diagnostics: Vec<Diagnostic>, // 🩺 Directive or macro, then lex, then parse findings
+}
+
+// ===============================================
+// 🔧 Body — Entry Point
+// ===============================================
+
+/// 🌐 Lexes and parses `source`, returning the findings as a JSON string.
+///
+/// Shape: `{"ok": bool, "nodes": n, "diagnostics": [{stage, message, line, column}]}`.
+/// The text passes are `compiler::compile_source`'s own: `#if` sections
+/// are filtered against `symbols`, then `define` macros expanded; a
+/// directive or macro error is reported and nothing is parsed.
+/// Unrecognized symbols are reported as lex errors and withheld from the
+/// parser, as `compile_source` does.
+pub fn compile_json(source: &str, symbols: &SymbolTable) -> String {
+    #[cfg(feature = "std")]
+    let registry = shared_registry();
+    #[cfg(not(feature = "std"))]
+    let registry = Arc::new(InstructionRegistry::new());
+
+    let mut tokens = Vec::new();
+    let mut diagnostics = Vec::new();
+    let (source, directive_error, macro_error) = prepare_source(source, symbols);
+    if let Some(error) = directive_error {
+        diagnostics.push(Diagnostic {
+            stage: "directive",
+            message: error.to_string(),
+            line: error.line(),
+            column: 0,
+        });
+    }
+    if let Some(error) = macro_error {
+        diagnostics.push(Diagnostic {
+            stage: "macro",
+            message: error.to_string(),
+            line: error.line().unwrap_or(0),
+            column: 0,
+        });
+    }
+    for result in Tokenizer::from_registry(&source, &registry).stream() {
+        match result {
+            Ok(token) => tokens.push(token),
+            Err(error) => diagnostics.push(Diagnostic {
+                stage: "lex",
+                message: error.to_string(),
+                line: error.line,
+                column: error.column,
+            }),
+        }
+    }
+
+    let mut parser = Parser::new(tokens).with_registry(registry);
+    let tree = parser.parse();
+    diagnostics.extend(parser.errors().iter().map(|error| Diagnostic {
+        stage: "parse",
+        message: error.message.clone(),
+        line: error.line,
+        column: error.column,
+    }));
+
+    let report = Report {
+        ok: diagnostics.is_empty(),
+        nodes: tree.nodes.len(),
+        diagnostics,
+    };
+
+    // 🧾 Plain structs of strings and integers always serialize
+    serde_json::to_string(&report).unwrap_or_default()
+}
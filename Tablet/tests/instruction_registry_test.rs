@@ -19,10 +19,19 @@
 // ----------------------------------------------------------
 
 use tablet::instruction_registry::{get_instruction_registry, Instruction}; // 📜 Source of truth for instructions
+use tablet::instruction_registry::{InstructionRegistry, RegistryError}; // 🗂 Runtime `.logos` registry
+use tablet::instruction_registry::{annotate_with_verse, Translation, VerseProvider}; // 📖 Verse lookup hook
+use tablet::instruction_registry::{BitMode, EncodeConfig, EncodeError, Endianness}; // 🧬 Bytecode encoding
+use tablet::instruction_registry::{OperandKind, OperandMismatch, OperandSchema}; // 🧮 Shared operand matching
+use tablet::instruction_registry::{opcode_range, OPCODE_RANGES}; // 🗺️ Reserved opcode blocks
+use tablet::instruction_registry::shared_registry; // 🤝 Built-once registry
+use tablet::operand_resolver::Operand; // 🔣 Bearer-constructed operands
 use std::collections::HashSet; // 🧮 Used to verify opcode uniqueness and detect duplicates
+use std::sync::Arc; // 🤝 Identity checks on the shared registry
+use std::thread; // 🧵 Cross-thread lookups
 
 
-#// =======================================================
+// =======================================================
 // ✅ Instruction Registry Test — Structural Integrity Pass
 // =======================================================
 //
@@ -48,6 +57,7 @@ use std::collections::HashSet; // 🧮 Used to verify opcode uniqueness and dete
 // =======================================================
 
 #[test]
+#[allow(unused_comparisons, clippy::absurd_extreme_comparisons)] // 💠 The u8 bound check documents intent
 fn test_instruction_registry_integrity() {
     let registry = get_instruction_registry();
 
@@ -108,9 +118,8 @@ fn test_instruction_registry_integrity() {
 
         // 🗺 Operand schema (if present) must match operand count
         if let (Some(schema), Some(count)) = (&instr.operand_schema, instr.operand_count) {
-            let parts = schema.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).count();
             assert_eq!(
-                parts, count,
+                schema.len(), count as usize,
                 "Operand schema mismatch for '{}'", keyword
             );
         }
@@ -158,6 +167,396 @@ fn test_instruction_registry_integrity() {
     );
 }
 
+// =======================================================
+// 📂 `.logos` Registry Loading — External Definitions
+// =======================================================
+//
+// 📜 Purpose:
+//   Confirms instructions inscribed in a `.logos` / TOML file
+//   load into the same `Instruction` shape as the compiled scroll.
+//
+// 🧭 Verifies:
+//   - Fixture `bless` matches the canonical registry entry
+//   - Duplicate opcodes are rejected as conflicts
+//
+// =======================================================
+
+#[test]
+fn test_registry_from_toml_matches_builtin() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bless.logos");
+    let loaded = InstructionRegistry::from_toml(path).expect("fixture should load");
+    let builtin = get_instruction_registry();
+
+    assert_eq!(loaded.len(), 1, "Fixture defines exactly one instruction");
+
+    let bless: &Instruction = loaded.get("bless").expect("bless should be registered");
+    assert_eq!(bless, &builtin["bless"], "Loaded `bless` should match the built-in entry");
+}
+
+#[test]
+fn test_shared_registry_is_one_instance_across_threads() {
+    // 🧪 Input: two calls here, one lookup in each of four threads
+    // 🧱 Expectation: same allocation every time; lookups agree with the built-in scroll
+    let first = shared_registry();
+    let second = shared_registry();
+    assert!(Arc::ptr_eq(&first, &second), "Registry should be built once");
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let registry = Arc::clone(&first);
+            thread::spawn(move || {
+                assert!(Arc::ptr_eq(&registry, &shared_registry()));
+                registry.get("bless").map(|i| i.opcode)
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), Some(0x60));
+    }
+}
+
+#[test]
+fn test_registry_from_toml_rejects_duplicate_opcode() {
+    let source = r#"
+[[instruction]]
+keyword = "rise"
+verse_anchor = "Isa 60:1"
+category = "Control"
+description = "Arise and shine."
+opcode = 0x90
+machine_code = "90"
+
+[[instruction]]
+keyword = "shine"
+verse_anchor = "Matt 5:16"
+category = "Control"
+description = "Let your light shine."
+opcode = 0x90
+machine_code = "90"
+"#;
+
+    match InstructionRegistry::from_toml_str(source) {
+        Err(RegistryError::Conflict(msg)) => assert!(msg.contains("0x90")),
+        other => panic!("Expected opcode conflict, got {:?}", other),
+    }
+}
+
+// =======================================================
+// 🗺️ Opcode Ranges — Reserved Bytes per Group
+// =======================================================
+//
+// 📜 Purpose:
+//   Confirms every grouped opcode sits inside the block its
+//   `instruction_group_id` reserves.
+//
+// 🧭 Verifies:
+//   - Every built-in instruction passes `check_opcode_range`
+//   - Reserved blocks do not overlap
+//   - An out-of-range opcode is rejected by `register`
+//
+// =======================================================
+
+#[test]
+fn test_builtin_opcodes_fit_group_ranges() {
+    for (keyword, instr) in get_instruction_registry() {
+        assert!(
+            instr.check_opcode_range().is_ok(),
+            "'{}' ({:#04X}) is outside its group range",
+            keyword,
+            instr.opcode()
+        );
+    }
+
+    for pair in OPCODE_RANGES.windows(2) {
+        assert!(
+            pair[0].last < pair[1].first,
+            "{} and {} ranges overlap",
+            pair[0].name,
+            pair[1].name
+        );
+    }
+}
+
+#[test]
+fn test_register_rejects_out_of_range_opcode() {
+    // 🧪 Input: `tithe` at 0x75 while claiming the Math group (0x60–0x6F)
+    // 🧱 Expectation: rejected as invalid, naming the opcode and group
+    let source = r#"
+[[instruction]]
+keyword = "tithe"
+verse_anchor = "Mal 3:10"
+category = "Math/Logic"
+description = "Set apart a tenth."
+opcode = 0x75
+machine_code = "75 TT"
+instruction_group_id = 0x60
+"#;
+
+    match InstructionRegistry::from_toml_str(source) {
+        Err(RegistryError::Invalid(msg)) => {
+            assert!(msg.contains("0x75") && msg.contains("Math"), "{}", msg)
+        }
+        other => panic!("Expected out-of-range rejection, got {:?}", other),
+    }
+
+    let math = opcode_range(0x60).expect("Math group should be reserved");
+    assert!(math.contains(0x6F) && !math.contains(0x75));
+}
+
+// =======================================================
+// 📖 Verse Annotation — Translation Lookup Hook
+// =======================================================
+//
+// 📜 Purpose:
+//   Confirms a `VerseProvider` can attach verse text to an
+//   instruction's `verse_anchor` for help output.
+//
+// 🧭 Verifies:
+//   - `let` (Gen 1:3) is annotated with the provider's text and translation
+//   - Anchors the provider does not know yield no annotation
+//
+// =======================================================
+
+struct StubProvider; // 🧪 Knows a single KJV verse
+
+impl VerseProvider for StubProvider {
+    fn translation(&self) -> Translation {
+        Translation::Kjv
+    }
+
+    fn verse(&self, reference: &str) -> Option<String> {
+        match reference {
+            "Gen 1:3" => Some("And God said, Let there be light: and there was light.".to_string()),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_annotate_with_verse() {
+    let registry = get_instruction_registry();
+
+    assert_eq!(
+        annotate_with_verse(&registry["let"], &StubProvider).as_deref(),
+        Some("Gen 1:3 (KJV) — And God said, Let there be light: and there was light.")
+    );
+    assert_eq!(annotate_with_verse(&registry["speak"], &StubProvider), None);
+}
+
+// =======================================================
+// 🧬 Bytecode Encoding — Operand Widths & Byte Order
+// =======================================================
+//
+// 📜 Purpose:
+//   Confirms `Instruction::encode` sizes operands by `BitMode`
+//   and honours the configured byte order.
+//
+// 🧭 Verifies:
+//   - `store` (70 TT VV) emits 4-byte operands in Bit32, 8-byte in Bit64
+//   - Big-endian flips operand byte order, not the opcode
+//   - Oversized values and wrong operand counts are rejected
+//
+// =======================================================
+
+#[test]
+fn test_encode_widths_follow_bit_mode() {
+    let registry = get_instruction_registry();
+    let store = &registry["store"];
+
+    let bit32 = store.encode(&[1, 0x0102], &EncodeConfig::new(BitMode::Bit32)).unwrap();
+    let bit64 = store.encode(&[1, 0x0102], &EncodeConfig::new(BitMode::Bit64)).unwrap();
+
+    assert_eq!(bit32, vec![0x70, 1, 0, 0, 0, 0x02, 0x01, 0, 0]);
+    assert_eq!(bit64.len(), 1 + 8 + 8);
+    assert_eq!(&bit64[9..11], &[0x02, 0x01]);
+
+    let big = EncodeConfig::new(BitMode::Bit32).with_endianness(Endianness::Big);
+    assert_eq!(
+        store.encode(&[1, 0x0102], &big).unwrap(),
+        vec![0x70, 0, 0, 0, 1, 0, 0, 0x01, 0x02]
+    );
+
+    assert_eq!(
+        store.encode(&[1, 1 << 40], &EncodeConfig::new(BitMode::Bit32)),
+        Err(EncodeError::OutOfRange { value: 1 << 40, width: 4 })
+    );
+    assert_eq!(
+        store.encode(&[1], &EncodeConfig::new(BitMode::Bit64)),
+        Err(EncodeError::Arity { expected: 2, found: 1 })
+    );
+}
+
+// =======================================================
+// 🧮 Operand Matching — Shared Schema Check
+// =======================================================
+//
+// 📜 Purpose:
+//   Confirms `Instruction::matches_operands` checks count, then kind,
+//   for the operand sets the Bearer constructs.
+//
+// 🧭 Verifies:
+//   - `store faith 5` (Target, Value) matches
+//   - A missing operand reports the expected and found counts
+//   - A literal in the `Target` slot reports its position and text
+//   - Instructions without a schema accept any operands
+//
+// =======================================================
+
+fn binding(name: &str) -> Operand {
+    Operand::Binding {
+        name: name.to_string(),
+        alignment: None,
+    }
+}
+
+fn literal(value: &str) -> Operand {
+    Operand::Literal {
+        value: value.to_string(),
+        dtype: None,
+    }
+}
+
+#[test]
+fn test_matches_operands_accepts_fitting_set() {
+    let registry = get_instruction_registry();
+
+    assert_eq!(
+        registry["store"].matches_operands(&[binding("faith"), literal("5")]),
+        Ok(())
+    );
+    assert_eq!(registry["store"].matches_operands(&[Operand::Wildcard, literal("5")]), Ok(()));
+}
+
+#[test]
+fn test_matches_operands_reports_count_then_kind() {
+    let registry = get_instruction_registry();
+    let store = &registry["store"];
+
+    assert_eq!(
+        store.matches_operands(&[binding("faith")]),
+        Err(OperandMismatch::Count { expected: 2, found: 1 })
+    );
+
+    let mismatch = store
+        .matches_operands(&[literal("5"), binding("faith")])
+        .unwrap_err();
+    assert_eq!(
+        mismatch,
+        OperandMismatch::Kind {
+            position: 0,
+            expected: OperandKind::Target,
+            found: "5".to_string(),
+        }
+    );
+    assert_eq!(mismatch.to_string(), "expected target, found '5' at position 0");
+
+    // 🕊 `then` declares no schema, so nothing is checked
+    assert_eq!(registry["then"].matches_operands(&[literal("1"), literal("2")]), Ok(()));
+}
+
+#[test]
+fn test_matches_operands_allows_trailing_optional_slot() {
+    // 🧪 Input: `curse` — [Target, Optional(Value)]
+    // 🧱 Expectation: one or two operands fit; zero or three report the nearer bound
+    let registry = get_instruction_registry();
+    let curse = &registry["curse"];
+
+    assert_eq!(curse.matches_operands(&[binding("faith")]), Ok(()));
+    assert_eq!(curse.matches_operands(&[binding("faith"), literal("5")]), Ok(()));
+    assert_eq!(
+        curse.matches_operands::<Operand>(&[]),
+        Err(OperandMismatch::Count { expected: 1, found: 0 })
+    );
+    assert_eq!(
+        curse.matches_operands(&[binding("faith"), literal("5"), literal("6")]),
+        Err(OperandMismatch::Count { expected: 2, found: 3 })
+    );
+
+    let config = EncodeConfig::new(BitMode::Bit32);
+    assert_eq!(curse.encode(&[1], &config).unwrap(), vec![0x61, 1, 0, 0, 0]);
+    assert_eq!(curse.encode(&[1, 5], &config).unwrap().len(), 1 + 4 + 4);
+}
+
+#[test]
+fn test_matches_operands_accepts_any_alternative_form() {
+    // 🧪 Input: `bless` — [Target, Value], or [Target] alone
+    // 🧱 Expectation: both forms fit and encode; three operands fit neither
+    let registry = get_instruction_registry();
+    let bless = &registry["bless"];
+
+    let schema = bless.schema().expect("bless declares a schema");
+    assert_eq!(schema.alternatives, vec![vec![OperandKind::Target]]);
+    assert_eq!((schema.required(), schema.max()), (1, Some(2)));
+
+    assert_eq!(bless.matches_operands(&[binding("faith")]), Ok(()));
+    assert_eq!(bless.matches_operands(&[binding("faith"), literal("5")]), Ok(()));
+    assert_eq!(
+        bless.matches_operands(&[binding("faith"), literal("5"), literal("6")]),
+        Err(OperandMismatch::Count { expected: 2, found: 3 })
+    );
+    assert_eq!(
+        bless.matches_operands(&[literal("5")]),
+        Err(OperandMismatch::Kind {
+            position: 0,
+            expected: OperandKind::Target,
+            found: "5".to_string(),
+        })
+    );
+
+    let config = EncodeConfig::new(BitMode::Bit32);
+    assert_eq!(bless.encode(&[1], &config).unwrap(), vec![0x60, 1, 0, 0, 0]);
+    assert_eq!(bless.encode(&[1, 5], &config).unwrap().len(), 1 + 4 + 4);
+    assert!(bless.encode(&[1, 5, 6], &config).is_err());
+}
+
+#[test]
+fn test_matches_operands_repeats_trailing_variadic_slot() {
+    // 🧪 Input: `speak` — [Literal, Variadic(Value)]
+    // 🧱 Expectation: one or more operands fit with no upper bound; zero
+    //    reports the minimum, and each extra is checked as a value
+    let registry = get_instruction_registry();
+    let speak = &registry["speak"];
+
+    assert_eq!(speak.matches_operands(&[literal("\"hi\"")]), Ok(()));
+    assert_eq!(
+        speak.matches_operands(&[
+            literal("\"score:\""),
+            binding("x"),
+            literal("\"/\""),
+            binding("total"),
+        ]),
+        Ok(())
+    );
+    assert_eq!(
+        speak.matches_operands::<Operand>(&[]),
+        Err(OperandMismatch::Count { expected: 1, found: 0 })
+    );
+    assert_eq!(speak.schema().and_then(|schema| schema.max()), None);
+
+    let config = EncodeConfig::new(BitMode::Bit32);
+    assert_eq!(speak.encode(&[1, 2, 3], &config).unwrap().len(), 1 + 3 * 4);
+}
+
+#[test]
+fn test_get_schema_reports_arity_and_kinds() {
+    // 🧪 Input: `store` from the shared registry, plus an unknown keyword
+    // 🧱 Expectation: arity 2 with [Target, Value]; unknown keywords have no schema
+    let registry = shared_registry();
+    let schema = registry
+        .get_schema("store")
+        .expect("store declares a schema");
+
+    assert_eq!(schema.arity, 2);
+    assert_eq!(schema.kinds, vec![OperandKind::Target, OperandKind::Value]);
+    assert_eq!(schema.required(), 2);
+    assert_eq!(
+        registry.get("store").and_then(Instruction::schema),
+        Some(schema)
+    );
+    assert_eq!(registry.get_schema("unknown"), None::<OperandSchema>);
+}
+
 // ===========================================================
 // 📋 Test Log Summary — Instruction Registry Verification
 // ===========================================================
@@ -183,6 +582,19 @@ fn test_instruction_registry_integrity() {
 #[test]
 fn test_log_instruction_registry_summary() {
     println!("✅ test_instruction_registry_integrity: PASSED");
+    println!("✅ test_registry_from_toml_matches_builtin: PASSED");
+    println!("✅ test_shared_registry_is_one_instance_across_threads: PASSED");
+    println!("✅ test_registry_from_toml_rejects_duplicate_opcode: PASSED");
+    println!("✅ test_builtin_opcodes_fit_group_ranges: PASSED");
+    println!("✅ test_register_rejects_out_of_range_opcode: PASSED");
+    println!("✅ test_annotate_with_verse: PASSED");
+    println!("✅ test_encode_widths_follow_bit_mode: PASSED");
+    println!("✅ test_matches_operands_accepts_fitting_set: PASSED");
+    println!("✅ test_matches_operands_reports_count_then_kind: PASSED");
+    println!("✅ test_matches_operands_allows_trailing_optional_slot: PASSED");
+    println!("✅ test_matches_operands_accepts_any_alternative_form: PASSED");
+    println!("✅ test_matches_operands_repeats_trailing_variadic_slot: PASSED");
+    println!("✅ test_get_schema_reports_arity_and_kinds: PASSED");
 
     // 🧭 This log confirms all instructions in the registry passed validation.
     //     Use this scroll as a lighthouse when expanding Tablet opcode logic.
@@ -0,0 +1,952 @@
+// ==========================================================
+// 🧪 Interpreter Test Suite — Scroll Execution & IO Channels
+// ==========================================================
+//
+// 🎯 Purpose:
+//   - Tests `tablet::interpreter` execution of parsed scrolls
+//   - Verifies `speak` / `hear` route through the injected `IoChannel`
+//   - Verifies `bless` / `curse` integer arithmetic
+//   - Verifies function calls bind parameters and check arity
+//   - Verifies tuple returns unpack into caller-side targets
+//   - Verifies `if` / `then` / `else` branching
+//   - Verifies comparator semantics over numeric and string operands
+//   - Verifies `+` adds integers, concatenates text, and flags mixes
+//
+// 📦 Imports:
+//   - Tokenizer + Parser for source → ScrollTree
+//   - `MemoryChannel` as the scripted IO double
+//
+// 🔮 Future-Ready:
+//   - Extend as more instructions gain runtime behavior
+// ----------------------------------------------------------
+
+use std::cmp::Ordering; // ⚖️ `if` comparison results
+use std::collections::BTreeMap; // 📚 Binding snapshots
+use std::thread; // 🧵 Fixed-size stacks for the call-depth check
+use std::time::Duration; // ⏳ Simulated `wait` totals
+
+use tablet::instruction_registry::shared_registry; // 🧭 Instruction source
+use tablet::interpreter::{
+    eval_condition, CoercionPolicy, Comparator, Interpreter, MemoryChannel, RuntimeError, Step,
+    Value, DEFAULT_MAX_CALL_DEPTH,
+}; // 🕯 Runtime under test
+use tablet::operand_resolver::BindingScope; // 🧭 Frame scope checks
+use tablet::parser::{Parser, ScrollTree}; // 🧱 Source → tree
+use tablet::tokenizer::Tokenizer; // 🧩 Source scanner
+
+// ----------------------------------------------------------
+// 🧰 Harness Helpers — Source parsing
+// ----------------------------------------------------------
+fn parse_source(source: &str) -> ScrollTree {
+    let tokens = Tokenizer::from_registry(source, &shared_registry())
+        .tokenize()
+        .tokens;
+    let mut parser = Parser::new(tokens);
+    let tree = parser.parse();
+    assert_eq!(parser.errors(), &[], "source should parse cleanly");
+    tree
+}
+
+// ==============================================
+// 🔌 IO Channel Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `speak` writes to the channel, not stdout
+//   - Several `speak` operands are joined by spaces
+//   - `hear` binds the next queued line
+//   - Exhausted input is a runtime error, not a hang
+//   - `wait` pauses through the channel, so tests simulate the delay
+// ==============================================
+
+#[test]
+fn test_speak_writes_to_channel() {
+    // 🧪 Input: speak "hi"
+    // 🧱 Expectation: "hi\n" captured by the test channel
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter.run(&parse_source("speak \"hi\"")).unwrap();
+
+    assert_eq!(interpreter.io().output, "hi\n");
+}
+
+#[test]
+fn test_speak_quoted_annotation_text() {
+    // 🧪 Input:
+    // speak "@trusted"
+    // speak @trusted "kept"
+    // 🧱 Expectation: the quoted text is spoken; the bare annotation is dropped
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("speak \"@trusted\"\nspeak @trusted \"kept\""))
+        .unwrap();
+
+    assert_eq!(interpreter.io().output, "@trusted\nkept\n");
+}
+
+#[test]
+fn test_speak_joins_multiple_operands() {
+    // 🧪 Input:
+    // x = 7
+    // total = 10
+    // speak "score:", x, "/", total
+    // speak "done"
+    // 🧱 Expectation: operands evaluated and joined by spaces; the
+    //    single-operand speak is unchanged
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source(
+            "x = 7\ntotal = 10\nspeak \"score:\", x, \"/\", total\nspeak \"done\"",
+        ))
+        .unwrap();
+
+    assert_eq!(interpreter.io().output, "score: 7 / 10\ndone\n");
+}
+
+#[test]
+fn test_hear_reads_queued_input() {
+    // 🧪 Input:
+    // hear x
+    // speak x
+    // 🧱 Expectation: `x` bound to the queued line and echoed back
+    let mut interpreter = Interpreter::new(MemoryChannel::with_input(["shalom"]));
+
+    interpreter.run(&parse_source("hear x\nspeak x")).unwrap();
+
+    assert_eq!(
+        interpreter.value("x"),
+        Some(&Value::Text("shalom".to_string()))
+    );
+
+    let io = interpreter.into_io();
+    assert_eq!(io.output, "shalom\n");
+    assert!(io.input.is_empty());
+}
+
+#[test]
+fn test_two_hears_consume_two_queued_lines() {
+    // 🧪 Input:
+    // hear name
+    // hear count
+    // 🧱 Expectation: each `hear` takes the next line in order; a later
+    //    run fails once the queue is drained, then succeeds after a refill
+    let scroll = parse_source("hear name\nhear count");
+    let mut interpreter = Interpreter::new(MemoryChannel::with_input(["Ruth", "3"]));
+
+    interpreter.run(&scroll).unwrap();
+
+    assert_eq!(
+        interpreter.value("name"),
+        Some(&Value::Text("Ruth".to_string()))
+    );
+    assert_eq!(interpreter.value("count"), Some(&Value::Integer(3)));
+    assert!(interpreter.io().input.is_empty());
+
+    assert_eq!(
+        interpreter.run(&scroll),
+        Err(RuntimeError::InputExhausted {
+            target: "name".to_string()
+        })
+    );
+
+    interpreter.io_mut().push_input("Naomi");
+    interpreter.io_mut().push_input("2");
+    interpreter.run(&scroll).unwrap();
+    assert_eq!(interpreter.value("count"), Some(&Value::Integer(2)));
+}
+
+#[test]
+fn test_hear_without_input_fails() {
+    // 🧪 Input: hear x with nothing queued
+    // 🧱 Expectation: InputExhausted naming the target
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    assert_eq!(
+        interpreter.run(&parse_source("hear x")),
+        Err(RuntimeError::InputExhausted {
+            target: "x".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_wait_pauses_for_its_duration() {
+    // 🧪 Input:
+    // wait 5s
+    // wait 200ms
+    // wait
+    // 🧱 Expectation: the channel records 5.2s without sleeping; a
+    //    unitless operand is an InvalidDuration
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("wait 5s\nwait 200ms\nwait"))
+        .unwrap();
+    assert_eq!(interpreter.io().slept, Duration::from_millis(5_200));
+
+    assert_eq!(
+        interpreter.run(&parse_source("wait 5")),
+        Err(RuntimeError::InvalidDuration {
+            instruction: "wait".to_string(),
+            operand: "5".to_string(),
+        })
+    );
+}
+
+// ==============================================
+// ➕ Arithmetic Tests — bless / curse
+// ==============================================
+//
+// 🧱 Focus:
+//   - `bless` / `curse` shift integers by 1 or an explicit amount
+//   - Non-numeric targets raise `RuntimeError::NonNumeric`
+// ==============================================
+
+#[test]
+fn test_bless_increments_integer() {
+    // 🧪 Input:
+    // let x = 5
+    // bless x
+    // 🧱 Expectation: x == 6
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("let x = 5\nbless x"))
+        .unwrap();
+
+    assert_eq!(interpreter.value("x"), Some(&Value::Integer(6)));
+}
+
+#[test]
+fn test_bless_by_amount() {
+    // 🧪 Input:
+    // let x = 0
+    // bless x 5
+    // 🧱 Expectation: x == 5
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("let x = 0\nbless x 5"))
+        .unwrap();
+
+    assert_eq!(interpreter.value("x"), Some(&Value::Integer(5)));
+}
+
+#[test]
+fn test_backtick_identifier_declares_and_assigns() {
+    // 🧪 Input:
+    // let `my var` = 1
+    // `my var` = 7
+    // 🧱 Expectation: one binding named "my var", now 7
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("let `my var` = 1\n`my var` = 7"))
+        .unwrap();
+
+    assert_eq!(interpreter.value("my var"), Some(&Value::Integer(7)));
+}
+
+#[test]
+fn test_bless_without_amount_adds_one() {
+    // 🧪 Input:
+    // let x = 0
+    // bless x
+    // 🧱 Expectation: x == 1 — the amount defaults to 1
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("let x = 0\nbless x"))
+        .unwrap();
+
+    assert_eq!(interpreter.value("x"), Some(&Value::Integer(1)));
+}
+
+#[test]
+fn test_curse_by_amount() {
+    // 🧪 Input:
+    // let x = 5
+    // curse x 7
+    // 🧱 Expectation: x == -2
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("let x = 5\ncurse x 7"))
+        .unwrap();
+
+    assert_eq!(interpreter.value("x"), Some(&Value::Integer(-2)));
+}
+
+#[test]
+fn test_curse_on_string_errors() {
+    // 🧪 Input:
+    // let s = "grace"
+    // curse s
+    // 🧱 Expectation: NonNumeric naming `s`, value untouched
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    assert_eq!(
+        interpreter.run(&parse_source("let s = \"grace\"\ncurse s")),
+        Err(RuntimeError::NonNumeric {
+            instruction: "curse".to_string(),
+            operand: "s".to_string(),
+        })
+    );
+    assert_eq!(
+        interpreter.value("s"),
+        Some(&Value::Text("grace".to_string()))
+    );
+}
+
+// ==============================================
+// ➕ Operator Tests — `+`
+// ==============================================
+//
+// 🧱 Focus:
+//   - `+` dispatches on operand types: integers add, text concatenates
+//   - Mixing integers and text is an error unless coercion allows it
+// ==============================================
+
+#[test]
+fn test_plus_concatenates_strings() {
+    // 🧪 Input:
+    // let s = "grace" + "ful"
+    // 🧱 Expectation: s == "graceful"
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("let s = \"grace\" + \"ful\""))
+        .unwrap();
+
+    assert_eq!(
+        interpreter.value("s"),
+        Some(&Value::Text("graceful".to_string()))
+    );
+}
+
+#[test]
+fn test_plus_inside_escaped_quotes_stays_text() {
+    // 🧪 Input:
+    // s = "a \" + b" + "!"
+    // 🧱 Expectation: two terms — the escaped quote does not end the string
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source(r#"s = "a \" + b" + "!""#))
+        .unwrap();
+
+    assert_eq!(
+        interpreter.value("s"),
+        Some(&Value::Text("a \" + b!".to_string()))
+    );
+}
+
+#[test]
+fn test_plus_adds_integers() {
+    // 🧪 Input:
+    // let x = 1 + 2
+    // let y = x + 4
+    // 🧱 Expectation: x == 3, y == 7
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("let x = 1 + 2\nlet y = x + 4"))
+        .unwrap();
+
+    assert_eq!(interpreter.value("x"), Some(&Value::Integer(3)));
+    assert_eq!(interpreter.value("y"), Some(&Value::Integer(7)));
+}
+
+#[test]
+fn test_plus_on_mixed_operands_errors_unless_coerced() {
+    // 🧪 Input:
+    // let m = "a" + 1
+    // 🧱 Expectation: IncompatibleOperands by default; "a1" under Stringify
+    let source = "let m = \"a\" + 1";
+
+    let mut strict = Interpreter::new(MemoryChannel::default());
+    assert_eq!(
+        strict.run(&parse_source(source)),
+        Err(RuntimeError::IncompatibleOperands {
+            operator: "+".to_string(),
+            left: Value::Text("a".to_string()),
+            right: Value::Integer(1),
+        })
+    );
+    assert_eq!(strict.value("m"), None);
+
+    let mut lenient =
+        Interpreter::new(MemoryChannel::default()).with_coercion(CoercionPolicy::Stringify);
+    lenient.run(&parse_source(source)).unwrap();
+    assert_eq!(lenient.value("m"), Some(&Value::Text("a1".to_string())));
+}
+
+// ==============================================
+// 📞 Function Call Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Arguments bind to parameters in a `Local` frame for the call
+//   - Argument count must match the parameter count
+//   - Named arguments bind by parameter name; unknown names error
+//   - `return (a, b)` unpacks into `a, b = f()` targets
+// ==============================================
+
+#[test]
+fn test_call_binds_parameters() {
+    // 🧪 Input:
+    // let gift = 7
+    // walk shepherd(flock, staff) { speak flock; speak staff }
+    // walk shepherd gift "rod"
+    // 🧱 Expectation: params bound per call, gone once the call returns
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "let gift = 7\n\
+                  walk shepherd(flock, staff) {\n    speak flock\n    speak staff\n}\n\
+                  walk shepherd gift \"rod\"";
+
+    interpreter.run(&parse_source(source)).unwrap();
+
+    assert_eq!(interpreter.io().output, "7\nrod\n");
+    assert_eq!(interpreter.value("flock"), None);
+    assert_eq!(interpreter.scope_of("flock"), None);
+    assert_eq!(interpreter.scope_of("gift"), Some(BindingScope::Global));
+}
+
+#[test]
+fn test_block_bindings_end_with_the_block() {
+    // 🧪 Input:
+    // let total = 1
+    // { total = 3; let inner = 2 }
+    // 🧱 Expectation: `inner` is gone after the block; `total` keeps the update
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "let total = 1\n{\n    total = 3\n    let inner = 2\n}";
+
+    interpreter.run(&parse_source(source)).unwrap();
+
+    assert_eq!(interpreter.value("inner"), None);
+    assert_eq!(interpreter.value("total"), Some(&Value::Integer(3)));
+    assert_eq!(interpreter.scope_of("total"), Some(BindingScope::Global));
+}
+
+#[test]
+fn test_call_with_wrong_arity_errors() {
+    // 🧪 Input:
+    // walk shepherd(flock) { speak flock }
+    // walk shepherd "a" "b"
+    // 🧱 Expectation: InvalidArgument, body never runs
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "walk shepherd(flock) {\n    speak flock\n}\nwalk shepherd \"a\" \"b\"";
+
+    assert_eq!(
+        interpreter.run(&parse_source(source)),
+        Err(RuntimeError::InvalidArgument {
+            function: "shepherd".to_string(),
+            expected: 1,
+            found: 2,
+        })
+    );
+    assert!(interpreter.io().output.is_empty());
+}
+
+#[test]
+fn test_named_and_positional_calls_bind_alike() {
+    // 🧪 Input:
+    // walk pair(x, y) { return (x, y) }
+    // a, b = pair(1, 2) / c, d = pair(y: 2, x: 1) / e, f = pair(1, y: 2)
+    // 🧱 Expectation: every form binds x = 1 and y = 2
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "walk pair(x, y) {\n    return (x, y)\n}\n\
+                  a, b = pair(1, 2)\nc, d = pair(y: 2, x: 1)\ne, f = pair(1, y: 2)";
+
+    interpreter.run(&parse_source(source)).unwrap();
+
+    for (first, second) in [("a", "b"), ("c", "d"), ("e", "f")] {
+        assert_eq!(interpreter.value(first), Some(&Value::Integer(1)), "{}", first);
+        assert_eq!(interpreter.value(second), Some(&Value::Integer(2)), "{}", second);
+    }
+}
+
+#[test]
+fn test_call_with_unknown_parameter_name_errors() {
+    // 🧪 Input:
+    // walk shepherd(flock) { speak flock }
+    // shepherd(herd: 1)
+    // 🧱 Expectation: UnknownParameter naming the function and the stray name
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "walk shepherd(flock) {\n    speak flock\n}\nshepherd(herd: 1)";
+
+    assert_eq!(
+        interpreter.run(&parse_source(source)),
+        Err(RuntimeError::UnknownParameter {
+            function: "shepherd".to_string(),
+            name: "herd".to_string(),
+        })
+    );
+    assert!(interpreter.io().output.is_empty());
+}
+
+#[test]
+fn test_quoted_call_argument_binds_positionally() {
+    // 🧪 Input:
+    // walk shepherd(flock) { speak flock }
+    // shepherd("herd: 1") / note = shepherd("herd: 2")
+    // 🧱 Expectation: the string is the `flock` argument, not a named `herd`
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "walk shepherd(flock) {\n    speak flock\n}\n\
+                  shepherd(\"herd: 1\")\nnote = shepherd(\"herd: 2\")";
+
+    interpreter.run(&parse_source(source)).unwrap();
+
+    assert_eq!(interpreter.io().output, "herd: 1\nherd: 2\n");
+}
+
+#[test]
+fn test_comma_inside_quoted_call_argument_stays_one_argument() {
+    // 🧪 Input:
+    // walk echo(line) { return line }
+    // x = echo("hi, there")
+    // 🧱 Expectation: one argument, so the call binds and x is the whole text
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "walk echo(line) {\n    return line\n}\nx = echo(\"hi, there\")";
+
+    interpreter.run(&parse_source(source)).unwrap();
+
+    assert_eq!(
+        interpreter.value("x"),
+        Some(&Value::Text("hi, there".to_string()))
+    );
+}
+
+#[test]
+fn test_tuple_return_binds_on_caller_side() {
+    // 🧪 Input:
+    // walk pair(x) { return (x, 2) }
+    // a, b = pair(1)
+    // speak a; speak b
+    // 🧱 Expectation: the tuple unpacks into `a` and `b` in order
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "walk pair(x) {\n    return (x, 2)\n    speak \"unreached\"\n}\n\
+                  a, b = pair(1)\nspeak a\nspeak b";
+
+    interpreter.run(&parse_source(source)).unwrap();
+
+    assert_eq!(interpreter.io().output, "1\n2\n");
+    assert_eq!(interpreter.value("b"), Some(&Value::Integer(2)));
+}
+
+#[test]
+fn test_tuple_target_count_mismatch_errors() {
+    // 🧪 Input: a, b, c = pair() where pair returns two values
+    // 🧱 Expectation: TupleMismatch naming the targets and both counts
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "walk pair() {\n    return (1, 2)\n}\na, b, c = pair()";
+
+    assert_eq!(
+        interpreter.run(&parse_source(source)),
+        Err(RuntimeError::TupleMismatch {
+            target: "a, b, c".to_string(),
+            expected: 3,
+            found: 2,
+        })
+    );
+}
+
+// ==============================================
+// 🧭 Conditional Branch Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `if a b` sets the condition flag from comparing its operands
+//   - `then` runs the next node only on a true condition, `else` only on false
+// ==============================================
+
+#[test]
+fn test_if_equal_operands_takes_then_branch() {
+    // 🧪 Input:
+    // if 5 5
+    // then speak "yes"
+    // else speak "no"
+    // 🧱 Expectation: condition true, only the `then` branch speaks
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "if 5 5\nthen speak \"yes\"\nelse speak \"no\"";
+
+    interpreter.run(&parse_source(source)).unwrap();
+
+    assert_eq!(interpreter.condition(), Some(true));
+    assert_eq!(interpreter.comparison(), Some(Ordering::Equal));
+    assert_eq!(interpreter.io().output, "yes\n");
+}
+
+#[test]
+fn test_if_unequal_operands_takes_else_branch() {
+    // 🧪 Input:
+    // if 5 3
+    // then speak "yes"
+    // else speak "no"
+    // 🧱 Expectation: condition false (5 > 3), only the `else` branch speaks
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    let source = "if 5 3\nthen speak \"yes\"\nelse speak \"no\"";
+
+    interpreter.run(&parse_source(source)).unwrap();
+
+    assert_eq!(interpreter.condition(), Some(false));
+    assert_eq!(interpreter.comparison(), Some(Ordering::Greater));
+    assert_eq!(interpreter.io().output, "no\n");
+}
+
+#[test]
+fn test_keyword_conditional_runs_one_block() {
+    // 🧪 Input: if x 2 then { speak "yes" } else { speak "no" } on one line
+    // 🧱 Expectation: x = 2 runs only the `then` block; x = 3 only the `else` block
+    for (x, spoken) in [(2, "yes\n"), (3, "no\n")] {
+        let source = format!(
+            "let x {}\nif x 2 then {{ speak \"yes\" }} else {{ speak \"no\" }}",
+            x
+        );
+        let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+        interpreter.run(&parse_source(&source)).unwrap();
+
+        assert_eq!(interpreter.io().output, spoken);
+    }
+}
+
+#[test]
+fn test_then_without_if_errors() {
+    // 🧪 Input: then speak "yes"
+    // 🧱 Expectation: NoCondition, nothing spoken
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    assert_eq!(
+        interpreter.run(&parse_source("then speak \"yes\"")),
+        Err(RuntimeError::NoCondition {
+            instruction: "then".to_string(),
+        })
+    );
+    assert!(interpreter.io().output.is_empty());
+}
+
+// ==============================================
+// ⚖️ Comparator Semantics Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `==`, `!=`, `<`, `<=`, `>`, `>=` over integers (numeric order)
+//   - The same operators over text (lexicographic byte order)
+//   - Infix `if a <op> b` drives `then` / `else`
+// ==============================================
+
+#[test]
+fn test_eval_condition_numeric_operands() {
+    // 🧪 Input: each comparator over (3, 5), (5, 5), (5, 3)
+    // 🧱 Expectation: results follow integer order, not text order
+    let (three, five) = (Value::Integer(3), Value::Integer(5));
+    let cases = [
+        (Comparator::Eq, [false, true, false]),
+        (Comparator::Ne, [true, false, true]),
+        (Comparator::Lt, [true, false, false]),
+        (Comparator::Le, [true, true, false]),
+        (Comparator::Gt, [false, false, true]),
+        (Comparator::Ge, [false, true, true]),
+    ];
+
+    for (op, [less, equal, greater]) in cases {
+        assert_eq!(eval_condition(&three, op, &five), less, "{:?} 3 5", op);
+        assert_eq!(eval_condition(&five, op, &five), equal, "{:?} 5 5", op);
+        assert_eq!(eval_condition(&five, op, &three), greater, "{:?} 5 3", op);
+    }
+
+    // 🔢 Numeric, not lexicographic: 10 > 9
+    let (ten, nine) = (Value::Integer(10), Value::Integer(9));
+    assert!(eval_condition(&ten, Comparator::Gt, &nine));
+}
+
+#[test]
+fn test_eval_condition_string_operands() {
+    // 🧪 Input: each comparator over (a, b), (a, a), (b, a) for a = "apple", b = "banana"
+    // 🧱 Expectation: results follow lexicographic order
+    let apple = Value::Text("apple".to_string());
+    let banana = Value::Text("banana".to_string());
+    let cases = [
+        (Comparator::Eq, [false, true, false]),
+        (Comparator::Ne, [true, false, true]),
+        (Comparator::Lt, [true, false, false]),
+        (Comparator::Le, [true, true, false]),
+        (Comparator::Gt, [false, false, true]),
+        (Comparator::Ge, [false, true, true]),
+    ];
+
+    for (op, [less, equal, greater]) in cases {
+        assert_eq!(eval_condition(&apple, op, &banana), less, "{:?} a b", op);
+        assert_eq!(eval_condition(&apple, op, &apple), equal, "{:?} a a", op);
+        assert_eq!(eval_condition(&banana, op, &apple), greater, "{:?} b a", op);
+    }
+
+    // 🔤 Text compares lexicographically: "10" < "9"
+    let ten = Value::Text("10".to_string());
+    let nine = Value::Text("9".to_string());
+    assert!(eval_condition(&ten, Comparator::Lt, &nine));
+}
+
+#[test]
+fn test_if_with_compound_operators_branches() {
+    // 🧪 Input: infix `if` scrolls using each compound and simple operator
+    // 🧱 Expectation: `then` speaks when the comparison holds, `else` otherwise
+    let cases = [
+        ("if 3 <= 5", "yes\n"),
+        ("if 5 >= 5", "yes\n"),
+        ("if 3 != 3", "no\n"),
+        ("if 4 == 4", "yes\n"),
+        ("if 9 < 10", "yes\n"),
+        ("if 3 > 5", "no\n"),
+        ("if \"apple\" != \"banana\"", "yes\n"),
+        ("if \"pear\" <= \"apple\"", "no\n"),
+    ];
+
+    for (condition, expected) in cases {
+        let mut interpreter = Interpreter::new(MemoryChannel::default());
+        let source = format!("{}\nthen speak \"yes\"\nelse speak \"no\"", condition);
+
+        interpreter.run(&parse_source(&source)).unwrap();
+
+        assert_eq!(interpreter.io().output, expected, "{}", condition);
+    }
+}
+
+#[test]
+fn test_if_with_non_comparison_operator_errors() {
+    // 🧪 Input: if 3 += 5
+    // 🧱 Expectation: UnknownComparator naming the operator
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    assert_eq!(
+        interpreter.run(&parse_source("if 3 += 5")),
+        Err(RuntimeError::UnknownComparator {
+            instruction: "if".to_string(),
+            operator: "+=".to_string(),
+        })
+    );
+}
+
+// ==============================================
+// 👣 Step & Trace Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `step` runs one top-level statement and snapshots the bindings
+//   - `run_traced` collects the same snapshots for a whole scroll
+// ==============================================
+
+/// 📸 Builds an expected snapshot from `(name, value)` pairs.
+fn bindings(pairs: &[(&str, i64)]) -> BTreeMap<String, Value> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), Value::Integer(*value)))
+        .collect()
+}
+
+#[test]
+fn test_step_snapshots_environment_after_each_statement() {
+    // 🧪 Input:
+    // let x 5
+    // bless x
+    // let y x
+    // 🧱 Expectation: {x: 5} → {x: 6} → {x: 6, y: 6}, then no more steps
+    let tree = parse_source("let x 5\nbless x\nlet y x");
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+    interpreter.load(&tree);
+
+    let expected = [
+        bindings(&[("x", 5)]),
+        bindings(&[("x", 6)]),
+        bindings(&[("x", 6), ("y", 6)]),
+    ];
+    for (node, bindings) in tree.nodes.iter().zip(expected) {
+        let step = interpreter.step().unwrap().expect("statement left to step");
+        assert_eq!(
+            step,
+            Step {
+                node: node.clone(),
+                skipped: false,
+                bindings,
+            }
+        );
+    }
+
+    assert_eq!(interpreter.step(), Ok(None));
+}
+
+#[test]
+fn test_run_traced_marks_skipped_branches() {
+    // 🧪 Input:
+    // let x 1
+    // if x 2
+    // then
+    // bless x
+    // 🧱 Expectation: four steps; `bless x` is skipped and `x` stays 1
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    let trace = interpreter
+        .run_traced(&parse_source("let x 1\nif x 2\nthen\nbless x"))
+        .unwrap();
+
+    let skipped: Vec<bool> = trace.iter().map(|step| step.skipped).collect();
+    assert_eq!(skipped, vec![false, false, false, true]);
+    assert_eq!(trace[3].bindings, bindings(&[("x", 1)]));
+}
+
+// ===========================================================
+// 📋 Test Log Summary — Interpreter Verification
+// ===========================================================
+//
+// 🛠 Usage:
+//   - Run with `cargo test -- --nocapture` to view this output
+//
+// ===========================================================
+
+// ==============================================
+// 🛑 Execution Limit Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `while` bodies repeat while their condition holds
+//   - Runaway loops stop at `max_steps`, runaway recursion at `max_call_depth`
+//   - Only calls count toward `max_call_depth`; the default fits a 2 MiB stack
+// ==============================================
+
+#[test]
+fn test_while_loop_runs_until_condition_fails() {
+    // 🧪 Input:
+    // let x 0
+    // while x < 3 { bless x }
+    // 🧱 Expectation: x == 3
+    let mut interpreter = Interpreter::new(MemoryChannel::default());
+
+    interpreter
+        .run(&parse_source("let x 0\nwhile x < 3 {\n    bless x\n}"))
+        .unwrap();
+
+    assert_eq!(interpreter.value("x"), Some(&Value::Integer(3)));
+}
+
+#[test]
+fn test_infinite_loop_aborts_at_max_steps() {
+    // 🧪 Input:
+    // let x 0
+    // while true { bless x }
+    // 🧱 Expectation: StepLimit at 100, after the loop made some progress
+    let mut interpreter = Interpreter::new(MemoryChannel::default()).with_max_steps(100);
+
+    assert_eq!(
+        interpreter.run(&parse_source("let x 0\nwhile true {\n    bless x\n}")),
+        Err(RuntimeError::StepLimit { limit: 100 })
+    );
+    assert!(matches!(interpreter.value("x"), Some(Value::Integer(n)) if *n > 0));
+}
+
+#[test]
+fn test_deep_recursion_aborts_at_max_call_depth() {
+    // 🧪 Input:
+    // walk dive(n) { walk dive n }
+    // walk dive 1
+    // 🧱 Expectation: CallDepth naming `dive` at 16; frames unwind to Global
+    let mut interpreter = Interpreter::new(MemoryChannel::default()).with_max_call_depth(16);
+
+    assert_eq!(
+        interpreter.run(&parse_source(
+            "walk dive(n) {\n    walk dive n\n}\nwalk dive 1"
+        )),
+        Err(RuntimeError::CallDepth {
+            function: "dive".to_string(),
+            limit: 16,
+        })
+    );
+    assert_eq!(interpreter.value("n"), None);
+}
+
+#[test]
+fn test_default_call_depth_fits_a_default_thread_stack() {
+    // 🧪 Input: the `dive` recursion above, with the default limit, on a
+    //    thread with a 2 MiB stack
+    // 🧱 Expectation: CallDepth at DEFAULT_MAX_CALL_DEPTH, not a stack overflow
+    let result = thread::Builder::new()
+        .stack_size(2 * 1024 * 1024)
+        .spawn(|| {
+            Interpreter::new(MemoryChannel::default())
+                .run(&parse_source("walk dive(n) {\n    walk dive n\n}\nwalk dive 1"))
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    assert_eq!(
+        result,
+        Err(RuntimeError::CallDepth {
+            function: "dive".to_string(),
+            limit: DEFAULT_MAX_CALL_DEPTH,
+        })
+    );
+}
+
+#[test]
+fn test_blocks_do_not_count_toward_call_depth() {
+    // 🧪 Input:
+    // let x 0
+    // walk one() { return 1 }
+    // { { { x = one() } } }
+    // 🧱 Expectation: with max_call_depth 1, the single call still runs
+    let mut interpreter = Interpreter::new(MemoryChannel::default()).with_max_call_depth(1);
+    let source = "let x 0\nwalk one() {\n    return 1\n}\n\
+                  {\n    {\n        {\n            x = one()\n        }\n    }\n}";
+
+    interpreter.run(&parse_source(source)).unwrap();
+
+    assert_eq!(interpreter.value("x"), Some(&Value::Integer(1)));
+}
+
+#[test]
+fn test_log_interpreter_summary() {
+    println!("✅ test_speak_writes_to_channel: PASSED");
+    println!("✅ test_speak_quoted_annotation_text: PASSED");
+    println!("✅ test_speak_joins_multiple_operands: PASSED");
+    println!("✅ test_hear_reads_queued_input: PASSED");
+    println!("✅ test_two_hears_consume_two_queued_lines: PASSED");
+    println!("✅ test_hear_without_input_fails: PASSED");
+    println!("✅ test_wait_pauses_for_its_duration: PASSED");
+    println!("✅ test_bless_increments_integer: PASSED");
+    println!("✅ test_bless_by_amount: PASSED");
+    println!("✅ test_bless_without_amount_adds_one: PASSED");
+    println!("✅ test_backtick_identifier_declares_and_assigns: PASSED");
+    println!("✅ test_curse_by_amount: PASSED");
+    println!("✅ test_curse_on_string_errors: PASSED");
+    println!("✅ test_plus_concatenates_strings: PASSED");
+    println!("✅ test_plus_inside_escaped_quotes_stays_text: PASSED");
+    println!("✅ test_plus_adds_integers: PASSED");
+    println!("✅ test_plus_on_mixed_operands_errors_unless_coerced: PASSED");
+    println!("✅ test_call_binds_parameters: PASSED");
+    println!("✅ test_block_bindings_end_with_the_block: PASSED");
+    println!("✅ test_call_with_wrong_arity_errors: PASSED");
+    println!("✅ test_named_and_positional_calls_bind_alike: PASSED");
+    println!("✅ test_call_with_unknown_parameter_name_errors: PASSED");
+    println!("✅ test_quoted_call_argument_binds_positionally: PASSED");
+    println!("✅ test_comma_inside_quoted_call_argument_stays_one_argument: PASSED");
+    println!("✅ test_tuple_return_binds_on_caller_side: PASSED");
+    println!("✅ test_tuple_target_count_mismatch_errors: PASSED");
+    println!("✅ test_if_equal_operands_takes_then_branch: PASSED");
+    println!("✅ test_if_unequal_operands_takes_else_branch: PASSED");
+    println!("✅ test_keyword_conditional_runs_one_block: PASSED");
+    println!("✅ test_then_without_if_errors: PASSED");
+    println!("✅ test_eval_condition_numeric_operands: PASSED");
+    println!("✅ test_eval_condition_string_operands: PASSED");
+    println!("✅ test_if_with_compound_operators_branches: PASSED");
+    println!("✅ test_if_with_non_comparison_operator_errors: PASSED");
+    println!("✅ test_step_snapshots_environment_after_each_statement: PASSED");
+    println!("✅ test_run_traced_marks_skipped_branches: PASSED");
+    println!("✅ test_while_loop_runs_until_condition_fails: PASSED");
+    println!("✅ test_infinite_loop_aborts_at_max_steps: PASSED");
+    println!("✅ test_deep_recursion_aborts_at_max_call_depth: PASSED");
+    println!("✅ test_default_call_depth_fits_a_default_thread_stack: PASSED");
+    println!("✅ test_blocks_do_not_count_toward_call_depth: PASSED");
+}
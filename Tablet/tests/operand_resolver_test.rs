@@ -0,0 +1,1080 @@
+// ==========================================================
+// 🧪 Operand Resolver Test Suite — Bearer Scope Integrity
+// ==========================================================
+//
+// 🎯 Purpose:
+//   - Tests the `tablet::operand_resolver` Bearer against hand-built ScrollTrees
+//   - Verifies scope-aware checks emitted before operand resolution
+//   - Verifies scoped binding frames in `BindingEnvironment`
+//   - Verifies `Group` operands hold a single operand type when required
+//   - Verifies `return` only appears inside function bodies
+//   - Verifies `resolve_all` keeps going past an invalid instruction
+//
+// 📦 Imports:
+//   - Pulls the Bearer and parser node structures from Tablet
+//   - Uses Watchtower `Severity` to confirm diagnostic banding
+//
+// 🔮 Future-Ready:
+//   - Expand once operand extraction and schema validation are wired through
+// ----------------------------------------------------------
+
+use tablet::operand_resolver::{
+    is_trust_annotation, Bearer, BindingEnvironment, BindingScope, Operand, OperandType,
+    ResolutionError, ResolutionStatus, TrustTier,
+}; // 🪙 Operand resolver under test
+use tablet::parser::{Parser, ScrollNode, ScrollTree}; // 🌳 Input tree structures
+use tablet::tokenizer::{OperandHint, TokenType, Tokenizer}; // 🧠 Hinted tokens from source
+use tablet::instruction_registry::{get_instruction_registry, InstructionRegistry, OperandKind}; // 📜 Opcode source of truth + slot kinds
+use watchtower::debugger::Severity; // 🚨 Diagnostic band for emitted entries
+use std::collections::HashMap; // 📚 Keyword maps handed to the tokenizer
+use std::sync::Arc; // 🤝 Registries handed to `Bearer::with_registry`
+use std::sync::Mutex; // 📶 Progress reports gathered by a plain `fn` callback
+use std::time::Duration; // ⏳ Expected `wait` operands
+
+// ----------------------------------------------------------
+// 🧰 Declaration Builder — Helper for compact node construction
+// ----------------------------------------------------------
+fn declare(name: &str) -> ScrollNode {
+    ScrollNode::declaration(name, None)
+}
+
+// ==============================================
+// 🪞 Shadowed Declaration Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Same-scope redeclaration is flagged as `Severity::Drift`
+//   - Redeclaration inside a nested block is permitted
+// ==============================================
+
+#[test]
+fn test_shadowed_declaration_same_scope() {
+    // 🧪 Input:
+    // let faith
+    // let faith
+    // 🧱 Expectation:
+    // One Drift entry naming `faith`
+    let tree = ScrollTree {
+        nodes: vec![declare("faith"), declare("faith")],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+    let found = bearer.detect_shadowed_declarations(&tree);
+
+    assert_eq!(found, 1);
+    assert_eq!(bearer.errors.len(), 1);
+    assert_eq!(bearer.errors[0].input, "faith");
+    assert!(matches!(bearer.errors[0].severity, Severity::Drift));
+}
+
+#[test]
+fn test_shadowed_declaration_nested_scope_allowed() {
+    // 🧪 Input:
+    // let faith
+    // { let faith }
+    // while covenant { let faith }
+    // 🧱 Expectation:
+    // No entries — each nested body opens its own scope
+    let tree = ScrollTree {
+        nodes: vec![
+            declare("faith"),
+            ScrollNode::Block(vec![declare("faith")]),
+            ScrollNode::Loop {
+                condition: "covenant".to_string(),
+                body: vec![declare("faith")],
+            },
+        ],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+    let found = bearer.detect_shadowed_declarations(&tree);
+
+    assert_eq!(found, 0);
+    assert!(bearer.errors.is_empty());
+}
+
+#[test]
+fn test_shadowed_declaration_inside_nested_block() {
+    // 🧪 Input:
+    // if grace { let hope; let hope }
+    // 🧱 Expectation:
+    // One entry — the redeclaration shares the conditional's scope
+    let tree = ScrollTree {
+        nodes: vec![ScrollNode::Conditional {
+            condition: "grace".to_string(),
+            body: vec![declare("hope"), declare("hope")],
+        }],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+
+    assert_eq!(bearer.detect_shadowed_declarations(&tree), 1);
+}
+
+// ==============================================
+// 🏛 Scoped Environment Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Declarations inside an `if` body do not leak past it
+//   - Outer declarations remain visible inside nested scopes
+// ==============================================
+
+#[test]
+fn test_environment_block_binding_does_not_leak() {
+    // 🧪 Input:
+    // let covenant
+    // if grace { let promise }
+    // let promise
+    // 🧱 Expectation:
+    // No entries — `promise` left scope with the `if` body
+    let tree = ScrollTree {
+        nodes: vec![
+            declare("covenant"),
+            ScrollNode::Conditional {
+                condition: "grace".to_string(),
+                body: vec![declare("promise")],
+            },
+            declare("promise"),
+        ],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+
+    assert_eq!(bearer.detect_shadowed_declarations(&tree), 0);
+}
+
+#[test]
+fn test_shadowed_declaration_pass_leaves_environment_untouched() {
+    // 🧪 Input: `let covenant`, checked twice by the same Bearer
+    // 🧱 Expectation: no entries either time; the Bearer's own environment stays empty
+    let tree = ScrollTree {
+        nodes: vec![declare("covenant")],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+
+    assert_eq!(bearer.detect_shadowed_declarations(&tree), 0);
+    assert_eq!(bearer.detect_shadowed_declarations(&tree), 0);
+    assert!(!bearer.environment.is_visible("covenant"));
+    assert_eq!(bearer.environment.depth(), 1);
+}
+
+#[test]
+fn test_environment_outer_binding_visible_inside() {
+    // 🧪 Input: outer `covenant`, then enter a scope and declare `promise`
+    // 🧱 Expectation: both visible inside; only `covenant` after exit
+    let mut env = BindingEnvironment::new();
+    env.declare("covenant");
+
+    env.push_scope();
+    env.declare("promise");
+    assert!(env.is_visible("covenant"));
+    assert!(env.is_visible("promise"));
+    assert_eq!(
+        env.lookup("promise"),
+        Some(&Operand::Binding {
+            name: "promise".to_string(),
+            alignment: Some(BindingScope::Local),
+        })
+    );
+
+    env.pop_scope();
+    assert!(env.is_visible("covenant"));
+    assert!(!env.is_visible("promise"));
+
+    // 🛡 Root frame is never popped
+    assert!(env.pop_scope().is_none());
+    assert_eq!(env.depth(), 1);
+}
+
+#[test]
+fn test_environment_resolves_imported_paths_as_extern() {
+    // 🧪 Input: import `psalms` with member `count`
+    // 🧱 Expectation: `psalms.count` and `psalms::count` find an Extern
+    //    binding; a missing member, an unknown scroll, and the bare name do not
+    let mut env = BindingEnvironment::new();
+    env.import_scroll("psalms", ["count"]);
+
+    let extern_count = Operand::Binding {
+        name: "count".to_string(),
+        alignment: Some(BindingScope::Extern),
+    };
+    assert!(env.is_imported("psalms"));
+    assert_eq!(env.lookup_path("psalms.count"), Some(&extern_count));
+    assert_eq!(env.lookup_path("psalms::count"), Some(&extern_count));
+    assert_eq!(env.lookup_path("psalms.missing"), None);
+    assert_eq!(env.lookup_path("hymns.count"), None);
+    assert!(!env.is_visible("count"));
+}
+
+// ==============================================
+// 🔗 InstructionRef Resolution Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `ref:` tokens resolve against the instruction registry
+//   - Unknown references are downgraded to `TrustTier::Invalid`
+// ==============================================
+
+#[test]
+fn test_instruction_ref_resolves_known_keyword() {
+    // 🧪 Input: ref:let
+    // 🧱 Expectation: Certain trust, opcode matches the registry
+    let mut bearer = Bearer::new();
+    let trust = bearer.resolve_instruction_ref("ref:let");
+
+    let expected = get_instruction_registry()["let"].opcode;
+
+    assert_eq!(trust, TrustTier::Certain);
+    assert_eq!(bearer.instruction_refs.get("let"), Some(&expected));
+    assert_eq!(bearer.trust_flags.get("ref:let"), Some(&TrustTier::Certain));
+    assert!(bearer.errors.is_empty());
+}
+
+#[test]
+fn test_instruction_ref_unknown_is_invalid() {
+    // 🧪 Input: ref:nope
+    // 🧱 Expectation: Invalid trust, no opcode stored, one error entry
+    let mut bearer = Bearer::new();
+    let trust = bearer.resolve_instruction_ref("ref:nope");
+
+    assert_eq!(trust, TrustTier::Invalid);
+    assert!(bearer.instruction_refs.is_empty());
+    assert_eq!(bearer.trust_flags.get("ref:nope"), Some(&TrustTier::Invalid));
+    assert_eq!(bearer.errors.len(), 1);
+    assert_eq!(bearer.errors[0].input, "ref:nope");
+}
+
+// ==============================================
+// 🌳 Resolution Entry Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `Bearer::for_tree` walks a tree and resolves instruction operands
+//   - Arity mismatches are reported instead of resolved
+// ==============================================
+
+#[test]
+fn test_for_tree_resolves_operands() {
+    // 🧪 Input:
+    // let faith
+    // speak "truth"
+    // 🧱 Expectation:
+    // One String literal operand, trusted as Certain
+    let tree = ScrollTree {
+        nodes: vec![
+            declare("faith"),
+            ScrollNode::instruction("speak", &["\"truth\""]),
+        ],
+        ..Default::default()
+    };
+
+    let bearer = Bearer::for_tree(tree);
+
+    assert_eq!(
+        bearer.resolved_operands,
+        vec![Operand::Literal {
+            value: "truth".to_string(),
+            dtype: Some(OperandType::String),
+        }]
+    );
+    assert_eq!(bearer.trust_flags.get("\"truth\""), Some(&TrustTier::Certain));
+    assert!(bearer.scroll_tree.is_some());
+    assert!(bearer.errors.is_empty());
+}
+
+#[test]
+fn test_for_tree_rejects_arity_mismatch() {
+    // 🧪 Input: wait 5s 1 — schema expects at most one operand
+    // 🧱 Expectation: nothing resolved, one trace entry flagged
+    let tree = ScrollTree {
+        nodes: vec![ScrollNode::instruction("wait", &["5s", "1"])],
+        ..Default::default()
+    };
+
+    let bearer = Bearer::for_tree(tree);
+
+    assert!(bearer.resolved_operands.is_empty());
+    assert!(bearer
+        .debug_trace
+        .iter()
+        .any(|entry| matches!(entry.severity, Severity::Error)));
+}
+
+#[test]
+fn test_begin_resolution_auto_loads_schema() {
+    // 🧪 Input: speak "truth" — nothing loaded beforehand
+    // 🧱 Expectation: Ok, with speak's schema loaded by the Bearer itself
+    let tree = ScrollTree {
+        nodes: vec![ScrollNode::instruction("speak", &["\"truth\""])],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+    assert!(bearer.instruction_schema.is_none());
+
+    assert_eq!(bearer.begin_resolution(tree), Ok(()));
+    assert_eq!(bearer.current_instruction.as_deref(), Some("speak"));
+    assert!(bearer.instruction_schema.is_some());
+    assert_eq!(bearer.resolved_operands.len(), 1);
+}
+
+#[test]
+fn test_begin_resolution_refuses_unknown_instruction() {
+    // 🧪 Input: chant "truth" — not in the registry
+    // 🧱 Expectation: UnknownInstruction, one Error entry, nothing resolved
+    let tree = ScrollTree {
+        nodes: vec![ScrollNode::instruction("chant", &["\"truth\""])],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+
+    assert_eq!(
+        bearer.begin_resolution(tree),
+        Err(ResolutionError::UnknownInstruction {
+            name: "chant".to_string(),
+        })
+    );
+    assert!(bearer.resolved_operands.is_empty());
+    assert!(bearer
+        .debug_trace
+        .iter()
+        .any(|entry| entry.input == "chant" && entry.severity == Severity::Error));
+}
+
+#[test]
+fn test_unknown_instruction_suggests_near_keyword() {
+    // 🧪 Input: walkk shepherd — one edit away from `walk`
+    // 🧱 Expectation: the Error entry suggests `walk`
+    let tree = ScrollTree {
+        nodes: vec![ScrollNode::instruction("walkk", &["shepherd"])],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+    assert!(bearer.begin_resolution(tree).is_err());
+
+    let entry = bearer
+        .debug_trace
+        .iter()
+        .find(|entry| entry.input == "walkk")
+        .expect("unknown instruction recorded");
+    assert!(entry
+        .suggestions
+        .contains(&"did you mean 'walk'?".to_string()));
+}
+
+#[test]
+fn test_distant_ref_gets_no_suggestion() {
+    // 🧪 Input: ref:zzzzzz — nothing within the edit threshold
+    // 🧱 Expectation: reported as Invalid, with no "did you mean"
+    let mut bearer = Bearer::new();
+
+    assert_eq!(
+        bearer.resolve_instruction_ref("ref:zzzzzz"),
+        TrustTier::Invalid
+    );
+    assert!(bearer.errors[0]
+        .suggestions
+        .iter()
+        .all(|note| !note.starts_with("did you mean")));
+}
+
+#[test]
+fn test_bearer_default_matches_new() {
+    let bearer = Bearer::default();
+
+    assert!(bearer.resolved_operands.is_empty());
+    assert!(bearer.scroll_tree.is_none());
+    assert_eq!(bearer.environment.depth(), 1);
+}
+
+// ==============================================
+// 🔁 Group Homogeneity Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - A group of one operand type passes a uniform slot
+//   - A mixed group is flagged as `Severity::Fault`
+//   - `Value` slots accept mixed groups
+// ==============================================
+
+fn literal(value: &str, dtype: OperandType) -> Operand {
+    Operand::Literal {
+        value: value.to_string(),
+        dtype: Some(dtype),
+    }
+}
+
+#[test]
+fn test_group_homogeneous_passes() {
+    // 🧪 Input: (1, 2, *) in a Literal slot
+    // 🧱 Expectation: passes — wildcards match any type
+    let group = Operand::Group(vec![
+        literal("1", OperandType::Integer),
+        literal("2", OperandType::Integer),
+        Operand::Wildcard,
+    ]);
+
+    let mut bearer = Bearer::new();
+
+    assert!(bearer.validate_group_homogeneity(&group, &OperandKind::Literal));
+    assert!(bearer.errors.is_empty());
+}
+
+#[test]
+fn test_group_mixed_kinds_flagged() {
+    // 🧪 Input: (exodus, 40) — a label and a literal
+    // 🧱 Expectation: flagged in a Label slot, accepted in a Value slot
+    let group = Operand::Group(vec![
+        Operand::Binding {
+            name: "exodus".to_string(),
+            alignment: None,
+        },
+        literal("40", OperandType::Integer),
+    ]);
+
+    let mut bearer = Bearer::new();
+
+    assert!(!bearer.validate_group_homogeneity(&group, &OperandKind::Label));
+    assert_eq!(bearer.errors.len(), 1);
+    assert_eq!(bearer.errors[0].severity, Severity::Fault);
+
+    assert!(bearer.validate_group_homogeneity(&group, &OperandKind::Value));
+    assert_eq!(bearer.errors.len(), 1);
+}
+
+// ==============================================
+// 🔚 Stray Return Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `return` inside a block within a function is allowed
+//   - A bare top-level `return` is flagged as `Severity::Drift`
+// ==============================================
+
+#[test]
+fn test_return_inside_function_block_allowed() {
+    // 🧪 Input:
+    // walk shepherd() { { return flock } }
+    // 🧱 Expectation: no entries
+    let tree = ScrollTree {
+        nodes: vec![ScrollNode::Function {
+            name: "shepherd".to_string(),
+            params: vec![],
+            body: vec![ScrollNode::Block(vec![ScrollNode::Return(
+                "flock".to_string(),
+            )])],
+        }],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+
+    assert_eq!(bearer.detect_stray_returns(&tree), 0);
+    assert!(bearer.errors.is_empty());
+}
+
+#[test]
+fn test_top_level_return_flagged() {
+    // 🧪 Input:
+    // return flock
+    // if grace { return mercy }
+    // 🧱 Expectation: two Drift entries — neither return has a function context
+    let tree = ScrollTree {
+        nodes: vec![
+            ScrollNode::Return("flock".to_string()),
+            ScrollNode::Conditional {
+                condition: "grace".to_string(),
+                body: vec![ScrollNode::Return("mercy".to_string())],
+            },
+        ],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+
+    assert_eq!(bearer.detect_stray_returns(&tree), 2);
+    assert_eq!(bearer.errors[0].input, "return flock");
+    assert_eq!(bearer.errors[0].severity, Severity::Drift);
+}
+
+#[test]
+fn test_tuple_return_constructs_group() {
+    // 🧪 Input: the value of `return (1, 2)`
+    // 🧱 Expectation: a two-element Group of Integer literals
+    let bearer = Bearer::new();
+    let integer = |value: &str| Operand::Literal {
+        value: value.to_string(),
+        dtype: Some(OperandType::Integer),
+    };
+
+    assert_eq!(
+        bearer.construct_return("(1, 2)"),
+        Operand::Group(vec![integer("1"), integer("2")])
+    );
+    assert_eq!(bearer.construct_return("1"), integer("1"));
+}
+
+// ==============================================
+// 🫙 Empty Body Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - An `if` with an empty body is flagged as `Severity::Drift`
+//   - A non-empty body passes
+//   - `allow_empty_blocks` silences the check
+// ==============================================
+
+fn conditional(body: Vec<ScrollNode>) -> ScrollTree {
+    ScrollTree {
+        nodes: vec![ScrollNode::Conditional {
+            condition: "grace".to_string(),
+            body,
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_empty_if_body_flagged() {
+    // 🧪 Input: if grace {}
+    // 🧱 Expectation: one Drift entry naming the conditional
+    let tree = conditional(vec![ScrollNode::Block(vec![])]);
+
+    let mut bearer = Bearer::new();
+
+    assert_eq!(bearer.detect_empty_blocks(&tree), 1);
+    assert_eq!(bearer.errors[0].input, "if grace");
+    assert_eq!(bearer.errors[0].severity, Severity::Drift);
+
+    // 🧪 Same scroll with empty blocks allowed → nothing flagged
+    let mut bearer = Bearer::new();
+    bearer.allow_empty_blocks = true;
+
+    assert_eq!(bearer.detect_empty_blocks(&tree), 0);
+    assert!(bearer.errors.is_empty());
+}
+
+#[test]
+fn test_non_empty_if_body_not_flagged() {
+    // 🧪 Input: if grace { speak "mercy" }
+    // 🧱 Expectation: no entries
+    let speak = ScrollNode::instruction("speak", &["\"mercy\""]);
+    let tree = conditional(vec![ScrollNode::Block(vec![speak])]);
+
+    let mut bearer = Bearer::new();
+
+    assert_eq!(bearer.detect_empty_blocks(&tree), 0);
+    assert!(bearer.errors.is_empty());
+}
+
+// ==============================================
+// ❌ Invalid Operand Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Unclassifiable tokens keep their text, a reason, and a line
+// ==============================================
+
+#[test]
+fn test_invalid_operand_records_reason_and_line() {
+    // 🧪 Input:
+    // speak 1
+    // {
+    //     speak %
+    // }
+    // 🧱 Expectation: the stray `%` is an InvalidOperand with a reason,
+    //    stamped with line 3 from the parsed tree's spans
+    let keywords = get_instruction_registry()
+        .keys()
+        .map(|keyword| (keyword.to_string(), TokenType::Instruction))
+        .collect();
+    let tokens = Tokenizer::new("speak 1\n{\n    speak %\n}", keywords)
+        .tokenize()
+        .tokens;
+    let tree = Parser::new(tokens).parse();
+
+    let bearer = Bearer::for_tree(tree);
+    let invalid = bearer
+        .resolved_operands
+        .iter()
+        .find(|operand| matches!(operand, Operand::InvalidOperand { .. }));
+
+    assert_eq!(
+        invalid,
+        Some(&Operand::InvalidOperand {
+            text: "%".to_string(),
+            reason: "expected literal, symbol, or path, got an unrecognized form".to_string(),
+            line: 3,
+        })
+    );
+
+    // 🔢 Outside a tree there is no line to stamp
+    let bearer = Bearer::new();
+    let operand_type = bearer.classify_operand_type("12abc");
+    assert_eq!(operand_type, OperandType::Unknown);
+    assert_eq!(
+        bearer.construct_operand("12abc", operand_type),
+        Operand::InvalidOperand {
+            text: "12abc".to_string(),
+            reason: "expected numeric, got a malformed number".to_string(),
+            line: 0,
+        }
+    );
+}
+
+// ==============================================
+// 🧠 Operand Hint Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Tokenizer hints override inference during classification
+//   - Unhinted tokens fall back to `classify_operand_type`
+// ==============================================
+
+#[test]
+fn test_hinted_token_resolves_to_hinted_type() {
+    // 🧪 Input: `10: 10` — the first number is written as a label
+    // 🧱 Expectation: hinted `10` is a Symbol; unhinted `10` infers Integer
+    let tokens = Tokenizer::new("10: 10", Default::default()).tokenize().tokens;
+    let bearer = Bearer::new();
+
+    assert_eq!(tokens[0].hint, Some(OperandHint::Label));
+    assert_eq!(bearer.classify_token(&tokens[0]), OperandType::Symbol);
+
+    let unhinted = tokens.last().unwrap();
+    assert_eq!(unhinted.hint, None);
+    assert_eq!(bearer.classify_token(unhinted), OperandType::Integer);
+}
+
+// ==============================================
+// 🏷 Trust Annotation Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `@trusted` sets the next operand's tier, overriding inference
+//   - The override is recorded in the trace, and the annotation is not an operand
+//   - A quoted `"@trusted"` is text, not an annotation
+// ==============================================
+
+#[test]
+fn test_trusted_annotation_raises_shadowed_operand() {
+    // 🧪 Input:
+    // bless externalValue
+    // bless @trusted externalValue
+    // 🧱 Expectation: the bare operand is Shadowed; the annotated one is
+    //    Trusted, with the override traced and one operand resolved
+    let keywords: HashMap<String, TokenType> = get_instruction_registry()
+        .keys()
+        .map(|keyword| (keyword.to_string(), TokenType::Instruction))
+        .collect();
+    let parse = |source: &str| {
+        let tokens = Tokenizer::new(source, keywords.clone()).tokenize().tokens;
+        Parser::new(tokens).parse()
+    };
+
+    let inferred = Bearer::for_tree(parse("bless externalValue"));
+    assert_eq!(
+        inferred.trust_flags.get("externalValue"),
+        Some(&TrustTier::Shadowed)
+    );
+
+    let tree = parse("bless @trusted externalValue");
+    assert_eq!(
+        tree.nodes[0],
+        ScrollNode::instruction("bless", &["@trusted", "externalValue"])
+    );
+
+    let annotated = Bearer::for_tree(tree);
+    assert_eq!(
+        annotated.trust_flags.get("externalValue"),
+        Some(&TrustTier::Trusted)
+    );
+    assert_eq!(annotated.resolved_operands.len(), 1);
+    assert!(annotated.debug_trace.iter().any(|entry| {
+        entry.expected == "Shadowed (inferred)" && entry.actual == "Trusted (annotated)"
+    }));
+    assert!(annotated.errors.is_empty(), "{:#?}", annotated.errors);
+}
+
+#[test]
+fn test_quoted_annotation_text_stays_an_operand() {
+    // 🧪 Input:
+    // speak "@trusted"
+    // 🧱 Expectation: the string literal keeps its quotes, so it is an
+    //    operand with no tier override rather than an annotation
+    let keywords: HashMap<String, TokenType> = get_instruction_registry()
+        .keys()
+        .map(|keyword| (keyword.to_string(), TokenType::Instruction))
+        .collect();
+    let tokens = Tokenizer::new("speak \"@trusted\"", keywords)
+        .tokenize()
+        .tokens;
+    let tree = Parser::new(tokens).parse();
+
+    let ScrollNode::Instruction { args, .. } = &tree.nodes[0] else {
+        panic!("Expected Instruction node");
+    };
+    assert_eq!(args, &["\"@trusted\""]);
+    assert!(!is_trust_annotation(&args[0]));
+
+    let (operands, overrides) = Bearer::split_trust_annotations(args);
+    assert_eq!(operands, vec!["\"@trusted\"".to_string()]);
+    assert_eq!(overrides, vec![None]);
+}
+
+// ==============================================
+// ⏳ Duration Operand Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Unit-suffixed literals resolve to a structured `Duration`
+//   - `wait` still resolves with no operand at all
+// ==============================================
+
+#[test]
+fn test_wait_duration_resolves_to_duration_operand() {
+    // 🧪 Input:
+    // wait 5s
+    // wait
+    // 🧱 Expectation: one operand, a five-second Duration trusted as Certain;
+    //    the bare `wait` resolves cleanly with nothing to add
+    let keywords = get_instruction_registry()
+        .keys()
+        .map(|keyword| (keyword.to_string(), TokenType::Instruction))
+        .collect();
+    let tokens = Tokenizer::new("wait 5s\nwait", keywords).tokenize().tokens;
+    let tree = Parser::new(tokens).parse();
+
+    assert_eq!(tree.nodes[0], ScrollNode::instruction("wait", &["5s"]));
+
+    let bearer = Bearer::for_tree(tree);
+
+    assert_eq!(
+        bearer.resolved_operands,
+        vec![Operand::Duration(Duration::from_secs(5))]
+    );
+    assert_eq!(bearer.trust_flags.get("5s"), Some(&TrustTier::Certain));
+    assert!(bearer.errors.is_empty(), "{:#?}", bearer.errors);
+}
+
+// ==============================================
+// 🕊️ Divine Privilege Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `Divine` instructions are Fatal without `divine_unlock`
+//   - With the unlock they resolve like any other instruction
+// ==============================================
+
+fn divine_bearer() -> Bearer {
+    let registry = InstructionRegistry::from_toml_str(
+        r#"
+[[instruction]]
+keyword = "erase"
+verse_anchor = "Rev 21:4"
+category = "Control"
+description = "Wipe away every tear."
+opcode = 0xE0
+machine_code = "E0"
+operand_count = 1
+operand_schema = ["Target"]
+privilege_level = "Divine"
+"#,
+    )
+    .expect("divine registry should load");
+
+    Bearer::new().with_registry(Arc::new(registry))
+}
+
+fn erase_tree() -> ScrollTree {
+    ScrollTree {
+        nodes: vec![ScrollNode::instruction("erase", &["tears"])],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_divine_instruction_without_unlock_is_fatal() {
+    // 🧪 Input: erase tears  (`erase` is Divine), no unlock
+    // 🧱 Expectation: one Fatal entry; no operands resolved
+    let mut bearer = divine_bearer();
+    bearer.begin_resolution(erase_tree()).unwrap();
+
+    let fatal: Vec<_> = bearer
+        .debug_trace
+        .iter()
+        .filter(|entry| entry.severity == Severity::Fatal)
+        .collect();
+    assert_eq!(fatal.len(), 1);
+    assert_eq!(fatal[0].input, "erase");
+    assert!(bearer.resolved_operands.is_empty());
+}
+
+#[test]
+fn test_divine_instruction_with_unlock_resolves() {
+    // 🧪 Input: erase tears with `divine_unlock` set
+    // 🧱 Expectation: no Fatal entry; `tears` resolves as an operand
+    let mut bearer = divine_bearer();
+    bearer.divine_unlock = true;
+    bearer.begin_resolution(erase_tree()).unwrap();
+
+    assert!(bearer
+        .debug_trace
+        .iter()
+        .all(|entry| entry.severity != Severity::Fatal));
+    assert_eq!(bearer.resolved_operands.len(), 1);
+}
+
+/// 📶 Progress reports heard by `record_progress`, in call order.
+static PROGRESS: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+fn record_progress(resolved: usize, total: usize) {
+    PROGRESS.lock().unwrap().push((resolved, total));
+}
+
+#[test]
+fn test_progress_reported_once_per_instruction() {
+    // 🧪 Input: speak, then a block holding wait and speak
+    // 🧱 Expectation: three reports, counting up to the total of three
+    let speak = || ScrollNode::instruction("speak", &["\"truth\""]);
+    let tree = ScrollTree {
+        nodes: vec![
+            speak(),
+            ScrollNode::Block(vec![ScrollNode::instruction("wait", &[]), speak()]),
+        ],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new().with_progress(record_progress);
+    bearer.begin_resolution(tree).unwrap();
+
+    assert_eq!(*PROGRESS.lock().unwrap(), vec![(1, 3), (2, 3), (3, 3)]);
+    assert_eq!(bearer.progress, (3, 3));
+}
+
+// ==============================================
+// 🗃 Resolution Cache Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Identical instructions reuse the first resolution
+//   - A swapped registry empties the cache
+//   - Binding operands are re-resolved in whatever scope is current
+// ----------------------------------------------
+
+#[test]
+fn test_repeated_instruction_hits_cache() {
+    // 🧪 Input: speak "truth" twice
+    // 🧱 Expectation: the second resolves from the cache, with equal operands
+    let speak = || ScrollNode::instruction("speak", &["\"truth\""]);
+    let tree = ScrollTree {
+        nodes: vec![speak(), speak()],
+        ..Default::default()
+    };
+
+    let bearer = Bearer::for_tree(tree);
+
+    assert_eq!(bearer.cache_hits, 1);
+    assert_eq!(bearer.resolution_cache.len(), 1);
+    assert_eq!(bearer.resolved_operands.len(), 2);
+    assert_eq!(bearer.resolved_operands[0], bearer.resolved_operands[1]);
+}
+
+#[test]
+fn test_binding_operands_resolve_against_current_scope() {
+    // 🧪 Input: speak x with `x` global, then again with a local `x` shadowing it
+    // 🧱 Expectation: each resolution carries the alignment of its own scope,
+    //    and the scope-dependent instruction never enters the cache
+    let speak = || ScrollTree {
+        nodes: vec![ScrollNode::instruction("speak", &["x"])],
+        ..Default::default()
+    };
+    let binding = |alignment| Operand::Binding {
+        name: "x".to_string(),
+        alignment: Some(alignment),
+    };
+
+    let mut bearer = Bearer::new();
+    bearer.environment.declare("x");
+    bearer.begin_resolution(speak()).unwrap();
+    assert_eq!(
+        bearer.resolved_operands.last(),
+        Some(&binding(BindingScope::Global))
+    );
+
+    bearer.environment.push_scope();
+    bearer.environment.declare("x");
+    bearer.begin_resolution(speak()).unwrap();
+    assert_eq!(
+        bearer.resolved_operands.last(),
+        Some(&binding(BindingScope::Local))
+    );
+
+    assert_eq!(bearer.cache_hits, 0);
+    assert!(bearer.resolution_cache.is_empty());
+}
+
+#[test]
+fn test_registry_change_invalidates_cache() {
+    // 🧪 Input: speak "truth", then a new registry, then speak "truth" again
+    // 🧱 Expectation: no hit — the cache was emptied by the swap
+    let speak = || ScrollTree {
+        nodes: vec![ScrollNode::instruction("speak", &["\"truth\""])],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+    bearer.begin_resolution(speak()).unwrap();
+    assert_eq!(bearer.resolution_cache.len(), 1);
+
+    bearer.instruction_registry = Arc::new(InstructionRegistry::new());
+    bearer.begin_resolution(speak()).unwrap();
+
+    assert_eq!(bearer.cache_hits, 0);
+    assert!(Arc::ptr_eq(
+        &bearer.cache_registry,
+        &bearer.instruction_registry
+    ));
+}
+
+// ==============================================
+// 🧺 Partial Resolution Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `resolve_all` resolves the rest of the tree past a bad instruction
+//   - The report carries one status per instruction, in tree order
+//   - `let x = 1` resolves; an unknown `ref:` does not
+// ----------------------------------------------
+
+#[test]
+fn test_resolve_all_reports_invalid_and_resolves_the_rest() {
+    // 🧪 Input:
+    // speak "truth"
+    // wait 5s 1   ← schema expects at most one operand
+    // wait 5s
+    // 🧱 Expectation: speak and the second wait resolve; the first wait is reported
+    let tree = ScrollTree {
+        nodes: vec![
+            ScrollNode::instruction("speak", &["\"truth\""]),
+            ScrollNode::instruction("wait", &["5s", "1"]),
+            ScrollNode::instruction("wait", &["5s"]),
+        ],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+    let report = bearer.resolve_all(&tree);
+
+    assert_eq!(report.instructions.len(), 3);
+    assert_eq!(report.resolved().count(), 2);
+    assert!(!report.is_clean());
+
+    let invalid: Vec<_> = report.invalid().collect();
+    assert_eq!(invalid.len(), 1);
+    assert_eq!(invalid[0].args, vec!["5s", "1"]);
+    assert!(matches!(invalid[0].status, ResolutionStatus::Invalid(_)));
+
+    assert_eq!(bearer.resolved_operands.len(), 2);
+    assert_eq!(bearer.progress, (3, 3));
+}
+
+#[test]
+fn test_resolve_all_accepts_let_with_equals() {
+    // 🧪 Input: let x = 1  (parsed from source)
+    // 🧱 Expectation: the `=` is not counted, so `let` resolves with 2 operands
+    let keywords = get_instruction_registry()
+        .keys()
+        .map(|keyword| (keyword.to_string(), TokenType::Instruction))
+        .collect();
+    let tokens = Tokenizer::new("let x = 1", keywords).tokenize().tokens;
+    let tree = Parser::new(tokens).parse();
+
+    let report = Bearer::new().resolve_all(&tree);
+
+    assert_eq!(report.instructions.len(), 1);
+    assert_eq!(report.instructions[0].args, vec!["x", "=", "1"]);
+    assert_eq!(report.instructions[0].status, ResolutionStatus::Resolved);
+}
+
+#[test]
+fn test_resolve_all_flags_unknown_instruction_ref() {
+    // 🧪 Input: speak ref:nope
+    // 🧱 Expectation: the unknown reference lands in `errors` and marks the
+    //    instruction Invalid
+    let tree = ScrollTree {
+        nodes: vec![ScrollNode::instruction("speak", &["ref:nope"])],
+        ..Default::default()
+    };
+
+    let mut bearer = Bearer::new();
+    let report = bearer.resolve_all(&tree);
+
+    assert_eq!(bearer.errors.len(), 1);
+    assert!(matches!(
+        &report.instructions[0].status,
+        ResolutionStatus::Invalid(reason) if reason.contains("nope")
+    ));
+}
+
+// ===========================================================
+// 📋 Test Log Summary — Operand Resolver Verification
+// ===========================================================
+//
+// 🛠 Usage:
+//   - Run with `cargo test -- --nocapture` to view this output
+//
+// 📌 Note:
+//   - This summary is **non-evaluative** (does not affect result state)
+//
+// ===========================================================
+
+#[test]
+fn test_log_operand_resolver_summary() {
+    println!("✅ test_shadowed_declaration_same_scope: PASSED");
+    println!("✅ test_shadowed_declaration_nested_scope_allowed: PASSED");
+    println!("✅ test_shadowed_declaration_inside_nested_block: PASSED");
+    println!("✅ test_environment_block_binding_does_not_leak: PASSED");
+    println!("✅ test_shadowed_declaration_pass_leaves_environment_untouched: PASSED");
+    println!("✅ test_environment_outer_binding_visible_inside: PASSED");
+    println!("✅ test_environment_resolves_imported_paths_as_extern: PASSED");
+    println!("✅ test_instruction_ref_resolves_known_keyword: PASSED");
+    println!("✅ test_instruction_ref_unknown_is_invalid: PASSED");
+    println!("✅ test_for_tree_resolves_operands: PASSED");
+    println!("✅ test_for_tree_rejects_arity_mismatch: PASSED");
+    println!("✅ test_begin_resolution_auto_loads_schema: PASSED");
+    println!("✅ test_begin_resolution_refuses_unknown_instruction: PASSED");
+    println!("✅ test_unknown_instruction_suggests_near_keyword: PASSED");
+    println!("✅ test_distant_ref_gets_no_suggestion: PASSED");
+    println!("✅ test_bearer_default_matches_new: PASSED");
+    println!("✅ test_group_homogeneous_passes: PASSED");
+    println!("✅ test_group_mixed_kinds_flagged: PASSED");
+    println!("✅ test_return_inside_function_block_allowed: PASSED");
+    println!("✅ test_top_level_return_flagged: PASSED");
+    println!("✅ test_tuple_return_constructs_group: PASSED");
+    println!("✅ test_empty_if_body_flagged: PASSED");
+    println!("✅ test_non_empty_if_body_not_flagged: PASSED");
+    println!("✅ test_invalid_operand_records_reason_and_line: PASSED");
+    println!("✅ test_hinted_token_resolves_to_hinted_type: PASSED");
+    println!("✅ test_trusted_annotation_raises_shadowed_operand: PASSED");
+    println!("✅ test_quoted_annotation_text_stays_an_operand: PASSED");
+    println!("✅ test_wait_duration_resolves_to_duration_operand: PASSED");
+    println!("✅ test_divine_instruction_without_unlock_is_fatal: PASSED");
+    println!("✅ test_divine_instruction_with_unlock_resolves: PASSED");
+    println!("✅ test_progress_reported_once_per_instruction: PASSED");
+    println!("✅ test_repeated_instruction_hits_cache: PASSED");
+    println!("✅ test_binding_operands_resolve_against_current_scope: PASSED");
+    println!("✅ test_registry_change_invalidates_cache: PASSED");
+    println!("✅ test_resolve_all_reports_invalid_and_resolves_the_rest: PASSED");
+    println!("✅ test_resolve_all_accepts_let_with_equals: PASSED");
+    println!("✅ test_resolve_all_flags_unknown_instruction_ref: PASSED");
+}
@@ -16,7 +16,12 @@
 // ----------------------------------------------------------
 
 use tablet::parser::*; // 🧱 Primary parser under test
-use tablet::tokenizer::{Token, TokenType}; // 🧩 Input token structure
+use tablet::tokenizer::{Token, TokenType, Tokenizer}; // 🧩 Input token structure + source scanner
+use tablet::instruction_registry::shared_registry; // 🧭 Instruction source for tokenizer registry
+use tablet::instruction_registry::{InstructionRegistry, PrivilegeLevel}; // 🩺 Registry consulted by `validate`
+use tablet::instruction_registry::{BitMode, EncodeConfig, EncodeError, JumpMode}; // 🧬 Targets for `assemble`
+use std::collections::BTreeMap; // 🛰 Imported scrolls by name
+use std::sync::Arc; // 🤝 Registries handed to `Parser::with_registry`
 
 // ----------------------------------------------------------
 // 🧰 Token Builder — Helper for manual token construction
@@ -31,9 +36,40 @@ fn token(t: TokenType, value: &str) -> Token {
         value: value.to_string(),
         line: 0,      // 🔢 Not relevant for unit tests
         column: 0,
+        hint: None,
+        quoted: false,
     }
 }
 
+// ----------------------------------------------------------
+// 🌳 Tree Helpers — Source parsing & structural comparison
+// ----------------------------------------------------------
+//
+//   `parse_source` runs raw scroll text through Tokenizer → Parser.
+//   `assert_tree_eq` compares two trees node-by-node, reporting the
+//   first diverging index instead of one large Debug dump.
+//
+fn parse_source(source: &str) -> ScrollTree {
+    let tokens = Tokenizer::from_registry(source, &shared_registry())
+        .tokenize()
+        .tokens;
+    Parser::new(tokens).parse()
+}
+
+fn assert_tree_eq(left: &ScrollTree, right: &ScrollTree) {
+    assert_eq!(
+        left.nodes.len(),
+        right.nodes.len(),
+        "ScrollTree node count differs"
+    );
+
+    for (i, (l, r)) in left.nodes.iter().zip(&right.nodes).enumerate() {
+        assert_eq!(l, r, "ScrollTree diverges at node {}", i);
+    }
+
+    assert_eq!(left, right);
+}
+
 // ==============================================
 // 🧪 Instruction & Sentence-Based Parsing Tests
 // ==============================================
@@ -94,6 +130,162 @@ fn test_scroll_sentence_parsing() {
     }
 }
 
+/// 🧹 Splits `source` into alternating `Whitespace` / `Identifier` tokens,
+/// as an external token source that keeps spacing might.
+fn spaced_tokens(source: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    for c in source.chars() {
+        let ty = if c.is_whitespace() {
+            TokenType::Whitespace
+        } else {
+            TokenType::Identifier
+        };
+        match tokens.last_mut() {
+            Some(last) if last.token_type == ty => last.value.push(c),
+            _ => tokens.push(token(ty, &c.to_string())),
+        }
+    }
+    tokens
+}
+
+#[test]
+fn test_scroll_sentence_skips_whitespace_tokens() {
+    // 🧪 Input: "  The   priest   speaks   truth  " with spacing kept as tokens
+    // 🧱 Expectation: a clean triple — no whitespace subject, verb, or object
+    let mut parser = Parser::new(spaced_tokens("  The   priest   speaks   truth  "));
+
+    assert_eq!(
+        parser.parse_scroll_sentence(),
+        Some(ScrollNode::ScrollSentence {
+            subject: "The priest".to_string(),
+            verb: "speaks".to_string(),
+            object: "truth".to_string(),
+        })
+    );
+
+    parser.skip_trivia();
+    assert!(parser.peek().is_none());
+}
+
+#[test]
+fn test_scroll_sentence_object_spans_expression() {
+    // 🧪 Input: "The priest speaks 5 + 3"
+    // 🧱 Expectation: the whole expression is the object, not just `5`
+    let mut parser = Parser::new(source_tokens("The priest speaks 5 + 3"));
+
+    assert_eq!(
+        parser.parse_scroll_sentence(),
+        Some(ScrollNode::ScrollSentence {
+            subject: "The priest".to_string(),
+            verb: "speaks".to_string(),
+            object: "5 + 3".to_string(),
+        })
+    );
+    assert!(parser.peek().is_none());
+}
+
+#[test]
+fn test_scroll_sentence_object_ends_at_terminator_or_line() {
+    // 🧪 Input: a sentence closed by `.`, then one closed by its line break
+    // 🧱 Expectation: each multi-word object stops at its sentence's end
+    let mut parser = Parser::new(source_tokens(
+        "God is light and truth. scroll invokes clarity of mind\nwalk",
+    ));
+
+    assert_eq!(
+        parser.parse_scroll_sentence(),
+        Some(ScrollNode::ScrollSentence {
+            subject: "God".to_string(),
+            verb: "is".to_string(),
+            object: "light and truth".to_string(),
+        })
+    );
+    assert_eq!(
+        parser.parse_scroll_sentence(),
+        Some(ScrollNode::ScrollSentence {
+            subject: "scroll".to_string(),
+            verb: "invokes".to_string(),
+            object: "clarity of mind".to_string(),
+        })
+    );
+    assert_eq!(parser.peek().map(|t| t.value.as_str()), Some("walk"));
+}
+
+#[test]
+fn test_walkers_skip_whitespace_before_and_between_args() {
+    // 🧪 Input: " shepherd( flock ,  staff ) " with spacing kept as tokens
+    // 🧱 Expectation: the same call node as the unspaced form
+    let tokens = vec![
+        token(TokenType::Whitespace, " "),
+        token(TokenType::Identifier, "shepherd"),
+        token(TokenType::GroupMarker, "("),
+        token(TokenType::Whitespace, " "),
+        token(TokenType::Identifier, "flock"),
+        token(TokenType::Punctuation, ","),
+        token(TokenType::Whitespace, "  "),
+        token(TokenType::Identifier, "staff"),
+        token(TokenType::Whitespace, " "),
+        token(TokenType::GroupMarker, ")"),
+        token(TokenType::Whitespace, " "),
+    ];
+
+    let tree = Parser::new(tokens).parse();
+
+    assert_eq!(
+        tree.nodes,
+        vec![ScrollNode::Call {
+            function: "shepherd".to_string(),
+            args: vec!["flock".to_string(), "staff".to_string()],
+            named: vec![],
+        }]
+    );
+}
+
+#[test]
+fn test_leading_blank_lines_produce_no_error_nodes() {
+    // 🧪 Input: blank lines before `speak "hi"`, then a hand-built stream
+    //    with newline trivia, stray `;` separators, and an empty end marker
+    // 🧱 Expectation: one instruction node each time, and no parse errors
+    let tree = parse_source("\n\n\nspeak \"hi\"");
+    assert_eq!(tree.nodes, vec![ScrollNode::instruction("speak", &["\"hi\""])]);
+
+    let tokens = vec![
+        token(TokenType::Whitespace, "\n\n"),
+        token(TokenType::Punctuation, ";"),
+        token(TokenType::Instruction, "speak"),
+        token(TokenType::Literal, "\"hi\""),
+        token(TokenType::Punctuation, ";"),
+        token(TokenType::Whitespace, "\n"),
+        token(TokenType::Error, ""),
+    ];
+    let mut parser = Parser::new(tokens);
+    let tree = parser.parse();
+
+    assert_eq!(
+        tree.nodes,
+        vec![ScrollNode::instruction("speak", &["\"hi\""])]
+    );
+    assert!(parser.errors().is_empty(), "{:#?}", parser.errors());
+}
+
+#[test]
+fn test_stray_separator_inside_block_keeps_block_open() {
+    // 🧪 Input: { speak "a"; speak "b" } across lines
+    // 🧱 Expectation: both instructions stay inside the block, no errors
+    let tokens = source_tokens("{\n    speak \"a\";\n    speak \"b\"\n}");
+    let mut parser = Parser::new(tokens);
+    let tree = parser.parse();
+
+    assert_eq!(
+        tree.nodes,
+        vec![ScrollNode::Block(vec![
+            ScrollNode::instruction("speak", &["\"a\""]),
+            ScrollNode::instruction("speak", &["\"b\""]),
+        ])]
+    );
+    assert!(parser.errors().is_empty(), "{:#?}", parser.errors());
+}
+
 // ==============================================
 // 🧾 Assignment & Call Tests
 // ==============================================
@@ -130,6 +322,31 @@ fn test_assignment_parsing() {
     }
 }
 
+#[test]
+fn test_sum_assignments_parse_whole() {
+    // 🧪 Input:
+    // x = "a" + name + 3
+    // let y = 2 + 3
+    // 🧱 Expectation: one Assignment holding the sum text, and a `let`
+    //    whose `2 + 3` fills a single operand slot — no errors
+    let (tree, errors) = parse_errors("x = \"a\" + name + 3\nlet y = 2 + 3");
+
+    assert_eq!(errors, vec![]);
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::assignment("x", "\"a\" + name + 3"),
+            ScrollNode::Instruction {
+                name: "let".to_string(),
+                args: vec!["y", "=", "2", "+", "3"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            },
+        ]
+    );
+}
+
 #[test]
 fn test_function_call() {
     // 🧪 Input:
@@ -148,7 +365,7 @@ fn test_function_call() {
     let node = parser.parse_assignment_or_call().unwrap();
 
     match node {
-        ScrollNode::Call { function, args } => {
+        ScrollNode::Call { function, args, .. } => {
             assert_eq!(function, "invoke");
             assert_eq!(args, vec!["\"grace\"", "\"mercy\""]);
         }
@@ -156,6 +373,58 @@ fn test_function_call() {
     }
 }
 
+#[test]
+fn test_named_call_arguments_keep_parameter_names() {
+    // 🧪 Input:
+    // bless(target: x, amount: 5)
+    // 🧱 Expectation: each argument is kept as a `(param, value)` pair, and
+    //    `split_named_argument` reads the same pair back from call text
+    let tokens = vec![
+        token(TokenType::Identifier, "bless"),
+        token(TokenType::Punctuation, "("),
+        token(TokenType::Identifier, "target"),
+        token(TokenType::Operator, ":"),
+        token(TokenType::Identifier, "x"),
+        token(TokenType::Punctuation, ","),
+        token(TokenType::Identifier, "amount"),
+        token(TokenType::Operator, ":"),
+        token(TokenType::Literal, "5"),
+        token(TokenType::Punctuation, ")"),
+    ];
+    let mut parser = Parser::new(tokens);
+    let node = parser.parse_assignment_or_call().unwrap();
+
+    assert_eq!(
+        node,
+        ScrollNode::named_call("bless", &[], &[("target", "x"), ("amount", "5")])
+    );
+    assert_eq!(split_named_argument("amount: 5"), Some(("amount", "5")));
+    assert_eq!(split_named_argument("\"note: this\""), None);
+}
+
+#[test]
+fn test_quoted_call_argument_is_not_named() {
+    // 🧪 Input:
+    // f("amount: 5")
+    // f(amount: 5)
+    // 🧱 Expectation: the string stays one positional argument; only the
+    //    bare form is named — in the tree, in `.stone`, and in bytes
+    let tree = parse_source("f(\"amount: 5\")\nf(amount: 5)");
+
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::call("f", &["\"amount: 5\""]),
+            ScrollNode::named_call("f", &[], &[("amount", "5")]),
+        ]
+    );
+
+    let stone = tree.to_stone();
+    assert_eq!(stone, "f(\"amount: 5\")\nf(amount: 5)\n");
+    assert_eq!(ScrollTree::from_stone(&stone).nodes, tree.nodes);
+    assert_eq!(ScrollTree::from_bytes(&tree.to_bytes()).unwrap(), tree);
+}
+
 // ==============================================
 // 🧱 Structure & Block-Based Tests
 // ==============================================
@@ -168,6 +437,7 @@ fn test_function_call() {
 //   - `{}` block groups
 //   - loop condition + block body
 //   - `let` declarations with optional type hint
+//   - `walk name(params) { ... }` definitions and call resolution
 //
 // 🛑 Parser-level only — no resolver or execution checks.
 // ==============================================
@@ -241,6 +511,168 @@ fn test_parse_declaration() {
     }
 }
 
+#[test]
+fn test_parse_declaration_untyped() {
+    // 🧪 Input: let x
+    // 🧱 Expectation: Declaration without type hint, no errors
+    let tokens = vec![
+        token(TokenType::Instruction, "let"),
+        token(TokenType::Identifier, "x"),
+    ];
+    let mut parser = Parser::new(tokens);
+
+    assert_eq!(
+        parser.parse_declaration(),
+        Some(ScrollNode::Declaration {
+            name: "x".to_string(),
+            dtype: None,
+        })
+    );
+    assert!(parser.errors().is_empty());
+}
+
+#[test]
+fn test_parse_declaration_invalid_name() {
+    // 🧪 Input: let = 5
+    // 🧱 Expectation: `=` rejected as a name with InvalidArgument
+    let tokens = vec![
+        token(TokenType::Instruction, "let"),
+        token(TokenType::Operator, "="),
+        token(TokenType::Literal, "5"),
+    ];
+    let mut parser = Parser::new(tokens);
+
+    assert!(matches!(parser.parse_declaration(), Some(ScrollNode::Error(_))));
+    assert_eq!(parser.errors().len(), 1);
+    assert_eq!(
+        parser.errors()[0].kind,
+        ParseErrorType::InvalidArgument("=".to_string())
+    );
+
+    // 🧪 Input: let (EOF) → MissingToken
+    let mut parser = Parser::new(vec![token(TokenType::Instruction, "let")]);
+
+    assert!(matches!(parser.parse_declaration(), Some(ScrollNode::Error(_))));
+    assert_eq!(parser.errors()[0].kind, ParseErrorType::MissingToken);
+}
+
+#[test]
+fn test_keyword_as_name_is_reserved() {
+    // 🧪 Input:
+    // let if = 5
+    // if = 6
+    // let flame = 5
+    // 🧱 Expectation: both keyword names are ReservedKeyword errors that
+    //    consume their line; `flame` binds normally
+    let mut parser = Parser::new(source_tokens("let if = 5\nif = 6\nlet flame = 5"));
+    let tree = parser.parse();
+
+    assert!(matches!(tree.nodes[0], ScrollNode::Error(_)));
+    assert!(matches!(tree.nodes[1], ScrollNode::Error(_)));
+    assert_eq!(
+        tree.nodes[2],
+        ScrollNode::Instruction {
+            name: "let".to_string(),
+            args: vec!["flame".to_string(), "=".to_string(), "5".to_string()],
+        }
+    );
+    assert_eq!(tree.nodes.len(), 3);
+
+    let kinds: Vec<_> = parser.errors().iter().map(|e| e.kind.clone()).collect();
+    assert_eq!(kinds, vec![ParseErrorType::ReservedKeyword; 2]);
+    assert!(parser.errors()[0].message.contains("'if'"));
+}
+
+#[test]
+fn test_unreserved_keywords_may_name_variables() {
+    // 🧪 Input: let if = 5 / if = 6, with reserved keywords turned off
+    // 🧱 Expectation: an ordinary `let` and an ordinary assignment, no errors
+    let mut parser = Parser::new(source_tokens("let if = 5\nif = 6")).with_reserved_keywords(false);
+    let tree = parser.parse();
+
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::Instruction {
+                name: "let".to_string(),
+                args: vec!["if".to_string(), "=".to_string(), "5".to_string()],
+            },
+            ScrollNode::Assignment {
+                target: "if".to_string(),
+                value: "6".to_string(),
+            },
+        ]
+    );
+    assert!(parser.errors().is_empty());
+}
+
+#[test]
+fn test_parse_function_definition() {
+    // 🧪 Input:
+    // walk shepherd(flock, staff) { speak flock }
+    // 🧱 Expectation:
+    // ScrollNode::Function with both params and a one-instruction body
+    let tree = parse_source("walk shepherd(flock, staff) {\n    speak flock\n}");
+
+    assert_eq!(
+        tree.nodes,
+        vec![ScrollNode::Function {
+            name: "shepherd".to_string(),
+            params: vec!["flock".to_string(), "staff".to_string()],
+            body: vec![ScrollNode::Instruction {
+                name: "speak".to_string(),
+                args: vec!["flock".to_string()],
+            }],
+        }]
+    );
+
+    // 🧪 Input: walk shepherd(3) { } → literal parameter rejected
+    let mut parser = Parser::new(vec![
+        token(TokenType::Instruction, "walk"),
+        token(TokenType::Identifier, "shepherd"),
+        token(TokenType::GroupMarker, "("),
+        token(TokenType::Literal, "3"),
+        token(TokenType::GroupMarker, ")"),
+    ]);
+
+    assert!(matches!(parser.parse_walk(), Some(ScrollNode::Error(_))));
+    assert_eq!(
+        parser.errors()[0].kind,
+        ParseErrorType::InvalidArgument("3".to_string())
+    );
+}
+
+#[test]
+fn test_call_resolves_to_function_definition() {
+    // 🧪 Input:
+    // walk shepherd(flock) { speak flock }
+    // walk shepherd
+    // 🧱 Expectation:
+    // Both the `walk` call and a `shepherd(...)` Call resolve to the definition
+    let tree = parse_source("walk shepherd(flock) {\n    speak flock\n}\nwalk shepherd");
+
+    assert_eq!(tree.nodes.len(), 2);
+    let definition = &tree.nodes[0];
+    assert!(matches!(definition, ScrollNode::Function { name, .. } if name == "shepherd"));
+
+    assert_eq!(tree.resolve_call(&tree.nodes[1]), Some(definition));
+
+    let call = ScrollNode::Call {
+        function: "shepherd".to_string(),
+        args: vec!["\"sheep\"".to_string()],
+        named: vec![],
+    };
+    assert_eq!(tree.resolve_call(&call), Some(definition));
+
+    // 🚫 Unknown names resolve to nothing
+    let stray = ScrollNode::Call {
+        function: "wander".to_string(),
+        args: vec![],
+        named: vec![],
+    };
+    assert_eq!(tree.resolve_call(&stray), None);
+}
+
 // ==============================================
 // 🗒️ Metadata, Comment, and Sentence Validations
 // ==============================================
@@ -285,6 +717,47 @@ fn test_parse_metadata() {
     }
 }
 
+#[test]
+fn test_scroll_header_populates_meta() {
+    // 🧪 Input: a `_key_: value` header, a comment, the body, then a stray header line
+    // 🧱 Expectation: known keys fill their fields, `_scroll_` lands in `extra`,
+    //    and metadata after the body begins is not header
+    let tree = parse_source(
+        "#! _author_:       Nova Dawn\n\
+         #! _version_:      0.0.1\n\
+         # a note between header lines\n\
+         #! _status_:       Dev\n\
+         #! _created_:      2025-06-15\n\
+         #! _last updated_: 2025-06-16\n\
+         #! _scroll_:       Psalm of Fire\n\
+         let x 5\n\
+         #! _license_:      late",
+    );
+
+    assert_eq!(tree.meta.author.as_deref(), Some("Nova Dawn"));
+    assert_eq!(tree.meta.version.as_deref(), Some("0.0.1"));
+    assert_eq!(tree.meta.status.as_deref(), Some("Dev"));
+    assert_eq!(tree.meta.created.as_deref(), Some("2025-06-15"));
+    assert_eq!(tree.meta.last_updated.as_deref(), Some("2025-06-16"));
+    assert_eq!(tree.meta.license, None);
+    assert_eq!(
+        tree.meta.extra.get("scroll").map(String::as_str),
+        Some("Psalm of Fire")
+    );
+
+    // 🧱 Metadata nodes stay in the tree alongside the structured header
+    assert!(matches!(tree.nodes[0], ScrollNode::Metadata(_)));
+}
+
+#[test]
+fn test_scroll_without_header_has_empty_meta() {
+    // 🧪 Input: body only, plus metadata that is not `_key_: value`
+    // 🧱 Expectation: default `ScrollMeta`
+    let tree = parse_source("#! strict\nlet x 5");
+
+    assert_eq!(tree.meta, ScrollMeta::default());
+}
+
 #[test]
 fn test_sentence_validation() {
     // 🧪 Validation cases for SVO grammar
@@ -295,6 +768,1211 @@ fn test_sentence_validation() {
     assert!(!parser.is_valid_sentence("", "speaks", Some("truth")));
 }
 
+#[test]
+fn test_operand_schema_mismatch_message() {
+    // 🧪 Input: store 5 faith  (`store` expects [Target, Value])
+    // 🧱 Expectation: standardized InvalidArgument naming slot, value, and position
+    let parser = Parser::new(vec![]);
+    let keyword = token(TokenType::Instruction, "store");
+    let operands = [
+        token(TokenType::Literal, "5"),
+        token(TokenType::Identifier, "faith"),
+    ];
+
+    let error = parser
+        .check_operand_schema(&keyword, &operands)
+        .expect_err("a literal cannot fill a target slot");
+    assert_eq!(error.kind, ParseErrorType::InvalidArgument("5".to_string()));
+    assert_eq!(error.message, "expected target, found '5' at position 0");
+
+    // ✅ Swapped operands fit the schema
+    let operands = [
+        token(TokenType::Identifier, "faith"),
+        token(TokenType::Literal, "5"),
+    ];
+    assert!(parser.check_operand_schema(&keyword, &operands).is_ok());
+}
+
+// ==============================================
+// 🌳 ScrollTree Equality Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Independently parsed identical scrolls yield equal trees
+//   - Differing scrolls are detected without `.stone` string comparison
+// ==============================================
+
+#[test]
+fn test_tree_equality_identical_scrolls() {
+    // 🧪 Input: the same scroll parsed twice from source
+    // 🧱 Expectation: structurally equal ScrollTrees
+    let source = "walk \"truth\"\n// author: seanje\nholiness = 100";
+    let first = parse_source(source);
+    let second = parse_source(source);
+
+    assert!(!first.nodes.is_empty());
+    assert_tree_eq(&first, &second);
+}
+
+#[test]
+fn test_tree_equality_token_built_scrolls() {
+    // 🧪 Input: { walk "north" } built from two separate token streams
+    // 🧱 Expectation: equal trees, including nested Block contents
+    let build = || {
+        vec![
+            token(TokenType::GroupMarker, "{"),
+            token(TokenType::Instruction, "walk"),
+            token(TokenType::Literal, "\"north\""),
+            token(TokenType::GroupMarker, "}"),
+        ]
+    };
+
+    let first = Parser::new(build()).parse();
+    let second = Parser::new(build()).parse();
+
+    assert_tree_eq(&first, &second);
+}
+
+#[test]
+fn test_tree_equality_streamed_parse() {
+    // 🧪 Input: one scroll parsed eagerly and lazily via `TokenIter`
+    // 🧱 Expectation: identical ScrollTrees
+    let source = "walk \"truth\"\n// author: seanje\nholiness = 100";
+    let stream = Tokenizer::from_registry(source, &shared_registry())
+        .stream()
+        .recovering();
+    let streamed = Parser::from_stream(stream).parse();
+
+    assert_tree_eq(&parse_source(source), &streamed);
+}
+
+#[test]
+fn test_tree_inequality_differing_scrolls() {
+    // 🧪 Input: two scrolls differing in one literal
+    // 🧱 Expectation: trees compare unequal
+    let first = parse_source("holiness = 100");
+    let second = parse_source("holiness = 99");
+
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_constructed_tree_matches_parsed() {
+    // 🧪 Input: a declaration, assignment, instruction, call, and loop —
+    //    once parsed from source, once built with `ScrollNode` constructors
+    // 🧱 Expectation: the two trees are node-for-node equal
+    let parsed = parse_source(
+        "let x: Int\nx = 5\nspeak \"truth\"\nshepherd(flock)\nwhile x < 3 {\n    bless x\n}",
+    );
+
+    let body = ScrollNode::Block(vec![ScrollNode::instruction("bless", &["x"])]);
+    let built = ScrollTree {
+        nodes: vec![
+            ScrollNode::declaration("x", Some("Int")),
+            ScrollNode::assignment("x", "5"),
+            ScrollNode::instruction("speak", &["\"truth\""]),
+            ScrollNode::call("shepherd", &["flock"]),
+            ScrollNode::while_loop("x < 3", vec![body]),
+        ],
+        ..Default::default()
+    };
+
+    assert_tree_eq(&built, &parsed);
+}
+
+#[test]
+fn test_tree_diff_reports_single_changed_node() {
+    // 🧪 Input: the same loop parsed twice, one inner instruction altered
+    // 🧱 Expectation: exactly one diff — a `Changed` at the inner path
+    let before = parse_source("speak \"truth\"\nwhile x < 3 {\n    bless x\n}");
+    let after = parse_source("speak \"truth\"\nwhile x < 3 {\n    bless y\n}");
+
+    let diffs = tree_diff(&before, &after);
+
+    assert_eq!(
+        diffs,
+        vec![TreeDiff::Changed {
+            path: vec![1, 0, 0],
+            before: ScrollNode::instruction("bless", &["x"]),
+            after: ScrollNode::instruction("bless", &["y"]),
+        }]
+    );
+    assert!(tree_diff(&before, &before).is_empty());
+}
+
+#[test]
+fn test_cloned_tree_equals_original() {
+    // 🧪 Input: a parsed loop, cloned
+    // 🧱 Expectation: the clone compares equal, spans included
+    let tree = parse_source("while x < 3 {\n    speak x\n}");
+    let copy = tree.clone();
+
+    assert_tree_eq(&copy, &tree);
+    assert_eq!(copy.spans, tree.spans);
+}
+
+#[test]
+fn test_remaining_tokens_does_not_consume() {
+    // 🧪 Input: let x 5, peeked mid-parse
+    // 🧱 Expectation: the unread tokens are listed and parsing still finishes
+    let mut parser = Parser::new(source_tokens("let x 5"));
+    parser.advance();
+
+    let rest: Vec<String> = parser
+        .remaining_tokens()
+        .into_iter()
+        .map(|t| t.value)
+        .collect();
+    assert_eq!(rest, vec!["x", "5"]);
+    assert_eq!(parser.advance().map(|t| t.value), Some("x".to_string()));
+    assert!(format!("{:?}", parser).starts_with("Parser {"));
+}
+
+#[test]
+fn test_peek_n_looks_ahead_without_advancing() {
+    // 🧪 Input: let x: int — read lazily, as a stream
+    // 🧱 Expectation: `peek_n(2)` is the `:`; `advance` still starts at `let`
+    let mut parser = Parser::from_stream(source_tokens("let x: int").into_iter());
+
+    assert_eq!(parser.peek_value_n(2), Some(":"));
+    assert_eq!(
+        parser.peek_n(1).map(|t| t.token_type.clone()),
+        Some(TokenType::Identifier)
+    );
+    assert_eq!(parser.peek_n(4), None);
+    assert_eq!(parser.peek().map(|t| t.value.as_str()), Some("let"));
+    assert_eq!(parser.advance().map(|t| t.value), Some("let".to_string()));
+    assert_eq!(parser.peek_value_n(2), Some("int"));
+}
+
+#[test]
+fn test_let_with_type_annotation_parses_as_declaration() {
+    // 🧪 Input:
+    // let x: int
+    // let y 5
+    // 🧱 Expectation: lookahead routes the typed form to a Declaration;
+    //    the untyped form stays a `let` instruction
+    let tree = parse_source("let x: int\nlet y 5");
+
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::Declaration {
+                name: "x".to_string(),
+                dtype: Some("int".to_string()),
+            },
+            ScrollNode::Instruction {
+                name: "let".to_string(),
+                args: vec!["y".to_string(), "5".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_with_registry_decodes_against_given_registry() {
+    // 🧪 Input: "bless x\nspeak x" against a registry holding only `bless`
+    // 🧱 Expectation: `bless` decodes; `speak` is reported as unknown
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bless.logos");
+    let registry = Arc::new(InstructionRegistry::from_toml(path).unwrap());
+    let tokens = Tokenizer::from_registry("bless x\nspeak x", &shared_registry())
+        .tokenize()
+        .tokens;
+    let mut parser = Parser::new(tokens).with_registry(registry);
+    let tree = parser.parse();
+
+    assert_eq!(
+        tree.nodes[0],
+        ScrollNode::Instruction {
+            name: "bless".to_string(),
+            args: vec!["x".to_string()],
+        }
+    );
+    let unknown: Vec<_> = parser
+        .errors()
+        .iter()
+        .filter(|e| e.kind == ParseErrorType::InvalidInstruction)
+        .collect();
+    assert_eq!(unknown.len(), 1);
+    assert!(unknown[0].message.contains("speak"));
+}
+
+// ==============================================
+// 📍 Cursor Lookup Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `find_node_at` returns the innermost node under a cursor
+//   - Positions outside every span find nothing
+// ==============================================
+
+#[test]
+fn test_find_node_at_prefers_inner_statement() {
+    // 🧪 Input:
+    // while x < 3 {
+    //     speak "hi"
+    // }
+    // 🧱 Expectation:
+    // A cursor on `speak` finds the inner Instruction, not the Loop
+    let tree = parse_source("while x < 3 {\n    speak \"hi\"\n}");
+    assert!(matches!(tree.nodes[0], ScrollNode::Loop { .. }));
+
+    match tree.find_node_at(2, 10) {
+        Some(ScrollNode::Instruction { name, .. }) => assert_eq!(name, "speak"),
+        other => panic!("Expected the inner Instruction, found {:?}", other),
+    }
+}
+
+#[test]
+fn test_find_node_at_loop_header_and_outside() {
+    // 🧪 Input: the same loop, cursor on `while` and past the scroll
+    // 🧱 Expectation: the header finds the Loop; line 9 finds nothing
+    let tree = parse_source("while x < 3 {\n    speak \"hi\"\n}");
+
+    assert!(matches!(
+        tree.find_node_at(1, 6),
+        Some(ScrollNode::Loop { .. })
+    ));
+    assert_eq!(tree.find_node_at(9, 1), None);
+}
+
+// ==============================================
+// 🔎 Filtered Accessor Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `instructions`, `declarations`, `calls`, and `fold` recurse into nested bodies
+//   - Results arrive depth-first in scroll order
+// ==============================================
+
+#[test]
+fn test_filtered_accessors_recurse_into_bodies() {
+    // 🧪 Input: instructions at the top level, in a loop, a block, and a function
+    // 🧱 Expectation: all four instructions, both declarations, and the call are found
+    let tree = ScrollTree {
+        nodes: vec![
+            ScrollNode::instruction("speak", &["a"]),
+            ScrollNode::while_loop(
+                "x < 3",
+                vec![
+                    ScrollNode::instruction("speak", &["x"]),
+                    ScrollNode::Block(vec![
+                        ScrollNode::instruction("bless", &["x", "7"]),
+                        ScrollNode::call("shepherd", &["flock"]),
+                    ]),
+                    ScrollNode::function(
+                        "rest",
+                        &[],
+                        vec![
+                            ScrollNode::instruction("wait", &[]),
+                            ScrollNode::declaration("peace", None),
+                        ],
+                    ),
+                ],
+            ),
+            ScrollNode::declaration("truth", Some("String")),
+        ],
+        spans: vec![],
+        meta: ScrollMeta::default(),
+    };
+
+    let names: Vec<&str> = tree.instructions().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["speak", "speak", "bless", "wait"]);
+    assert_eq!(tree.instructions().nth(2).unwrap().1, ["x", "7"]);
+
+    let declared: Vec<_> = tree.declarations().collect();
+    assert_eq!(declared, vec![("peace", None), ("truth", Some("String"))]);
+
+    let calls: Vec<_> = tree.calls().collect();
+    assert_eq!(calls, vec![("shepherd", &["flock".to_string()][..])]);
+}
+
+#[test]
+fn test_fold_counts_nested_calls() {
+    // 🧪 Input: calls at the top level, in a loop, in a block inside it, and in a function
+    // 🧱 Expectation: fold visits every body and counts all four calls
+    let call = |function: &str| ScrollNode::call(function, &[]);
+    let tree = ScrollTree {
+        nodes: vec![
+            call("rise"),
+            ScrollNode::while_loop(
+                "x < 3",
+                vec![call("shine"), ScrollNode::Block(vec![call("shepherd")])],
+            ),
+            ScrollNode::function("rest", &[], vec![call("abide")]),
+        ],
+        ..Default::default()
+    };
+
+    let calls = tree.fold(0, |count, node| match node {
+        ScrollNode::Call { .. } => count + 1,
+        _ => count,
+    });
+    assert_eq!(calls, 4);
+
+    // 🧮 Any accumulator works — here, call names in visiting order
+    let names = tree.fold(Vec::new(), |mut names, node| {
+        if let ScrollNode::Call { function, .. } = node {
+            names.push(function.as_str());
+        }
+        names
+    });
+    assert_eq!(names, vec!["rise", "shine", "shepherd", "abide"]);
+}
+
+// ==============================================
+// 🧱 Binary Tree Cache Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `to_bytes` / `from_bytes` round-trip nested trees exactly
+//   - Buffers from another format version are rejected
+//   - Blocks nested past `MAX_NESTING_DEPTH` are rejected while decoding
+// ==============================================
+
+#[test]
+fn test_tree_bytes_round_trip() {
+    // 🧪 Input: a function definition, a call, and an assignment
+    // 🧱 Expectation: decoding the encoded bytes yields an equal tree
+    let mut tree =
+        parse_source("walk shepherd(flock) {\n    speak flock\n}\nwalk shepherd\nholiness = 100");
+    tree.nodes.push(ScrollNode::Declaration {
+        name: "truth".to_string(),
+        dtype: Some("String".to_string()),
+    });
+
+    let bytes = tree.to_bytes();
+    assert!(bytes.starts_with(TREE_MAGIC));
+
+    let restored = ScrollTree::from_bytes(&bytes).expect("round trip decodes");
+    assert_tree_eq(&tree, &restored);
+}
+
+#[test]
+fn test_tree_bytes_version_mismatch_rejected() {
+    // 🧪 Input: valid bytes with the version field bumped
+    // 🧱 Expectation: VersionMismatch naming both versions
+    let mut bytes = parse_source("holiness = 100").to_bytes();
+    let next = TREE_FORMAT_VERSION + 1;
+    bytes[4..6].copy_from_slice(&next.to_le_bytes());
+
+    assert_eq!(
+        ScrollTree::from_bytes(&bytes),
+        Err(TreeBytesError::VersionMismatch {
+            found: next,
+            expected: TREE_FORMAT_VERSION,
+        })
+    );
+    assert_eq!(
+        ScrollTree::from_bytes(b"nope"),
+        Err(TreeBytesError::BadMagic)
+    );
+}
+
+#[test]
+fn test_tree_bytes_nesting_capped() {
+    // 🧪 Input: blocks nested as deep as the parser allows, and one deeper
+    // 🧱 Expectation: the first round-trips; the second is NestingTooDeep
+    let nested = |levels: usize| {
+        let mut tree = parse_source("holiness = 100");
+        for _ in 0..levels {
+            tree.nodes = vec![ScrollNode::Block(std::mem::take(&mut tree.nodes))];
+        }
+        tree
+    };
+
+    let deepest = nested(MAX_NESTING_DEPTH);
+    let restored = ScrollTree::from_bytes(&deepest.to_bytes()).expect("parser depth decodes");
+    assert_tree_eq(&deepest, &restored);
+
+    assert_eq!(
+        ScrollTree::from_bytes(&nested(MAX_NESTING_DEPTH + 1).to_bytes()),
+        Err(TreeBytesError::NestingTooDeep)
+    );
+}
+
+// ==============================================
+// 🔠 Keyword Case Sensitivity Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Case-insensitive keywords normalize `LET` to `let` end to end
+//   - The default (sensitive) mode leaves `LET` as a plain identifier
+// ==============================================
+
+fn parse_with_case(source: &str, case_sensitive: bool) -> ScrollTree {
+    let tokens = Tokenizer::from_registry(source, &shared_registry())
+        .with_case_sensitive_keywords(case_sensitive)
+        .tokenize()
+        .tokens;
+    Parser::new(tokens)
+        .with_case_sensitive_keywords(case_sensitive)
+        .parse()
+}
+
+#[test]
+fn test_case_insensitive_keywords_parse_as_registered() {
+    // 🧪 Input: LET x 5 (keywords case-insensitive)
+    // 🧱 Expectation: Instruction `let` with args [x, 5]
+    let tree = parse_with_case("LET x 5", false);
+
+    assert_eq!(
+        tree.nodes,
+        vec![ScrollNode::Instruction {
+            name: "let".to_string(),
+            args: vec!["x".to_string(), "5".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn test_case_sensitive_keywords_reject_other_casing() {
+    // 🧪 Input: LET x 5 (default, case-sensitive)
+    // 🧱 Expectation: no `let` instruction is produced
+    let tree = parse_with_case("LET x 5", true);
+
+    assert!(!tree
+        .nodes
+        .iter()
+        .any(|node| matches!(node, ScrollNode::Instruction { name, .. } if name == "let")));
+}
+
+// ==============================================
+// 🧬 Instruction Metadata Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Instruction spans carry the registry opcode and category
+//   - Non-instruction nodes carry none
+// ==============================================
+
+#[test]
+fn test_instruction_span_carries_registry_metadata() {
+    // 🧪 Input: let x 5, then a comment
+    // 🧱 Expectation: `let` span holds opcode 0x72 / "Memory/Data"; comment holds nothing
+    let tree = parse_source("let x 5\n# amen");
+
+    assert!(matches!(&tree.nodes[0], ScrollNode::Instruction { name, .. } if name == "let"));
+    assert_eq!(
+        tree.spans[0].instruction,
+        Some(InstructionMeta {
+            opcode: 0x72,
+            category: "Memory/Data",
+        })
+    );
+    assert_eq!(tree.spans[1].instruction, None);
+}
+
+// ==============================================
+// 🪨 Stone Annotation Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Comments (`;;`) and metadata (`;;!`) survive `to_stone` / `from_stone`
+//   - Annotations inside a block stay inside it
+// ==============================================
+
+#[test]
+fn test_stone_round_trip_keeps_annotations() {
+    // 🧪 Input: a scroll with a leading comment, metadata, and a commented block
+    // 🧱 Expectation: `.stone` marks annotations, and reading it back
+    //    reproduces the same tree
+    let tree = parse_source(
+        "# opening note\nlet x 5\n#! author: seanje\nspeak x\n{\n# inside\nbless x\n}",
+    );
+    let stone = tree.to_stone();
+
+    assert!(stone.contains(";; # opening note\n"));
+    assert!(stone.contains(";;! #! author: seanje\n"));
+    assert!(stone.contains("{\n  ;; # inside\n  bless x\n}\n"));
+
+    let restored = ScrollTree::from_stone(&stone);
+    assert_tree_eq(&restored, &tree);
+    assert_eq!(restored.to_stone(), stone);
+}
+
+#[test]
+fn test_enriched_stone_annotates_operand_types() {
+    // 🧪 Input: # note / speak "truth"
+    // 🧱 Expectation: enriched output tags the argument with type and trust;
+    //    raw output matches `to_stone` and carries no tags
+    let tree = ScrollTree {
+        nodes: vec![
+            ScrollNode::Comment("# note".to_string()),
+            ScrollNode::Instruction {
+                name: "speak".to_string(),
+                args: vec!["\"truth\"".to_string()],
+            },
+        ],
+        ..Default::default()
+    };
+
+    let enriched = tree.to_stone_opts(StoneOptions::enriched());
+    assert!(enriched.contains("speak \"truth\":String#Certain\n"));
+
+    let raw = tree.to_stone_opts(StoneOptions::raw());
+    assert_eq!(raw, tree.to_stone());
+    assert!(raw.contains("speak \"truth\"\n"));
+    assert!(!raw.contains(":String"));
+
+    let quiet = tree.to_stone_opts(StoneOptions {
+        include_types: true,
+        include_trust: false,
+        include_comments: false,
+    });
+    assert_eq!(quiet, "speak \"truth\":String\n");
+}
+
+#[test]
+fn test_tuple_return_parses_and_round_trips() {
+    // 🧪 Input:
+    // return (1, 2)
+    // a, b = pair(1)
+    // 🧱 Expectation: a two-element tuple return and a two-target
+    //    assignment, both surviving `.stone` and back
+    let tree = parse_source("return (1, 2)\na, b = pair(1)");
+
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::Return("(1, 2)".to_string()),
+            ScrollNode::assignment("a, b", "pair(1)"),
+        ]
+    );
+
+    let stone = tree.to_stone();
+    assert_eq!(stone, "return (1, 2)\na, b = pair(1)\n");
+    assert_tree_eq(&ScrollTree::from_stone(&stone), &tree);
+}
+
+#[test]
+fn test_stone_quotes_and_escapes_string_literals() {
+    // 🧪 Input: literals `say "hi"` (a space and quotes), a two-line
+    //    string, and the number 42
+    // 🧱 Expectation: strings are quoted with specials escaped, the number
+    //    stays bare, and reading back restores every value exactly
+    let tree = ScrollTree {
+        nodes: vec![
+            ScrollNode::Literal("say \"hi\"".to_string()),
+            ScrollNode::Literal("first\nsecond".to_string()),
+            ScrollNode::Literal("42".to_string()),
+        ],
+        ..Default::default()
+    };
+
+    let stone = tree.to_stone();
+    assert_eq!(
+        stone,
+        "literal \"say \\\"hi\\\"\"\nliteral \"first\\nsecond\"\nliteral 42\n"
+    );
+    assert_tree_eq(&ScrollTree::from_stone(&stone), &tree);
+}
+
+// ==============================================
+// 🔢 Operand Count Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Arguments beyond the registry's `operand_count` are flagged
+//   - Instructions at their declared count parse cleanly
+// ==============================================
+
+fn parse_errors(source: &str) -> (ScrollTree, Vec<ParseError>) {
+    let tokens = Tokenizer::from_registry(source, &shared_registry())
+        .tokenize()
+        .tokens;
+    let mut parser = Parser::new(tokens);
+    let tree = parser.parse();
+    (tree, parser.errors().to_vec())
+}
+
+#[test]
+fn test_operand_overflow_is_flagged() {
+    // 🧪 Input: wait 5s 1  (`wait` declares one optional duration)
+    // 🧱 Expectation: UnexpectedToken at `1`; the node is still emitted
+    let (tree, errors) = parse_errors("wait 5s 1");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, ParseErrorType::UnexpectedToken);
+    assert_eq!(
+        errors[0].message,
+        "'wait' takes 1 operand(s), found 2 (first extra: '1')"
+    );
+    assert!(matches!(&tree.nodes[0], ScrollNode::Instruction { name, .. } if name == "wait"));
+}
+
+#[test]
+fn test_operand_count_within_limit_is_clean() {
+    // 🧪 Input: wait, and let x = 5 (`=` is syntax, not an operand)
+    // 🧱 Expectation: no errors
+    assert!(parse_errors("wait").1.is_empty());
+    assert!(parse_errors("let x = 5").1.is_empty());
+}
+
+// ==============================================
+// 📐 Indentation Block Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Indent opens a block and dedent closes it, matching the braced form
+//   - A single dedent can close several nested levels
+// ==============================================
+
+fn parse_indented(source: &str) -> ScrollTree {
+    let tokens = Tokenizer::from_registry(source, &shared_registry())
+        .with_indentation_blocks(true)
+        .tokenize()
+        .tokens;
+    Parser::new(tokens).parse()
+}
+
+#[test]
+fn test_indented_if_matches_braced_if() {
+    // 🧪 Input: the same `if` written with indentation and with braces
+    // 🧱 Expectation: both parse into the same tree
+    let indented = parse_indented("if x > 3\n  speak x\nlet y 2");
+    let braced = parse_source("if x > 3 {\n  speak x\n}\nlet y 2");
+
+    assert_tree_eq(&indented, &braced);
+    assert!(matches!(&indented.nodes[1], ScrollNode::Block(body) if body.len() == 1));
+}
+
+#[test]
+fn test_indented_dedent_closes_nested_blocks() {
+    // 🧪 Input: two nested levels closed by one dedent, plus one left open at EOF
+    // 🧱 Expectation: same tree as the fully braced form
+    let indented = parse_indented(
+        "while x < 3\n  if x > 1\n    speak x\nlet y 2\nwhile y < 3\n  speak y",
+    );
+    let braced = parse_source(
+        "while x < 3 {\n  if x > 1 {\n    speak x\n  }\n}\nlet y 2\nwhile y < 3 {\n  speak y\n}",
+    );
+
+    assert_tree_eq(&indented, &braced);
+}
+
+// ==============================================
+// 🎯 Expect Helper Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - `expect` / `expect_value` return the token on a match
+//   - A mismatch is a positioned `MissingToken`; EOF is `UnexpectedEOF`
+//   - Walkers record the helper's error and emit an error node
+// ==============================================
+
+fn source_tokens(source: &str) -> Vec<Token> {
+    Tokenizer::from_registry(source, &shared_registry()).tokenize().tokens
+}
+
+#[test]
+fn test_expect_mismatch_returns_positioned_error() {
+    // 🧪 Input: holiness 100 — expect a literal first, then the value `100`
+    // 🧱 Expectation: mismatch at `holiness`'s position; `100` then matches
+    let tokens = source_tokens("\n  holiness 100");
+    let (line, column) = (tokens[0].line, tokens[0].column);
+    let mut parser = Parser::new(tokens);
+
+    let error = parser.expect(TokenType::Literal).unwrap_err();
+    assert_eq!(error.kind, ParseErrorType::MissingToken);
+    assert_eq!(error.message, "Expected Literal, found 'holiness'");
+    assert_eq!((error.line, error.column), (line, column));
+
+    assert_eq!(parser.expect_value("100").unwrap().value, "100");
+
+    // 🧪 Input: nothing left
+    // 🧱 Expectation: UnexpectedEOF at the last consumed token
+    let error = parser.expect_value("}").unwrap_err();
+    assert_eq!(error.kind, ParseErrorType::UnexpectedEOF);
+    assert_eq!(error.message, "Expected '}', found end of scroll");
+}
+
+#[test]
+fn test_walker_records_expect_error() {
+    // 🧪 Input: parse_block over `speak` (no opening brace)
+    // 🧱 Expectation: error node plus one recorded MissingToken
+    let mut parser = Parser::new(source_tokens("speak"));
+
+    assert_eq!(
+        parser.parse_block(),
+        Some(ScrollNode::Error("Expected '{', found 'speak'".to_string()))
+    );
+    assert_eq!(parser.errors().len(), 1);
+    assert_eq!(parser.errors()[0].kind, ParseErrorType::MissingToken);
+}
+
+// ==============================================
+// 🔤 Multi-Byte Position Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Columns count chars, so emoji ahead of an error don't skew it
+// ==============================================
+
+#[test]
+fn test_error_column_counts_chars_after_emoji() {
+    // 🧪 Input: speak "🔥🔥🔥" ?  — three 4-byte emoji, then an unknown symbol
+    // 🧱 Expectation: the `?` error sits at char column 12, not byte column 21
+    let source = "speak \"🔥🔥🔥\" ?";
+    let prefix = "speak \"🔥🔥🔥\" ";
+    let mut parser = Parser::new(source_tokens(source));
+    parser.parse();
+
+    let error = parser
+        .errors()
+        .iter()
+        .find(|e| e.message.contains('?'))
+        .expect("`?` should be reported");
+    assert_eq!(error.line, 1);
+    assert_eq!(error.column, prefix.chars().count());
+    assert_ne!(error.column, prefix.len());
+}
+
+// ==============================================
+// 🧭 Keyword Conditionals — if / then / else
+// ==============================================
+
+#[test]
+fn test_keyword_conditional_parses_as_flat_nodes() {
+    // 🧪 Input: if x 2 then { speak x } else { bless x }
+    // 🧱 Expectation: `if`, `then`, block, `else`, block — in scroll order
+    let tree = parse_source("if x 2 then { speak x } else { bless x }");
+
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::instruction("if", &["x", "2"]),
+            ScrollNode::instruction("then", &[]),
+            ScrollNode::Block(vec![ScrollNode::instruction("speak", &["x"])]),
+            ScrollNode::instruction("else", &[]),
+            ScrollNode::Block(vec![ScrollNode::instruction("bless", &["x"])]),
+        ]
+    );
+}
+
+#[test]
+fn test_then_does_not_take_operands_from_its_line() {
+    // 🧪 Input: if x 2 then x = 3
+    // 🧱 Expectation: `then` stands alone; the assignment is its own node
+    let mut parser = Parser::new(source_tokens("if x 2 then x = 3"));
+    let tree = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::instruction("if", &["x", "2"]),
+            ScrollNode::instruction("then", &[]),
+            ScrollNode::assignment("x", "3"),
+        ]
+    );
+}
+
+#[test]
+fn test_instruction_operands_end_at_line_break() {
+    // 🧪 Input:
+    // let x 0
+    // while x < 3 { bless x }
+    // 🧱 Expectation: `let` keeps only its own line; the loop parses whole
+    let mut parser = Parser::new(source_tokens("let x 0\nwhile x < 3 {\n    bless x\n}"));
+    let tree = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    let body = ScrollNode::Block(vec![ScrollNode::instruction("bless", &["x"])]);
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::instruction("let", &["x", "0"]),
+            ScrollNode::while_loop("x < 3", vec![body]),
+        ]
+    );
+}
+
+#[test]
+fn test_restore_rewinds_failed_speculation_exactly() {
+    // 🧪 Input: a block `{ let x = 5 }` attempted as a map, then restored
+    // 🧱 Expectation: the map attempt fails part-way; restore puts back every
+    //    token it consumed, in order, with positions intact
+    let source = "{ let x = 5 }\nholiness = 1";
+    let mut parser = Parser::new(source_tokens(source));
+
+    let start = parser.checkpoint();
+    assert_eq!(parser.parse_map(), None);
+    assert_eq!(
+        parser.peek_value_n(0),
+        Some("x"),
+        "attempt consumed `{{` and `let`"
+    );
+
+    parser.restore(start);
+    assert_eq!(parser.checkpoint(), start);
+    assert_eq!(parser.remaining_tokens(), source_tokens(source));
+    assert!(matches!(parser.parse_node(), Some(ScrollNode::Block(_))));
+}
+
+#[test]
+fn test_resolved_checkpoints_stop_recording() {
+    // 🧪 Input: a map parsed under a checkpoint that is then released, and
+    //    a nested pair of checkpoints restored inner-first
+    // 🧱 Expectation: once the outermost checkpoint resolves its tokens are
+    //    dropped, so a stale restore is ignored; while an outer one is still
+    //    open, restoring it rewinds past the inner one
+    let mut parser = Parser::new(source_tokens("{ faith: 1 }\nholiness = 1"));
+
+    let start = parser.checkpoint();
+    assert!(parser.parse_map().is_some());
+    parser.release();
+    let after_map = parser.remaining_tokens();
+    parser.restore(start);
+    assert_eq!(parser.remaining_tokens(), after_map, "released checkpoint was rewound");
+
+    let outer = parser.checkpoint();
+    parser.advance();
+    let inner = parser.checkpoint();
+    parser.advance();
+    parser.restore(inner);
+    parser.restore(outer);
+    assert_eq!(parser.remaining_tokens(), after_map);
+}
+
+#[test]
+fn test_brace_parses_as_map_or_block() {
+    // 🧪 Input:
+    // { faith: 1, hope: 2, }
+    // { let x = 5 }
+    // 🧱 Expectation: the first braces are a map literal, the second a block
+    let mut parser = Parser::new(source_tokens("{ faith: 1, hope: 2, }\n{ let x = 5 }"));
+    let tree = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::Literal("{faith: 1, hope: 2}".to_string()),
+            ScrollNode::Block(vec![ScrollNode::instruction("let", &["x", "=", "5"])]),
+        ]
+    );
+}
+
+// ==============================================
+// 🧷 Assembler Directives — `.org` / `.byte` / `.align`
+// ==============================================
+
+#[test]
+fn test_directives_parse_and_round_trip() {
+    // 🧪 Input:
+    // .org 0x10
+    // .byte 0x41, 66
+    // .align 4
+    // 🧱 Expectation: one Directive node each; `.stone` and binary forms read back equal
+    let mut parser = Parser::new(source_tokens(".org 0x10\n.byte 0x41, 66\n.align 4"));
+    let tree = parser.parse();
+
+    assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+    assert_eq!(
+        tree.nodes,
+        vec![
+            ScrollNode::Directive(AsmDirective::Org(0x10)),
+            ScrollNode::Directive(AsmDirective::Byte(vec![0x41, 0x42])),
+            ScrollNode::Directive(AsmDirective::Align(4)),
+        ]
+    );
+    assert_eq!(tree.to_stone(), ".org 0x10\n.byte 0x41, 0x42\n.align 4\n");
+    assert_eq!(ScrollTree::from_stone(&tree.to_stone()), tree);
+    assert_eq!(ScrollTree::from_bytes(&tree.to_bytes()), Ok(tree));
+}
+
+#[test]
+fn test_directive_with_bad_values_is_reported() {
+    // 🧪 Input: `.byte 300` and `.align x`
+    // 🧱 Expectation: one InvalidArgument error per line
+    let mut parser = Parser::new(source_tokens(".byte 300\n.align x"));
+    parser.parse();
+
+    let kinds: Vec<&ParseErrorType> = parser.errors().iter().map(|e| &e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            &ParseErrorType::InvalidArgument(".byte".to_string()),
+            &ParseErrorType::InvalidArgument("x".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_assemble_lays_out_directive_bytes() {
+    // 🧪 Input:
+    // .byte 0x41, 0x42
+    // .align 4
+    // wait
+    // .org 8
+    // 🧱 Expectation: A B, two pad bytes, `wait`'s opcode, zeros up to offset 8
+    let registry = InstructionRegistry::new();
+    let config = EncodeConfig::new(BitMode::Bit32);
+    let wait = registry.get("wait").unwrap().opcode;
+
+    let tree = parse_source(".byte 0x41, 0x42\n.align 4\nwait\n.org 8");
+    assert_eq!(
+        tree.assemble(&registry, &config),
+        Ok(vec![0x41, 0x42, 0, 0, wait, 0, 0, 0])
+    );
+
+    let behind = parse_source(".byte 1, 2, 3\n.org 2");
+    assert_eq!(
+        behind.assemble(&registry, &config),
+        Err(EncodeError::OrgBehind { offset: 2, at: 3 })
+    );
+}
+
+#[test]
+fn test_assemble_rejects_zero_alignment_and_oversized_padding() {
+    // 🧪 Input: hand-built `.align 0`, `.org` past the image cap, and a
+    //    `.align` whose boundary would pad past it
+    // 🧱 Expectation: errors rather than a panic or a huge allocation;
+    //    the cached form refuses `.align 0` too
+    let registry = InstructionRegistry::new();
+    let config = EncodeConfig::new(BitMode::Bit32);
+    let tree_of = |directives: Vec<AsmDirective>| ScrollTree {
+        nodes: directives.into_iter().map(ScrollNode::Directive).collect(),
+        ..Default::default()
+    };
+
+    let zero = tree_of(vec![AsmDirective::Align(0)]);
+    assert_eq!(zero.assemble(&registry, &config), Err(EncodeError::ZeroAlignment));
+    assert!(matches!(
+        ScrollTree::from_bytes(&zero.to_bytes()),
+        Err(TreeBytesError::InvalidDirective(_))
+    ));
+
+    let far = tree_of(vec![AsmDirective::Org(u64::MAX)]);
+    assert_eq!(
+        far.assemble(&registry, &config),
+        Err(EncodeError::ImageTooLarge {
+            size: u64::MAX,
+            limit: MAX_IMAGE_SIZE,
+        })
+    );
+
+    let wide = tree_of(vec![AsmDirective::Byte(vec![1]), AsmDirective::Align(1 << 40)]);
+    assert_eq!(
+        wide.assemble(&registry, &config),
+        Err(EncodeError::ImageTooLarge {
+            size: 1 << 40,
+            limit: MAX_IMAGE_SIZE,
+        })
+    );
+}
+
+#[test]
+fn test_assemble_encodes_jumps_in_both_modes() {
+    // 🧪 Input:
+    // .label top
+    // wait
+    // go done
+    // go top
+    // wait
+    // .label done
+    // 🧱 Expectation: `top` sits at 0 and `done` at 12; the forward jump
+    //    starts at 1 and the backward one at 6, so relative targets are
+    //    11 and -6 while absolute targets are 12 and 0
+    let registry = InstructionRegistry::new();
+    let wait = registry.get("wait").unwrap().opcode;
+    let go = registry.get("go").unwrap().opcode;
+
+    let tree = parse_source(".label top\nwait\ngo done\ngo top\nwait\n.label done");
+    assert_eq!(
+        tree.nodes[0],
+        ScrollNode::Directive(AsmDirective::Label("top".to_string()))
+    );
+    assert_eq!(ScrollTree::from_stone(&tree.to_stone()), tree);
+    assert_eq!(ScrollTree::from_bytes(&tree.to_bytes()), Ok(tree.clone()));
+
+    let absolute = EncodeConfig::new(BitMode::Bit32);
+    assert_eq!(
+        tree.assemble(&registry, &absolute),
+        Ok(vec![wait, go, 12, 0, 0, 0, go, 0, 0, 0, 0, wait])
+    );
+
+    let relative = EncodeConfig::new(BitMode::Bit32).with_jump_mode(JumpMode::Relative);
+    assert_eq!(
+        tree.assemble(&registry, &relative),
+        Ok(vec![wait, go, 11, 0, 0, 0, go, 0xFA, 0xFF, 0xFF, 0xFF, wait])
+    );
+
+    let missing = parse_source("go nowhere");
+    assert_eq!(
+        missing.assemble(&registry, &relative),
+        Err(EncodeError::UnknownLabel("nowhere".to_string()))
+    );
+}
+
+// ==============================================
+// 🩺 Combined Validation — ScrollTree::validate
+// ==============================================
+
+#[test]
+fn test_validate_reports_undeclared_and_arity_together() {
+    // 🧪 Input:
+    // let x 5
+    // bless y
+    // if x
+    // 🧱 Expectation: `y` is undeclared and `if` is one operand short —
+    //    both reported by a single call; `x` is bound and stays quiet
+    let tree = parse_source("let x 5\nbless y\nif x");
+    let registry = InstructionRegistry::new();
+
+    let entries = tree.validate(&registry, ValidateOptions::default());
+
+    assert_eq!(entries.len(), 2, "{:#?}", entries);
+    assert_eq!(entries[0].input, "y");
+    assert_eq!(entries[0].actual, "'y' is not declared");
+    assert_eq!(entries[1].input, "if x");
+    assert_eq!(
+        (entries[1].expected.as_str(), entries[1].actual.as_str()),
+        ("2 operands", "1 operands")
+    );
+
+    let arity_only = ValidateOptions {
+        symbols: false,
+        ..ValidateOptions::default()
+    };
+    assert_eq!(tree.validate(&registry, arity_only).len(), 1);
+}
+
+#[test]
+fn test_validate_flags_unreachable_and_privilege() {
+    // 🧪 Input: a `break` (Kernel) followed by more work
+    // 🧱 Expectation: the trailing node is unreachable and `break` is over
+    //    the User ceiling; raising the ceiling leaves only the dead code
+    let tree = parse_source("let x 5\nbreak\nspeak x");
+    let registry = InstructionRegistry::new();
+
+    let entries = tree.validate(&registry, ValidateOptions::default());
+    let actuals: Vec<&str> = entries.iter().map(|e| e.actual.as_str()).collect();
+    assert_eq!(actuals, vec!["node after return or break", "Kernel privilege"]);
+
+    let kernel = ValidateOptions {
+        max_privilege: PrivilegeLevel::Kernel,
+        ..ValidateOptions::default()
+    };
+    assert_eq!(tree.validate(&registry, kernel).len(), 1);
+}
+
+#[test]
+fn test_validate_flags_string_assigned_to_int() {
+    // 🧪 Input:
+    // let x: Int
+    // x = "hello"
+    // 🧱 Expectation: one conflict — declared Integer, found String —
+    //    and the quoted value is not mistaken for an undeclared name
+    let tree = parse_source("let x: Int\nx = \"hello\"");
+    let registry = InstructionRegistry::new();
+
+    let entries = tree.validate(&registry, ValidateOptions::default());
+
+    assert_eq!(entries.len(), 1, "{:#?}", entries);
+    assert_eq!(entries[0].input, "x = \"hello\"");
+    assert_eq!(
+        (entries[0].expected.as_str(), entries[0].actual.as_str()),
+        ("Integer", "String")
+    );
+}
+
+#[test]
+fn test_validate_flags_numeric_looking_string_assigned_to_int() {
+    // 🧪 Input:
+    // let x: Int
+    // x = "42"
+    // 🧱 Expectation: one conflict — the quotes make `"42"` a String,
+    //    however much it reads like a number
+    let tree = parse_source("let x: Int\nx = \"42\"");
+    let registry = InstructionRegistry::new();
+
+    let entries = tree.validate(&registry, ValidateOptions::default());
+
+    assert_eq!(entries.len(), 1, "{:#?}", entries);
+    assert_eq!(entries[0].input, "x = \"42\"");
+    assert_eq!(
+        (entries[0].expected.as_str(), entries[0].actual.as_str()),
+        ("Integer", "String")
+    );
+}
+
+#[test]
+fn test_validate_accepts_int_reassigned_to_int() {
+    // 🧪 Input: an `Int` reassigned twice with integers, copied into a
+    //    `Float`, then a string bound to an untyped name
+    // 🧱 Expectation: nothing flagged — the type survives reassignment,
+    //    integers widen into `Float`, and untyped names take anything
+    let source = "let x: Int\nx = 5\nx = 7\nlet y: Float\ny = x\nz = \"hi\"";
+    let tree = parse_source(source);
+    let registry = InstructionRegistry::new();
+
+    let entries = tree.validate(&registry, ValidateOptions::default());
+
+    assert!(entries.is_empty(), "{:#?}", entries);
+}
+
+#[test]
+fn test_validate_resolves_paths_into_imported_scrolls() {
+    // 🧪 Input: `psalms` binds `count` and `verse`; this scroll imports
+    //    it, then reads psalms.count, psalms.missing, and hymns.count
+    // 🧱 Expectation: the imported member resolves; the missing member
+    //    and the never-imported scroll are each flagged once
+    let psalms = parse_source("let count 1\nlet verse 23");
+    assert_eq!(psalms.exports(), vec!["count", "verse"]);
+
+    let tree = ScrollTree {
+        nodes: vec![
+            ScrollNode::Import("\"lib/psalms.ns\"".to_string()),
+            ScrollNode::instruction("bless", &["psalms.count"]),
+            ScrollNode::assignment("x", "psalms.missing"),
+            ScrollNode::call("sing", &["hymns.count"]),
+        ],
+        ..Default::default()
+    };
+    let imports = BTreeMap::from([("psalms".to_string(), psalms)]);
+    let registry = InstructionRegistry::new();
+    let symbols_only = ValidateOptions {
+        unreachable: false,
+        arity: false,
+        privilege: false,
+        scripture: false,
+        ..ValidateOptions::default()
+    };
+
+    let entries = tree.validate_with_imports(&registry, symbols_only, &imports);
+    let actuals: Vec<&str> = entries.iter().map(|e| e.actual.as_str()).collect();
+
+    assert_eq!(
+        actuals,
+        vec!["'psalms' has no member 'missing'", "'hymns' is not an imported scroll"]
+    );
+}
+
+#[test]
+fn test_validate_reads_only_identifier_paths() {
+    // 🧪 Input: a quoted file name stored, an annotated binding, and a
+    //    named call argument reading an unimported path
+    // 🧱 Expectation: the quoted text reads nothing; the annotated `let`
+    //    exports `x`; the named argument's path is still checked
+    let mut tree = parse_source("let @trusted x 1\nstore x \"notes.txt\"");
+    tree.nodes.push(ScrollNode::named_call("sing", &[], &[("verse", "hymns.count")]));
+    assert_eq!(tree.exports(), vec!["x"]);
+
+    let symbols_only = ValidateOptions {
+        unreachable: false,
+        arity: false,
+        privilege: false,
+        scripture: false,
+        ..ValidateOptions::default()
+    };
+    let entries = tree.validate(&InstructionRegistry::new(), symbols_only);
+    let actuals: Vec<&str> = entries.iter().map(|e| e.actual.as_str()).collect();
+
+    assert_eq!(actuals, vec!["'hymns' is not an imported scroll"]);
+}
+
 // ==============================================
 // 📋 Test Log Summary — Parser Output Review
 // ==============================================
@@ -317,14 +1995,82 @@ fn test_sentence_validation() {
 fn test_log_summary() {
     println!("✅ test_instruction_with_args: PASSED");
     println!("✅ test_scroll_sentence_parsing: PASSED");
+    println!("✅ test_scroll_sentence_skips_whitespace_tokens: PASSED");
+    println!("✅ test_scroll_sentence_object_spans_expression: PASSED");
+    println!("✅ test_scroll_sentence_object_ends_at_terminator_or_line: PASSED");
+    println!("✅ test_walkers_skip_whitespace_before_and_between_args: PASSED");
+    println!("✅ test_leading_blank_lines_produce_no_error_nodes: PASSED");
+    println!("✅ test_stray_separator_inside_block_keeps_block_open: PASSED");
     println!("✅ test_assignment_parsing: PASSED");
+    println!("✅ test_sum_assignments_parse_whole: PASSED");
     println!("✅ test_function_call: PASSED");
+    println!("✅ test_named_call_arguments_keep_parameter_names: PASSED");
+    println!("✅ test_quoted_call_argument_is_not_named: PASSED");
     println!("✅ test_parse_block: PASSED");
     println!("✅ test_parse_loop: PASSED");
     println!("✅ test_parse_declaration: PASSED");
+    println!("✅ test_parse_declaration_untyped: PASSED");
+    println!("✅ test_parse_declaration_invalid_name: PASSED");
+    println!("✅ test_parse_function_definition: PASSED");
+    println!("✅ test_call_resolves_to_function_definition: PASSED");
     println!("✅ test_parse_comment: PASSED");
     println!("✅ test_parse_metadata: PASSED");
+    println!("✅ test_scroll_header_populates_meta: PASSED");
+    println!("✅ test_scroll_without_header_has_empty_meta: PASSED");
     println!("✅ test_sentence_validation: PASSED");
+    println!("✅ test_operand_schema_mismatch_message: PASSED");
+    println!("✅ test_tree_equality_identical_scrolls: PASSED");
+    println!("✅ test_tree_equality_token_built_scrolls: PASSED");
+    println!("✅ test_tree_inequality_differing_scrolls: PASSED");
+    println!("✅ test_constructed_tree_matches_parsed: PASSED");
+    println!("✅ test_tree_diff_reports_single_changed_node: PASSED");
+    println!("✅ test_tree_equality_streamed_parse: PASSED");
+    println!("✅ test_cloned_tree_equals_original: PASSED");
+    println!("✅ test_remaining_tokens_does_not_consume: PASSED");
+    println!("✅ test_peek_n_looks_ahead_without_advancing: PASSED");
+    println!("✅ test_let_with_type_annotation_parses_as_declaration: PASSED");
+    println!("✅ test_find_node_at_prefers_inner_statement: PASSED");
+    println!("✅ test_find_node_at_loop_header_and_outside: PASSED");
+    println!("✅ test_filtered_accessors_recurse_into_bodies: PASSED");
+    println!("✅ test_fold_counts_nested_calls: PASSED");
+    println!("✅ test_tree_bytes_round_trip: PASSED");
+    println!("✅ test_tree_bytes_version_mismatch_rejected: PASSED");
+    println!("✅ test_tree_bytes_nesting_capped: PASSED");
+    println!("✅ test_case_insensitive_keywords_parse_as_registered: PASSED");
+    println!("✅ test_case_sensitive_keywords_reject_other_casing: PASSED");
+    println!("✅ test_instruction_span_carries_registry_metadata: PASSED");
+    println!("✅ test_stone_round_trip_keeps_annotations: PASSED");
+    println!("✅ test_enriched_stone_annotates_operand_types: PASSED");
+    println!("✅ test_tuple_return_parses_and_round_trips: PASSED");
+    println!("✅ test_stone_quotes_and_escapes_string_literals: PASSED");
+    println!("✅ test_operand_overflow_is_flagged: PASSED");
+    println!("✅ test_operand_count_within_limit_is_clean: PASSED");
+    println!("✅ test_indented_if_matches_braced_if: PASSED");
+    println!("✅ test_indented_dedent_closes_nested_blocks: PASSED");
+    println!("✅ test_expect_mismatch_returns_positioned_error: PASSED");
+    println!("✅ test_walker_records_expect_error: PASSED");
+    println!("✅ test_error_column_counts_chars_after_emoji: PASSED");
+    println!("✅ test_keyword_conditional_parses_as_flat_nodes: PASSED");
+    println!("✅ test_then_does_not_take_operands_from_its_line: PASSED");
+    println!("✅ test_instruction_operands_end_at_line_break: PASSED");
+    println!("✅ test_validate_reports_undeclared_and_arity_together: PASSED");
+    println!("✅ test_validate_flags_unreachable_and_privilege: PASSED");
+    println!("✅ test_validate_flags_string_assigned_to_int: PASSED");
+    println!("✅ test_validate_flags_numeric_looking_string_assigned_to_int: PASSED");
+    println!("✅ test_validate_accepts_int_reassigned_to_int: PASSED");
+    println!("✅ test_validate_resolves_paths_into_imported_scrolls: PASSED");
+    println!("✅ test_validate_reads_only_identifier_paths: PASSED");
+    println!("✅ test_with_registry_decodes_against_given_registry: PASSED");
+    println!("✅ test_keyword_as_name_is_reserved: PASSED");
+    println!("✅ test_unreserved_keywords_may_name_variables: PASSED");
+    println!("✅ test_restore_rewinds_failed_speculation_exactly: PASSED");
+    println!("✅ test_resolved_checkpoints_stop_recording: PASSED");
+    println!("✅ test_brace_parses_as_map_or_block: PASSED");
+    println!("✅ test_directives_parse_and_round_trip: PASSED");
+    println!("✅ test_directive_with_bad_values_is_reported: PASSED");
+    println!("✅ test_assemble_lays_out_directive_bytes: PASSED");
+    println!("✅ test_assemble_rejects_zero_alignment_and_oversized_padding: PASSED");
+    println!("✅ test_assemble_encodes_jumps_in_both_modes: PASSED");
 
     // 🧭 Final confirmation log — used during scroll-phase testing
     //     Not a replacement for assertions, but a covenant of coverage.
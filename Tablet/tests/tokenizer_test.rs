@@ -15,10 +15,15 @@
 //   - Additional token validation hooks will be tested once enabled
 // ----------------------------------------------------------
 
-use tablet::tokenizer::{Tokenizer, Token, TokenType}; // 🧱 Tokenizer under test
+use tablet::tokenizer::{Tokenizer, Token, TokenType, TokenizeError}; // 🧱 Tokenizer under test
+use tablet::tokenizer::SourceNormalization; // 🧼 BOM / CRLF cleanup report
+use tablet::tokenizer::{lint_whitespace, HygieneIssue, WhitespaceLint}; // 🧹 Scroll hygiene checks
+use watchtower::debugger::Severity; // 🪛 Lint entry band
 use tablet::instruction_registry::get_instruction_registry; // 🧭 Instruction source
+use tablet::instruction_registry::{BitMode, Instruction, InstructionRegistry}; // 📚 Injected registry
 
 use std::collections::HashMap; // 📚 Used for registry construction
+use std::collections::HashSet; // 🧮 Token dedupe by position
 
 // ----------------------------------------------------------
 // 🧰 Instruction Registry Builder — Keyword/Opcode Setup
@@ -29,8 +34,8 @@ use std::collections::HashMap; // 📚 Used for registry construction
 //
 fn build_registry() -> HashMap<String, TokenType> {
     get_instruction_registry()
-        .iter()
-        .map(|(k, _)| (k.to_string(), TokenType::Instruction))
+        .keys()
+        .map(|k| (k.to_string(), TokenType::Instruction))
         .collect::<HashMap<String, TokenType>>()
 }
 
@@ -164,6 +169,362 @@ fn test_tokenize_grouping_and_number() {
     assert_eq!(tokens[3].token_type, TokenType::GroupMarker);
 }
 
+// ===============================================
+// 🌊 Tokenizer Test — Streaming Matches Eager
+// ===============================================
+//
+// 🧪 Input:
+//   A multi-line scroll with comments, literals, operators, and groups
+//
+// 🧱 Expectation:
+//   - `stream()` yields the same type/value/line/column sequence as `tokenize()`
+//   - Unknown symbols surface as `Err(TokenizeError)` at the same position
+//
+// ===============================================
+
+fn shape(tokens: &[Token]) -> Vec<(TokenType, String, usize, usize)> {
+    tokens
+        .iter()
+        .map(|t| (t.token_type.clone(), t.value.clone(), t.line, t.column))
+        .collect()
+}
+
+#[test]
+fn test_stream_matches_eager_tokenize() {
+    let source = "# scroll of light\nlet flame = \"holy fire\"\nwalk (42) + x\n@";
+
+    let eager = Tokenizer::new(source, build_registry()).tokenize().tokens;
+
+    let results: Vec<Result<Token, TokenizeError>> =
+        Tokenizer::new(source, build_registry()).stream().collect();
+    let errors: Vec<&TokenizeError> = results.iter().filter_map(|r| r.as_ref().err()).collect();
+
+    assert_eq!(errors.len(), 1, "Expected one unrecognized symbol");
+    assert_eq!(errors[0].value, "@");
+    assert_eq!(errors[0].line, 4);
+
+    let streamed: Vec<Token> = Tokenizer::new(source, build_registry())
+        .stream()
+        .recovering()
+        .collect();
+
+    assert_eq!(shape(&streamed), shape(&eager));
+}
+
+// ===============================================
+// 📚 Tokenizer Test — Keywords From Injected Registry
+// ===============================================
+//
+// 🧪 Input:
+//   `shine "forth"` with `shine` registered at runtime
+//
+// 🧱 Expectation:
+//   - `shine` is an Instruction once registered, an Identifier before
+//
+// ===============================================
+
+#[test]
+fn test_from_registry_recognizes_custom_instruction() {
+    let source = "shine \"forth\"";
+    let mut registry = InstructionRegistry::new();
+
+    let before = Tokenizer::from_registry(source, &registry).tokenize().tokens;
+    assert_eq!(before[0].token_type, TokenType::Identifier);
+
+    registry
+        .register(Instruction {
+            keyword: "shine",
+            verse_anchor: "Matt 5:16",
+            traditional: &["OUT"],
+            category: "IO",
+            description: "Let your light shine.",
+            opcode: 0xF0,
+            machine_code: "F0 VV",
+            bit_mode: BitMode::Both,
+            operand_count: Some(1),
+            operand_schema: None,
+            operand_alternatives: None,
+            flags_effects: None,
+            cycle_cost: None,
+            privilege_level: None,
+            instruction_group_id: None,
+            phase_level: None,
+        })
+        .expect("shine registers cleanly");
+
+    let after = Tokenizer::from_registry(source, &registry).tokenize().tokens;
+    assert_eq!(after[0].token_type, TokenType::Instruction);
+    assert_eq!(after[0].value, "shine");
+}
+
+// ===============================================
+// 🧹 Tokenizer Test — Whitespace Hygiene Lint
+// ===============================================
+//
+// 🧪 Input:
+//   A line ending in spaces; a scroll with three blank lines in a row
+//
+// 🧱 Expectation:
+//   - Each is flagged once, as an informational entry
+//   - Disabled checks report nothing
+//
+// ===============================================
+
+#[test]
+fn test_lint_flags_trailing_whitespace() {
+    let source = "speak \"grace\"  \nspeak \"peace\"";
+
+    let issues = lint_whitespace(source, &WhitespaceLint::default());
+    assert_eq!(issues, vec![HygieneIssue::TrailingWhitespace { line: 1 }]);
+    assert!(matches!(issues[0].to_entry().severity, Severity::Info));
+
+    let off = WhitespaceLint {
+        trailing: false,
+        ..WhitespaceLint::default()
+    };
+    assert!(lint_whitespace(source, &off).is_empty());
+}
+
+#[test]
+fn test_lint_flags_blank_line_runs() {
+    let source = "speak \"grace\"\n\n\n\nspeak \"peace\"\n\nspeak \"amen\"";
+
+    let issues = lint_whitespace(source, &WhitespaceLint::default());
+    assert_eq!(issues, vec![HygieneIssue::BlankRun { line: 2, count: 3 }]);
+
+    let off = WhitespaceLint {
+        blank_runs: false,
+        ..WhitespaceLint::default()
+    };
+    assert!(lint_whitespace(source, &off).is_empty());
+}
+
+// ===============================================
+// ⚖️ Tokenizer Test — Token Equality & Hashing
+// ===============================================
+//
+// 🧪 Input:
+//   speak "grace"
+//   speak "grace"
+//
+// 🧱 Expectation:
+//   - The two `speak` tokens differ (`==`) but match by `value_eq`
+//   - A set keeps all four tokens; deduping by value leaves two
+//
+// ===============================================
+
+#[test]
+fn test_value_equal_tokens_differ_by_position() {
+    let source = "speak \"grace\"\nspeak \"grace\"";
+    let mut tokenizer = Tokenizer::new(source, build_registry());
+
+    let tokens = tokenizer.tokenize().tokens;
+    assert_eq!(tokens.len(), 4);
+
+    let (first, second) = (&tokens[0], &tokens[2]);
+    assert_ne!(first, second, "Line numbers differ");
+    assert!(first.value_eq(second));
+    assert_eq!(first, &tokens[0].clone());
+    assert!(!first.value_eq(&tokens[1]), "`speak` is not `grace`");
+
+    let unique: HashSet<&Token> = tokens.iter().collect();
+    assert_eq!(unique.len(), 4);
+
+    let by_value: HashSet<(&TokenType, &str)> = tokens
+        .iter()
+        .map(|token| (&token.token_type, token.value.as_str()))
+        .collect();
+    assert_eq!(by_value.len(), 2);
+}
+
+// ===============================================
+// 🪪 Tokenizer Test — Backtick-Quoted Identifiers
+// ===============================================
+//
+// 🧪 Input:
+//   let `my var` = `let`
+//   `open
+//
+// 🧱 Expectation:
+//   - `my var` and `let` are each one Identifier, quotes dropped
+//   - A quote left open at the line's end is an Error token
+//
+// ===============================================
+
+#[test]
+fn test_backtick_identifier_is_one_token() {
+    let source = "let `my var` = `let`\n`open";
+    let mut tokenizer = Tokenizer::new(source, build_registry());
+
+    let tokens = tokenizer.tokenize().tokens;
+    let shapes: Vec<(TokenType, &str)> = tokens
+        .iter()
+        .map(|token| (token.token_type.clone(), token.value.as_str()))
+        .collect();
+
+    assert_eq!(
+        shapes,
+        vec![
+            (TokenType::Instruction, "let"),
+            (TokenType::Identifier, "my var"),
+            (TokenType::Operator, "="),
+            (TokenType::Identifier, "let"),
+            (TokenType::Error, "`open"),
+        ]
+    );
+}
+
+// ===============================================
+// 🧷 Tokenizer Test — Directive Words & Hex
+// ===============================================
+//
+// 🧪 Input:
+//   .byte 0x41, 0x42
+//   God is light.
+//
+// 🧱 Expectation:
+//   - `.byte` opening a line is one Keyword token; hex stays one Literal
+//   - A `.` that does not open its line is not a directive
+//
+// ===============================================
+
+#[test]
+fn test_directive_word_and_hex_literal() {
+    let source = ".byte 0x41, 0x42\nGod is light.";
+    let mut tokenizer = Tokenizer::new(source, build_registry());
+
+    let tokens = tokenizer.tokenize().tokens;
+    let shapes: Vec<(TokenType, &str)> = tokens
+        .iter()
+        .map(|token| (token.token_type.clone(), token.value.as_str()))
+        .collect();
+
+    assert_eq!(
+        shapes,
+        vec![
+            (TokenType::Keyword, ".byte"),
+            (TokenType::Literal, "0x41"),
+            (TokenType::Punctuation, ","),
+            (TokenType::Literal, "0x42"),
+            (TokenType::Identifier, "God"),
+            (TokenType::Identifier, "is"),
+            (TokenType::Identifier, "light"),
+            (TokenType::Error, "."),
+        ]
+    );
+}
+
+// ===============================================
+// ⏳ Tokenizer Test — Duration Literals
+// ===============================================
+//
+// 🧪 Input:
+//   wait 5s
+//   wait 200ms 3sec
+//
+// 🧱 Expectation:
+//   - A number and its time unit stay one Literal
+//   - Letters past a unit (`sec`) are not a unit; the word splits off
+//
+// ===============================================
+
+#[test]
+fn test_duration_literal_is_one_token() {
+    let source = "wait 5s\nwait 200ms 3sec";
+    let mut tokenizer = Tokenizer::new(source, build_registry());
+
+    let tokens = tokenizer.tokenize().tokens;
+    let shapes: Vec<(TokenType, &str)> = tokens
+        .iter()
+        .map(|token| (token.token_type.clone(), token.value.as_str()))
+        .collect();
+
+    assert_eq!(
+        shapes,
+        vec![
+            (TokenType::Instruction, "wait"),
+            (TokenType::Literal, "5s"),
+            (TokenType::Instruction, "wait"),
+            (TokenType::Literal, "200ms"),
+            (TokenType::Literal, "3"),
+            (TokenType::Identifier, "sec"),
+        ]
+    );
+}
+
+// ===============================================
+// 🏷 Tokenizer Test — Operand Annotations
+// ===============================================
+//
+// 🧪 Input:
+//   bless @trusted externalValue
+//   speak "a@b"
+//
+// 🧱 Expectation:
+//   - `@trusted` is one Keyword token, `@` included
+//   - An `@` inside a string stays part of the Literal
+//
+// ===============================================
+
+#[test]
+fn test_trust_annotation_is_one_keyword_token() {
+    let source = "bless @trusted externalValue\nspeak \"a@b\"";
+    let mut tokenizer = Tokenizer::new(source, build_registry());
+
+    let tokens = tokenizer.tokenize().tokens;
+    let shapes: Vec<(TokenType, &str)> = tokens
+        .iter()
+        .map(|token| (token.token_type.clone(), token.value.as_str()))
+        .collect();
+
+    assert_eq!(
+        shapes,
+        vec![
+            (TokenType::Instruction, "bless"),
+            (TokenType::Keyword, "@trusted"),
+            (TokenType::Identifier, "externalValue"),
+            (TokenType::Instruction, "speak"),
+            (TokenType::Literal, "a@b"),
+        ]
+    );
+}
+
+// ===============================================
+// 🪟 Tokenizer Test — BOM & CRLF Scrolls
+// ===============================================
+//
+// 🧪 Input:
+//   The same three-line scroll, once clean (LF) and once as Windows
+//   saves it: a UTF-8 BOM up front and CRLF line endings
+//
+// 🧱 Expectation:
+//   - Identical tokens — values, lines, and columns all match
+//   - The Windows copy reports one stripped BOM and two CRLF endings
+//
+// ===============================================
+
+#[test]
+fn test_bom_and_crlf_tokenize_like_clean_lf() {
+    let clean = "let faith = 1\nspeak \"truth\"\nbless faith";
+    let windows = format!("\u{feff}{}", clean.replace('\n', "\r\n"));
+
+    let expected = Tokenizer::new(clean, build_registry()).tokenize();
+    let cleaned = Tokenizer::new(&windows, build_registry()).tokenize();
+
+    assert_eq!(cleaned.tokens, expected.tokens);
+    assert_eq!(cleaned.tokens[0].value, "let");
+    assert_eq!(cleaned.tokens.last().unwrap().line, 3);
+
+    assert!(expected.normalization.is_clean());
+    assert_eq!(
+        cleaned.normalization,
+        SourceNormalization {
+            bom_stripped: true,
+            crlf_lines: 2,
+        }
+    );
+}
+
 // ==============================================
 // 📋 Test Log Summary — Tokenizer Output Review
 // ==============================================
@@ -182,11 +543,49 @@ fn test_tokenize_grouping_and_number() {
 //
 // ==============================================
 
+// ===============================================
+// 🔤 Tokenizer Test — Quoted Literals
+// ===============================================
+//
+// 🧪 Input:
+//   `x = "42"` and `y = 42`
+//
+// 🧱 Expectation:
+//   - Both values are Literal tokens reading `42`
+//   - Only the quoted one is flagged `quoted`, so it stays text
+//
+// ===============================================
+
+#[test]
+fn test_quoted_literal_is_flagged() {
+    let mut tokenizer = Tokenizer::new("x = \"42\"\ny = 42", build_registry());
+
+    let tokens = tokenizer.tokenize().tokens;
+    let literals: Vec<(&str, bool)> = tokens
+        .iter()
+        .filter(|token| token.token_type == TokenType::Literal)
+        .map(|token| (token.value.as_str(), token.quoted))
+        .collect();
+
+    assert_eq!(literals, vec![("42", true), ("42", false)]);
+}
+
 #[test]
 fn test_log_tokenizer_summary() {
     println!("✅ test_tokenize_simple_assignment: PASSED");
     println!("✅ test_tokenize_comment_and_metadata: PASSED");
     println!("✅ test_tokenize_grouping_and_number: PASSED");
+    println!("✅ test_stream_matches_eager_tokenize: PASSED");
+    println!("✅ test_from_registry_recognizes_custom_instruction: PASSED");
+    println!("✅ test_lint_flags_trailing_whitespace: PASSED");
+    println!("✅ test_lint_flags_blank_line_runs: PASSED");
+    println!("✅ test_value_equal_tokens_differ_by_position: PASSED");
+    println!("✅ test_backtick_identifier_is_one_token: PASSED");
+    println!("✅ test_directive_word_and_hex_literal: PASSED");
+    println!("✅ test_duration_literal_is_one_token: PASSED");
+    println!("✅ test_trust_annotation_is_one_keyword_token: PASSED");
+    println!("✅ test_bom_and_crlf_tokenize_like_clean_lf: PASSED");
+    println!("✅ test_quoted_literal_is_flagged: PASSED");
 
     // 🧭 This log confirms the scroll-tokenizer behaves as expected
     //      Output is for traceability during development phases
@@ -0,0 +1,159 @@
+// ===============================================
+// 📜 Metadata — Watchtower Alignment Score v0.0.1
+// ===============================================
+// _author_:        Seanje Lenox-Wise / Nova Dawn
+// _version_:       0.0.1
+// _status_:        Dev
+// _created_:       2025-06-03
+// _last updated_:  2025-06-03
+// _license_:       CreativeWorkzStudio LLC — Kingdom-First Proprietary Use
+// _component_:     Aggregate Alignment Scoring
+// _project_:       OmniCode / Millennium OS
+// _description_:   Folds many scored DebugEntries into a single 0–100 alignment figure
+//
+// _notes_:
+// - Used by compile policies to gate on overall scroll health
+// - An empty report is fully aligned (nothing was found wanting)
+// - `divergence_report` mirrors `.stone` against `.logos` line by line
+// ===============================================
+
+// ===============================================
+// 🌀 Opening — Imports & Declarations
+// ===============================================
+
+use crate::debugger::{DebugEntry, Severity};
+
+// ===============================================
+// 🔧 Body — Aggregate Scoring
+// ===============================================
+
+/// 🌡 Mean alignment score across all entries (100 when empty).
+pub fn alignment(entries: &[DebugEntry]) -> u8 {
+    if entries.is_empty() {
+        return 100;
+    }
+
+    let total: usize = entries.iter().map(|entry| entry.score as usize).sum();
+    (total / entries.len()) as u8
+}
+
+/// 🚨 Lowest (most severe) band present, if any entries exist.
+pub fn worst_severity(entries: &[DebugEntry]) -> Option<Severity> {
+    entries
+        .iter()
+        .map(|entry| entry.severity)
+        .min_by_key(|severity| severity.ceiling())
+}
+
+// ===============================================
+// 🪞 Body — `.stone` / `.logos` Divergence
+// ===============================================
+
+/// 🪞 Flags for `divergence_report_opts`: what counts as the same line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DivergenceOptions {
+    pub trim_whitespace: bool, // ✂️ Compare lines with outer whitespace stripped
+    pub ignore_case: bool,     // 🔡 Compare lines case-insensitively
+}
+
+impl Default for DivergenceOptions {
+    /// 🪞 Indentation is ignored; wording and case must match.
+    fn default() -> Self {
+        Self {
+            trim_whitespace: true,
+            ignore_case: false,
+        }
+    }
+}
+
+/// 🧭 One line where the intermediate and theological representations part ways.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    pub line: usize,           // 📍 Line number in both sources (1-based)
+    pub stone: Option<String>, // 🪨 `.stone` text (None once `.stone` has ended)
+    pub logos: Option<String>, // 📖 `.logos` text (None once `.logos` has ended)
+}
+
+/// 📋 Line-by-line comparison of a `.stone` and a `.logos` rendering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DivergenceReport {
+    pub compared: usize,              // 🔢 Lines examined (the longer side's count)
+    pub divergences: Vec<Divergence>, // 🧭 Mismatched lines, in order
+    pub score: u8,                    // 📉 Share of lines that diverge, 0–100
+}
+
+impl DivergenceReport {
+    /// 🌡 The complement of `score`: 100 when the two mirror each other.
+    pub fn alignment(&self) -> u8 {
+        100 - self.score
+    }
+}
+
+/// 🪞 Diffs corresponding lines of `stone` and `logos` with default options.
+pub fn divergence_report(stone: &str, logos: &str) -> DivergenceReport {
+    divergence_report_opts(stone, logos, DivergenceOptions::default())
+}
+
+/// 🪞 Diffs corresponding lines of `stone` and `logos`.
+///
+/// Lines pair by number; a line present on only one side diverges. The
+/// score rounds up, so a single diverging line never scores zero.
+pub fn divergence_report_opts(
+    stone: &str,
+    logos: &str,
+    opts: DivergenceOptions,
+) -> DivergenceReport {
+    let normalize = |line: &str| {
+        let line = if opts.trim_whitespace {
+            line.trim()
+        } else {
+            line
+        };
+        if opts.ignore_case {
+            line.to_lowercase()
+        } else {
+            line.to_string()
+        }
+    };
+
+    let stone_lines: Vec<&str> = stone.lines().collect();
+    let logos_lines: Vec<&str> = logos.lines().collect();
+    let compared = stone_lines.len().max(logos_lines.len());
+
+    let divergences: Vec<Divergence> = (0..compared)
+        .filter_map(|index| {
+            let left = stone_lines.get(index).copied();
+            let right = logos_lines.get(index).copied();
+            let same = match (left, right) {
+                (Some(left), Some(right)) => normalize(left) == normalize(right),
+                _ => false,
+            };
+            (!same).then(|| Divergence {
+                line: index + 1,
+                stone: left.map(str::to_string),
+                logos: right.map(str::to_string),
+            })
+        })
+        .collect();
+
+    let score = match compared {
+        0 => 0,
+        _ => (divergences.len() * 100).div_ceil(compared) as u8,
+    };
+
+    DivergenceReport {
+        compared,
+        divergences,
+        score,
+    }
+}
+
+// ===================================================
+// 🔚 Closing — Alignment Score Notes
+// ===================================================
+//
+// 🪧 Future:
+//     • Weighted scoring by command or location
+//     • Trend tracking across successive scroll passes
+//
+// ---------------------------------------------------
@@ -33,6 +33,15 @@ use chrono::Utc;
 use std::fs::OpenOptions;
 use std::io::{self, Write};
 
+// std::sync::atomic:
+// Hands out monotonic creation sequence numbers
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+// std::sync locks + std::collections::HashMap:
+// Serialize JSON log writers per path
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
 // std::path::Path:
 // Used to validate and resolve log paths
 // use std::path::Path;
@@ -54,7 +63,7 @@ use serde_json;
 // ===============================================
 
 /// 🎯 `Severity` captures diagnostic health in 10-point intervals.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Severity {
     Fatal,       // 0–9   🛑 Collapse / irreparable failure
     Critical,    // 10–19 🔥 Emergency systemic failure
@@ -68,6 +77,83 @@ pub enum Severity {
     Pass,        // 90–100 ✅ Full alignment
 }
 
+impl Severity {
+    /// 🌡 Highest alignment score that still falls within this band
+    pub fn ceiling(&self) -> u8 {
+        match self {
+            Severity::Fatal => 9,
+            Severity::Critical => 19,
+            Severity::Error => 29,
+            Severity::Fault => 39,
+            Severity::Weakness => 49,
+            Severity::Instability => 59,
+            Severity::Degraded => 69,
+            Severity::Drift => 79,
+            Severity::Info => 89,
+            Severity::Pass => 100,
+        }
+    }
+
+    /// 🎨 ANSI escape used when this band is rendered in color
+    pub fn ansi_color(&self) -> &'static str {
+        match self {
+            Severity::Fatal | Severity::Critical | Severity::Error => "\x1b[31m", // 🔴 Red
+            Severity::Fault | Severity::Weakness => "\x1b[33m",                   // 🟡 Yellow
+            Severity::Instability | Severity::Degraded => "\x1b[35m",             // 🟣 Magenta
+            Severity::Drift => "\x1b[36m",                                        // 🔵 Cyan
+            Severity::Info => "\x1b[34m",                                         // 🔷 Blue
+            Severity::Pass => "\x1b[32m",                                         // 🟢 Green
+        }
+    }
+}
+
+// ===============================================
+// 🔊 Verbosity — Runtime Trace Filtering
+// ===============================================
+
+/// 🔊 `Verbosity` decides which severities reach the console at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verbosity {
+    Off,      // 🔇 Emit nothing
+    Errors,   // ❌ Fatal, Critical, and Error bands only
+    Warnings, // ⚠️ Everything below Info
+    Trace,    // 🪵 Every entry, including Info and Pass
+}
+
+impl Verbosity {
+    /// 🚦 Whether an entry of the given severity should be emitted at this level
+    pub fn admits(&self, severity: Severity) -> bool {
+        match self {
+            Verbosity::Off => false,
+            Verbosity::Errors => severity.ceiling() <= Severity::Error.ceiling(),
+            Verbosity::Warnings => severity.ceiling() <= Severity::Drift.ceiling(),
+            Verbosity::Trace => true,
+        }
+    }
+
+    fn from_u8(level: u8) -> Self {
+        match level {
+            0 => Verbosity::Off,
+            1 => Verbosity::Errors,
+            2 => Verbosity::Warnings,
+            _ => Verbosity::Trace,
+        }
+    }
+}
+
+/// 🎚 Process-wide trace level — defaults to `Trace` so existing output is unchanged
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Trace as u8);
+
+/// 🎚 Set the runtime trace level used by [`DebugEntry::emit`]
+pub fn set_verbosity(level: Verbosity) {
+    VERBOSITY.store(level as u8, Ordering::Relaxed);
+}
+
+/// 🔎 Current runtime trace level
+pub fn verbosity() -> Verbosity {
+    Verbosity::from_u8(VERBOSITY.load(Ordering::Relaxed))
+}
+
 // ===============================================
 // 🧪 DebugResponse — What To Do With This Finding
 // ===============================================
@@ -85,6 +171,22 @@ pub enum DebugResponse {
 // 📋 DebugEntry — Scored Snapshot of System State
 // ===============================================
 
+/// 🔢 Next creation sequence number — shared by every entry in the process
+static NEXT_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// 🧾 Next temp-file suffix — keeps concurrent `write_json` calls apart
+static NEXT_TEMP: AtomicU64 = AtomicU64::new(0);
+
+/// 🔒 One lock per JSON log path, so read-splice-rename runs one writer at a time
+fn json_log_lock(path: &str) -> Arc<Mutex<()>> {
+    static LOCKS: OnceLock<Mutex<HashMap<String, Arc<Mutex<()>>>>> = OnceLock::new();
+    let mut locks = LOCKS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    Arc::clone(locks.entry(path.to_string()).or_default())
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DebugEntry {
     pub command: String,             // 🔑 Operation under test
@@ -97,7 +199,9 @@ pub struct DebugEntry {
     pub response: DebugResponse,     // 📨 What to do next
     pub score: u8,                   // 🌡 0–100 alignment
     pub severity: Severity,          // 🚨 Diagnostic band
-    pub timestamp: String,           // 🕰 UTC time
+    pub timestamp: String,           // 🕰 UTC creation time
+    #[serde(default)]
+    pub sequence: u64,               // 🔢 Monotonic creation order within this process
 }
 
 impl DebugEntry {
@@ -128,6 +232,7 @@ impl DebugEntry {
 
         let severity = Self::resolve_severity(score);
         let timestamp = Utc::now().to_rfc3339();
+        let sequence = NEXT_SEQUENCE.fetch_add(1, Ordering::Relaxed);
 
         DebugEntry {
             command: command.to_string(),
@@ -141,9 +246,18 @@ impl DebugEntry {
             score,
             severity,
             timestamp,
+            sequence,
         }
     }
 
+    /// 🔢 Sorts a trace into creation order.
+    ///
+    /// Timestamps can tie within a burst (or step backwards with the
+    /// wall clock); the sequence number never does.
+    pub fn sort_trace(entries: &mut [DebugEntry]) {
+        entries.sort_by_key(|entry| entry.sequence);
+    }
+
     /// 🧭 Classify score range into severity
     fn resolve_severity(score: u8) -> Severity {
         match score {
@@ -167,23 +281,33 @@ impl DebugEntry {
         self
     }
 
-    /// ➕ Add a suggestion to this entry
     // pub fn add_suggestion(mut self, note: &str) -> Self {
     //    self.suggestions.push(note.to_string());
     //    self
     // }
 
+    /// ➕ Add a suggestion to this entry
     pub fn with_suggestion(mut self, note: &str) -> Self {
         self.suggestions.push(note.to_string());
         self
     }
 
+    /// ➕ Override the score-derived severity band
+    /// Score is clamped to the band's ceiling so both stay consistent.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        if Self::resolve_severity(self.score) != severity {
+            self.score = severity.ceiling();
+        }
+        self.severity = severity;
+        self
+    }
+
     /// 📜 Format as scroll
     pub fn to_scroll(&self) -> String {
         let mut block = format!(
             "\
 ==============================================
-📜 Watchtower Scroll — {}
+📜 Watchtower Scroll — {} (#{})
 ==============================================
 🔑 Command:     {}
 📤 Input:       {}
@@ -192,6 +316,7 @@ impl DebugEntry {
 🌡 Score:       {}/100
 🚨 Severity:    {:?}",
             self.timestamp,
+            self.sequence,
             self.command,
             self.input,
             self.expected,
@@ -219,17 +344,54 @@ impl DebugEntry {
         block
     }
 
-    /// 🧾 Write JSON format to disk
+    /// 🧾 Append this entry to the JSON array stored at `path`
+    ///
+    /// The file always holds one valid JSON array. The new entry is spliced in
+    /// ahead of the closing `]` without re-parsing earlier entries, and the
+    /// result is written to a sibling temp file that is renamed over the log,
+    /// so readers never see a half-written array. A legacy log of concatenated
+    /// objects (the old append format) is migrated into an array on the first
+    /// write; anything else is left untouched and reported as `InvalidData`.
+    /// Writers in this process are serialized per `path`, so concurrent appends
+    /// are all kept.
     pub fn write_json(&self, path: &str) -> io::Result<()> {
+        let lock = json_log_lock(path);
+        let _guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+
         // 🌱 Ensure parent directories exist
         if let Some(parent) = std::path::Path::new(path).parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        let serialized = serde_json::to_string_pretty(&self)?;
-        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
-        writeln!(file, "{}", serialized)?;
-        Ok(())
+        // 📖 Read back earlier entries — a missing or empty file starts a new array
+        let existing = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let array = match existing.trim() {
+            "" => String::from("[]"),
+            text if text.starts_with('[') && text.ends_with(']') => text.to_string(),
+            text => migrate_legacy_json(text)?,
+        };
+
+        // ✂️ Splice the entry in ahead of the closing bracket
+        let entry = serde_json::to_string_pretty(self)?.replace('\n', "\n  ");
+        let head = array[..array.len() - 1].trim_end();
+        let separator = if head == "[" { "" } else { "," };
+        let serialized = format!("{}{}\n  {}\n]\n", head, separator, entry);
+
+        // 🔁 Write beside the log, then rename over it in one step
+        let temp = format!(
+            "{}.{}.{}.tmp",
+            path,
+            std::process::id(),
+            NEXT_TEMP.fetch_add(1, Ordering::Relaxed)
+        );
+        std::fs::write(&temp, serialized)?;
+        std::fs::rename(&temp, path).inspect_err(|_| {
+            let _ = std::fs::remove_file(&temp);
+        })
     }
 
     /// 🪶 Write plain-text scroll to disk
@@ -243,6 +405,102 @@ impl DebugEntry {
         writeln!(file, "{}", self.to_scroll())?;
         Ok(())
     }
+
+    /// 📚 Write both scroll and JSON forms, surfacing the first IO failure
+    pub fn write_logs(&self, scroll_path: &str, json_path: &str) -> io::Result<()> {
+        self.write_scroll(scroll_path)?;
+        self.write_json(json_path)
+    }
+
+    /// 🪵 Print this entry if the current verbosity admits its severity — returns whether it was shown
+    pub fn emit(&self) -> bool {
+        let shown = verbosity().admits(self.severity);
+        if shown {
+            println!("{self:#?}");
+        }
+        shown
+    }
+}
+
+// ===============================================
+// 🎨 Diagnostics Rendering — Colored Console Output
+// ===============================================
+
+/// 🧹 ANSI reset sequence closing every colored span
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// 🚦 Whether color should actually be used — a non-empty `NO_COLOR` always
+/// wins; set but empty, it is ignored (per no-color.org)
+pub fn color_enabled(use_color: bool) -> bool {
+    use_color && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+/// 🖋 Render diagnostics into any writer, one block per entry.
+///
+/// The severity tag is wrapped in its band's ANSI color only when
+/// `use_color` is set; no `NO_COLOR` check happens here, so callers
+/// (and tests) get exactly the mode they ask for.
+pub fn write_diagnostics<W: Write>(
+    out: &mut W,
+    diags: &[DebugEntry],
+    use_color: bool,
+) -> io::Result<()> {
+    for entry in diags {
+        let tag = format!("{:?}", entry.severity).to_lowercase();
+        if use_color {
+            write!(out, "{}{}{}", entry.severity.ansi_color(), tag, ANSI_RESET)?;
+        } else {
+            write!(out, "{}", tag)?;
+        }
+        writeln!(out, " [{}/100]: {}", entry.score, entry.command)?;
+
+        if let Some(ref l) = entry.location {
+            writeln!(out, "  --> {}", l)?;
+        }
+
+        match entry.discrepancy {
+            Some(ref d) => writeln!(out, "  = {}", d)?,
+            None => writeln!(
+                out,
+                "  = expected '{}', got '{}'",
+                entry.expected, entry.actual
+            )?,
+        }
+
+        for s in &entry.suggestions {
+            writeln!(out, "  help: {}", s)?;
+        }
+    }
+    Ok(())
+}
+
+/// 🖨 Print diagnostics to stderr, honoring `NO_COLOR` on top of `use_color`
+pub fn print_diagnostics(diags: &[DebugEntry], use_color: bool) {
+    let stderr = io::stderr();
+    let mut handle = stderr.lock();
+    // ⚠️ A closed stderr has nowhere left to report to — drop the failure
+    let _ = write_diagnostics(&mut handle, diags, color_enabled(use_color));
+}
+
+/// 🧳 Convert a legacy JSON log (one pretty object per `write_json` call,
+/// concatenated) into a single JSON array
+///
+/// Text that is not a run of JSON objects is reported as `InvalidData`.
+fn migrate_legacy_json(text: &str) -> io::Result<String> {
+    let invalid = |e: serde_json::Error| io::Error::new(io::ErrorKind::InvalidData, e);
+    let entries = serde_json::Deserializer::from_str(text)
+        .into_iter::<serde_json::Value>()
+        .map(|value| {
+            value.map_err(invalid).and_then(|value| match value {
+                serde_json::Value::Object(_) => Ok(value),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "legacy JSON log holds a non-object value",
+                )),
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    Ok(serde_json::to_string_pretty(&entries)?)
 }
 
 // ===================================================
@@ -254,11 +512,13 @@ impl DebugEntry {
 //    - `write_json()` for structured machine parsing
 //
 // ✅ Diagnostic metadata includes alignment, severity,
-//    discrepancy, suggestions, and UTC timestamp.
+//    discrepancy, suggestions, UTC timestamp, and a
+//    creation sequence number for deterministic ordering.
 //
-// ⚠️ Current implementation uses `append` mode:
-//    - Multiple logs may be written in one session
-//    - No deduplication or overwrite guards exist yet
+// ⚠️ Write modes differ:
+//    - `write_scroll()` appends, so one session may add many blocks
+//    - `write_json()` rewrites the array through a temp file + rename
+//    - No deduplication guards exist yet
 //
 // ---------------------------------------------------
 // 🧾 Change Policy Notice:
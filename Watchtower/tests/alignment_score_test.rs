@@ -0,0 +1,104 @@
+// ==========================================================
+// 🧪 Alignment Score Test Suite — `.stone` / `.logos` Divergence
+// ==========================================================
+//
+// 🎯 Purpose:
+//   - Tests `watchtower::alignment_score::divergence_report` line mirroring
+//   - Verifies diverging pairs score above zero and name the lines that differ
+//
+// 📦 Imports:
+//   - Divergence report types under test
+// ----------------------------------------------------------
+
+use watchtower::alignment_score::{divergence_report, divergence_report_opts}; // 🪞 Reports under test
+use watchtower::alignment_score::{Divergence, DivergenceOptions}; // 🧭 Findings and flags
+
+// ==============================================
+// 🪞 Divergence Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Mirrored renderings are fully aligned
+//   - Changed or missing lines diverge and raise the score
+// ==============================================
+
+#[test]
+fn test_mirrored_pair_has_no_divergence() {
+    // 🧪 Input: the same two lines, `.logos` indented differently
+    // 🧱 Expectation: score 0, alignment 100
+    let report = divergence_report(
+        "let faith = 1\nspeak \"truth\"",
+        "  let faith = 1\nspeak \"truth\"  ",
+    );
+
+    assert_eq!(report.compared, 2);
+    assert!(report.divergences.is_empty());
+    assert_eq!((report.score, report.alignment()), (0, 100));
+}
+
+#[test]
+fn test_diverging_pair_scores_nonzero() {
+    // 🧪 Input: `.logos` rewords line 2 and drops line 3
+    // 🧱 Expectation: two of three lines diverge, score rounds up to 67
+    let stone = "let faith = 1\nspeak \"truth\"\nbless faith";
+    let logos = "let faith = 1\nspeak \"mercy\"";
+    let report = divergence_report(stone, logos);
+
+    assert_eq!(report.compared, 3);
+    assert_eq!(
+        report.divergences,
+        vec![
+            Divergence {
+                line: 2,
+                stone: Some("speak \"truth\"".to_string()),
+                logos: Some("speak \"mercy\"".to_string()),
+            },
+            Divergence {
+                line: 3,
+                stone: Some("bless faith".to_string()),
+                logos: None,
+            },
+        ]
+    );
+    assert_eq!(report.score, 67);
+    assert_eq!(report.alignment(), 33);
+}
+
+#[test]
+fn test_divergence_options_control_line_matching() {
+    // 🧪 Input: lines differing only in case and indentation
+    // 🧱 Expectation: default options flag the case change; ignore_case clears it,
+    //    and turning trimming off flags the indentation instead
+    let stone = "Speak \"truth\"";
+    let logos = "  speak \"truth\"";
+
+    assert_eq!(divergence_report(stone, logos).score, 100);
+
+    let ignore_case = DivergenceOptions {
+        ignore_case: true,
+        ..DivergenceOptions::default()
+    };
+    assert_eq!(divergence_report_opts(stone, logos, ignore_case).score, 0);
+
+    let exact = DivergenceOptions {
+        trim_whitespace: false,
+        ignore_case: true,
+    };
+    assert_eq!(divergence_report_opts(stone, logos, exact).score, 100);
+}
+
+// ===========================================================
+// 📋 Test Log Summary — Alignment Score Verification
+// ===========================================================
+//
+// 🛠 Usage:
+//   - Run with `cargo test -- --nocapture` to view this output
+//
+// ===========================================================
+
+#[test]
+fn test_log_alignment_score_summary() {
+    println!("✅ test_mirrored_pair_has_no_divergence: PASSED");
+    println!("✅ test_diverging_pair_scores_nonzero: PASSED");
+    println!("✅ test_divergence_options_control_line_matching: PASSED");
+}
